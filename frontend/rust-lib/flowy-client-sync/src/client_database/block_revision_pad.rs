@@ -187,6 +187,16 @@ impl DatabaseBlockRevisionPad {
         is_changed = Some(());
       }
 
+      if let Some(last_modified) = changeset.last_modified {
+        row.last_modified = last_modified;
+        is_changed = Some(());
+      }
+
+      if let Some(document_id) = changeset.document_id {
+        row.document_id = Some(document_id);
+        is_changed = Some(());
+      }
+
       if !changeset.cell_by_field_id.is_empty() {
         is_changed = Some(());
         changeset
@@ -334,6 +344,10 @@ mod tests {
       cells: Default::default(),
       height: 0,
       visibility: false,
+      created_at: 0,
+      last_modified: 0,
+      auto_increment_id: 0,
+      document_id: None,
     };
 
     let change = pad.add_row_rev(row.clone(), None).unwrap().unwrap();
@@ -384,6 +398,10 @@ mod tests {
       cells: Default::default(),
       height: 0,
       visibility: false,
+      created_at: 0,
+      last_modified: 0,
+      auto_increment_id: 0,
+      document_id: None,
     }
   }
 
@@ -435,6 +453,10 @@ mod tests {
       cells: Default::default(),
       height: 0,
       visibility: false,
+      created_at: 0,
+      last_modified: 0,
+      auto_increment_id: 0,
+      document_id: None,
     };
 
     let _ = pad.add_row_rev(row.clone(), None).unwrap().unwrap();
@@ -459,12 +481,18 @@ mod tests {
       cells: Default::default(),
       height: 0,
       visibility: false,
+      created_at: 0,
+      last_modified: 0,
+      auto_increment_id: 0,
+      document_id: None,
     };
 
     let changeset = RowChangeset {
       row_id: row.id.clone(),
       height: Some(100),
       visibility: Some(true),
+      last_modified: None,
+      document_id: None,
       cell_by_field_id: Default::default(),
     };
 
@@ -42,6 +42,11 @@ impl DatabaseBuilder {
     block_meta_rev.row_count += 1;
   }
 
+  /// Returns the auto increment id of the next row that is added to the database.
+  pub fn next_auto_increment_id(&self) -> i64 {
+    self.build_context.blocks.first().unwrap().rows.len() as i64 + 1
+  }
+
   pub fn add_empty_row(&mut self) {
     let row = RowRevision::new(self.block_id());
     self.add_row(row);
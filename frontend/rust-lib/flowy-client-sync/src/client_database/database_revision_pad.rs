@@ -450,6 +450,30 @@ impl DatabaseRevisionPad {
   pub fn json_str(&self) -> SyncResult<String> {
     make_database_rev_json_str(&self.database_rev)
   }
+
+  /// Replaces the content of the database with the content of the other database, for example,
+  /// the database at one of its checkpoints. The blocks are kept as they are because the rows
+  /// are saved in the blocks, which have their own revisions.
+  pub fn restore_database(
+    &mut self,
+    other: DatabaseRevisionPad,
+  ) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+    let mut database_rev = other.database_rev.as_ref().clone();
+    database_rev.blocks = self.database_rev.blocks.clone();
+    let old = self.json_str()?;
+    let new = make_database_rev_json_str(&database_rev)?;
+    match cal_diff::<EmptyAttributes>(old, new) {
+      None => Ok(None),
+      Some(operations) => {
+        self.operations = self.operations.compose(&operations)?;
+        self.database_rev = Arc::new(database_rev);
+        Ok(Some(DatabaseRevisionChangeset {
+          operations,
+          md5: self.database_md5(),
+        }))
+      },
+    }
+  }
 }
 
 pub fn make_database_rev_json_str(grid_revision: &DatabaseRevision) -> SyncResult<String> {
@@ -1,8 +1,8 @@
 use crate::errors::{internal_sync_error, SyncError, SyncResult};
 use crate::util::cal_diff;
 use database_model::{
-  DatabaseViewRevision, FieldRevision, FieldTypeRevision, FilterRevision,
-  GroupConfigurationRevision, LayoutRevision, SortRevision,
+  CalculationRevision, DatabaseViewRevision, FieldRevision, FieldTypeRevision, FilterGroupRevision,
+  FilterOperatorRevision, FilterRevision, GroupConfigurationRevision, LayoutRevision, SortRevision,
 };
 use flowy_sync::util::make_operations_from_revisions;
 use lib_infra::util::md5;
@@ -92,6 +92,47 @@ impl DatabaseViewRevisionPad {
     })
   }
 
+  pub fn get_sub_groups_by_field_revs(
+    &self,
+    field_revs: &[Arc<FieldRevision>],
+  ) -> Vec<Arc<GroupConfigurationRevision>> {
+    self.sub_groups.get_objects_by_field_revs(field_revs)
+  }
+
+  pub fn get_all_sub_groups(&self) -> Vec<Arc<GroupConfigurationRevision>> {
+    self.sub_groups.get_all_objects()
+  }
+
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub fn insert_or_update_sub_group_configuration(
+    &mut self,
+    field_id: &str,
+    field_type: &FieldTypeRevision,
+    group_configuration_rev: GroupConfigurationRevision,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      // Only save one sub group
+      view.sub_groups.clear();
+      view
+        .sub_groups
+        .add_object(field_id, field_type, group_configuration_rev);
+      Ok(Some(()))
+    })
+  }
+
+  pub fn delete_sub_group_configuration(
+    &mut self,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      if view.sub_groups.get_all_objects().is_empty() {
+        Ok(None)
+      } else {
+        view.sub_groups.clear();
+        Ok(Some(()))
+      }
+    })
+  }
+
   #[tracing::instrument(level = "trace", skip_all)]
   pub fn contains_group(&self, field_id: &str, field_type: &FieldTypeRevision) -> bool {
     self.view.groups.get_objects(field_id, field_type).is_some()
@@ -141,7 +182,7 @@ impl DatabaseViewRevisionPad {
     self.sorts.get_all_objects()
   }
 
-  /// For the moment, a field type only have one filter.
+  /// Returns all the filters of the field with `field_type_rev`.
   pub fn get_sorts(
     &self,
     field_id: &str,
@@ -221,6 +262,63 @@ impl DatabaseViewRevisionPad {
     })
   }
 
+  pub fn get_all_calculations(
+    &self,
+    field_revs: &[Arc<FieldRevision>],
+  ) -> Vec<Arc<CalculationRevision>> {
+    self.calculations.get_objects_by_field_revs(field_revs)
+  }
+
+  /// For the moment, a field only has one calculation.
+  pub fn get_calculation(
+    &self,
+    field_id: &str,
+    field_type_rev: &FieldTypeRevision,
+  ) -> Option<Arc<CalculationRevision>> {
+    self
+      .calculations
+      .get_objects(field_id, field_type_rev)
+      .and_then(|mut calculations| calculations.pop())
+  }
+
+  /// Replaces the calculation of the field with the passed-in calculation.
+  pub fn insert_or_update_calculation(
+    &mut self,
+    field_id: &str,
+    calculation_rev: CalculationRevision,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      let field_type = calculation_rev.field_type;
+      match view.calculations.get_mut_objects(field_id, &field_type) {
+        None => view
+          .calculations
+          .add_object(field_id, &field_type, calculation_rev),
+        Some(calculations) => {
+          calculations.clear();
+          calculations.push(Arc::new(calculation_rev));
+        },
+      }
+      Ok(Some(()))
+    })
+  }
+
+  pub fn delete_calculation<T: Into<FieldTypeRevision>>(
+    &mut self,
+    field_id: &str,
+    field_type: T,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    let field_type = field_type.into();
+    self.modify(
+      |view| match view.calculations.get_mut_objects(field_id, &field_type) {
+        Some(calculations) if !calculations.is_empty() => {
+          calculations.clear();
+          Ok(Some(()))
+        },
+        _ => Ok(None),
+      },
+    )
+  }
+
   pub fn get_all_filters(&self, field_revs: &[Arc<FieldRevision>]) -> Vec<Arc<FilterRevision>> {
     self.filters.get_objects_by_field_revs(field_revs)
   }
@@ -248,22 +346,30 @@ impl DatabaseViewRevisionPad {
       .get_object(field_id, field_type_rev, |filter| filter.id == filter_id)
   }
 
+  /// Inserts the filter into the filter group with `group_id`. The filter will be inserted into
+  /// the root group if the `group_id` is None or the group is not exist.
   pub fn insert_filter(
     &mut self,
     field_id: &str,
     filter_rev: FilterRevision,
+    group_id: Option<&str>,
   ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
     self.modify(|view| {
       let field_type = filter_rev.field_type;
+      let filter_id = filter_rev.id.clone();
       view.filters.add_object(field_id, &field_type, filter_rev);
+      add_filter_to_group(&mut view.filters.root, &filter_id, group_id);
       Ok(Some(()))
     })
   }
 
+  /// Updates the filter. The filter will be moved to the filter group with `group_id` if the
+  /// `group_id` is not None.
   pub fn update_filter(
     &mut self,
     field_id: &str,
     filter_rev: FilterRevision,
+    group_id: Option<&str>,
   ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
     self.modify(|view| {
       if let Some(filter) =
@@ -276,6 +382,10 @@ impl DatabaseViewRevisionPad {
         let filter = Arc::make_mut(filter);
         filter.condition = filter_rev.condition;
         filter.content = filter_rev.content;
+        if group_id.is_some() {
+          view.filters.root.remove_filter(&filter_rev.id);
+          add_filter_to_group(&mut view.filters.root, &filter_rev.id, group_id);
+        }
         Ok(Some(()))
       } else {
         Ok(None)
@@ -291,6 +401,7 @@ impl DatabaseViewRevisionPad {
   ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
     let field_type = field_type.into();
     self.modify(|view| {
+      view.filters.root.remove_filter(filter_id);
       if let Some(filters) = view.filters.get_mut_objects(field_id, &field_type) {
         filters.retain(|filter| filter.id != filter_id);
         Ok(Some(()))
@@ -300,6 +411,81 @@ impl DatabaseViewRevisionPad {
     })
   }
 
+  /// Returns the root of the filter groups.
+  pub fn get_filter_group(&self) -> FilterGroupRevision {
+    self.filters.root.clone()
+  }
+
+  /// Returns the filters that are belong to the filter group with `group_id`, including the
+  /// filters of its descendant groups.
+  pub fn get_filters_in_group(&self, group_id: &str) -> Vec<Arc<FilterRevision>> {
+    let filter_ids = match self.filters.root.find_group(group_id) {
+      None => return vec![],
+      Some(group) => group.filter_ids(),
+    };
+    self
+      .filters
+      .get_all_objects()
+      .into_iter()
+      .filter(|filter| filter_ids.contains(&filter.id))
+      .collect()
+  }
+
+  /// Inserts the filter group into the group with `parent_group_id`. The filter group will be
+  /// inserted into the root group if the `parent_group_id` is None or the group is not exist.
+  pub fn insert_filter_group(
+    &mut self,
+    group_rev: FilterGroupRevision,
+    parent_group_id: Option<&str>,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      let root = &mut view.filters.root;
+      match parent_group_id.and_then(|group_id| root.find_group_mut(group_id)) {
+        None => root.add_group(group_rev),
+        Some(parent) => parent.add_group(group_rev),
+      }
+      Ok(Some(()))
+    })
+  }
+
+  pub fn update_filter_group(
+    &mut self,
+    group_id: &str,
+    operator: FilterOperatorRevision,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| match view.filters.root.find_group_mut(group_id) {
+      None => Ok(None),
+      Some(group) => {
+        group.operator = operator;
+        Ok(Some(()))
+      },
+    })
+  }
+
+  /// Deletes the filter group and the filters that are belong to it.
+  pub fn delete_filter_group(
+    &mut self,
+    group_id: &str,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| match view.filters.root.remove_group(group_id) {
+      None => Ok(None),
+      Some(group) => {
+        let filter_ids = group.filter_ids();
+        for filter_rev in view.filters.get_all_objects() {
+          if filter_ids.contains(&filter_rev.id) {
+            if let Some(filters) = view
+              .filters
+              .get_mut_objects(&filter_rev.field_id, &filter_rev.field_type)
+            {
+              filters.retain(|filter| filter.id != filter_rev.id);
+            }
+          }
+        }
+        Ok(Some(()))
+      },
+    })
+  }
+
   /// Returns the settings for the given layout. If it's not exists then will return the
   /// default settings for the given layout.
   /// Each [database view](https://appflowy.gitbook.io/docs/essential-documentation/contribute-to-appflowy/architecture/frontend/database-view) has its own settings.
@@ -331,10 +517,27 @@ impl DatabaseViewRevisionPad {
     make_database_view_rev_json_str(&self.view)
   }
 
+  pub fn operations_json_str(&self) -> String {
+    self.operations.json_str()
+  }
+
   pub fn layout(&self) -> LayoutRevision {
     self.layout.clone()
   }
 
+  /// Replaces the settings of the view, its groups, filters, sorts and calculations, with the
+  /// settings of the other view, for example, the view at one of its checkpoints.
+  pub fn restore_view(
+    &mut self,
+    other: DatabaseViewRevisionPad,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    let view = other.view.as_ref().clone();
+    self.modify(|this| {
+      *this = view;
+      Ok(Some(()))
+    })
+  }
+
   fn modify<F>(&mut self, f: F) -> SyncResult<Option<DatabaseViewRevisionChangeset>>
   where
     F: FnOnce(&mut DatabaseViewRevision) -> SyncResult<Option<()>>,
@@ -358,6 +561,13 @@ impl DatabaseViewRevisionPad {
   }
 }
 
+fn add_filter_to_group(root: &mut FilterGroupRevision, filter_id: &str, group_id: Option<&str>) {
+  match group_id.and_then(|group_id| root.find_group_mut(group_id)) {
+    None => root.add_filter(filter_id),
+    Some(group) => group.add_filter(filter_id),
+  }
+}
+
 #[derive(Debug)]
 pub struct DatabaseViewRevisionChangeset {
   pub operations: DatabaseViewOperations,
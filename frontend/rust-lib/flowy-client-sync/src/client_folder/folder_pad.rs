@@ -4,7 +4,9 @@ use crate::{
   client_folder::builder::FolderPadBuilder,
   errors::{SyncError, SyncResult},
 };
-use folder_model::{AppRevision, FolderRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+use folder_model::{
+  AppRevision, FavoriteRevision, FolderRevision, TrashRevision, ViewRevision, WorkspaceRevision,
+};
 use lib_infra::util::md5;
 use lib_infra::util::move_vec_element;
 use lib_ot::core::*;
@@ -26,6 +28,7 @@ impl FolderPad {
     let folder_rev = FolderRevision {
       workspaces: workspaces.into_iter().map(Arc::new).collect(),
       trash: trash.into_iter().map(Arc::new).collect(),
+      favorites: vec![],
     };
     Self::from_folder_rev(folder_rev)
   }
@@ -370,6 +373,79 @@ impl FolderPad {
     }
   }
 
+  /// Appends the view to the end of the favorites. Does nothing if the view is already one of the
+  /// favorites.
+  pub fn create_favorite(
+    &mut self,
+    favorite: FavoriteRevision,
+  ) -> SyncResult<Option<FolderChangeset>> {
+    self.with_favorites(|favorites| {
+      if favorites.iter().any(|item| item.id == favorite.id) {
+        return Ok(None);
+      }
+      favorites.push(Arc::new(favorite));
+      Ok(Some(()))
+    })
+  }
+
+  pub fn read_favorites(&self) -> SyncResult<Vec<FavoriteRevision>> {
+    Ok(
+      self
+        .folder_rev
+        .favorites
+        .iter()
+        .map(|favorite| favorite.as_ref().clone())
+        .collect(),
+    )
+  }
+
+  pub fn delete_favorites(&mut self, view_ids: Vec<String>) -> SyncResult<Option<FolderChangeset>> {
+    self.with_favorites(|favorites| {
+      let len = favorites.len();
+      favorites.retain(|favorite| !view_ids.contains(&favorite.id));
+      if favorites.len() == len {
+        Ok(None)
+      } else {
+        Ok(Some(()))
+      }
+    })
+  }
+
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub fn move_favorite(
+    &mut self,
+    view_id: &str,
+    from: usize,
+    to: usize,
+  ) -> SyncResult<Option<FolderChangeset>> {
+    self.with_favorites(|favorites| {
+      match move_vec_element(favorites, |favorite| favorite.id == view_id, from, to)
+        .map_err(internal_sync_error)?
+      {
+        true => Ok(Some(())),
+        false => Ok(None),
+      }
+    })
+  }
+
+  /// Replaces the content of the folder with the content of the other folder, for example, the
+  /// folder at one of its checkpoints.
+  pub fn restore_folder(&mut self, other: FolderPad) -> SyncResult<Option<FolderChangeset>> {
+    let old = self.to_json()?;
+    let new = other.to_json()?;
+    match cal_diff::<EmptyAttributes>(old, new) {
+      None => Ok(None),
+      Some(operations) => {
+        self.operations = self.operations.compose(&operations)?;
+        self.folder_rev = other.folder_rev;
+        Ok(Some(FolderChangeset {
+          operations,
+          md5: self.folder_md5(),
+        }))
+      },
+    }
+  }
+
   pub fn folder_md5(&self) -> String {
     md5(&self.operations.json_bytes())
   }
@@ -455,6 +531,30 @@ impl FolderPad {
     }
   }
 
+  fn with_favorites<F>(&mut self, f: F) -> SyncResult<Option<FolderChangeset>>
+  where
+    F: FnOnce(&mut Vec<Arc<FavoriteRevision>>) -> SyncResult<Option<()>>,
+  {
+    let cloned_self = self.clone();
+    match f(&mut self.folder_rev.favorites)? {
+      None => Ok(None),
+      Some(_) => {
+        let old = cloned_self.to_json()?;
+        let new = self.to_json()?;
+        match cal_diff::<EmptyAttributes>(old, new) {
+          None => Ok(None),
+          Some(operations) => {
+            self.operations = self.operations.compose(&operations)?;
+            Ok(Some(FolderChangeset {
+              operations,
+              md5: self.folder_md5(),
+            }))
+          },
+        }
+      },
+    }
+  }
+
   fn with_app<F>(&mut self, app_id: &str, f: F) -> SyncResult<Option<FolderChangeset>>
   where
     F: FnOnce(&mut AppRevision) -> SyncResult<Option<()>>,
@@ -527,7 +627,9 @@ mod tests {
   use crate::client_folder::folder_pad::FolderPad;
   use crate::client_folder::{FolderOperations, FolderOperationsBuilder};
   use chrono::Utc;
-  use folder_model::{AppRevision, FolderRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+  use folder_model::{
+    AppRevision, FavoriteRevision, FolderRevision, TrashRevision, ViewRevision, WorkspaceRevision,
+  };
   use lib_ot::core::OperationTransform;
   use serde::Deserialize;
 
@@ -597,6 +699,28 @@ mod tests {
     );
   }
 
+  #[test]
+  fn folder_restore_test() {
+    let (mut folder, initial_operations, workspace) = test_folder();
+    let checkpoint = folder.clone();
+    let operations_1 = folder
+      .update_workspace(&workspace.id, Some("☺️ rename workspace️".to_string()), None)
+      .unwrap()
+      .unwrap()
+      .operations;
+
+    let operations_2 = folder
+      .restore_folder(checkpoint.clone())
+      .unwrap()
+      .unwrap()
+      .operations;
+    assert_eq!(folder.to_json().unwrap(), checkpoint.to_json().unwrap());
+
+    let folder_from_operations =
+      make_folder_from_operations(initial_operations, vec![operations_1, operations_2]);
+    assert_eq!(folder, folder_from_operations);
+  }
+
   #[test]
   fn folder_add_app() {
     let (folder, initial_operations, _app) = test_app_folder();
@@ -875,6 +999,82 @@ mod tests {
     );
   }
 
+  #[test]
+  fn folder_add_favorite() {
+    let (folder, initial_operations, _favorite) = test_favorite();
+    assert_folder_equal(
+      &folder,
+      &make_folder_from_operations(initial_operations, vec![]),
+      r#"{
+                    "workspaces": [],
+                    "trash": [],
+                    "favorites": [
+                        {
+                            "id": "1",
+                            "create_time": 0
+                        }
+                    ]
+                }
+            "#,
+    );
+  }
+
+  #[test]
+  fn folder_add_duplicate_favorite() {
+    let (mut folder, _initial_operations, favorite) = test_favorite();
+    assert!(folder.create_favorite(favorite).unwrap().is_none());
+    assert_eq!(folder.read_favorites().unwrap().len(), 1);
+  }
+
+  #[test]
+  fn folder_move_favorite() {
+    let (mut folder, initial_operations, favorite) = test_favorite();
+    let mut second_favorite = FavoriteRevision::default();
+    second_favorite.id = "2".to_owned();
+    let operations_1 = folder
+      .create_favorite(second_favorite.clone())
+      .unwrap()
+      .unwrap()
+      .operations;
+    let operations_2 = folder
+      .move_favorite(&second_favorite.id, 1, 0)
+      .unwrap()
+      .unwrap()
+      .operations;
+    assert_eq!(
+      folder
+        .read_favorites()
+        .unwrap()
+        .into_iter()
+        .map(|favorite| favorite.id)
+        .collect::<Vec<String>>(),
+      vec![second_favorite.id, favorite.id]
+    );
+    assert_eq!(
+      folder,
+      make_folder_from_operations(initial_operations, vec![operations_1, operations_2])
+    );
+  }
+
+  #[test]
+  fn folder_delete_favorite() {
+    let (mut folder, initial_operations, favorite) = test_favorite();
+    let operations = folder
+      .delete_favorites(vec![favorite.id])
+      .unwrap()
+      .unwrap()
+      .operations;
+    assert_folder_equal(
+      &folder,
+      &make_folder_from_operations(initial_operations, vec![operations]),
+      r#"{
+                    "workspaces": [],
+                    "trash": []
+                }
+            "#,
+    );
+  }
+
   fn test_folder() -> (FolderPad, FolderOperations, WorkspaceRevision) {
     let folder_rev = FolderRevision::default();
     let folder_json = serde_json::to_string(&folder_rev).unwrap();
@@ -959,6 +1159,27 @@ mod tests {
     (folder, operations, trash_rev)
   }
 
+  fn test_favorite() -> (FolderPad, FolderOperations, FavoriteRevision) {
+    let folder_rev = FolderRevision::default();
+    let folder_json = serde_json::to_string(&folder_rev).unwrap();
+    let mut operations = FolderOperationsBuilder::new().insert(&folder_json).build();
+
+    let mut favorite_rev = FavoriteRevision::default();
+    favorite_rev.id = "1".to_owned();
+    let mut folder = FolderPad::from_folder_rev(folder_rev).unwrap();
+    operations = operations
+      .compose(
+        &folder
+          .create_favorite(favorite_rev.clone())
+          .unwrap()
+          .unwrap()
+          .operations,
+      )
+      .unwrap();
+
+    (folder, operations, favorite_rev)
+  }
+
   fn make_folder_from_operations(
     mut initial_operation: FolderOperations,
     operations: Vec<FolderOperations>,
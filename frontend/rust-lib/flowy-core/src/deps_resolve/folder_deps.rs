@@ -5,7 +5,11 @@ use database_model::BuildDatabaseContext;
 use flowy_client_ws::FlowyWebSocketConnect;
 use flowy_database::entities::LayoutTypePB;
 use flowy_database::manager::{create_new_database, link_existing_database, DatabaseManager};
-use flowy_database::util::{make_default_board, make_default_calendar, make_default_grid};
+use flowy_database::services::share::DatabaseArchive;
+use flowy_database::util::{
+  make_default_board, make_default_calendar, make_default_gallery, make_default_grid,
+  make_default_timeline,
+};
 use flowy_document::editor::make_transaction_from_document_content;
 use flowy_document::DocumentManager;
 
@@ -18,7 +22,7 @@ use flowy_folder::{
 };
 use flowy_net::ClientServerConfiguration;
 use flowy_net::{http_server::folder::FolderHttpCloudService, local_server::LocalServer};
-use flowy_revision::{RevisionWebSocket, WSStateReceiver};
+use flowy_revision::{RevisionCheckpoint, RevisionWebSocket, WSStateReceiver};
 use flowy_user::services::UserSession;
 use futures_core::future::BoxFuture;
 use lib_infra::future::{BoxResultFuture, FutureResult};
@@ -123,6 +127,14 @@ impl WorkspaceUser for WorkspaceUserImpl {
       .token()
       .map_err(|e| FlowyError::internal().context(e))
   }
+
+  fn get_settings(&self) -> Result<Option<String>, FlowyError> {
+    Ok(self.0.get_settings())
+  }
+
+  fn set_settings(&self, settings: String) -> Result<(), FlowyError> {
+    self.0.set_settings(settings)
+  }
 }
 
 struct FolderRevisionWebSocket(Arc<FlowyWebSocketConnect>);
@@ -176,6 +188,15 @@ impl ViewDataProcessor for DocumentViewDataProcessor {
     })
   }
 
+  fn delete_view(&self, view_id: &str) -> FutureResult<(), FlowyError> {
+    let manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      manager.delete_document(view_id).await?;
+      Ok(())
+    })
+  }
+
   fn get_view_data(&self, view: &ViewPB) -> FutureResult<Bytes, FlowyError> {
     let view_id = view.id.clone();
     let manager = self.0.clone();
@@ -215,9 +236,23 @@ impl ViewDataProcessor for DocumentViewDataProcessor {
     _name: &str,
     data: Vec<u8>,
     layout: ViewLayoutTypePB,
-    _ext: HashMap<String, String>,
+    ext: HashMap<String, String>,
   ) -> FutureResult<(), FlowyError> {
     debug_assert_eq!(layout, ViewLayoutTypePB::Document);
+    if let Some(DocumentImportType::Markdown) =
+      DocumentExtParams::from_map(ext).map(|params| params.import_type)
+    {
+      let view_id = view_id.to_string();
+      let manager = self.0.clone();
+      return FutureResult::new(async move {
+        let markdown = String::from_utf8(data).map_err(internal_error)?;
+        manager
+          .create_document_from_markdown(view_id, &markdown)
+          .await?;
+        Ok(())
+      });
+    }
+
     let view_data = match String::from_utf8(data) {
       Ok(content) => match make_transaction_from_document_content(&content) {
         Ok(transaction) => transaction.to_bytes().unwrap_or_else(|_| vec![]),
@@ -239,6 +274,76 @@ impl ViewDataProcessor for DocumentViewDataProcessor {
   fn data_types(&self) -> Vec<ViewDataFormatPB> {
     vec![ViewDataFormatPB::DeltaFormat, ViewDataFormatPB::NodeFormat]
   }
+
+  fn get_view_checkpoints(
+    &self,
+    view_id: &str,
+  ) -> FutureResult<Vec<RevisionCheckpoint>, FlowyError> {
+    let manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      let editor = manager.open_document_editor(view_id).await?;
+      editor.checkpoints().await
+    })
+  }
+
+  fn read_view_checkpoint(&self, view_id: &str, rev_id: i64) -> FutureResult<String, FlowyError> {
+    let manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      let editor = manager.open_document_editor(view_id).await?;
+      editor.read_checkpoint(rev_id).await
+    })
+  }
+
+  fn restore_view_checkpoint(&self, view_id: &str, rev_id: i64) -> FutureResult<(), FlowyError> {
+    let manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      let editor = manager.open_document_editor(view_id).await?;
+      editor.restore_checkpoint(rev_id).await
+    })
+  }
+
+  /// The documents are exported as a JSON object that maps the ids of the views to the contents
+  /// of the documents.
+  fn export_views(&self, view_ids: Vec<String>) -> FutureResult<String, FlowyError> {
+    let manager = self.0.clone();
+    FutureResult::new(async move {
+      let mut documents = HashMap::new();
+      for view_id in view_ids {
+        let editor = manager.open_document_editor(&view_id).await?;
+        let document_content = editor.duplicate().await?;
+        documents.insert(view_id, document_content);
+      }
+      serde_json::to_string(&documents).map_err(internal_error)
+    })
+  }
+
+  fn import_views(
+    &self,
+    content: String,
+    view_id_map: HashMap<String, String>,
+  ) -> FutureResult<(), FlowyError> {
+    let manager = self.0.clone();
+    FutureResult::new(async move {
+      let documents: HashMap<String, String> =
+        serde_json::from_str(&content).map_err(internal_error)?;
+      for (view_id, document_content) in documents {
+        let view_id = match view_id_map.get(&view_id) {
+          None => continue,
+          Some(view_id) => view_id.clone(),
+        };
+        let document_data = match make_transaction_from_document_content(&document_content) {
+          Ok(transaction) => transaction.to_bytes().unwrap_or_else(|_| vec![]),
+          Err(_) => vec![],
+        };
+        let revision = Revision::initial_revision(&view_id, Bytes::from(document_data));
+        manager.create_document(view_id, vec![revision]).await?;
+      }
+      Ok(())
+    })
+  }
 }
 
 struct DatabaseViewDataProcessor(Arc<DatabaseManager>);
@@ -252,6 +357,15 @@ impl ViewDataProcessor for DatabaseViewDataProcessor {
     })
   }
 
+  fn delete_view(&self, view_id: &str) -> FutureResult<(), FlowyError> {
+    let database_manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      database_manager.delete_database_view(view_id).await?;
+      Ok(())
+    })
+  }
+
   fn get_view_data(&self, view: &ViewPB) -> FutureResult<Bytes, FlowyError> {
     let database_manager = self.0.clone();
     let view_id = view.id.clone();
@@ -285,6 +399,8 @@ impl ViewDataProcessor for DatabaseViewDataProcessor {
           ViewLayoutTypePB::Grid => (make_default_grid(), LayoutTypePB::Grid),
           ViewLayoutTypePB::Board => (make_default_board(), LayoutTypePB::Board),
           ViewLayoutTypePB::Calendar => (make_default_calendar(), LayoutTypePB::Calendar),
+          ViewLayoutTypePB::Timeline => (make_default_timeline(), LayoutTypePB::Timeline),
+          ViewLayoutTypePB::Gallery => (make_default_gallery(), LayoutTypePB::Gallery),
           ViewLayoutTypePB::Document => {
             return FutureResult::new(async move {
               Err(FlowyError::internal().context(format!("Can't handle {:?} layout type", layout)))
@@ -337,6 +453,60 @@ impl ViewDataProcessor for DatabaseViewDataProcessor {
   fn data_types(&self) -> Vec<ViewDataFormatPB> {
     vec![ViewDataFormatPB::DatabaseFormat]
   }
+
+  fn get_view_checkpoints(
+    &self,
+    view_id: &str,
+  ) -> FutureResult<Vec<RevisionCheckpoint>, FlowyError> {
+    let database_manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      let editor = database_manager.open_database_view(&view_id).await?;
+      editor.get_checkpoints().await
+    })
+  }
+
+  fn read_view_checkpoint(&self, view_id: &str, rev_id: i64) -> FutureResult<String, FlowyError> {
+    let database_manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      let editor = database_manager.open_database_view(&view_id).await?;
+      editor.read_checkpoint(rev_id).await
+    })
+  }
+
+  fn restore_view_checkpoint(&self, view_id: &str, rev_id: i64) -> FutureResult<(), FlowyError> {
+    let database_manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      let editor = database_manager.open_database_view(&view_id).await?;
+      editor.restore_checkpoint(rev_id).await
+    })
+  }
+
+  /// The databases are exported as a JSON [DatabaseArchive]. The database shared by several
+  /// views is only exported once.
+  fn export_views(&self, view_ids: Vec<String>) -> FutureResult<String, FlowyError> {
+    let database_manager = self.0.clone();
+    FutureResult::new(async move {
+      let archive = database_manager.export_databases(view_ids).await?;
+      serde_json::to_string(&archive).map_err(internal_error)
+    })
+  }
+
+  fn import_views(
+    &self,
+    content: String,
+    view_id_map: HashMap<String, String>,
+  ) -> FutureResult<(), FlowyError> {
+    let database_manager = self.0.clone();
+    FutureResult::new(async move {
+      let archive: DatabaseArchive = serde_json::from_str(&content).map_err(internal_error)?;
+      database_manager
+        .import_databases(archive, &view_id_map)
+        .await
+    })
+  }
 }
 
 pub fn layout_type_from_view_layout(layout: ViewLayoutTypePB) -> LayoutTypePB {
@@ -344,10 +514,30 @@ pub fn layout_type_from_view_layout(layout: ViewLayoutTypePB) -> LayoutTypePB {
     ViewLayoutTypePB::Grid => LayoutTypePB::Grid,
     ViewLayoutTypePB::Board => LayoutTypePB::Board,
     ViewLayoutTypePB::Calendar => LayoutTypePB::Calendar,
+    ViewLayoutTypePB::Timeline => LayoutTypePB::Timeline,
+    ViewLayoutTypePB::Gallery => LayoutTypePB::Gallery,
     ViewLayoutTypePB::Document => LayoutTypePB::Grid,
   }
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct DocumentExtParams {
+  import_type: DocumentImportType,
+}
+
+#[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum DocumentImportType {
+  Markdown,
+}
+
+impl DocumentExtParams {
+  pub fn from_map(map: HashMap<String, String>) -> Option<Self> {
+    let value = serde_json::to_value(map).ok()?;
+    serde_json::from_value::<Self>(value).ok()
+  }
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct DatabaseExtParams {
   database_id: String,
@@ -1,15 +1,20 @@
 use crate::FlowyError;
 use bytes::Bytes;
 use flowy_client_ws::FlowyWebSocketConnect;
-use flowy_database::manager::{DatabaseManager, DatabaseUser};
+use flowy_database::manager::{DatabaseManager, DatabaseRowDocument, DatabaseUser};
 use flowy_database::services::persistence::DatabaseDBConnection;
+use flowy_document::editor::{
+  first_image_src_from_document_content, make_transaction_from_document_content,
+};
+use flowy_document::DocumentManager;
 use flowy_revision::{RevisionWebSocket, WSStateReceiver};
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
 use flowy_user::services::UserSession;
 use futures_core::future::BoxFuture;
-use lib_infra::future::BoxResultFuture;
+use lib_infra::future::{BoxResultFuture, FutureResult};
 use lib_ws::{WSChannel, WebSocketRawMessage};
+use revision_model::Revision;
 use std::convert::TryInto;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -22,6 +27,7 @@ impl DatabaseDepsResolver {
     ws_conn: Arc<FlowyWebSocketConnect>,
     user_session: Arc<UserSession>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
+    document_manager: &Arc<DocumentManager>,
   ) -> Arc<DatabaseManager> {
     let user = Arc::new(GridUserImpl(user_session.clone()));
     let rev_web_socket = Arc::new(GridRevisionWebSocket(ws_conn));
@@ -30,6 +36,7 @@ impl DatabaseDepsResolver {
       rev_web_socket,
       task_scheduler,
       Arc::new(DatabaseDBConnectionImpl(user_session)),
+      Arc::new(DatabaseRowDocumentImpl(document_manager.clone())),
     ))
   }
 }
@@ -44,6 +51,84 @@ impl DatabaseDBConnection for DatabaseDBConnectionImpl {
   }
 }
 
+struct DatabaseRowDocumentImpl(Arc<DocumentManager>);
+impl DatabaseRowDocument for DatabaseRowDocumentImpl {
+  fn create_document(&self, document_id: &str) -> FutureResult<(), FlowyError> {
+    let manager = self.0.clone();
+    let document_id = document_id.to_string();
+    let document_content = self.0.initial_document_content();
+    FutureResult::new(async move {
+      let revision = Revision::initial_revision(&document_id, Bytes::from(document_content));
+      manager.create_document(document_id, vec![revision]).await?;
+      Ok(())
+    })
+  }
+
+  fn duplicate_document(
+    &self,
+    from_document_id: &str,
+    to_document_id: &str,
+  ) -> FutureResult<(), FlowyError> {
+    let manager = self.0.clone();
+    let from_document_id = from_document_id.to_string();
+    let to_document_id = to_document_id.to_string();
+    FutureResult::new(async move {
+      let editor = manager.open_document_editor(&from_document_id).await?;
+      let document_content = editor.duplicate().await?;
+      let document_data = make_transaction_from_document_content(&document_content)?.to_bytes()?;
+      let revision = Revision::initial_revision(&to_document_id, Bytes::from(document_data));
+      manager
+        .create_document(to_document_id, vec![revision])
+        .await?;
+      Ok(())
+    })
+  }
+
+  fn delete_document(&self, document_id: &str) -> FutureResult<(), FlowyError> {
+    let manager = self.0.clone();
+    let document_id = document_id.to_string();
+    FutureResult::new(async move {
+      manager.delete_document(document_id).await?;
+      Ok(())
+    })
+  }
+
+  fn document_rev_id(&self, document_id: &str) -> FutureResult<i64, FlowyError> {
+    let manager = self.0.clone();
+    let document_id = document_id.to_string();
+    FutureResult::new(async move { manager.read_document_rev_id(&document_id) })
+  }
+
+  fn first_image_src(&self, document_id: &str) -> FutureResult<Option<String>, FlowyError> {
+    let manager = self.0.clone();
+    let document_id = document_id.to_string();
+    FutureResult::new(async move {
+      let document_content = manager.read_document_content(&document_id)?;
+      first_image_src_from_document_content(&document_content)
+    })
+  }
+
+  fn export_document(&self, document_id: &str) -> FutureResult<String, FlowyError> {
+    let manager = self.0.clone();
+    let document_id = document_id.to_string();
+    FutureResult::new(async move {
+      let editor = manager.open_document_editor(&document_id).await?;
+      editor.duplicate().await
+    })
+  }
+
+  fn import_document(&self, document_id: &str, content: String) -> FutureResult<(), FlowyError> {
+    let manager = self.0.clone();
+    let document_id = document_id.to_string();
+    FutureResult::new(async move {
+      let document_data = make_transaction_from_document_content(&content)?.to_bytes()?;
+      let revision = Revision::initial_revision(&document_id, Bytes::from(document_data));
+      manager.create_document(document_id, vec![revision]).await?;
+      Ok(())
+    })
+  }
+}
+
 struct GridUserImpl(Arc<UserSession>);
 impl DatabaseUser for GridUserImpl {
   fn user_id(&self) -> Result<String, FlowyError> {
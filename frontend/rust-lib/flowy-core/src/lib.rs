@@ -159,6 +159,7 @@ impl AppFlowyCore {
           ws_conn.clone(),
           user_session.clone(),
           task_dispatcher.clone(),
+          &document_manager,
         )
         .await;
 
@@ -324,13 +325,15 @@ impl UserStatusListener {
             .into_iter()
             .flat_map(|app| app.belongings.items)
             .flat_map(|view| match view.layout {
-              ViewLayoutTypePB::Grid | ViewLayoutTypePB::Board | ViewLayoutTypePB::Calendar => {
-                Some((
-                  view.id,
-                  view.name,
-                  layout_type_from_view_layout(view.layout),
-                ))
-              },
+              ViewLayoutTypePB::Grid
+              | ViewLayoutTypePB::Board
+              | ViewLayoutTypePB::Calendar
+              | ViewLayoutTypePB::Timeline
+              | ViewLayoutTypePB::Gallery => Some((
+                view.id,
+                view.name,
+                layout_type_from_view_layout(view.layout),
+              )),
               _ => None,
             })
             .collect::<Vec<(String, String, LayoutTypePB)>>()
@@ -0,0 +1,232 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::FieldType;
+use crate::services::calculation::{is_calculation_supported, CalculationValue};
+use database_model::{CalculationRevision, CalculationTypeRevision, FieldTypeRevision};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+use std::sync::Arc;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct CalculationPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub field_type: FieldType,
+
+  #[pb(index = 4)]
+  pub calculation_type: CalculationTypePB,
+}
+
+impl std::convert::From<&CalculationRevision> for CalculationPB {
+  fn from(calculation_rev: &CalculationRevision) -> Self {
+    Self {
+      id: calculation_rev.id.clone(),
+      field_id: calculation_rev.field_id.clone(),
+      field_type: calculation_rev.field_type.into(),
+      calculation_type: calculation_rev.calculation_type.clone().into(),
+    }
+  }
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct RepeatedCalculationPB {
+  #[pb(index = 1)]
+  pub items: Vec<CalculationPB>,
+}
+
+impl std::convert::From<Vec<Arc<CalculationRevision>>> for RepeatedCalculationPB {
+  fn from(revs: Vec<Arc<CalculationRevision>>) -> Self {
+    RepeatedCalculationPB {
+      items: revs.into_iter().map(|rev| rev.as_ref().into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum CalculationTypePB {
+  Count = 0,
+  CountEmpty = 1,
+  CountUnique = 2,
+  Sum = 3,
+  Average = 4,
+  Median = 5,
+  Min = 6,
+  Max = 7,
+  PercentChecked = 8,
+  EarliestDate = 9,
+  LatestDate = 10,
+}
+
+impl std::default::Default for CalculationTypePB {
+  fn default() -> Self {
+    Self::Count
+  }
+}
+
+impl std::convert::From<CalculationTypeRevision> for CalculationTypePB {
+  fn from(calculation_type: CalculationTypeRevision) -> Self {
+    match calculation_type {
+      CalculationTypeRevision::Count => CalculationTypePB::Count,
+      CalculationTypeRevision::CountEmpty => CalculationTypePB::CountEmpty,
+      CalculationTypeRevision::CountUnique => CalculationTypePB::CountUnique,
+      CalculationTypeRevision::Sum => CalculationTypePB::Sum,
+      CalculationTypeRevision::Average => CalculationTypePB::Average,
+      CalculationTypeRevision::Median => CalculationTypePB::Median,
+      CalculationTypeRevision::Min => CalculationTypePB::Min,
+      CalculationTypeRevision::Max => CalculationTypePB::Max,
+      CalculationTypeRevision::PercentChecked => CalculationTypePB::PercentChecked,
+      CalculationTypeRevision::EarliestDate => CalculationTypePB::EarliestDate,
+      CalculationTypeRevision::LatestDate => CalculationTypePB::LatestDate,
+    }
+  }
+}
+
+impl std::convert::From<CalculationTypePB> for CalculationTypeRevision {
+  fn from(calculation_type: CalculationTypePB) -> Self {
+    (calculation_type as u8).into()
+  }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UpdateCalculationPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub field_type: FieldType,
+
+  #[pb(index = 4)]
+  pub calculation_type: CalculationTypePB,
+}
+
+impl TryInto<UpdateCalculationParams> for UpdateCalculationPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateCalculationParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+
+    let field_id = NotEmptyStr::parse(self.field_id)
+      .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+      .0;
+
+    let calculation_type: CalculationTypeRevision = self.calculation_type.into();
+    if !is_calculation_supported(&calculation_type, &self.field_type) {
+      return Err(ErrorCode::InvalidData);
+    }
+
+    Ok(UpdateCalculationParams {
+      view_id,
+      field_id,
+      field_type: self.field_type.into(),
+      calculation_type,
+    })
+  }
+}
+
+#[derive(Debug)]
+pub struct UpdateCalculationParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub field_type: FieldTypeRevision,
+  pub calculation_type: CalculationTypeRevision,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct DeleteCalculationPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub field_type: FieldType,
+}
+
+impl TryInto<DeleteCalculationParams> for DeleteCalculationPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<DeleteCalculationParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+    let field_id = NotEmptyStr::parse(self.field_id)
+      .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+      .0;
+
+    Ok(DeleteCalculationParams {
+      view_id,
+      field_id,
+      field_type: self.field_type.into(),
+    })
+  }
+}
+
+#[derive(Debug)]
+pub struct DeleteCalculationParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub field_type: FieldTypeRevision,
+}
+
+/// [CalculationValuePB] is the calculated value of the field over the visible rows of the view,
+/// or over the visible rows of the group if the `group_id` is set.
+#[derive(ProtoBuf, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CalculationValuePB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub calculation_type: CalculationTypePB,
+
+  #[pb(index = 3)]
+  pub value: String,
+
+  #[pb(index = 4, one_of)]
+  pub group_id: Option<String>,
+}
+
+impl std::convert::From<CalculationValue> for CalculationValuePB {
+  fn from(value: CalculationValue) -> Self {
+    Self {
+      field_id: value.field_id,
+      calculation_type: value.calculation_type.into(),
+      value: value.value,
+      group_id: value.group_id,
+    }
+  }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct RepeatedCalculationValuePB {
+  #[pb(index = 1)]
+  pub items: Vec<CalculationValuePB>,
+}
+
+impl std::convert::From<Vec<CalculationValue>> for RepeatedCalculationValuePB {
+  fn from(values: Vec<CalculationValue>) -> Self {
+    Self {
+      items: values.into_iter().map(|value| value.into()).collect(),
+    }
+  }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct CalculationValuesChangesetPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The values that were changed.
+  #[pb(index = 2)]
+  pub values: Vec<CalculationValuePB>,
+}
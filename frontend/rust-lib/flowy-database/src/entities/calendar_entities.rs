@@ -115,6 +115,16 @@ pub struct CalendarEventPB {
 
   #[pb(index = 4)]
   pub timestamp: i64,
+
+  /// The timestamp of the occurrence that the event is expanded from. It's the same as the
+  /// `timestamp` unless the occurrence is rescheduled.
+  #[pb(index = 5)]
+  pub occurrence_timestamp: i64,
+
+  /// The end of the event if the date cell holds a date range. The event spans multiple days
+  /// if the end is on a later day.
+  #[pb(index = 6, one_of)]
+  pub end_timestamp: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
@@ -134,3 +144,75 @@ pub struct MoveCalendarEventPB {
   #[pb(index = 3)]
   pub timestamp: i64,
 }
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct CalendarEventRangeRequestPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub start_timestamp: i64,
+
+  #[pb(index = 3)]
+  pub end_timestamp: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CalendarEventRangeRequestParams {
+  pub view_id: String,
+  pub start_timestamp: i64,
+  pub end_timestamp: i64,
+}
+
+impl TryInto<CalendarEventRangeRequestParams> for CalendarEventRangeRequestPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<CalendarEventRangeRequestParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    Ok(CalendarEventRangeRequestParams {
+      view_id: view_id.0,
+      start_timestamp: self.start_timestamp,
+      end_timestamp: self.end_timestamp,
+    })
+  }
+}
+
+/// Reschedules or cancels one occurrence of a recurring event.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct UpdateCalendarEventOccurrencePB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub occurrence_timestamp: i64,
+
+  /// The occurrence is cancelled if it's None.
+  #[pb(index = 4, one_of)]
+  pub timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateCalendarEventOccurrenceParams {
+  pub view_id: String,
+  pub row_id: String,
+  pub occurrence_timestamp: i64,
+  pub timestamp: Option<i64>,
+}
+
+impl TryInto<UpdateCalendarEventOccurrenceParams> for UpdateCalendarEventOccurrencePB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateCalendarEventOccurrenceParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    Ok(UpdateCalendarEventOccurrenceParams {
+      view_id: view_id.0,
+      row_id: row_id.0,
+      occurrence_timestamp: self.occurrence_timestamp,
+      timestamp: self.timestamp,
+    })
+  }
+}
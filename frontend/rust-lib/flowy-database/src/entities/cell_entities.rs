@@ -167,6 +167,8 @@ impl std::convert::From<CellChangesetPB> for RowChangeset {
       row_id: changeset.row_id,
       height: None,
       visibility: None,
+      last_modified: None,
+      document_id: None,
       cell_by_field_id,
     }
   }
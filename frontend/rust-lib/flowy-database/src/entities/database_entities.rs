@@ -118,6 +118,10 @@ pub struct MoveGroupRowPayloadPB {
 
   #[pb(index = 4, one_of)]
   pub to_row_id: Option<String>,
+
+  /// The row will be moved to this sub group too if the view has a sub grouping field.
+  #[pb(index = 5, one_of)]
+  pub to_sub_group_id: Option<String>,
 }
 
 pub struct MoveGroupRowParams {
@@ -125,6 +129,7 @@ pub struct MoveGroupRowParams {
   pub from_row_id: String,
   pub to_group_id: String,
   pub to_row_id: Option<String>,
+  pub to_sub_group_id: Option<String>,
 }
 
 impl TryInto<MoveGroupRowParams> for MoveGroupRowPayloadPB {
@@ -145,11 +150,21 @@ impl TryInto<MoveGroupRowParams> for MoveGroupRowPayloadPB {
       ),
     };
 
+    let to_sub_group_id = match self.to_sub_group_id {
+      None => None,
+      Some(to_sub_group_id) => Some(
+        NotEmptyStr::parse(to_sub_group_id)
+          .map_err(|_| ErrorCode::GroupIdIsEmpty)?
+          .0,
+      ),
+    };
+
     Ok(MoveGroupRowParams {
       view_id: view_id.0,
       from_row_id: from_row_id.0,
       to_group_id: to_group_id.0,
       to_row_id,
+      to_sub_group_id,
     })
   }
 }
@@ -203,3 +218,42 @@ pub struct DatabaseLayoutIdPB {
   #[pb(index = 2)]
   pub layout: LayoutTypePB,
 }
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseExportDataPB {
+  #[pb(index = 1)]
+  pub data: String,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ImportCSVPayloadPB {
+  /// The id of the view that the imported database will be bound to.
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub data: String,
+}
+
+pub struct ImportCSVParams {
+  pub view_id: String,
+  pub name: String,
+  pub data: String,
+}
+
+impl TryInto<ImportCSVParams> for ImportCSVPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ImportCSVParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let data = NotEmptyStr::parse(self.data).map_err(|_| ErrorCode::InvalidData)?;
+    Ok(ImportCSVParams {
+      view_id: view_id.0,
+      name: self.name,
+      data: data.0,
+    })
+  }
+}
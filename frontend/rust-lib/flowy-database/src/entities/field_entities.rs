@@ -494,6 +494,12 @@ pub enum FieldType {
   Checkbox = 5,
   URL = 6,
   Checklist = 7,
+  Formula = 8,
+  Relation = 9,
+  Rollup = 10,
+  CreatedTime = 11,
+  LastEditedTime = 12,
+  AutoIncrementId = 13,
 }
 
 pub const RICH_TEXT_FIELD: FieldType = FieldType::RichText;
@@ -504,6 +510,12 @@ pub const MULTI_SELECT_FIELD: FieldType = FieldType::MultiSelect;
 pub const CHECKBOX_FIELD: FieldType = FieldType::Checkbox;
 pub const URL_FIELD: FieldType = FieldType::URL;
 pub const CHECKLIST_FIELD: FieldType = FieldType::Checklist;
+pub const FORMULA_FIELD: FieldType = FieldType::Formula;
+pub const RELATION_FIELD: FieldType = FieldType::Relation;
+pub const ROLLUP_FIELD: FieldType = FieldType::Rollup;
+pub const CREATED_TIME_FIELD: FieldType = FieldType::CreatedTime;
+pub const LAST_EDITED_TIME_FIELD: FieldType = FieldType::LastEditedTime;
+pub const AUTO_INCREMENT_ID_FIELD: FieldType = FieldType::AutoIncrementId;
 
 impl std::default::Default for FieldType {
   fn default() -> Self {
@@ -530,7 +542,7 @@ impl FieldType {
 
   pub fn default_cell_width(&self) -> i32 {
     match self {
-      FieldType::DateTime => 180,
+      FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => 180,
       _ => 150,
     }
   }
@@ -571,6 +583,39 @@ impl FieldType {
     self == &CHECKLIST_FIELD
   }
 
+  pub fn is_formula(&self) -> bool {
+    self == &FORMULA_FIELD
+  }
+
+  pub fn is_relation(&self) -> bool {
+    self == &RELATION_FIELD
+  }
+
+  pub fn is_rollup(&self) -> bool {
+    self == &ROLLUP_FIELD
+  }
+
+  pub fn is_created_time(&self) -> bool {
+    self == &CREATED_TIME_FIELD
+  }
+
+  pub fn is_last_edited_time(&self) -> bool {
+    self == &LAST_EDITED_TIME_FIELD
+  }
+
+  pub fn is_timestamp(&self) -> bool {
+    self.is_created_time() || self.is_last_edited_time()
+  }
+
+  pub fn is_auto_increment_id(&self) -> bool {
+    self == &AUTO_INCREMENT_ID_FIELD
+  }
+
+  /// The cells of the system fields are maintained by the database instead of being edited.
+  pub fn is_system(&self) -> bool {
+    self.is_timestamp() || self.is_auto_increment_id()
+  }
+
   pub fn can_be_group(&self) -> bool {
     self.is_select_option() || self.is_checkbox() || self.is_url()
   }
@@ -605,6 +650,12 @@ impl std::convert::From<FieldTypeRevision> for FieldType {
       5 => FieldType::Checkbox,
       6 => FieldType::URL,
       7 => FieldType::Checklist,
+      8 => FieldType::Formula,
+      9 => FieldType::Relation,
+      10 => FieldType::Rollup,
+      11 => FieldType::CreatedTime,
+      12 => FieldType::LastEditedTime,
+      13 => FieldType::AutoIncrementId,
       _ => {
         tracing::error!("Can't convert FieldTypeRevision: {} to FieldType", ty);
         FieldType::RichText
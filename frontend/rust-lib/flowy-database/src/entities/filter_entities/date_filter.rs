@@ -52,6 +52,9 @@ pub enum DateFilterConditionPB {
   DateWithIn = 5,
   DateIsEmpty = 6,
   DateIsNotEmpty = 7,
+  /// The date range of the cell overlaps the range from `start` to `end`. A cell holding a
+  /// single date is treated as a range of one day.
+  DateOverlaps = 8,
 }
 
 impl std::convert::From<DateFilterConditionPB> for u32 {
@@ -77,6 +80,8 @@ impl std::convert::TryFrom<u8> for DateFilterConditionPB {
       4 => Ok(DateFilterConditionPB::DateOnOrAfter),
       5 => Ok(DateFilterConditionPB::DateWithIn),
       6 => Ok(DateFilterConditionPB::DateIsEmpty),
+      7 => Ok(DateFilterConditionPB::DateIsNotEmpty),
+      8 => Ok(DateFilterConditionPB::DateOverlaps),
       _ => Err(ErrorCode::InvalidData),
     }
   }
@@ -0,0 +1,175 @@
+use crate::entities::parser::NotEmptyStr;
+use database_model::{FilterGroupRevision, FilterNodeRevision, FilterOperatorRevision};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum FilterOperatorPB {
+  And = 0,
+  Or = 1,
+}
+
+impl std::default::Default for FilterOperatorPB {
+  fn default() -> Self {
+    FilterOperatorPB::And
+  }
+}
+
+impl std::convert::From<FilterOperatorRevision> for FilterOperatorPB {
+  fn from(rev: FilterOperatorRevision) -> Self {
+    match rev {
+      FilterOperatorRevision::And => FilterOperatorPB::And,
+      FilterOperatorRevision::Or => FilterOperatorPB::Or,
+    }
+  }
+}
+
+impl std::convert::From<FilterOperatorPB> for FilterOperatorRevision {
+  fn from(operator: FilterOperatorPB) -> Self {
+    match operator {
+      FilterOperatorPB::And => FilterOperatorRevision::And,
+      FilterOperatorPB::Or => FilterOperatorRevision::Or,
+    }
+  }
+}
+
+/// [FilterGroupPB] combines its children with the `operator`. The filters that are not in the
+/// tree are treated as the children of the root group.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FilterGroupPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub operator: FilterOperatorPB,
+
+  #[pb(index = 3)]
+  pub children: Vec<FilterNodePB>,
+}
+
+/// A child of the [FilterGroupPB]. Only one of the `filter_id` and the `group` will be set.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FilterNodePB {
+  #[pb(index = 1, one_of)]
+  pub filter_id: Option<String>,
+
+  #[pb(index = 2, one_of)]
+  pub group: Option<FilterGroupPB>,
+}
+
+impl std::convert::From<FilterGroupRevision> for FilterGroupPB {
+  fn from(rev: FilterGroupRevision) -> Self {
+    Self {
+      id: rev.id,
+      operator: rev.operator.into(),
+      children: rev.children.into_iter().map(FilterNodePB::from).collect(),
+    }
+  }
+}
+
+impl std::convert::From<FilterNodeRevision> for FilterNodePB {
+  fn from(rev: FilterNodeRevision) -> Self {
+    match rev {
+      FilterNodeRevision::Filter { filter_id } => Self {
+        filter_id: Some(filter_id),
+        group: None,
+      },
+      FilterNodeRevision::Group(group) => Self {
+        filter_id: None,
+        group: Some(group.into()),
+      },
+    }
+  }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct AlterFilterGroupPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// Create a new filter group if the group_id is None
+  #[pb(index = 2, one_of)]
+  pub group_id: Option<String>,
+
+  /// The new filter group will be inserted into the root group if the parent_group_id is None
+  #[pb(index = 3, one_of)]
+  pub parent_group_id: Option<String>,
+
+  #[pb(index = 4)]
+  pub operator: FilterOperatorPB,
+}
+
+impl TryInto<AlterFilterGroupParams> for AlterFilterGroupPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<AlterFilterGroupParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+
+    let group_id = match self.group_id {
+      None => None,
+      Some(group_id) => Some(
+        NotEmptyStr::parse(group_id)
+          .map_err(|_| ErrorCode::FilterGroupIdIsEmpty)?
+          .0,
+      ),
+    };
+
+    let parent_group_id = match self.parent_group_id {
+      None => None,
+      Some(group_id) => Some(
+        NotEmptyStr::parse(group_id)
+          .map_err(|_| ErrorCode::FilterGroupIdIsEmpty)?
+          .0,
+      ),
+    };
+
+    Ok(AlterFilterGroupParams {
+      view_id,
+      group_id,
+      parent_group_id,
+      operator: self.operator.into(),
+    })
+  }
+}
+
+#[derive(Debug)]
+pub struct AlterFilterGroupParams {
+  pub view_id: String,
+  /// Create a new filter group if the group_id is None
+  pub group_id: Option<String>,
+  pub parent_group_id: Option<String>,
+  pub operator: FilterOperatorRevision,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct DeleteFilterGroupPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub group_id: String,
+}
+
+impl TryInto<DeleteFilterGroupParams> for DeleteFilterGroupPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<DeleteFilterGroupParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+    let group_id = NotEmptyStr::parse(self.group_id)
+      .map_err(|_| ErrorCode::FilterGroupIdIsEmpty)?
+      .0;
+
+    Ok(DeleteFilterGroupParams { view_id, group_id })
+  }
+}
+
+#[derive(Debug)]
+pub struct DeleteFilterGroupParams {
+  pub view_id: String,
+  pub group_id: String,
+}
@@ -0,0 +1,36 @@
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::ProtoBuf;
+
+/// The filter of the formula field. Its condition and content are the same as the filter of the
+/// field type that matches the result type of the formula. For example, the condition is one of
+/// the `NumberFilterConditionPB` if the formula evaluates to numbers.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FormulaFilterPB {
+  #[pb(index = 1)]
+  pub condition: u32,
+
+  #[pb(index = 2)]
+  pub content: String,
+}
+
+impl FromFilterString for FormulaFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    FormulaFilterPB {
+      condition: filter_rev.condition as u32,
+      content: filter_rev.content.clone(),
+    }
+  }
+}
+
+impl std::convert::From<&FilterRevision> for FormulaFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    FormulaFilterPB {
+      condition: rev.condition as u32,
+      content: rev.content.clone(),
+    }
+  }
+}
@@ -0,0 +1,62 @@
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct RelationFilterPB {
+  #[pb(index = 1)]
+  pub condition: RelationFilterConditionPB,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum RelationFilterConditionPB {
+  IsEmpty = 0,
+  IsNotEmpty = 1,
+}
+
+impl std::convert::From<RelationFilterConditionPB> for u32 {
+  fn from(value: RelationFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::default::Default for RelationFilterConditionPB {
+  fn default() -> Self {
+    RelationFilterConditionPB::IsNotEmpty
+  }
+}
+
+impl std::convert::TryFrom<u8> for RelationFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(RelationFilterConditionPB::IsEmpty),
+      1 => Ok(RelationFilterConditionPB::IsNotEmpty),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for RelationFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    RelationFilterPB {
+      condition: RelationFilterConditionPB::try_from(filter_rev.condition)
+        .unwrap_or(RelationFilterConditionPB::IsNotEmpty),
+    }
+  }
+}
+
+impl std::convert::From<&FilterRevision> for RelationFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    RelationFilterPB {
+      condition: RelationFilterConditionPB::try_from(rev.condition)
+        .unwrap_or(RelationFilterConditionPB::IsNotEmpty),
+    }
+  }
+}
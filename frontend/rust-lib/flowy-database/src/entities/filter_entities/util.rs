@@ -1,7 +1,7 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
   CheckboxFilterPB, ChecklistFilterPB, DateFilterContentPB, DateFilterPB, FieldType,
-  NumberFilterPB, SelectOptionFilterPB, TextFilterPB,
+  FormulaFilterPB, NumberFilterPB, RelationFilterPB, SelectOptionFilterPB, TextFilterPB,
 };
 use crate::services::field::SelectOptionIds;
 use crate::services::filter::FilterType;
@@ -32,13 +32,19 @@ impl std::convert::From<&FilterRevision> for FilterPB {
     let field_type: FieldType = rev.field_type.into();
     let bytes: Bytes = match field_type {
       FieldType::RichText => TextFilterPB::from(rev).try_into().unwrap(),
-      FieldType::Number => NumberFilterPB::from(rev).try_into().unwrap(),
-      FieldType::DateTime => DateFilterPB::from(rev).try_into().unwrap(),
+      FieldType::Number | FieldType::Rollup | FieldType::AutoIncrementId => {
+        NumberFilterPB::from(rev).try_into().unwrap()
+      },
+      FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+        DateFilterPB::from(rev).try_into().unwrap()
+      },
       FieldType::SingleSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
       FieldType::MultiSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
       FieldType::Checklist => ChecklistFilterPB::from(rev).try_into().unwrap(),
       FieldType::Checkbox => CheckboxFilterPB::from(rev).try_into().unwrap(),
       FieldType::URL => TextFilterPB::from(rev).try_into().unwrap(),
+      FieldType::Formula => FormulaFilterPB::from(rev).try_into().unwrap(),
+      FieldType::Relation => RelationFilterPB::from(rev).try_into().unwrap(),
     };
     Self {
       id: rev.id.clone(),
@@ -136,6 +142,11 @@ pub struct AlterFilterPayloadPB {
 
   #[pb(index = 5)]
   pub view_id: String,
+
+  /// The filter will be inserted into the root filter group if the group_id is None.
+  /// Specifying the group_id when updating a filter moves the filter to that group.
+  #[pb(index = 6, one_of)]
+  pub group_id: Option<String>,
 }
 
 impl AlterFilterPayloadPB {
@@ -152,6 +163,7 @@ impl AlterFilterPayloadPB {
       field_type: field_rev.ty.into(),
       filter_id: None,
       data: data.to_vec(),
+      group_id: None,
     }
   }
 }
@@ -175,6 +187,14 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
           .0,
       ),
     };
+    let group_id = match self.group_id {
+      None => None,
+      Some(group_id) => Some(
+        NotEmptyStr::parse(group_id)
+          .map_err(|_| ErrorCode::FilterGroupIdIsEmpty)?
+          .0,
+      ),
+    };
     let condition;
     let mut content = "".to_string();
     let bytes: &[u8] = self.data.as_ref();
@@ -189,12 +209,12 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
         let filter = CheckboxFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
         condition = filter.condition as u8;
       },
-      FieldType::Number => {
+      FieldType::Number | FieldType::Rollup | FieldType::AutoIncrementId => {
         let filter = NumberFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
         condition = filter.condition as u8;
         content = filter.content;
       },
-      FieldType::DateTime => {
+      FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
         let filter = DateFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
         condition = filter.condition as u8;
         content = DateFilterContentPB {
@@ -209,6 +229,15 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
         condition = filter.condition as u8;
         content = SelectOptionIds::from(filter.option_ids).to_string();
       },
+      FieldType::Formula => {
+        let filter = FormulaFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = filter.content;
+      },
+      FieldType::Relation => {
+        let filter = RelationFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+      },
     }
 
     Ok(AlterFilterParams {
@@ -218,6 +247,7 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
       field_type: self.field_type.into(),
       condition,
       content,
+      group_id,
     })
   }
 }
@@ -231,4 +261,6 @@ pub struct AlterFilterParams {
   pub field_type: FieldTypeRevision,
   pub condition: u8,
   pub content: String,
+  /// The filter group that the filter belongs to
+  pub group_id: Option<String>,
 }
@@ -0,0 +1,123 @@
+use database_model::{GalleryCardSize, GalleryCoverSource, GalleryLayoutSetting};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, ProtoBuf)]
+pub struct GalleryLayoutSettingPB {
+  #[pb(index = 1)]
+  pub cover_source: GalleryCoverSourcePB,
+
+  #[pb(index = 2, one_of)]
+  pub cover_field_id: Option<String>,
+
+  #[pb(index = 3)]
+  pub card_size: GalleryCardSizePB,
+
+  #[pb(index = 4)]
+  pub visible_field_ids: Vec<String>,
+}
+
+impl std::convert::From<GalleryLayoutSettingPB> for GalleryLayoutSetting {
+  fn from(pb: GalleryLayoutSettingPB) -> Self {
+    GalleryLayoutSetting {
+      cover_source: pb.cover_source.into(),
+      cover_field_id: pb.cover_field_id,
+      card_size: pb.card_size.into(),
+      visible_field_ids: pb.visible_field_ids,
+    }
+  }
+}
+
+impl std::convert::From<GalleryLayoutSetting> for GalleryLayoutSettingPB {
+  fn from(setting: GalleryLayoutSetting) -> Self {
+    GalleryLayoutSettingPB {
+      cover_source: setting.cover_source.into(),
+      cover_field_id: setting.cover_field_id,
+      card_size: setting.card_size.into(),
+      visible_field_ids: setting.visible_field_ids,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum GalleryCoverSourcePB {
+  None = 0,
+  URLField = 1,
+  DocumentImage = 2,
+}
+
+impl std::default::Default for GalleryCoverSourcePB {
+  fn default() -> Self {
+    GalleryCoverSourcePB::None
+  }
+}
+
+impl std::convert::From<GalleryCoverSourcePB> for GalleryCoverSource {
+  fn from(pb: GalleryCoverSourcePB) -> Self {
+    match pb {
+      GalleryCoverSourcePB::None => GalleryCoverSource::None,
+      GalleryCoverSourcePB::URLField => GalleryCoverSource::URLField,
+      GalleryCoverSourcePB::DocumentImage => GalleryCoverSource::DocumentImage,
+    }
+  }
+}
+
+impl std::convert::From<GalleryCoverSource> for GalleryCoverSourcePB {
+  fn from(source: GalleryCoverSource) -> Self {
+    match source {
+      GalleryCoverSource::None => GalleryCoverSourcePB::None,
+      GalleryCoverSource::URLField => GalleryCoverSourcePB::URLField,
+      GalleryCoverSource::DocumentImage => GalleryCoverSourcePB::DocumentImage,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum GalleryCardSizePB {
+  Small = 0,
+  Medium = 1,
+  Large = 2,
+}
+
+impl std::default::Default for GalleryCardSizePB {
+  fn default() -> Self {
+    GalleryCardSizePB::Medium
+  }
+}
+
+impl std::convert::From<GalleryCardSizePB> for GalleryCardSize {
+  fn from(pb: GalleryCardSizePB) -> Self {
+    match pb {
+      GalleryCardSizePB::Small => GalleryCardSize::Small,
+      GalleryCardSizePB::Medium => GalleryCardSize::Medium,
+      GalleryCardSizePB::Large => GalleryCardSize::Large,
+    }
+  }
+}
+
+impl std::convert::From<GalleryCardSize> for GalleryCardSizePB {
+  fn from(size: GalleryCardSize) -> Self {
+    match size {
+      GalleryCardSize::Small => GalleryCardSizePB::Small,
+      GalleryCardSize::Medium => GalleryCardSizePB::Medium,
+      GalleryCardSize::Large => GalleryCardSizePB::Large,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct GalleryCardPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  /// The URL of the cover image. It's None if the card doesn't have a cover.
+  #[pb(index = 2, one_of)]
+  pub cover_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedGalleryCardPB {
+  #[pb(index = 1)]
+  pub items: Vec<GalleryCardPB>,
+}
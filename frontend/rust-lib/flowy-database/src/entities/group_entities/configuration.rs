@@ -78,6 +78,18 @@ impl std::default::Default for DateCondition {
   }
 }
 
+impl std::convert::From<DateCondition> for database_model::DateCondition {
+  fn from(condition: DateCondition) -> Self {
+    match condition {
+      DateCondition::Relative => database_model::DateCondition::Relative,
+      DateCondition::Day => database_model::DateCondition::Day,
+      DateCondition::Week => database_model::DateCondition::Week,
+      DateCondition::Month => database_model::DateCondition::Month,
+      DateCondition::Year => database_model::DateCondition::Year,
+    }
+  }
+}
+
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct CheckboxGroupConfigurationPB {
   #[pb(index = 1)]
@@ -1,7 +1,7 @@
 use crate::entities::parser::NotEmptyStr;
-use crate::entities::{FieldType, RowPB};
+use crate::entities::{DateCondition, FieldType, RowPB};
 use crate::services::group::Group;
-use database_model::{FieldTypeRevision, GroupConfigurationRevision};
+use database_model::{FieldTypeRevision, GroupConfigurationRevision, NumberRangeRevision};
 use flowy_derive::ProtoBuf;
 use flowy_error::ErrorCode;
 use std::convert::TryInto;
@@ -63,6 +63,11 @@ pub struct GroupPB {
 
   #[pb(index = 6)]
   pub is_visible: bool,
+
+  /// The rows of the group are grouped again by the sub grouping field. It's empty if the view
+  /// has no sub grouping field.
+  #[pb(index = 7)]
+  pub sub_groups: Vec<GroupPB>,
 }
 
 impl std::convert::From<Group> for GroupPB {
@@ -74,6 +79,7 @@ impl std::convert::From<Group> for GroupPB {
       rows: group.rows,
       is_default: group.is_default,
       is_visible: group.is_visible,
+      sub_groups: vec![],
     }
   }
 }
@@ -180,3 +186,186 @@ pub struct DeleteGroupParams {
   pub group_id: String,
   pub field_type_rev: FieldTypeRevision,
 }
+
+/// Changes the condition that is used to group the rows by the date field.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UpdateDateGroupConditionPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub condition: DateCondition,
+}
+
+impl TryInto<UpdateDateGroupConditionParams> for UpdateDateGroupConditionPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateDateGroupConditionParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    let field_id = NotEmptyStr::parse(self.field_id)
+      .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+      .0;
+
+    Ok(UpdateDateGroupConditionParams {
+      view_id,
+      field_id,
+      condition: self.condition.into(),
+    })
+  }
+}
+
+pub struct UpdateDateGroupConditionParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub condition: database_model::DateCondition,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone, PartialEq)]
+pub struct NumberRangePB {
+  #[pb(index = 1)]
+  pub start: f64,
+
+  #[pb(index = 2)]
+  pub end: f64,
+}
+
+impl std::convert::From<NumberRangePB> for NumberRangeRevision {
+  fn from(range: NumberRangePB) -> Self {
+    NumberRangeRevision::new(range.start, range.end)
+  }
+}
+
+/// Changes how the rows are grouped by the number field. The numbers are grouped into the
+/// `ranges` if there are any. Otherwise, they are grouped into the buckets of `bucket_width`.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UpdateNumberGroupSettingPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub ranges: Vec<NumberRangePB>,
+
+  #[pb(index = 4)]
+  pub bucket_width: f64,
+}
+
+impl TryInto<UpdateNumberGroupSettingParams> for UpdateNumberGroupSettingPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateNumberGroupSettingParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    let field_id = NotEmptyStr::parse(self.field_id)
+      .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+      .0;
+    if self.bucket_width < 0.0
+      || self
+        .ranges
+        .iter()
+        .any(|range| range.start.is_nan() || range.end.is_nan() || range.start >= range.end)
+    {
+      return Err(ErrorCode::InvalidData);
+    }
+
+    Ok(UpdateNumberGroupSettingParams {
+      view_id,
+      field_id,
+      ranges: self
+        .ranges
+        .into_iter()
+        .map(NumberRangeRevision::from)
+        .collect(),
+      bucket_width: self.bucket_width,
+    })
+  }
+}
+
+pub struct UpdateNumberGroupSettingParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub ranges: Vec<NumberRangeRevision>,
+  pub bucket_width: f64,
+}
+
+/// Changes how the rows are grouped by the text field.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UpdateTextGroupSettingPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub case_insensitive: bool,
+}
+
+impl TryInto<UpdateTextGroupSettingParams> for UpdateTextGroupSettingPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateTextGroupSettingParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    let field_id = NotEmptyStr::parse(self.field_id)
+      .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+      .0;
+
+    Ok(UpdateTextGroupSettingParams {
+      view_id,
+      field_id,
+      case_insensitive: self.case_insensitive,
+    })
+  }
+}
+
+pub struct UpdateTextGroupSettingParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub case_insensitive: bool,
+}
+
+/// Groups the rows of each group by the field again. The sub grouping will be removed if the
+/// `field_id` is None.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SubGroupByFieldPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2, one_of)]
+  pub field_id: Option<String>,
+}
+
+impl TryInto<SubGroupByFieldParams> for SubGroupByFieldPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SubGroupByFieldParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    let field_id = match self.field_id {
+      None => None,
+      Some(field_id) => Some(
+        NotEmptyStr::parse(field_id)
+          .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+          .0,
+      ),
+    };
+
+    Ok(SubGroupByFieldParams { view_id, field_id })
+  }
+}
+
+pub struct SubGroupByFieldParams {
+  pub view_id: String,
+  pub field_id: Option<String>,
+}
@@ -1,23 +1,33 @@
+mod calculation_entities;
 mod calendar_entities;
 mod cell_entities;
 mod database_entities;
 mod field_entities;
 pub mod filter_entities;
+mod gallery_entities;
 mod group_entities;
 pub mod parser;
+mod row_activity_entities;
 mod row_entities;
+mod search_entities;
 pub mod setting_entities;
 mod sort_entities;
+mod timeline_entities;
 mod view_entities;
 
+pub use calculation_entities::*;
 pub use calendar_entities::*;
 pub use cell_entities::*;
 pub use database_entities::*;
 pub use database_entities::*;
 pub use field_entities::*;
 pub use filter_entities::*;
+pub use gallery_entities::*;
 pub use group_entities::*;
+pub use row_activity_entities::*;
 pub use row_entities::*;
+pub use search_entities::*;
 pub use setting_entities::*;
 pub use sort_entities::*;
+pub use timeline_entities::*;
 pub use view_entities::*;
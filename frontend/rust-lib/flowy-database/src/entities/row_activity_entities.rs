@@ -0,0 +1,208 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::services::persistence::row_activity::RowActivity;
+use crate::services::persistence::row_comment::RowComment;
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+/// [RowActivityPB] describes a change of the cell. The `old_value` and the `new_value` are the
+/// display strings of the cell before and after the change.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowActivityPB {
+  #[pb(index = 1)]
+  pub id: i32,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub field_id: String,
+
+  #[pb(index = 4)]
+  pub old_value: String,
+
+  #[pb(index = 5)]
+  pub new_value: String,
+
+  #[pb(index = 6)]
+  pub user_id: String,
+
+  #[pb(index = 7)]
+  pub timestamp: i64,
+}
+
+impl std::convert::From<RowActivity> for RowActivityPB {
+  fn from(activity: RowActivity) -> Self {
+    Self {
+      id: activity.id,
+      row_id: activity.row_id,
+      field_id: activity.field_id,
+      old_value: activity.old_value,
+      new_value: activity.new_value,
+      user_id: activity.user_id,
+      timestamp: activity.timestamp,
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowActivityPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowActivityPB>,
+}
+
+impl std::convert::From<Vec<RowActivity>> for RepeatedRowActivityPB {
+  fn from(activities: Vec<RowActivity>) -> Self {
+    Self {
+      items: activities
+        .into_iter()
+        .map(|activity| activity.into())
+        .collect(),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowCommentPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub content: String,
+
+  #[pb(index = 4)]
+  pub user_id: String,
+
+  #[pb(index = 5)]
+  pub created_at: i64,
+
+  #[pb(index = 6)]
+  pub modified_at: i64,
+}
+
+impl std::convert::From<RowComment> for RowCommentPB {
+  fn from(comment: RowComment) -> Self {
+    Self {
+      id: comment.id,
+      row_id: comment.row_id,
+      content: comment.content,
+      user_id: comment.user_id,
+      created_at: comment.created_at,
+      modified_at: comment.modified_at,
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowCommentPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowCommentPB>,
+}
+
+impl std::convert::From<Vec<RowComment>> for RepeatedRowCommentPB {
+  fn from(comments: Vec<RowComment>) -> Self {
+    Self {
+      items: comments.into_iter().map(|comment| comment.into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CreateRowCommentPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub content: String,
+}
+
+pub struct CreateRowCommentParams {
+  pub view_id: String,
+  pub row_id: String,
+  pub content: String,
+}
+
+impl TryInto<CreateRowCommentParams> for CreateRowCommentPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<CreateRowCommentParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    let content = NotEmptyStr::parse(self.content).map_err(|_| ErrorCode::UnexpectedEmptyString)?;
+
+    Ok(CreateRowCommentParams {
+      view_id: view_id.0,
+      row_id: row_id.0,
+      content: content.0,
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct UpdateRowCommentPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub comment_id: String,
+
+  #[pb(index = 3)]
+  pub content: String,
+}
+
+pub struct UpdateRowCommentParams {
+  pub view_id: String,
+  pub comment_id: String,
+  pub content: String,
+}
+
+impl TryInto<UpdateRowCommentParams> for UpdateRowCommentPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateRowCommentParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let comment_id =
+      NotEmptyStr::parse(self.comment_id).map_err(|_| ErrorCode::CommentIdIsEmpty)?;
+    let content = NotEmptyStr::parse(self.content).map_err(|_| ErrorCode::UnexpectedEmptyString)?;
+
+    Ok(UpdateRowCommentParams {
+      view_id: view_id.0,
+      comment_id: comment_id.0,
+      content: content.0,
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DeleteRowCommentPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub comment_id: String,
+}
+
+pub struct DeleteRowCommentParams {
+  pub view_id: String,
+  pub comment_id: String,
+}
+
+impl TryInto<DeleteRowCommentParams> for DeleteRowCommentPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<DeleteRowCommentParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let comment_id =
+      NotEmptyStr::parse(self.comment_id).map_err(|_| ErrorCode::CommentIdIsEmpty)?;
+
+    Ok(DeleteRowCommentParams {
+      view_id: view_id.0,
+      comment_id: comment_id.0,
+    })
+  }
+}
@@ -162,6 +162,16 @@ impl TryInto<RowIdParams> for RowIdPB {
   }
 }
 
+/// [RowDocumentPB] contains the id of the document that is used as the body of the row.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowDocumentPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub document_id: String,
+}
+
 #[derive(Debug, Default, Clone, ProtoBuf)]
 pub struct BlockRowIdPB {
   #[pb(index = 1)]
@@ -184,6 +194,10 @@ pub struct CreateRowPayloadPB {
 
   #[pb(index = 4, one_of)]
   pub data: Option<RowDataPB>,
+
+  /// The row will be created in this sub group too if the view has a sub grouping field.
+  #[pb(index = 5, one_of)]
+  pub sub_group_id: Option<String>,
 }
 
 #[derive(ProtoBuf, Default)]
@@ -197,6 +211,7 @@ pub struct CreateRowParams {
   pub view_id: String,
   pub start_row_id: Option<String>,
   pub group_id: Option<String>,
+  pub sub_group_id: Option<String>,
   pub cell_data_by_field_id: Option<HashMap<String, String>>,
 }
 
@@ -218,6 +233,7 @@ impl TryInto<CreateRowParams> for CreateRowPayloadPB {
       view_id: view_id.0,
       start_row_id,
       group_id: self.group_id,
+      sub_group_id: self.sub_group_id,
       cell_data_by_field_id: self.data.map(|data| data.cell_data_by_field_id),
     })
   }
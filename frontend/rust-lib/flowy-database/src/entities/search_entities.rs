@@ -0,0 +1,97 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::services::search::{CellSearchResult, RowSearchResult};
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct SearchRowsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub query: String,
+}
+
+pub struct SearchRowsParams {
+  pub view_id: String,
+  pub query: String,
+}
+
+impl TryInto<SearchRowsParams> for SearchRowsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SearchRowsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    Ok(SearchRowsParams {
+      view_id: view_id.0,
+      query: self.query,
+    })
+  }
+}
+
+/// [TextRangePB] is measured in UTF-16 code units. The `end` is exclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ProtoBuf)]
+pub struct TextRangePB {
+  #[pb(index = 1)]
+  pub start: i32,
+
+  #[pb(index = 2)]
+  pub end: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, ProtoBuf)]
+pub struct CellSearchResultPB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub ranges: Vec<TextRangePB>,
+}
+
+impl std::convert::From<CellSearchResult> for CellSearchResultPB {
+  fn from(result: CellSearchResult) -> Self {
+    Self {
+      field_id: result.field_id,
+      ranges: result
+        .ranges
+        .into_iter()
+        .map(|range| TextRangePB {
+          start: range.start as i32,
+          end: range.end as i32,
+        })
+        .collect(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, ProtoBuf)]
+pub struct RowSearchResultPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub cells: Vec<CellSearchResultPB>,
+}
+
+impl std::convert::From<RowSearchResult> for RowSearchResultPB {
+  fn from(result: RowSearchResult) -> Self {
+    Self {
+      row_id: result.row_id,
+      cells: result.cells.into_iter().map(|cell| cell.into()).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedRowSearchResultPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowSearchResultPB>,
+}
+
+impl std::convert::From<Vec<RowSearchResult>> for RepeatedRowSearchResultPB {
+  fn from(results: Vec<RowSearchResult>) -> Self {
+    Self {
+      items: results.into_iter().map(|result| result.into()).collect(),
+    }
+  }
+}
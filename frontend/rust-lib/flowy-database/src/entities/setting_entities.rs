@@ -1,11 +1,15 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
-  AlterFilterParams, AlterFilterPayloadPB, AlterSortParams, AlterSortPayloadPB,
-  CalendarLayoutSettingsPB, DeleteFilterParams, DeleteFilterPayloadPB, DeleteGroupParams,
-  DeleteGroupPayloadPB, DeleteSortParams, DeleteSortPayloadPB, InsertGroupParams,
-  InsertGroupPayloadPB, RepeatedFilterPB, RepeatedGroupConfigurationPB, RepeatedSortPB,
+  AlterFilterGroupParams, AlterFilterGroupPayloadPB, AlterFilterParams, AlterFilterPayloadPB,
+  AlterSortParams, AlterSortPayloadPB, CalendarLayoutSettingsPB, DeleteFilterGroupParams,
+  DeleteFilterGroupPayloadPB, DeleteFilterParams, DeleteFilterPayloadPB, DeleteGroupParams,
+  DeleteGroupPayloadPB, DeleteSortParams, DeleteSortPayloadPB, FilterGroupPB,
+  GalleryLayoutSettingPB, InsertGroupParams, InsertGroupPayloadPB, RepeatedCalculationPB,
+  RepeatedFilterPB, RepeatedGroupConfigurationPB, RepeatedSortPB, TimelineLayoutSettingPB,
+};
+use database_model::{
+  CalendarLayoutSetting, GalleryLayoutSetting, LayoutRevision, TimelineLayoutSetting,
 };
-use database_model::{CalendarLayoutSetting, LayoutRevision};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 use std::convert::TryInto;
@@ -28,6 +32,15 @@ pub struct DatabaseViewSettingPB {
 
   #[pb(index = 5)]
   pub sorts: RepeatedSortPB,
+
+  #[pb(index = 6)]
+  pub filter_group: FilterGroupPB,
+
+  #[pb(index = 7)]
+  pub sub_group_configurations: RepeatedGroupConfigurationPB,
+
+  #[pb(index = 8)]
+  pub calculations: RepeatedCalculationPB,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum, EnumIter)]
@@ -36,6 +49,8 @@ pub enum LayoutTypePB {
   Grid = 0,
   Board = 1,
   Calendar = 2,
+  Timeline = 3,
+  Gallery = 4,
 }
 
 impl std::default::Default for LayoutTypePB {
@@ -50,6 +65,8 @@ impl std::convert::From<LayoutRevision> for LayoutTypePB {
       LayoutRevision::Grid => LayoutTypePB::Grid,
       LayoutRevision::Board => LayoutTypePB::Board,
       LayoutRevision::Calendar => LayoutTypePB::Calendar,
+      LayoutRevision::Timeline => LayoutTypePB::Timeline,
+      LayoutRevision::Gallery => LayoutTypePB::Gallery,
     }
   }
 }
@@ -60,6 +77,8 @@ impl std::convert::From<LayoutTypePB> for LayoutRevision {
       LayoutTypePB::Grid => LayoutRevision::Grid,
       LayoutTypePB::Board => LayoutRevision::Board,
       LayoutTypePB::Calendar => LayoutRevision::Calendar,
+      LayoutTypePB::Timeline => LayoutRevision::Timeline,
+      LayoutTypePB::Gallery => LayoutRevision::Gallery,
     }
   }
 }
@@ -89,6 +108,12 @@ pub struct DatabaseSettingChangesetPB {
 
   #[pb(index = 8, one_of)]
   pub delete_sort: Option<DeleteSortPayloadPB>,
+
+  #[pb(index = 9, one_of)]
+  pub alter_filter_group: Option<AlterFilterGroupPayloadPB>,
+
+  #[pb(index = 10, one_of)]
+  pub delete_filter_group: Option<DeleteFilterGroupPayloadPB>,
 }
 
 impl TryInto<DatabaseSettingChangesetParams> for DatabaseSettingChangesetPB {
@@ -129,6 +154,16 @@ impl TryInto<DatabaseSettingChangesetParams> for DatabaseSettingChangesetPB {
       Some(payload) => Some(payload.try_into()?),
     };
 
+    let insert_filter_group = match self.alter_filter_group {
+      None => None,
+      Some(payload) => Some(payload.try_into()?),
+    };
+
+    let delete_filter_group = match self.delete_filter_group {
+      None => None,
+      Some(payload) => Some(payload.try_into()?),
+    };
+
     Ok(DatabaseSettingChangesetParams {
       view_id,
       layout_type: self.layout_type.into(),
@@ -138,6 +173,8 @@ impl TryInto<DatabaseSettingChangesetParams> for DatabaseSettingChangesetPB {
       delete_group,
       alert_sort,
       delete_sort,
+      insert_filter_group,
+      delete_filter_group,
     })
   }
 }
@@ -151,11 +188,16 @@ pub struct DatabaseSettingChangesetParams {
   pub delete_group: Option<DeleteGroupParams>,
   pub alert_sort: Option<AlterSortParams>,
   pub delete_sort: Option<DeleteSortParams>,
+  pub insert_filter_group: Option<AlterFilterGroupParams>,
+  pub delete_filter_group: Option<DeleteFilterGroupParams>,
 }
 
 impl DatabaseSettingChangesetParams {
   pub fn is_filter_changed(&self) -> bool {
-    self.insert_filter.is_some() || self.delete_filter.is_some()
+    self.insert_filter.is_some()
+      || self.delete_filter.is_some()
+      || self.insert_filter_group.is_some()
+      || self.delete_filter_group.is_some()
   }
 }
 
@@ -195,6 +237,12 @@ impl TryInto<UpdateLayoutSettingParams> for UpdateLayoutSettingPB {
 pub struct LayoutSettingPB {
   #[pb(index = 1, one_of)]
   pub calendar: Option<CalendarLayoutSettingsPB>,
+
+  #[pb(index = 2, one_of)]
+  pub timeline: Option<TimelineLayoutSettingPB>,
+
+  #[pb(index = 3, one_of)]
+  pub gallery: Option<GalleryLayoutSettingPB>,
 }
 
 impl LayoutSettingPB {
@@ -207,6 +255,8 @@ impl std::convert::From<LayoutSettingParams> for LayoutSettingPB {
   fn from(params: LayoutSettingParams) -> Self {
     Self {
       calendar: params.calendar.map(|calender| calender.into()),
+      timeline: params.timeline.map(|timeline| timeline.into()),
+      gallery: params.gallery.map(|gallery| gallery.into()),
     }
   }
 }
@@ -215,6 +265,8 @@ impl std::convert::From<LayoutSettingPB> for LayoutSettingParams {
   fn from(params: LayoutSettingPB) -> Self {
     Self {
       calendar: params.calendar.map(|calender| calender.into()),
+      timeline: params.timeline.map(|timeline| timeline.into()),
+      gallery: params.gallery.map(|gallery| gallery.into()),
     }
   }
 }
@@ -222,4 +274,6 @@ impl std::convert::From<LayoutSettingPB> for LayoutSettingParams {
 #[derive(Debug, Default, Clone)]
 pub struct LayoutSettingParams {
   pub calendar: Option<CalendarLayoutSetting>,
+  pub timeline: Option<TimelineLayoutSetting>,
+  pub gallery: Option<GalleryLayoutSetting>,
 }
@@ -0,0 +1,137 @@
+use crate::entities::parser::NotEmptyStr;
+use database_model::TimelineLayoutSetting;
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, ProtoBuf)]
+pub struct TimelineLayoutSettingPB {
+  #[pb(index = 1)]
+  pub start_field_id: String,
+
+  #[pb(index = 2)]
+  pub end_field_id: String,
+
+  #[pb(index = 3, one_of)]
+  pub dependency_field_id: Option<String>,
+}
+
+impl std::convert::From<TimelineLayoutSettingPB> for TimelineLayoutSetting {
+  fn from(pb: TimelineLayoutSettingPB) -> Self {
+    TimelineLayoutSetting {
+      start_field_id: pb.start_field_id,
+      end_field_id: pb.end_field_id,
+      dependency_field_id: pb.dependency_field_id,
+    }
+  }
+}
+
+impl std::convert::From<TimelineLayoutSetting> for TimelineLayoutSettingPB {
+  fn from(setting: TimelineLayoutSetting) -> Self {
+    TimelineLayoutSettingPB {
+      start_field_id: setting.start_field_id,
+      end_field_id: setting.end_field_id,
+      dependency_field_id: setting.dependency_field_id,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct TimelineBarRequestPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub start_timestamp: i64,
+
+  #[pb(index = 3)]
+  pub end_timestamp: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TimelineBarRequestParams {
+  pub view_id: String,
+  pub start_timestamp: i64,
+  pub end_timestamp: i64,
+}
+
+impl TryInto<TimelineBarRequestParams> for TimelineBarRequestPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<TimelineBarRequestParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    Ok(TimelineBarRequestParams {
+      view_id: view_id.0,
+      start_timestamp: self.start_timestamp,
+      end_timestamp: self.end_timestamp,
+    })
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct TimelineBarPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub title: String,
+
+  #[pb(index = 3)]
+  pub start_timestamp: i64,
+
+  #[pb(index = 4)]
+  pub end_timestamp: i64,
+
+  /// The ids of the rows that the row depends on. It's empty if the timeline doesn't have a
+  /// dependency field.
+  #[pb(index = 5)]
+  pub dependencies: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedTimelineBarPB {
+  #[pb(index = 1)]
+  pub items: Vec<TimelineBarPB>,
+}
+
+/// Moves or resizes a bar. The start and end dates of the row are set to the timestamps.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct MoveTimelineBarPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub start_timestamp: i64,
+
+  #[pb(index = 4)]
+  pub end_timestamp: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MoveTimelineBarParams {
+  pub view_id: String,
+  pub row_id: String,
+  pub start_timestamp: i64,
+  pub end_timestamp: i64,
+}
+
+impl TryInto<MoveTimelineBarParams> for MoveTimelineBarPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<MoveTimelineBarParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    if self.end_timestamp < self.start_timestamp {
+      return Err(ErrorCode::InvalidData);
+    }
+
+    Ok(MoveTimelineBarParams {
+      view_id: view_id.0,
+      row_id: row_id.0,
+      start_timestamp: self.start_timestamp,
+      end_timestamp: self.end_timestamp,
+    })
+  }
+}
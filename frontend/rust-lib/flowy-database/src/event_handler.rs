@@ -1,13 +1,16 @@
 use crate::entities::*;
-use crate::manager::DatabaseManager;
+use crate::manager::{create_new_database, DatabaseManager};
 use crate::services::cell::{FromCellString, ToCellChangesetString, TypeCellData};
 use crate::services::field::{
   default_type_option_builder_from_type, select_type_option_from_field_rev,
-  type_option_builder_from_json_str, DateCellChangeset, DateChangesetPB, SelectOptionCellChangeset,
-  SelectOptionCellChangesetPB, SelectOptionCellChangesetParams, SelectOptionCellDataPB,
-  SelectOptionChangeset, SelectOptionChangesetPB, SelectOptionIds, SelectOptionPB,
+  type_option_builder_from_json_str, DateCellChangeset, DateChangesetPB, RelationCellChangeset,
+  RelationCellChangesetPB, RelationCellChangesetParams, RepeatedRelatedRowPB,
+  SelectOptionCellChangeset, SelectOptionCellChangesetPB, SelectOptionCellChangesetParams,
+  SelectOptionCellDataPB, SelectOptionChangeset, SelectOptionChangesetPB, SelectOptionIds,
+  SelectOptionPB,
 };
 use crate::services::row::make_row_from_row_rev;
+use crate::services::share::csv::{CSVExport, CSVImporter};
 use database_model::FieldRevision;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
@@ -59,6 +62,16 @@ pub(crate) async fn update_database_setting_handler(
     editor.delete_filter(delete_filter).await?;
   }
 
+  if let Some(alter_filter_group) = params.insert_filter_group {
+    editor
+      .create_or_update_filter_group(alter_filter_group)
+      .await?;
+  }
+
+  if let Some(delete_filter_group) = params.delete_filter_group {
+    editor.delete_filter_group(delete_filter_group).await?;
+  }
+
   if let Some(alter_sort) = params.alert_sort {
     let _ = editor.create_or_update_sort(alter_sort).await?;
   }
@@ -129,6 +142,7 @@ pub(crate) async fn update_field_handler(
   let changeset: FieldChangesetParams = data.into_inner().try_into()?;
   let editor = manager.get_database_editor(&changeset.view_id).await?;
   editor.update_field(changeset).await?;
+  manager.did_update_fields(&editor.database_id).await?;
   Ok(())
 }
 
@@ -148,6 +162,7 @@ pub(crate) async fn update_field_type_option_handler(
       old_field_rev,
     )
     .await?;
+  manager.did_update_fields(&editor.database_id).await?;
   Ok(())
 }
 
@@ -159,6 +174,7 @@ pub(crate) async fn delete_field_handler(
   let params: FieldIdParams = data.into_inner().try_into()?;
   let editor = manager.get_database_editor(&params.view_id).await?;
   editor.delete_field(&params.field_id).await?;
+  manager.did_update_fields(&editor.database_id).await?;
   Ok(())
 }
 
@@ -190,7 +206,7 @@ pub(crate) async fn switch_to_field_handler(
       old_field_rev,
     )
     .await?;
-
+  manager.did_update_fields(&editor.database_id).await?;
   Ok(())
 }
 
@@ -239,6 +255,7 @@ pub(crate) async fn create_field_type_option_data_handler(
   let field_rev = editor
     .create_new_field_rev_with_type_option(&params.field_type, params.type_option_data)
     .await?;
+  manager.did_update_fields(&editor.database_id).await?;
   let field_type: FieldType = field_rev.ty.into();
   let type_option_data = get_type_option_data(&field_rev, &field_type).await?;
 
@@ -295,6 +312,20 @@ pub(crate) async fn get_row_handler(
   data_result_ok(OptionalRowPB { row })
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_row_document_handler(
+  data: AFPluginData<RowIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RowDocumentPB, FlowyError> {
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let document_id = editor.get_or_create_row_document(&params.row_id).await?;
+  data_result_ok(RowDocumentPB {
+    row_id: params.row_id,
+    document_id,
+  })
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn delete_row_handler(
   data: AFPluginData<RowIdPB>,
@@ -303,6 +334,9 @@ pub(crate) async fn delete_row_handler(
   let params: RowIdParams = data.into_inner().try_into()?;
   let editor = manager.get_database_editor(&params.view_id).await?;
   editor.delete_row(&params.row_id).await?;
+  manager
+    .did_delete_rows(&editor.database_id, vec![params.row_id])
+    .await?;
   Ok(())
 }
 
@@ -368,6 +402,9 @@ pub(crate) async fn update_cell_handler(
       changeset.type_cell_data,
     )
     .await?;
+  manager
+    .did_update_rows(&editor.database_id, vec![changeset.row_id])
+    .await?;
   Ok(())
 }
 
@@ -506,6 +543,9 @@ pub(crate) async fn update_select_option_cell_handler(
       changeset,
     )
     .await?;
+  manager
+    .did_update_rows(&editor.database_id, vec![params.cell_identifier.row_id])
+    .await?;
   Ok(())
 }
 
@@ -521,15 +561,60 @@ pub(crate) async fn update_date_cell_handler(
     time: data.time,
     include_time: data.include_time,
     is_utc: data.is_utc,
+    occurrence_override: None,
+    end_date: data.end_date,
+    end_time: data.end_time,
+    is_range: data.is_range,
+    timezone_id: data.timezone_id,
   };
 
   let editor = manager.get_database_editor(&cell_path.view_id).await?;
   editor
-    .update_cell(cell_path.row_id, cell_path.field_id, cell_changeset)
+    .update_cell(cell_path.row_id.clone(), cell_path.field_id, cell_changeset)
+    .await?;
+  manager
+    .did_update_rows(&editor.database_id, vec![cell_path.row_id])
     .await?;
   Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_relation_cell_handler(
+  data: AFPluginData<RelationCellChangesetPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: RelationCellChangesetParams = data.into_inner().try_into()?;
+  let editor = manager
+    .get_database_editor(&params.cell_identifier.view_id)
+    .await?;
+  let changeset = RelationCellChangeset {
+    inserted_row_ids: params.inserted_row_ids,
+    removed_row_ids: params.removed_row_ids,
+  };
+
+  editor
+    .update_cell_with_changeset(
+      &params.cell_identifier.row_id,
+      &params.cell_identifier.field_id,
+      changeset,
+    )
+    .await?;
+  manager
+    .did_update_rows(&editor.database_id, vec![params.cell_identifier.row_id])
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_related_rows_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRelatedRowPB, FlowyError> {
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let related_rows = manager.get_related_rows(&params).await?;
+  data_result_ok(related_rows.into())
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn get_groups_handler(
   data: AFPluginData<DatabaseViewIdPB>,
@@ -570,7 +655,11 @@ pub(crate) async fn move_group_row_handler(
 ) -> FlowyResult<()> {
   let params: MoveGroupRowParams = data.into_inner().try_into()?;
   let editor = manager.get_database_editor(params.view_id.as_ref()).await?;
+  let row_id = params.from_row_id.clone();
   editor.move_group_row(params).await?;
+  manager
+    .did_update_rows(&editor.database_id, vec![row_id])
+    .await?;
   Ok(())
 }
 
@@ -644,3 +733,243 @@ pub(crate) async fn get_calendar_event_handler(
     Some(event) => data_result_ok(event),
   }
 }
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_calendar_events_in_range_handler(
+  data: AFPluginData<CalendarEventRangeRequestPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedCalendarEventPB, FlowyError> {
+  let params: CalendarEventRangeRequestParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_editor(&params.view_id).await?;
+  let events = database_editor.get_calendar_events_in_range(params).await;
+  data_result_ok(RepeatedCalendarEventPB { items: events })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_calendar_event_occurrence_handler(
+  data: AFPluginData<UpdateCalendarEventOccurrencePB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: UpdateCalendarEventOccurrenceParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_editor(&params.view_id).await?;
+  database_editor
+    .update_calendar_event_occurrence(params)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_timeline_bars_handler(
+  data: AFPluginData<TimelineBarRequestPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedTimelineBarPB, FlowyError> {
+  let params: TimelineBarRequestParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_editor(&params.view_id).await?;
+  let bars = database_editor.get_timeline_bars(params).await;
+  data_result_ok(RepeatedTimelineBarPB { items: bars })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn move_timeline_bar_handler(
+  data: AFPluginData<MoveTimelineBarPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: MoveTimelineBarParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_editor(&params.view_id).await?;
+  let row_id = params.row_id.clone();
+  database_editor.move_timeline_bar(params).await?;
+  manager
+    .did_update_rows(&database_editor.database_id, vec![row_id])
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_gallery_cards_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedGalleryCardPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager.get_database_editor(view_id.as_ref()).await?;
+  let cards = database_editor.get_gallery_cards(view_id.as_ref()).await?;
+  data_result_ok(RepeatedGalleryCardPB { items: cards })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn export_csv_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager.open_database_view(view_id.as_ref()).await?;
+  let data = CSVExport
+    .export_database(view_id.as_ref(), &database_editor)
+    .await?;
+  data_result_ok(DatabaseExportDataPB { data })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn import_csv_handler(
+  data: AFPluginData<ImportCSVPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: ImportCSVParams = data.into_inner().try_into()?;
+  let build_context = CSVImporter.import_csv_from_string(&params.data)?;
+  create_new_database(
+    &params.view_id,
+    params.name,
+    LayoutTypePB::Grid,
+    manager.get_ref().clone(),
+    build_context,
+  )
+  .await
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn search_rows_handler(
+  data: AFPluginData<SearchRowsPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRowSearchResultPB, FlowyError> {
+  let params: SearchRowsParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let results = editor.search_rows(&params.view_id, &params.query).await?;
+  data_result_ok(results.into())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_date_group_condition_handler(
+  data: AFPluginData<UpdateDateGroupConditionPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: UpdateDateGroupConditionParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  editor.update_date_group_condition(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_number_group_setting_handler(
+  data: AFPluginData<UpdateNumberGroupSettingPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: UpdateNumberGroupSettingParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  editor.update_number_group_setting(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_text_group_setting_handler(
+  data: AFPluginData<UpdateTextGroupSettingPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: UpdateTextGroupSettingParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  editor.update_text_group_setting(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn set_sub_group_by_field_handler(
+  data: AFPluginData<SubGroupByFieldPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: SubGroupByFieldParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  editor.set_sub_group_by_field(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_calculation_handler(
+  data: AFPluginData<UpdateCalculationPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: UpdateCalculationParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  editor.update_calculation(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn delete_calculation_handler(
+  data: AFPluginData<DeleteCalculationPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: DeleteCalculationParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  editor.delete_calculation(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_calculation_values_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedCalculationValuePB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.open_database_view(view_id.as_ref()).await?;
+  let values = RepeatedCalculationValuePB {
+    items: editor.get_calculation_values(view_id.as_ref()).await?,
+  };
+  data_result_ok(values)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_row_activities_handler(
+  data: AFPluginData<RowIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRowActivityPB, FlowyError> {
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let activities = RepeatedRowActivityPB {
+    items: editor.get_row_activities(&params.row_id).await?,
+  };
+  data_result_ok(activities)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_row_comments_handler(
+  data: AFPluginData<RowIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRowCommentPB, FlowyError> {
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let comments = RepeatedRowCommentPB {
+    items: editor.get_row_comments(&params.row_id).await?,
+  };
+  data_result_ok(comments)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn create_row_comment_handler(
+  data: AFPluginData<CreateRowCommentPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RowCommentPB, FlowyError> {
+  let params: CreateRowCommentParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let comment = editor.create_row_comment(params).await?;
+  data_result_ok(comment)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_row_comment_handler(
+  data: AFPluginData<UpdateRowCommentPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RowCommentPB, FlowyError> {
+  let params: UpdateRowCommentParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let comment = editor.update_row_comment(params).await?;
+  data_result_ok(comment)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn delete_row_comment_handler(
+  data: AFPluginData<DeleteRowCommentPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: DeleteRowCommentParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  editor.delete_row_comment(params).await?;
+  Ok(())
+}
@@ -32,6 +32,7 @@ pub fn init(database_manager: Arc<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::GetRow, get_row_handler)
         .event(DatabaseEvent::DeleteRow, delete_row_handler)
         .event(DatabaseEvent::DuplicateRow, duplicate_row_handler)
+        .event(DatabaseEvent::GetRowDocument, get_row_document_handler)
         .event(DatabaseEvent::MoveRow, move_row_handler)
         // Cell
         .event(DatabaseEvent::GetCell, get_cell_handler)
@@ -43,19 +44,48 @@ pub fn init(database_manager: Arc<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::UpdateSelectOptionCell, update_select_option_cell_handler)
         // Date
         .event(DatabaseEvent::UpdateDateCell, update_date_cell_handler)
+        // Relation
+        .event(DatabaseEvent::UpdateRelationCell, update_relation_cell_handler)
+        .event(DatabaseEvent::GetRelatedRows, get_related_rows_handler)
         // Group
         .event(DatabaseEvent::MoveGroup, move_group_handler)
         .event(DatabaseEvent::MoveGroupRow, move_group_row_handler)
         .event(DatabaseEvent::GetGroups, get_groups_handler)
         .event(DatabaseEvent::GetGroup, get_group_handler)
+        .event(DatabaseEvent::UpdateDateGroupCondition, update_date_group_condition_handler)
+        .event(DatabaseEvent::UpdateNumberGroupSetting, update_number_group_setting_handler)
+        .event(DatabaseEvent::UpdateTextGroupSetting, update_text_group_setting_handler)
+        .event(DatabaseEvent::SetSubGroupByField, set_sub_group_by_field_handler)
+        // Calculation
+        .event(DatabaseEvent::UpdateCalculation, update_calculation_handler)
+        .event(DatabaseEvent::DeleteCalculation, delete_calculation_handler)
+        .event(DatabaseEvent::GetCalculationValues, get_calculation_values_handler)
+        // Row activity
+        .event(DatabaseEvent::GetRowActivities, get_row_activities_handler)
+        .event(DatabaseEvent::GetRowComments, get_row_comments_handler)
+        .event(DatabaseEvent::CreateRowComment, create_row_comment_handler)
+        .event(DatabaseEvent::UpdateRowComment, update_row_comment_handler)
+        .event(DatabaseEvent::DeleteRowComment, delete_row_comment_handler)
         // Database
         .event(DatabaseEvent::GetDatabases, get_databases_handler)
         // Calendar
         .event(DatabaseEvent::GetAllCalendarEvents, get_calendar_events_handler)
         .event(DatabaseEvent::GetCalendarEvent, get_calendar_event_handler)
+        .event(DatabaseEvent::GetCalendarEventsInRange, get_calendar_events_in_range_handler)
+        .event(DatabaseEvent::UpdateCalendarEventOccurrence, update_calendar_event_occurrence_handler)
+        // Timeline
+        .event(DatabaseEvent::GetTimelineBars, get_timeline_bars_handler)
+        .event(DatabaseEvent::MoveTimelineBar, move_timeline_bar_handler)
+        // Gallery
+        .event(DatabaseEvent::GetGalleryCards, get_gallery_cards_handler)
         // Layout setting
         .event(DatabaseEvent::SetLayoutSetting, set_layout_setting_handler)
-        .event(DatabaseEvent::GetLayoutSetting, get_layout_setting_handler);
+        .event(DatabaseEvent::GetLayoutSetting, get_layout_setting_handler)
+        // Import and export
+        .event(DatabaseEvent::ExportCSV, export_csv_handler)
+        .event(DatabaseEvent::ImportCSV, import_csv_handler)
+        // Search
+        .event(DatabaseEvent::SearchRows, search_rows_handler);
 
   plugin
 }
@@ -223,6 +253,17 @@ pub enum DatabaseEvent {
   #[event(input = "DateChangesetPB")]
   UpdateDateCell = 80,
 
+  /// [UpdateRelationCell] event is used to link or unlink the rows of the target database.
+  /// [RelationCellChangesetPB] contains the row ids that will be inserted or removed. It can be
+  /// cast to [CellChangesetPB] that will be used by the `update_cell` function.
+  #[event(input = "RelationCellChangesetPB")]
+  UpdateRelationCell = 90,
+
+  /// [GetRelatedRows] event is used to get the rows linked by the relation cell. Each row comes
+  /// with the content of its primary field in the target database.
+  #[event(input = "CellIdPB", output = "RepeatedRelatedRowPB")]
+  GetRelatedRows = 91,
+
   #[event(input = "DatabaseViewIdPB", output = "RepeatedGroupPB")]
   GetGroups = 100,
 
@@ -256,4 +297,100 @@ pub enum DatabaseEvent {
 
   #[event(input = "MoveCalendarEventPB")]
   MoveCalendarEvent = 119,
+
+  /// [ExportCSV] event is used to export the rows of the view as CSV. The rows are filtered and
+  /// sorted by the view, and each cell is exported as its display string.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
+  ExportCSV = 120,
+
+  /// [ImportCSV] event is used to create a new database from the CSV data. The first record is
+  /// the header, and the field types are inferred from the contents of the columns.
+  #[event(input = "ImportCSVPayloadPB")]
+  ImportCSV = 121,
+
+  /// [SearchRows] event is used to search the query in the rows of the view. It returns the
+  /// matched rows and the ranges of the matched text in each cell's display string.
+  #[event(input = "SearchRowsPayloadPB", output = "RepeatedRowSearchResultPB")]
+  SearchRows = 122,
+
+  /// [UpdateDateGroupCondition] event is used to change how the rows are grouped by the date
+  /// field, for example, by day or by month. The groups are regenerated with the new condition.
+  #[event(input = "UpdateDateGroupConditionPayloadPB")]
+  UpdateDateGroupCondition = 123,
+
+  /// [UpdateNumberGroupSetting] event is used to change the ranges or the bucket width that the
+  /// rows are grouped into by the number field.
+  #[event(input = "UpdateNumberGroupSettingPayloadPB")]
+  UpdateNumberGroupSetting = 124,
+
+  /// [UpdateTextGroupSetting] event is used to group the rows by the text field with or without
+  /// ignoring the case of the texts.
+  #[event(input = "UpdateTextGroupSettingPayloadPB")]
+  UpdateTextGroupSetting = 125,
+
+  /// [SetSubGroupByField] event is used to group the rows of each group by another field. The
+  /// sub grouping is removed if the field id is not set.
+  #[event(input = "SubGroupByFieldPayloadPB")]
+  SetSubGroupByField = 126,
+
+  /// [UpdateCalculation] event is used to set the calculation of the field, such as sum or
+  /// average. It replaces the field's previous calculation.
+  #[event(input = "UpdateCalculationPayloadPB")]
+  UpdateCalculation = 127,
+
+  #[event(input = "DeleteCalculationPayloadPB")]
+  DeleteCalculation = 128,
+
+  /// [GetCalculationValues] event is used to get the calculated values over the visible rows of
+  /// the view. The values of each group are returned as well if the view is a board.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedCalculationValuePB")]
+  GetCalculationValues = 129,
+
+  /// [GetRowActivities] event is used to get the changes of the row's cells, the latest first.
+  /// Each change contains the display strings of the cell before and after the change.
+  #[event(input = "RowIdPB", output = "RepeatedRowActivityPB")]
+  GetRowActivities = 130,
+
+  #[event(input = "RowIdPB", output = "RepeatedRowCommentPB")]
+  GetRowComments = 131,
+
+  #[event(input = "CreateRowCommentPayloadPB", output = "RowCommentPB")]
+  CreateRowComment = 132,
+
+  #[event(input = "UpdateRowCommentPayloadPB", output = "RowCommentPB")]
+  UpdateRowComment = 133,
+
+  #[event(input = "DeleteRowCommentPayloadPB")]
+  DeleteRowComment = 134,
+
+  /// [GetRowDocument] event is used to get the document that is used as the body of the row.
+  /// The document is created when the row's body is opened for the first time.
+  #[event(input = "RowIdPB", output = "RowDocumentPB")]
+  GetRowDocument = 135,
+
+  /// Returns the calendar events within the range. A repeated date is expanded into one event
+  /// for each occurrence.
+  #[event(
+    input = "CalendarEventRangeRequestPB",
+    output = "RepeatedCalendarEventPB"
+  )]
+  GetCalendarEventsInRange = 136,
+
+  /// Reschedules or cancels one occurrence of a repeated calendar event.
+  #[event(input = "UpdateCalendarEventOccurrencePB")]
+  UpdateCalendarEventOccurrence = 137,
+
+  /// Returns the bars of the timeline that overlap the date window.
+  #[event(input = "TimelineBarRequestPB", output = "RepeatedTimelineBarPB")]
+  GetTimelineBars = 138,
+
+  /// Moves or resizes a bar of the timeline. The dates are written back to the date cells of
+  /// the row.
+  #[event(input = "MoveTimelineBarPB")]
+  MoveTimelineBar = 139,
+
+  /// Returns the cards of the gallery with their covers, in the order of the filtered and
+  /// sorted rows.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedGalleryCardPB")]
+  GetGalleryCards = 140,
 }
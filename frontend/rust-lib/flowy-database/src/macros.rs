@@ -46,6 +46,33 @@ macro_rules! impl_type_option {
       }
     }
 
+    $crate::impl_type_option!(@serde $target);
+  };
+
+  // The type option that is shared by multiple field types is stored with the field's own type.
+  ($target: ident) => {
+    impl std::convert::From<&FieldRevision> for $target {
+      fn from(field_rev: &FieldRevision) -> $target {
+        match field_rev.get_type_option::<$target>(field_rev.ty) {
+          None => $target::default(),
+          Some(target) => target,
+        }
+      }
+    }
+
+    impl std::convert::From<&std::sync::Arc<FieldRevision>> for $target {
+      fn from(field_rev: &std::sync::Arc<FieldRevision>) -> $target {
+        match field_rev.get_type_option::<$target>(field_rev.ty) {
+          None => $target::default(),
+          Some(target) => target,
+        }
+      }
+    }
+
+    $crate::impl_type_option!(@serde $target);
+  };
+
+  (@serde $target: ident) => {
     impl std::convert::From<$target> for String {
       fn from(type_option: $target) -> String {
         type_option.json_str()
@@ -1,4 +1,5 @@
-use crate::entities::LayoutTypePB;
+use crate::entities::{CellIdParams, FieldType, LayoutTypePB};
+use crate::services::cell::{FromCellString, TypeCellData};
 use crate::services::database::{
   make_database_block_rev_manager, DatabaseEditor, DatabaseRefIndexerQuery,
   DatabaseRevisionCloudService, DatabaseRevisionMergeable, DatabaseRevisionSerde,
@@ -6,6 +7,10 @@ use crate::services::database::{
 use crate::services::database_view::{
   make_database_view_rev_manager, make_database_view_revision_pad, DatabaseViewEditor,
 };
+use crate::services::field::{
+  RelatedRowPB, RelationCellChangeset, RelationCellData, RelationTypeOptionPB, RollupCellData,
+  RollupTypeOptionPB,
+};
 use crate::services::persistence::block_index::BlockRowIndexer;
 use crate::services::persistence::database_ref::{DatabaseInfo, DatabaseRefs, DatabaseViewRef};
 use crate::services::persistence::kv::DatabaseKVPersistence;
@@ -13,23 +18,27 @@ use crate::services::persistence::migration::DatabaseMigration;
 use crate::services::persistence::rev_sqlite::{
   SQLiteDatabaseRevisionPersistence, SQLiteDatabaseRevisionSnapshotPersistence,
 };
+use crate::services::persistence::row_activity::RowActivityPersistence;
+use crate::services::persistence::row_comment::RowCommentPersistence;
 use crate::services::persistence::DatabaseDBConnection;
 use std::collections::HashMap;
 
+use crate::services::share::{ArchivedDatabase, DatabaseArchive};
 use database_model::{
-  gen_database_id, BuildDatabaseContext, DatabaseRevision, DatabaseViewRevision,
+  gen_database_id, BuildDatabaseContext, DatabaseBlockRevision, DatabaseRevision,
+  DatabaseViewRevision, FieldRevision, RowRevision,
 };
 use flowy_client_sync::client_database::{
   make_database_block_operations, make_database_operations, make_database_view_operations,
 };
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
 use flowy_revision::{
   RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration, RevisionWebSocket,
 };
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
 
-use lib_infra::future::Fut;
+use lib_infra::future::{Fut, FutureResult};
 use revision_model::Revision;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -40,11 +49,46 @@ pub trait DatabaseUser: Send + Sync {
   fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
 }
 
+/// Manages the documents that are used as the bodies of the rows. The documents are stored by
+/// the document module, so the database module uses them through this trait.
+pub trait DatabaseRowDocument: Send + Sync {
+  /// Creates an empty document with the id.
+  fn create_document(&self, document_id: &str) -> FutureResult<(), FlowyError>;
+
+  /// Creates a new document with the id `to_document_id` that has the same content as the
+  /// document `from_document_id`.
+  fn duplicate_document(
+    &self,
+    from_document_id: &str,
+    to_document_id: &str,
+  ) -> FutureResult<(), FlowyError>;
+
+  fn delete_document(&self, document_id: &str) -> FutureResult<(), FlowyError>;
+
+  /// Returns the rev_id of the latest revision of the document. It changes when the document is
+  /// edited.
+  fn document_rev_id(&self, document_id: &str) -> FutureResult<i64, FlowyError>;
+
+  /// Returns the source of the first image in the document without opening the document. It's
+  /// used as the cover of the gallery cards.
+  fn first_image_src(&self, document_id: &str) -> FutureResult<Option<String>, FlowyError>;
+
+  /// Returns the content of the document, which can be used to create the document again with
+  /// [DatabaseRowDocument::import_document].
+  fn export_document(&self, document_id: &str) -> FutureResult<String, FlowyError>;
+
+  /// Creates the document with the id from the exported content.
+  fn import_document(&self, document_id: &str, content: String) -> FutureResult<(), FlowyError>;
+}
+
 pub struct DatabaseManager {
   editors_by_database_id: RwLock<HashMap<String, Arc<DatabaseEditor>>>,
   database_user: Arc<dyn DatabaseUser>,
   block_indexer: Arc<BlockRowIndexer>,
   database_refs: Arc<DatabaseRefs>,
+  row_activities: Arc<RowActivityPersistence>,
+  row_comments: Arc<RowCommentPersistence>,
+  row_document: Arc<dyn DatabaseRowDocument>,
   #[allow(dead_code)]
   kv_persistence: Arc<DatabaseKVPersistence>,
   task_scheduler: Arc<RwLock<TaskDispatcher>>,
@@ -58,10 +102,13 @@ impl DatabaseManager {
     _rev_web_socket: Arc<dyn RevisionWebSocket>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
     database_db: Arc<dyn DatabaseDBConnection>,
+    row_document: Arc<dyn DatabaseRowDocument>,
   ) -> Self {
     let editors_by_database_id = RwLock::new(HashMap::new());
     let kv_persistence = Arc::new(DatabaseKVPersistence::new(database_db.clone()));
     let block_indexer = Arc::new(BlockRowIndexer::new(database_db.clone()));
+    let row_activities = Arc::new(RowActivityPersistence::new(database_db.clone()));
+    let row_comments = Arc::new(RowCommentPersistence::new(database_db.clone()));
     let database_refs = Arc::new(DatabaseRefs::new(database_db));
     let migration = DatabaseMigration::new(database_user.clone(), database_refs.clone());
     Self {
@@ -70,6 +117,9 @@ impl DatabaseManager {
       kv_persistence,
       block_indexer,
       database_refs,
+      row_activities,
+      row_comments,
+      row_document,
       task_scheduler,
       migration,
     }
@@ -174,6 +224,27 @@ impl DatabaseManager {
     Ok(())
   }
 
+  /// Deletes the view of the database. The rows are shared by all the views of the database, so
+  /// the documents of the rows are deleted along with the last view.
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub async fn delete_database_view<T: AsRef<str>>(&self, view_id: T) -> FlowyResult<()> {
+    let view_id = view_id.as_ref();
+    let database_info = self.database_refs.get_database_with_view(view_id)?;
+    let is_last_view = self
+      .database_refs
+      .get_ref_views_with_database(&database_info.database_id)?
+      .iter()
+      .all(|view_ref| view_ref.view_id == view_id);
+    if is_last_view {
+      let database_editor = self.get_database_editor(view_id).await?;
+      database_editor.delete_row_documents().await?;
+    }
+
+    self.close_database_view(view_id).await?;
+    self.database_refs.unbind(view_id)?;
+    Ok(())
+  }
+
   // #[tracing::instrument(level = "debug", skip(self), err)]
   pub async fn get_database_editor(&self, view_id: &str) -> FlowyResult<Arc<DatabaseEditor>> {
     let database_info = self.database_refs.get_database_with_view(view_id)?;
@@ -192,10 +263,374 @@ impl DatabaseManager {
     }
   }
 
+  /// Returns the editor of the database. The database is opened with its first view if it isn't
+  /// opened yet.
+  pub async fn get_database_editor_with_database_id(
+    &self,
+    database_id: &str,
+  ) -> FlowyResult<Arc<DatabaseEditor>> {
+    let database_editor = self
+      .editors_by_database_id
+      .read()
+      .await
+      .get(database_id)
+      .cloned();
+    if let Some(database_editor) = database_editor {
+      return Ok(database_editor);
+    }
+
+    let view_ref = self
+      .database_refs
+      .get_ref_views_with_database(database_id)?
+      .into_iter()
+      .next()
+      .ok_or_else(|| {
+        FlowyError::record_not_found().context(format!("Can't find the database: {}", database_id))
+      })?;
+    self.get_database_editor(&view_ref.view_id).await
+  }
+
+  /// Returns the rows that are linked by the relation cell. The name of each row is the content of
+  /// its primary field in the target database. The rows that don't exist anymore are unlinked.
+  pub async fn get_related_rows(&self, params: &CellIdParams) -> FlowyResult<Vec<RelatedRowPB>> {
+    let editor = self.get_database_editor(&params.view_id).await?;
+    let field_rev = editor
+      .get_field_rev(&params.field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    if !FieldType::from(field_rev.ty).is_relation() {
+      return Err(FlowyError::invalid_data().context("The field is not a relation field"));
+    }
+
+    let row_ids = editor
+      .get_cell_rev(&params.row_id, &params.field_id)
+      .await?
+      .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+      .filter(|type_cell_data| type_cell_data.is_relation())
+      .and_then(|type_cell_data| RelationCellData::from_cell_str(&type_cell_data.cell_str).ok())
+      .map(|cell_data| cell_data.row_ids)
+      .unwrap_or_default();
+    if row_ids.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let type_option = RelationTypeOptionPB::from(&field_rev);
+    let target_editor = self
+      .get_database_editor_with_database_id(&type_option.database_id)
+      .await?;
+    let mut related_rows = vec![];
+    let mut removed_row_ids = vec![];
+    for row_id in row_ids {
+      match target_editor.get_row_name(&row_id).await {
+        None => removed_row_ids.push(row_id),
+        Some(name) => related_rows.push(RelatedRowPB { row_id, name }),
+      }
+    }
+
+    if !removed_row_ids.is_empty() {
+      let changeset = RelationCellChangeset::from_remove_row_ids(removed_row_ids);
+      editor
+        .update_cell_with_changeset(&params.row_id, &params.field_id, changeset)
+        .await?;
+    }
+    Ok(related_rows)
+  }
+
+  /// Unlinks the deleted rows from the relation cells of the opened databases. The databases that
+  /// are not opened unlink them when their related rows are read.
+  pub async fn did_delete_rows(&self, database_id: &str, row_ids: Vec<String>) -> FlowyResult<()> {
+    for editor in self.get_opened_editors().await {
+      let updated_row_ids = editor.remove_related_row_ids(database_id, &row_ids).await?;
+      if !updated_row_ids.is_empty() {
+        self
+          .refresh_rollup_cells(&editor, |row_rev, _, _| {
+            updated_row_ids.contains(&row_rev.id)
+          })
+          .await?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Recalculates the rollup cells that depend on the updated rows. They are the rollup cells of
+  /// the updated rows themselves and the ones that link the updated rows.
+  pub async fn did_update_rows(&self, database_id: &str, row_ids: Vec<String>) -> FlowyResult<()> {
+    for editor in self.get_opened_editors().await {
+      let is_updated_database = editor.database_id == database_id;
+      self
+        .refresh_rollup_cells(&editor, |row_rev, related_database_id, cell_data| {
+          (is_updated_database && row_ids.contains(&row_rev.id))
+            || (related_database_id == database_id && cell_data.contains_any(&row_ids))
+        })
+        .await?;
+    }
+    Ok(())
+  }
+
+  /// Recalculates the rollup cells of the database and the rollup cells that aggregate its rows.
+  /// It's called after the fields of the database are changed.
+  pub async fn did_update_fields(&self, database_id: &str) -> FlowyResult<()> {
+    for editor in self.get_opened_editors().await {
+      let is_updated_database = editor.database_id == database_id;
+      self
+        .refresh_rollup_cells(&editor, |_, related_database_id, _| {
+          is_updated_database || related_database_id == database_id
+        })
+        .await?;
+    }
+    Ok(())
+  }
+
+  async fn get_opened_editors(&self) -> Vec<Arc<DatabaseEditor>> {
+    self
+      .editors_by_database_id
+      .read()
+      .await
+      .values()
+      .cloned()
+      .collect::<Vec<Arc<DatabaseEditor>>>()
+  }
+
+  /// Recalculates the rollup cells of the editor. The `is_affected` callback receives the row, the
+  /// id of the database its relation links to and the linked rows, and decides whether the row's
+  /// rollup cell needs to be recalculated.
+  async fn refresh_rollup_cells<F>(
+    &self,
+    editor: &Arc<DatabaseEditor>,
+    is_affected: F,
+  ) -> FlowyResult<()>
+  where
+    F: Fn(&RowRevision, &str, &RelationCellData) -> bool,
+  {
+    let field_revs = editor.get_field_revs(None).await?;
+    let rollup_field_revs = field_revs
+      .iter()
+      .filter(|field_rev| FieldType::from(field_rev.ty).is_rollup())
+      .cloned()
+      .collect::<Vec<Arc<FieldRevision>>>();
+    if rollup_field_revs.is_empty() {
+      return Ok(());
+    }
+
+    let row_revs = editor
+      .get_blocks(None)
+      .await?
+      .into_iter()
+      .flat_map(|block| block.row_revs)
+      .collect::<Vec<Arc<RowRevision>>>();
+    for rollup_field_rev in rollup_field_revs {
+      let type_option = RollupTypeOptionPB::from(&rollup_field_rev);
+      let relation_field_rev = field_revs.iter().find(|field_rev| {
+        field_rev.id == type_option.relation_field_id && FieldType::from(field_rev.ty).is_relation()
+      });
+      let related_database_id = match relation_field_rev {
+        None => continue,
+        Some(field_rev) => RelationTypeOptionPB::from(field_rev).database_id,
+      };
+      let related_editor = match self
+        .get_database_editor_with_database_id(&related_database_id)
+        .await
+      {
+        Ok(related_editor) => related_editor,
+        Err(err) => {
+          tracing::error!("Can't open the related database: {:?}", err);
+          continue;
+        },
+      };
+      let target_field_rev = match related_editor
+        .get_field_rev(&type_option.target_field_id)
+        .await
+      {
+        None => continue,
+        Some(target_field_rev) => target_field_rev,
+      };
+
+      for row_rev in row_revs.iter() {
+        let relation_cell_data = row_rev
+          .cells
+          .get(&type_option.relation_field_id)
+          .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+          .filter(|type_cell_data| type_cell_data.is_relation())
+          .and_then(|type_cell_data| RelationCellData::from_cell_str(&type_cell_data.cell_str).ok())
+          .unwrap_or_default();
+        if !is_affected(row_rev, &related_database_id, &relation_cell_data) {
+          continue;
+        }
+
+        // The rows that are deleted but not unlinked yet are skipped.
+        let mut cell_revs = vec![];
+        for row_id in relation_cell_data.row_ids.iter() {
+          if let Some(related_row_rev) = related_editor.get_row_rev(row_id).await? {
+            cell_revs.push(related_row_rev.cells.get(&target_field_rev.id).cloned());
+          }
+        }
+        let cell_data = type_option.calculate(&target_field_rev, &cell_revs);
+        let old_cell_data = row_rev
+          .cells
+          .get(&rollup_field_rev.id)
+          .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+          .filter(|type_cell_data| type_cell_data.is_rollup())
+          .and_then(|type_cell_data| RollupCellData::from_cell_str(&type_cell_data.cell_str).ok());
+        if old_cell_data.as_ref() != Some(&cell_data) {
+          editor
+            .update_cell_with_changeset(&row_rev.id, &rollup_field_rev.id, cell_data.to_string())
+            .await?;
+        }
+      }
+    }
+    Ok(())
+  }
+
   pub async fn get_databases(&self) -> FlowyResult<Vec<DatabaseInfo>> {
     self.database_refs.get_all_databases()
   }
 
+  /// Exports the databases of the views. The views that are linked to the same database are
+  /// exported along with it.
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub async fn export_databases(&self, view_ids: Vec<String>) -> FlowyResult<DatabaseArchive> {
+    let mut view_ids_by_database_id: Vec<(String, Vec<String>)> = vec![];
+    for view_id in view_ids {
+      let database_id = self
+        .database_refs
+        .get_database_with_view(&view_id)?
+        .database_id;
+      match view_ids_by_database_id
+        .iter_mut()
+        .find(|(id, _)| id == &database_id)
+      {
+        None => view_ids_by_database_id.push((database_id, vec![view_id])),
+        Some((_, view_ids)) => view_ids.push(view_id),
+      }
+    }
+
+    let mut databases = vec![];
+    for (database_id, view_ids) in view_ids_by_database_id {
+      let mut views = vec![];
+      for view_id in view_ids.iter() {
+        let editor = self.open_database_view(view_id).await?;
+        views.push(editor.get_database_view_data(view_id).await?);
+      }
+
+      let editor = self.open_database_view(&view_ids[0]).await?;
+      let fields = editor
+        .get_field_revs(None)
+        .await?
+        .into_iter()
+        .map(|field_rev| field_rev.as_ref().clone())
+        .collect();
+      let block_metas = editor
+        .get_block_meta_revs()
+        .await?
+        .into_iter()
+        .map(|block_meta| block_meta.as_ref().clone())
+        .collect();
+      let blocks = editor
+        .get_blocks(None)
+        .await?
+        .into_iter()
+        .map(|block| DatabaseBlockRevision {
+          block_id: block.block_id,
+          rows: block.row_revs,
+        })
+        .collect::<Vec<DatabaseBlockRevision>>();
+
+      let mut row_documents = HashMap::new();
+      for row_rev in blocks.iter().flat_map(|block| block.rows.iter()) {
+        if let Some(document_id) = row_rev.document_id.as_ref() {
+          let content = self.row_document.export_document(document_id).await?;
+          row_documents.insert(document_id.clone(), content);
+        }
+      }
+
+      databases.push(ArchivedDatabase {
+        database_id,
+        fields,
+        block_metas,
+        blocks,
+        views,
+        row_documents,
+      });
+    }
+    Ok(DatabaseArchive { databases })
+  }
+
+  /// Imports the databases of the archive under new ids. The `view_id_map` maps the ids of the
+  /// views in the archive to the ids of the imported views.
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub async fn import_databases(
+    &self,
+    mut archive: DatabaseArchive,
+    view_id_map: &HashMap<String, String>,
+  ) -> FlowyResult<()> {
+    archive.regenerate_ids(view_id_map)?;
+    for database in archive.databases {
+      let view_revs = database
+        .views
+        .into_iter()
+        .map(DatabaseViewRevision::from_json)
+        .collect::<Result<Vec<DatabaseViewRevision>, _>>()
+        .map_err(internal_error)?;
+      let base_view_rev = match view_revs.first() {
+        None => continue,
+        Some(view_rev) => view_rev,
+      };
+
+      for block in database.blocks.iter() {
+        block.rows.iter().for_each(|row| {
+          let _ = self.block_indexer.insert(&row.block_id, &row.id);
+        });
+        let database_block_ops = make_database_block_operations(block);
+        let revision = Revision::initial_revision(&block.block_id, database_block_ops.json_bytes());
+        self
+          .create_database_block(&block.block_id, vec![revision])
+          .await?;
+      }
+
+      let field_revs = database.fields.into_iter().map(Arc::new).collect();
+      let database_rev = DatabaseRevision::from_build_context(
+        &database.database_id,
+        field_revs,
+        database.block_metas,
+      );
+      let database_ops = make_database_operations(&database_rev);
+      let revision = Revision::initial_revision(&database.database_id, database_ops.json_bytes());
+      self
+        .create_database(
+          &database.database_id,
+          &base_view_rev.view_id,
+          &base_view_rev.name,
+          vec![revision],
+        )
+        .await?;
+
+      for view_rev in view_revs.iter() {
+        let database_view_ops = make_database_view_operations(view_rev);
+        let revision =
+          Revision::initial_revision(&view_rev.view_id, database_view_ops.json_bytes());
+        self
+          .create_database_view(&view_rev.view_id, vec![revision])
+          .await?;
+        if !view_rev.is_base {
+          let _ = self.database_refs.bind(
+            &database.database_id,
+            &view_rev.view_id,
+            false,
+            &view_rev.name,
+          );
+        }
+      }
+
+      for (document_id, content) in database.row_documents {
+        self
+          .row_document
+          .import_document(&document_id, content)
+          .await?;
+      }
+    }
+    Ok(())
+  }
+
   pub async fn get_database_ref_views(
     &self,
     database_id: &str,
@@ -283,6 +718,9 @@ impl DatabaseManager {
       rev_manager,
       self.block_indexer.clone(),
       self.database_refs.clone(),
+      self.row_activities.clone(),
+      self.row_comments.clone(),
+      self.row_document.clone(),
       self.task_scheduler.clone(),
     )
     .await?;
@@ -29,8 +29,18 @@ pub enum DatabaseNotification {
   DidReorderRows = 65,
   /// Trigger after editing the row that hit the sort rule
   DidReorderSingleRow = 66,
+  /// Trigger after the number of sub groups is changed
+  DidUpdateSubGroups = 67,
+  /// Trigger after inserting/deleting/updating/moving a row in the sub groups
+  DidUpdateSubGroupRow = 68,
+  /// Trigger after the calculated values of the fields are changed
+  DidUpdateCalculations = 69,
   /// Trigger when the settings of the database are changed
   DidUpdateSettings = 70,
+  /// Trigger after editing a cell of the row, which appends an activity to the row
+  DidUpdateRowActivities = 71,
+  /// Trigger after creating/updating/deleting a comment of the row
+  DidUpdateRowComments = 72,
   // Trigger when the layout setting of the database is updated
   DidUpdateLayoutSettings = 80,
   // Trigger when the layout field of the database is changed
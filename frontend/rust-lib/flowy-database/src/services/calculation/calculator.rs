@@ -0,0 +1,198 @@
+use crate::entities::FieldType;
+use crate::services::cell::{
+  get_type_cell_data, stringify_cell_data, AtomicCellDataCache, TypeCellData,
+};
+use crate::services::field::{
+  CheckboxCellData, DateCellData, NumberCellData, NumberTypeOptionPB, StrCellData,
+};
+use database_model::{CalculationTypeRevision, CellRevision, FieldRevision, RowRevision};
+use rust_decimal::prelude::ToPrimitive;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Calculates the value of the field over the rows. The value is an empty string if it can't be
+/// calculated, for example, the average of a field that has no numbers.
+///
+/// The dates are returned as timestamps and the percentages are in the range of 0 to 100, so the
+/// client can format them with the field's setting.
+pub fn calculate(
+  calculation_type: &CalculationTypeRevision,
+  field_rev: &FieldRevision,
+  row_revs: &[Arc<RowRevision>],
+  cell_data_cache: &AtomicCellDataCache,
+) -> String {
+  let cells = row_revs
+    .iter()
+    .map(|row_rev| row_rev.cells.get(&field_rev.id))
+    .collect::<Vec<Option<&CellRevision>>>();
+
+  match calculation_type {
+    CalculationTypeRevision::Count => cells.len().to_string(),
+    CalculationTypeRevision::CountEmpty => cells
+      .iter()
+      .filter(|cell_rev| cell_display_str(**cell_rev, field_rev).is_empty())
+      .count()
+      .to_string(),
+    CalculationTypeRevision::CountUnique => cells
+      .iter()
+      .map(|cell_rev| cell_display_str(*cell_rev, field_rev))
+      .filter(|s| !s.is_empty())
+      .collect::<HashSet<String>>()
+      .len()
+      .to_string(),
+    CalculationTypeRevision::Sum => {
+      let numbers = cell_numbers(&cells, field_rev, cell_data_cache);
+      format_number(numbers.iter().sum())
+    },
+    CalculationTypeRevision::Average => {
+      let numbers = cell_numbers(&cells, field_rev, cell_data_cache);
+      if numbers.is_empty() {
+        "".to_owned()
+      } else {
+        format_number(numbers.iter().sum::<f64>() / numbers.len() as f64)
+      }
+    },
+    CalculationTypeRevision::Median => {
+      let numbers = cell_numbers(&cells, field_rev, cell_data_cache);
+      median(numbers).map(format_number).unwrap_or_default()
+    },
+    CalculationTypeRevision::Min => cell_numbers(&cells, field_rev, cell_data_cache)
+      .into_iter()
+      .reduce(f64::min)
+      .map(format_number)
+      .unwrap_or_default(),
+    CalculationTypeRevision::Max => cell_numbers(&cells, field_rev, cell_data_cache)
+      .into_iter()
+      .reduce(f64::max)
+      .map(format_number)
+      .unwrap_or_default(),
+    CalculationTypeRevision::PercentChecked => {
+      if cells.is_empty() {
+        return "".to_owned();
+      }
+      let checked = cells
+        .iter()
+        .filter(|cell_rev| {
+          cell_rev
+            .and_then(|cell_rev| {
+              get_type_cell_data::<_, CheckboxCellData>(
+                cell_rev,
+                field_rev,
+                Some(cell_data_cache.clone()),
+              )
+            })
+            .map(|cell_data| cell_data.is_check())
+            .unwrap_or(false)
+        })
+        .count();
+      format_number(checked as f64 * 100.0 / cells.len() as f64)
+    },
+    CalculationTypeRevision::EarliestDate => cell_timestamps(&cells, field_rev, cell_data_cache)
+      .into_iter()
+      .min()
+      .map(|timestamp| timestamp.to_string())
+      .unwrap_or_default(),
+    CalculationTypeRevision::LatestDate => cell_timestamps(&cells, field_rev, cell_data_cache)
+      .into_iter()
+      .max()
+      .map(|timestamp| timestamp.to_string())
+      .unwrap_or_default(),
+  }
+}
+
+/// Returns the display string of the cell, or an empty string if the cell doesn't exist.
+fn cell_display_str(cell_rev: Option<&CellRevision>, field_rev: &FieldRevision) -> String {
+  let field_type: FieldType = field_rev.ty.into();
+  cell_rev
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .map(|type_cell_data| {
+      stringify_cell_data(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &field_type,
+        field_rev,
+      )
+    })
+    .map(|s| s.trim().to_owned())
+    .unwrap_or_default()
+}
+
+/// Returns the numbers of the cells. The empty cells are skipped.
+fn cell_numbers(
+  cells: &[Option<&CellRevision>],
+  field_rev: &FieldRevision,
+  cell_data_cache: &AtomicCellDataCache,
+) -> Vec<f64> {
+  let type_option = match field_rev.get_type_option::<NumberTypeOptionPB>(field_rev.ty) {
+    None => return vec![],
+    Some(type_option) => type_option,
+  };
+
+  cells
+    .iter()
+    .flatten()
+    .flat_map(|cell_rev| {
+      let cell_data =
+        get_type_cell_data::<_, StrCellData>(*cell_rev, field_rev, Some(cell_data_cache.clone()))?;
+      // The decoded cell data is formatted with the number format of the field
+      let s = cell_data.trim();
+      let number_cell_data = match s.strip_prefix('-') {
+        None => NumberCellData::from_format_str(s, true, &type_option.format),
+        Some(s) => NumberCellData::from_format_str(s, false, &type_option.format),
+      };
+      number_cell_data.ok()?.decimal().as_ref()?.to_f64()
+    })
+    .collect()
+}
+
+/// Returns the timestamps of the cells. The empty cells are skipped.
+fn cell_timestamps(
+  cells: &[Option<&CellRevision>],
+  field_rev: &FieldRevision,
+  cell_data_cache: &AtomicCellDataCache,
+) -> Vec<i64> {
+  cells
+    .iter()
+    .flatten()
+    .flat_map(|cell_rev| {
+      get_type_cell_data::<_, DateCellData>(*cell_rev, field_rev, Some(cell_data_cache.clone()))?
+        .timestamp
+    })
+    .collect()
+}
+
+fn median(mut numbers: Vec<f64>) -> Option<f64> {
+  if numbers.is_empty() {
+    return None;
+  }
+  numbers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+  let mid = numbers.len() / 2;
+  if numbers.len() % 2 == 0 {
+    Some((numbers[mid - 1] + numbers[mid]) / 2.0)
+  } else {
+    Some(numbers[mid])
+  }
+}
+
+fn format_number(number: f64) -> String {
+  number.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::calculation::calculator::{format_number, median};
+
+  #[test]
+  fn median_test() {
+    assert_eq!(median(vec![]), None);
+    assert_eq!(median(vec![3.0, 1.0, 2.0]), Some(2.0));
+    assert_eq!(median(vec![4.0, 1.0, 3.0, 2.0]), Some(2.5));
+  }
+
+  #[test]
+  fn format_number_test() {
+    assert_eq!(format_number(3.0), "3");
+    assert_eq!(format_number(-2.5), "-2.5");
+  }
+}
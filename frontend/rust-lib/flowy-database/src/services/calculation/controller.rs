@@ -0,0 +1,220 @@
+use crate::services::calculation::{calculate, CalculationResultNotification, CalculationValue};
+use crate::services::cell::AtomicCellDataCache;
+use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
+use crate::services::group::Group;
+use database_model::{CalculationRevision, FieldRevision, RowRevision};
+use flowy_error::FlowyResult;
+use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
+use lib_infra::future::Fut;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+pub trait CalculationDelegate: Send + Sync {
+  fn get_calculation_revs(&self) -> Fut<Vec<Arc<CalculationRevision>>>;
+  /// Returns all the rows after applying the view's filter
+  fn get_row_revs(&self) -> Fut<Vec<Arc<RowRevision>>>;
+  fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> Fut<Vec<Arc<FieldRevision>>>;
+  /// Returns the groups if the view displays the rows by groups, for example, the board.
+  fn get_groups(&self) -> Fut<Vec<Group>>;
+}
+
+/// The key of the [CalculationValue] in the cache, (group_id, field_id).
+type CalculationValueKey = (Option<String>, String);
+
+pub struct CalculationController {
+  view_id: String,
+  handler_id: String,
+  delegate: Box<dyn CalculationDelegate>,
+  task_scheduler: Arc<RwLock<TaskDispatcher>>,
+  cell_data_cache: AtomicCellDataCache,
+  /// The values that were sent to the client. Only the changed values will be sent after the
+  /// rows changed.
+  value_cache: HashMap<CalculationValueKey, CalculationValue>,
+  notifier: DatabaseViewChangedNotifier,
+}
+
+impl Drop for CalculationController {
+  fn drop(&mut self) {
+    tracing::trace!("Drop {}", std::any::type_name::<Self>());
+  }
+}
+
+impl CalculationController {
+  pub fn new<T>(
+    view_id: &str,
+    handler_id: &str,
+    delegate: T,
+    task_scheduler: Arc<RwLock<TaskDispatcher>>,
+    cell_data_cache: AtomicCellDataCache,
+    notifier: DatabaseViewChangedNotifier,
+  ) -> Self
+  where
+    T: CalculationDelegate + 'static,
+  {
+    Self {
+      view_id: view_id.to_string(),
+      handler_id: handler_id.to_string(),
+      delegate: Box::new(delegate),
+      task_scheduler,
+      cell_data_cache,
+      value_cache: Default::default(),
+      notifier,
+    }
+  }
+
+  pub async fn close(&self) {
+    if let Ok(mut task_scheduler) = self.task_scheduler.try_write() {
+      task_scheduler.unregister_handler(&self.handler_id).await;
+    } else {
+      tracing::error!("Try to get the lock of task_scheduler failed");
+    }
+  }
+
+  /// Recalculates the values after the rows of the view or the visibility of the rows changed.
+  pub async fn did_receive_rows_changed(&self) {
+    self
+      .gen_task(
+        CalculationEvent::RowsDidChanged,
+        QualityOfService::Background,
+      )
+      .await;
+  }
+
+  /// Recalculates all the values after the calculations or the groups of the view changed.
+  pub async fn did_receive_changes(&self) {
+    self
+      .gen_task(
+        CalculationEvent::CalculationDidChanged,
+        QualityOfService::UserInteractive,
+      )
+      .await;
+  }
+
+  #[tracing::instrument(name = "process_calculation_task", level = "trace", skip_all, err)]
+  pub async fn process(&mut self, predicate: &str) -> FlowyResult<()> {
+    let event_type = CalculationEvent::from_str(predicate).unwrap();
+    let values = self.calculate_all().await;
+    let value_by_key = values
+      .into_iter()
+      .map(|value| ((value.group_id.clone(), value.field_id.clone()), value))
+      .collect::<HashMap<CalculationValueKey, CalculationValue>>();
+
+    let changed_values = match event_type {
+      CalculationEvent::CalculationDidChanged => value_by_key.values().cloned().collect(),
+      CalculationEvent::RowsDidChanged => value_by_key
+        .iter()
+        .filter(|(key, value)| self.value_cache.get(*key) != Some(*value))
+        .map(|(_, value)| value.clone())
+        .collect::<Vec<CalculationValue>>(),
+    };
+    self.value_cache = value_by_key;
+
+    if !changed_values.is_empty() {
+      let notification = CalculationResultNotification {
+        view_id: self.view_id.clone(),
+        values: changed_values,
+      };
+      let _ = self
+        .notifier
+        .send(DatabaseViewChanged::CalculationNotification(notification));
+    }
+    Ok(())
+  }
+
+  #[tracing::instrument(name = "schedule_calculation_task", level = "trace", skip(self))]
+  async fn gen_task(&self, task_type: CalculationEvent, qos: QualityOfService) {
+    let task_id = self.task_scheduler.read().await.next_task_id();
+    let task = Task::new(
+      &self.handler_id,
+      task_id,
+      TaskContent::Text(task_type.to_string()),
+      qos,
+    );
+    self.task_scheduler.write().await.add_task(task);
+  }
+
+  /// Returns the values of all the calculations over the visible rows of the view, followed by
+  /// the values over the visible rows of each group.
+  pub async fn calculate_all(&self) -> Vec<CalculationValue> {
+    let calculation_revs = self.delegate.get_calculation_revs().await;
+    if calculation_revs.is_empty() {
+      return vec![];
+    }
+
+    let field_revs = self.delegate.get_field_revs(None).await;
+    let row_revs = self.delegate.get_row_revs().await;
+    let groups = self.delegate.get_groups().await;
+
+    let mut values = self.calculate_rows(&calculation_revs, &field_revs, &row_revs, None);
+    for group in groups {
+      let row_ids = group
+        .rows
+        .iter()
+        .map(|row| row.id.as_str())
+        .collect::<HashSet<&str>>();
+      let group_row_revs = row_revs
+        .iter()
+        .filter(|row_rev| row_ids.contains(row_rev.id.as_str()))
+        .cloned()
+        .collect::<Vec<Arc<RowRevision>>>();
+      values.extend(self.calculate_rows(
+        &calculation_revs,
+        &field_revs,
+        &group_row_revs,
+        Some(group.id.clone()),
+      ));
+    }
+    values
+  }
+
+  fn calculate_rows(
+    &self,
+    calculation_revs: &[Arc<CalculationRevision>],
+    field_revs: &[Arc<FieldRevision>],
+    row_revs: &[Arc<RowRevision>],
+    group_id: Option<String>,
+  ) -> Vec<CalculationValue> {
+    calculation_revs
+      .iter()
+      .flat_map(|calculation_rev| {
+        let field_rev = field_revs
+          .iter()
+          .find(|field_rev| field_rev.id == calculation_rev.field_id)?;
+        let value = calculate(
+          &calculation_rev.calculation_type,
+          field_rev,
+          row_revs,
+          &self.cell_data_cache,
+        );
+        Some(CalculationValue {
+          field_id: field_rev.id.clone(),
+          group_id: group_id.clone(),
+          calculation_type: calculation_rev.calculation_type.clone(),
+          value,
+        })
+      })
+      .collect()
+  }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+enum CalculationEvent {
+  CalculationDidChanged,
+  RowsDidChanged,
+}
+
+impl ToString for CalculationEvent {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap()
+  }
+}
+
+impl FromStr for CalculationEvent {
+  type Err = serde_json::Error;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    serde_json::from_str(s)
+  }
+}
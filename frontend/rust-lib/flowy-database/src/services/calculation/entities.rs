@@ -0,0 +1,39 @@
+use crate::entities::FieldType;
+use database_model::CalculationTypeRevision;
+
+/// The calculated value of a field. The value is calculated over all the visible rows of the
+/// view if the `group_id` is None, otherwise over the visible rows of the group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalculationValue {
+  pub field_id: String,
+  pub group_id: Option<String>,
+  pub calculation_type: CalculationTypeRevision,
+  pub value: String,
+}
+
+#[derive(Clone)]
+pub struct CalculationResultNotification {
+  pub view_id: String,
+  pub values: Vec<CalculationValue>,
+}
+
+/// Returns true if the calculation can be applied to the cells of the field type.
+pub fn is_calculation_supported(
+  calculation_type: &CalculationTypeRevision,
+  field_type: &FieldType,
+) -> bool {
+  match calculation_type {
+    CalculationTypeRevision::Count
+    | CalculationTypeRevision::CountEmpty
+    | CalculationTypeRevision::CountUnique => true,
+    CalculationTypeRevision::Sum
+    | CalculationTypeRevision::Average
+    | CalculationTypeRevision::Median
+    | CalculationTypeRevision::Min
+    | CalculationTypeRevision::Max => field_type.is_number(),
+    CalculationTypeRevision::PercentChecked => field_type.is_checkbox(),
+    CalculationTypeRevision::EarliestDate | CalculationTypeRevision::LatestDate => {
+      field_type.is_date() || field_type.is_timestamp()
+    },
+  }
+}
@@ -0,0 +1,9 @@
+mod calculator;
+mod controller;
+mod entities;
+mod task;
+
+pub use calculator::*;
+pub use controller::*;
+pub use entities::*;
+pub use task::*;
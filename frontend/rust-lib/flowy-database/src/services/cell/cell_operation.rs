@@ -3,7 +3,7 @@ use crate::services::cell::{AtomicCellDataCache, CellProtobufBlob, TypeCellData}
 use crate::services::field::*;
 
 use crate::services::group::make_no_status_group;
-use database_model::{CellRevision, FieldRevision};
+use database_model::{CellRevision, FieldRevision, RowRevision};
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 
 use std::fmt::Debug;
@@ -218,7 +218,7 @@ pub fn insert_text_cell(s: String, field_rev: &FieldRevision) -> CellRevision {
   CellRevision::new(data)
 }
 
-pub fn insert_number_cell(num: i64, field_rev: &FieldRevision) -> CellRevision {
+pub fn insert_number_cell<T: ToString>(num: T, field_rev: &FieldRevision) -> CellRevision {
   let data = apply_cell_data_changeset(num.to_string(), None, field_rev, None).unwrap();
   CellRevision::new(data)
 }
@@ -254,6 +254,11 @@ pub fn insert_date_cell(date_cell_data: DateCellData, field_rev: &FieldRevision)
     time: None,
     include_time: Some(date_cell_data.include_time),
     is_utc: true,
+    occurrence_override: None,
+    end_date: date_cell_data.end_timestamp.map(|t| t.to_string()),
+    end_time: None,
+    is_range: None,
+    timezone_id: Some(date_cell_data.timezone_id),
   })
   .unwrap();
   let data = apply_cell_data_changeset(cell_data, None, field_rev, None).unwrap();
@@ -280,6 +285,38 @@ pub fn delete_select_option_cell(
   CellRevision::new(data)
 }
 
+pub fn insert_relation_cell(row_ids: Vec<String>, field_rev: &FieldRevision) -> CellRevision {
+  let changeset = RelationCellChangeset::from_insert_row_ids(row_ids).to_cell_changeset_str();
+  let data = apply_cell_data_changeset(changeset, None, field_rev, None).unwrap();
+  CellRevision::new(data)
+}
+
+/// Returns the cell of the system field that is filled from the row's metadata. Returns None if
+/// the field is not a system field.
+pub fn make_system_cell(field_type: &FieldType, row_rev: &RowRevision) -> Option<CellRevision> {
+  let value = match field_type {
+    FieldType::CreatedTime => row_rev.created_at,
+    FieldType::LastEditedTime => row_rev.last_modified,
+    FieldType::AutoIncrementId => row_rev.auto_increment_id,
+    _ => return None,
+  };
+  // The rows that were created before the metadata was recorded keep the empty cells.
+  let cell_str = if value == 0 {
+    "".to_owned()
+  } else {
+    value.to_string()
+  };
+  Some(CellRevision::new(
+    TypeCellData::new(cell_str, field_type.clone()).to_json(),
+  ))
+}
+
+/// Returns the cell of the formula field that saves the calculated data. The formula cells reject
+/// the changesets, so the calculated cells are made with this instead.
+pub fn make_formula_cell(cell_data: &FormulaCellData) -> CellRevision {
+  CellRevision::new(TypeCellData::new(cell_data.to_string(), FieldType::Formula).to_json())
+}
+
 /// Deserialize the String into cell specific data type.
 pub trait FromCellString {
   fn from_cell_str(s: &str) -> FlowyResult<Self>
@@ -116,6 +116,26 @@ impl TypeCellData {
     self.field_type == FieldType::URL
   }
 
+  pub fn is_formula(&self) -> bool {
+    self.field_type == FieldType::Formula
+  }
+
+  pub fn is_relation(&self) -> bool {
+    self.field_type == FieldType::Relation
+  }
+
+  pub fn is_rollup(&self) -> bool {
+    self.field_type == FieldType::Rollup
+  }
+
+  pub fn is_timestamp(&self) -> bool {
+    self.field_type.is_timestamp()
+  }
+
+  pub fn is_auto_increment_id(&self) -> bool {
+    self.field_type == FieldType::AutoIncrementId
+  }
+
   pub fn is_select_option(&self) -> bool {
     self.field_type == FieldType::MultiSelect || self.field_type == FieldType::SingleSelect
   }
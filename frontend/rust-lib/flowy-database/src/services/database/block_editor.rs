@@ -1,6 +1,10 @@
+use crate::entities::FieldType;
+use crate::services::cell::{make_system_cell, TypeCellData};
 use crate::services::database::retry::GetRowDataRetryAction;
 use bytes::Bytes;
-use database_model::{CellRevision, DatabaseBlockRevision, RowChangeset, RowRevision};
+use database_model::{
+  CellRevision, DatabaseBlockRevision, FieldRevision, RowChangeset, RowRevision,
+};
 use flowy_client_sync::client_database::{
   DatabaseBlockRevisionChangeset, DatabaseBlockRevisionPad,
 };
@@ -13,6 +17,7 @@ use flowy_revision::{
 use flowy_sqlite::ConnectionPool;
 use lib_infra::future::FutureResult;
 use lib_infra::retry::spawn_retry;
+use lib_infra::util::timestamp;
 use lib_ot::core::EmptyAttributes;
 use revision_model::Revision;
 use std::borrow::Cow;
@@ -53,18 +58,53 @@ impl DatabaseBlockEditor {
   }
 
   pub async fn close(&self) {
-    self.rev_manager.generate_snapshot().await;
+    self.write_checkpoint().await;
     self.rev_manager.close().await;
   }
 
+  pub async fn write_checkpoint(&self) {
+    let pad = self.pad.read().await;
+    let data = Bytes::from(pad.operations_json_str());
+    if let Err(e) = self.rev_manager.write_checkpoint(data).await {
+      tracing::error!(
+        "Write the checkpoint of block:{} failed: {}",
+        self.block_id,
+        e
+      );
+    }
+  }
+
+  /// Returns the rows of the block as they were at the timestamp, or None if the block has no
+  /// checkpoint by then.
+  pub async fn read_rows_at(&self, timestamp: i64) -> FlowyResult<Option<Vec<Arc<RowRevision>>>> {
+    let pad = self
+      .rev_manager
+      .read_checkpoint_object_at::<DatabaseBlockRevisionSerde>(timestamp)
+      .await?;
+    Ok(pad.map(|pad| pad.rows.clone()))
+  }
+
+  /// Replaces the rows of the block, for example, with the rows at one of its checkpoints.
+  pub async fn restore_rows(&self, row_revs: Vec<Arc<RowRevision>>) -> FlowyResult<()> {
+    self
+      .modify(|pad| {
+        Ok(pad.modify(|rows| {
+          *rows = row_revs;
+          Ok(Some(()))
+        })?)
+      })
+      .await
+  }
+
   pub async fn duplicate_block(&self, duplicated_block_id: &str) -> DatabaseBlockRevision {
     self.pad.read().await.duplicate_data(duplicated_block_id)
   }
 
-  /// Create a row after the the with prev_row_id. If prev_row_id is None, the row will be appended to the list
+  /// Create a row after the the with prev_row_id. If prev_row_id is None, the row will be appended to the list.
+  /// The creation time and the auto increment id of the row are assigned here.
   pub(crate) async fn create_row(
     &self,
-    row: RowRevision,
+    mut row: RowRevision,
     prev_row_id: Option<String>,
   ) -> FlowyResult<(i32, Option<i32>)> {
     let mut row_count = 0;
@@ -78,6 +118,19 @@ impl DatabaseBlockEditor {
           }
         }
 
+        let now = timestamp();
+        row.created_at = now;
+        row.last_modified = now;
+        row.auto_increment_id = block_pad
+          .rows
+          .iter()
+          .map(|row_rev| row_rev.auto_increment_id)
+          .max()
+          .unwrap_or(0)
+          + 1;
+        let system_cells = changed_system_cells(&row, &system_fields_of_row(&row));
+        row.cells.extend(system_cells);
+
         let change = block_pad.add_row_rev(row, prev_row_id)?;
         row_count = block_pad.number_of_rows();
 
@@ -103,11 +156,55 @@ impl DatabaseBlockEditor {
     Ok(row_count)
   }
 
-  pub async fn update_row(&self, changeset: RowChangeset) -> FlowyResult<()> {
+  /// Returns the applied changeset, which contains the last edited time cells of the row if the
+  /// row is edited.
+  pub async fn update_row(&self, mut changeset: RowChangeset) -> FlowyResult<RowChangeset> {
     self
-      .modify(|block_pad| Ok(block_pad.update_row(changeset)?))
+      .modify(|block_pad| {
+        if is_edited(&changeset) {
+          if let Some((_, row_rev)) = block_pad.get_row_rev(&changeset.row_id) {
+            let mut row_rev = RowRevision::clone(&row_rev);
+            row_rev.last_modified = timestamp();
+            changeset.last_modified = Some(row_rev.last_modified);
+            changeset.cell_by_field_id.extend(changed_system_cells(
+              &row_rev,
+              &system_fields_of_row(&row_rev),
+            ));
+          }
+        }
+        Ok(block_pad.update_row(changeset.clone())?)
+      })
       .await?;
-    Ok(())
+    Ok(changeset)
+  }
+
+  /// Fills the cells of the system fields from the metadata of the rows. It's called when a
+  /// system field is created or when a field is switched to a system field.
+  pub async fn fill_system_cells(&self, field_revs: &[Arc<FieldRevision>]) -> FlowyResult<()> {
+    let system_fields = field_revs
+      .iter()
+      .map(|field_rev| (field_rev.id.clone(), FieldType::from(field_rev.ty)))
+      .filter(|(_, field_type)| field_type.is_system())
+      .collect::<Vec<(String, FieldType)>>();
+    if system_fields.is_empty() {
+      return Ok(());
+    }
+
+    self
+      .modify(|block_pad| {
+        Ok(block_pad.modify(|row_revs| {
+          let mut is_changed = None;
+          for row_rev in row_revs.iter_mut() {
+            let cells = changed_system_cells(row_rev, &system_fields);
+            if !cells.is_empty() {
+              Arc::make_mut(row_rev).cells.extend(cells);
+              is_changed = Some(());
+            }
+          }
+          Ok(is_changed)
+        })?)
+      })
+      .await
   }
 
   pub async fn move_row(&self, row_id: &str, from: usize, to: usize) -> FlowyResult<()> {
@@ -191,6 +288,56 @@ impl DatabaseBlockEditor {
   }
 }
 
+/// Returns true if the changeset edits the row. The system cells and the calculated cells are
+/// maintained by the database, so updating them doesn't change the last edited time.
+fn is_edited(changeset: &RowChangeset) -> bool {
+  changeset.height.is_some()
+    || changeset.visibility.is_some()
+    || changeset
+      .cell_by_field_id
+      .values()
+      .any(|cell_rev| match TypeCellData::try_from(cell_rev) {
+        Ok(type_cell_data) => {
+          let field_type = type_cell_data.field_type;
+          !(field_type.is_system() || field_type.is_formula() || field_type.is_rollup())
+        },
+        Err(_) => true,
+      })
+}
+
+/// Returns the system fields whose cells exist in the row.
+fn system_fields_of_row(row_rev: &RowRevision) -> Vec<(String, FieldType)> {
+  row_rev
+    .cells
+    .iter()
+    .filter_map(|(field_id, cell_rev)| {
+      let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+      if type_cell_data.field_type.is_system() {
+        Some((field_id.clone(), type_cell_data.field_type))
+      } else {
+        None
+      }
+    })
+    .collect()
+}
+
+/// Returns the cells of the system fields that are out of date with the metadata of the row.
+fn changed_system_cells(
+  row_rev: &RowRevision,
+  system_fields: &[(String, FieldType)],
+) -> Vec<(String, CellRevision)> {
+  system_fields
+    .iter()
+    .filter_map(|(field_id, field_type)| {
+      let cell_rev = make_system_cell(field_type, row_rev)?;
+      match row_rev.cells.get(field_id) {
+        Some(old_cell_rev) if old_cell_rev.type_cell_data == cell_rev.type_cell_data => None,
+        _ => Some((field_id.clone(), cell_rev)),
+      }
+    })
+    .collect()
+}
+
 struct DatabaseBlockRevisionCloudService {
   #[allow(dead_code)]
   token: String,
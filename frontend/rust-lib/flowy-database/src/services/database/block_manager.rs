@@ -9,13 +9,14 @@ use crate::services::persistence::rev_sqlite::{
 use crate::services::row::{make_row_from_row_rev, DatabaseBlockRow, DatabaseBlockRowRevision};
 use dashmap::DashMap;
 use database_model::{
-  DatabaseBlockMetaRevision, DatabaseBlockMetaRevisionChangeset, RowChangeset, RowRevision,
+  CellRevision, DatabaseBlockMetaRevision, DatabaseBlockMetaRevisionChangeset, FieldRevision,
+  RowChangeset, RowRevision,
 };
-use flowy_error::FlowyResult;
+use flowy_error::{FlowyError, FlowyResult};
 use flowy_revision::{RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration};
 use flowy_sqlite::ConnectionPool;
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::broadcast;
 
@@ -72,6 +73,101 @@ impl DatabaseBlocks {
     }
   }
 
+  pub(crate) async fn write_checkpoints(&self) {
+    for block_editor in self.block_editors.iter() {
+      block_editor.write_checkpoint().await;
+    }
+  }
+
+  /// Returns the rows of each block as they were at the timestamp. The blocks that are not in
+  /// `block_ids` were created after the timestamp, so they have no rows then.
+  pub(crate) async fn read_rows_at(
+    &self,
+    timestamp: i64,
+    block_ids: &[String],
+  ) -> FlowyResult<Vec<DatabaseBlockRowRevision>> {
+    let mut blocks = vec![];
+    for iter in self.block_editors.iter() {
+      let editor = iter.value();
+      let block_id = editor.block_id.clone();
+      let row_revs = if block_ids.contains(&block_id) {
+        editor.read_rows_at(timestamp).await?.ok_or_else(|| {
+          FlowyError::record_not_found()
+            .context(format!("Can't find the checkpoint of block:{}", block_id))
+        })?
+      } else {
+        vec![]
+      };
+      blocks.push(DatabaseBlockRowRevision { block_id, row_revs });
+    }
+    Ok(blocks)
+  }
+
+  /// Replaces the rows of each block and notifies the views of the rows that are inserted,
+  /// updated or deleted.
+  pub(crate) async fn restore_rows(
+    &self,
+    blocks: Vec<DatabaseBlockRowRevision>,
+  ) -> FlowyResult<Vec<DatabaseBlockMetaRevisionChangeset>> {
+    let mut changesets = vec![];
+    for block in blocks {
+      let DatabaseBlockRowRevision { block_id, row_revs } = block;
+      let editor = self.get_or_create_block_editor(&block_id).await?;
+      let old_row_revs = editor.get_row_revs::<&str>(None).await?;
+      editor.restore_rows(row_revs.clone()).await?;
+
+      for old_row_rev in old_row_revs.iter() {
+        if !row_revs.iter().any(|row_rev| row_rev.id == old_row_rev.id) {
+          let _ = self.event_notifier.send(DatabaseBlockEvent::DeleteRow {
+            block_id: block_id.clone(),
+            row_id: old_row_rev.id.clone(),
+          });
+        }
+      }
+
+      for (index, row_rev) in row_revs.iter().enumerate() {
+        match old_row_revs
+          .iter()
+          .find(|old_row_rev| old_row_rev.id == row_rev.id)
+        {
+          None => {
+            self.persistence.insert(&block_id, &row_rev.id)?;
+            let mut row = InsertedRowPB::from(row_rev.as_ref());
+            row.index = Some(index as i32);
+            let _ = self.event_notifier.send(DatabaseBlockEvent::InsertRow {
+              block_id: block_id.clone(),
+              row,
+            });
+          },
+          Some(old_row_rev) if old_row_rev != row_rev => {
+            let changed_field_ids = row_rev
+              .cells
+              .keys()
+              .chain(old_row_rev.cells.keys())
+              .filter(|field_id| row_rev.cells.get(*field_id) != old_row_rev.cells.get(*field_id))
+              .cloned()
+              .collect::<HashSet<String>>();
+            let row = UpdatedRowPB {
+              row: make_row_from_row_rev(row_rev.clone()),
+              field_ids: changed_field_ids.into_iter().collect(),
+            };
+            let _ = self.event_notifier.send(DatabaseBlockEvent::UpdateRow {
+              block_id: block_id.clone(),
+              row,
+            });
+          },
+          Some(_) => {},
+        }
+      }
+
+      changesets.push(DatabaseBlockMetaRevisionChangeset::from_row_count(
+        block_id,
+        editor.number_of_rows().await,
+      ));
+    }
+    Ok(changesets)
+  }
+
   // #[tracing::instrument(level = "trace", skip(self))]
   pub(crate) async fn get_or_create_block_editor(
     &self,
@@ -149,7 +245,7 @@ impl DatabaseBlocks {
 
   pub async fn update_row(&self, changeset: RowChangeset) -> FlowyResult<()> {
     let editor = self.get_editor_from_row_id(&changeset.row_id).await?;
-    editor.update_row(changeset.clone()).await?;
+    let changeset = editor.update_row(changeset).await?;
     match editor.get_row_rev(&changeset.row_id).await? {
       None => tracing::error!(
         "Update row failed, can't find the row with id: {}",
@@ -175,6 +271,16 @@ impl DatabaseBlocks {
     Ok(())
   }
 
+  pub(crate) async fn fill_system_cells(
+    &self,
+    field_revs: &[Arc<FieldRevision>],
+  ) -> FlowyResult<()> {
+    for editor in self.block_editors.iter() {
+      editor.fill_system_cells(field_revs).await?;
+    }
+    Ok(())
+  }
+
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn delete_row(&self, row_id: &str) -> FlowyResult<Option<Arc<RowRevision>>> {
     let row_id = row_id.to_owned();
@@ -251,7 +357,24 @@ impl DatabaseBlocks {
   pub async fn update_cell(&self, changeset: CellChangesetPB) -> FlowyResult<()> {
     let row_changeset: RowChangeset = changeset.clone().into();
     self.update_row(row_changeset).await?;
-    self.notify_did_update_cell(changeset).await?;
+    self.notify_did_update_cell(&changeset.row_id, &changeset.field_id);
+    Ok(())
+  }
+
+  /// Writes the cells that are calculated by the database, for example, the formula cells. The
+  /// cells are written as they are, because the changesets of their type options are rejected.
+  pub(crate) async fn update_calculated_cells(
+    &self,
+    row_id: &str,
+    cell_by_field_id: HashMap<String, CellRevision>,
+  ) -> FlowyResult<()> {
+    let field_ids = cell_by_field_id.keys().cloned().collect::<Vec<String>>();
+    let mut row_changeset = RowChangeset::new(row_id.to_owned());
+    row_changeset.cell_by_field_id = cell_by_field_id;
+    self.update_row(row_changeset).await?;
+    for field_id in field_ids {
+      self.notify_did_update_cell(row_id, &field_id);
+    }
     Ok(())
   }
 
@@ -260,7 +383,6 @@ impl DatabaseBlocks {
     editor.get_row_rev(row_id).await
   }
 
-  #[allow(dead_code)]
   pub async fn get_row_revs(&self) -> FlowyResult<Vec<Arc<RowRevision>>> {
     let mut row_revs = vec![];
     for iter in self.block_editors.iter() {
@@ -295,10 +417,9 @@ impl DatabaseBlocks {
     Ok(blocks)
   }
 
-  async fn notify_did_update_cell(&self, changeset: CellChangesetPB) -> FlowyResult<()> {
-    let id = format!("{}:{}", changeset.row_id, changeset.field_id);
+  fn notify_did_update_cell(&self, row_id: &str, field_id: &str) {
+    let id = format!("{}:{}", row_id, field_id);
     send_notification(&id, DatabaseNotification::DidUpdateCell).send();
-    Ok(())
   }
 }
 
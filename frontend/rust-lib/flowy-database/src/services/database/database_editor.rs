@@ -1,15 +1,17 @@
 use crate::entities::CellIdParams;
 use crate::entities::*;
-use crate::manager::DatabaseUser;
+use crate::manager::{DatabaseRowDocument, DatabaseUser};
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::cell::{
-  apply_cell_data_changeset, get_type_cell_protobuf, stringify_cell_data, AnyTypeCache,
-  AtomicCellDataCache, CellProtobufBlob, ToCellChangesetString, TypeCellData,
+  apply_cell_data_changeset, get_type_cell_protobuf, make_formula_cell, stringify_cell_data,
+  AnyTypeCache, AtomicCellDataCache, CellProtobufBlob, FromCellString, ToCellChangesetString,
+  TypeCellData,
 };
 use crate::services::database::DatabaseBlocks;
 use crate::services::field::{
   default_type_option_builder_from_type, transform_type_option, type_option_builder_from_bytes,
-  FieldBuilder, RowSingleCellData,
+  DateCellChangeset, FieldBuilder, FormulaCalculator, FormulaTypeOptionPB, OccurrenceOverride,
+  RelationCellChangeset, RelationCellData, RelationTypeOptionPB, RowSingleCellData, URLCellData,
 };
 
 use crate::services::database::DatabaseViewDataImpl;
@@ -19,7 +21,10 @@ use crate::services::database_view::{
 use crate::services::filter::FilterType;
 use crate::services::persistence::block_index::BlockRowIndexer;
 use crate::services::persistence::database_ref::DatabaseViewRef;
+use crate::services::persistence::row_activity::{RowActivity, RowActivityPersistence};
+use crate::services::persistence::row_comment::{RowComment, RowCommentPersistence};
 use crate::services::row::{DatabaseBlockRow, DatabaseBlockRowRevision, RowRevisionBuilder};
+use crate::services::search::{RowSearch, RowSearchResult};
 use bytes::Bytes;
 use database_model::*;
 use flowy_client_sync::client_database::{
@@ -27,10 +32,10 @@ use flowy_client_sync::client_database::{
 };
 use flowy_client_sync::errors::{SyncError, SyncResult};
 use flowy_client_sync::make_operations_from_revisions;
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
 use flowy_revision::{
-  RevisionCloudService, RevisionManager, RevisionMergeable, RevisionObjectDeserializer,
-  RevisionObjectSerializer,
+  RevisionCheckpoint, RevisionCloudService, RevisionManager, RevisionMergeable,
+  RevisionObjectDeserializer, RevisionObjectSerializer, AUTO_GEN_SNAPSHOT_PER_10_REVISION,
 };
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
@@ -38,6 +43,8 @@ use lib_infra::future::{to_fut, FutureResult};
 use lib_ot::core::EmptyAttributes;
 use revision_model::Revision;
 use std::collections::HashMap;
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::Ordering::SeqCst;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 
@@ -54,6 +61,20 @@ pub struct DatabaseEditor {
   pub database_view_data: Arc<dyn DatabaseViewData>,
   pub cell_data_cache: AtomicCellDataCache,
   database_ref_query: Arc<dyn DatabaseRefIndexerQuery>,
+  user: Arc<dyn DatabaseUser>,
+  row_activities: Arc<RowActivityPersistence>,
+  row_comments: Arc<RowCommentPersistence>,
+  row_document: Arc<dyn DatabaseRowDocument>,
+  document_covers: RwLock<HashMap<String, DocumentCover>>,
+  checkpoint_rev_id: AtomicI64,
+}
+
+/// The cover of the gallery card that is read from the row's document. It's read again if the
+/// document is changed.
+struct DocumentCover {
+  document_id: String,
+  rev_id: i64,
+  image_src: Option<String>,
 }
 
 impl Drop for DatabaseEditor {
@@ -71,9 +92,13 @@ impl DatabaseEditor {
     rev_manager: RevisionManager<Arc<ConnectionPool>>,
     persistence: Arc<BlockRowIndexer>,
     database_ref_query: Arc<dyn DatabaseRefIndexerQuery>,
+    row_activities: Arc<RowActivityPersistence>,
+    row_comments: Arc<RowCommentPersistence>,
+    row_document: Arc<dyn DatabaseRowDocument>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
   ) -> FlowyResult<Arc<Self>> {
     let rev_manager = Arc::new(rev_manager);
+    let checkpoint_rev_id = AtomicI64::new(rev_manager.rev_id());
     let cell_data_cache = AnyTypeCache::<u64>::new();
 
     // Block manager
@@ -107,6 +132,12 @@ impl DatabaseEditor {
       cell_data_cache,
       database_ref_query,
       database_view_data,
+      user,
+      row_activities,
+      row_comments,
+      row_document,
+      document_covers: RwLock::new(HashMap::new()),
+      checkpoint_rev_id,
     });
 
     Ok(editor)
@@ -122,7 +153,7 @@ impl DatabaseEditor {
   }
 
   pub async fn dispose(&self) {
-    self.rev_manager.generate_snapshot().await;
+    self.write_checkpoint().await;
     self.database_blocks.close().await;
     self.rev_manager.close().await;
   }
@@ -181,6 +212,7 @@ impl DatabaseEditor {
       .did_update_field_type_option(view_id, field_id, old_field_rev)
       .await?;
     self.notify_did_update_database_field(field_id).await?;
+    self.refresh_formula_fields().await?;
     Ok(())
   }
 
@@ -200,6 +232,7 @@ impl DatabaseEditor {
     self
       .modify(|pad| Ok(pad.create_field_rev(field_rev, None)?))
       .await?;
+    self.fill_system_cells().await?;
     self.notify_did_insert_database_field(&field_id).await?;
 
     Ok(())
@@ -218,7 +251,9 @@ impl DatabaseEditor {
     self
       .modify(|pad| Ok(pad.create_field_rev(field_rev.clone(), None)?))
       .await?;
+    self.fill_system_cells().await?;
     self.notify_did_insert_database_field(&field_rev.id).await?;
+    self.refresh_formula_fields().await?;
 
     Ok(field_rev)
   }
@@ -255,7 +290,9 @@ impl DatabaseEditor {
         Ok(changeset)
       })
       .await?;
+    self.fill_system_cells().await?;
     self.notify_did_update_database_field(&field_id).await?;
+    self.refresh_formula_fields().await?;
     Ok(())
   }
 
@@ -296,6 +333,7 @@ impl DatabaseEditor {
     let field_order = FieldIdPB::from(field_id);
     let notified_changeset = DatabaseFieldChangesetPB::delete(&self.database_id, vec![field_order]);
     self.notify_did_update_database(notified_changeset).await?;
+    self.refresh_formula_fields().await?;
     Ok(())
   }
 
@@ -353,7 +391,9 @@ impl DatabaseEditor {
       })
       .await?;
 
+    self.fill_system_cells().await?;
     self.notify_did_update_database_field(field_id).await?;
+    self.refresh_formula_fields().await?;
 
     Ok(())
   }
@@ -363,6 +403,7 @@ impl DatabaseEditor {
     self
       .modify(|pad| Ok(pad.duplicate_field_rev(field_id, &duplicated_field_id)?))
       .await?;
+    self.fill_system_cells().await?;
 
     self
       .notify_did_insert_database_field(&duplicated_field_id)
@@ -433,6 +474,7 @@ impl DatabaseEditor {
       .database_views
       .will_create_row(&mut row_rev, &params)
       .await;
+    self.insert_formula_cells(&mut row_rev).await?;
 
     let row_pb = self
       .create_row_pb(row_rev, params.start_row_id.clone())
@@ -497,6 +539,16 @@ impl DatabaseEditor {
     Ok(all_rows)
   }
 
+  /// Returns the rows of the view that contain the query. Each cell is matched against its
+  /// display string, see [Self::get_cell_display_str].
+  pub async fn search_rows(&self, view_id: &str, query: &str) -> FlowyResult<Vec<RowSearchResult>> {
+    let field_revs = self.get_field_revs(None).await?;
+    let row_revs = self.get_all_row_revs(view_id).await?;
+    let results =
+      RowSearch::new(&field_revs, Some(self.cell_data_cache.clone())).search(query, &row_revs);
+    Ok(results)
+  }
+
   pub async fn get_row_rev(&self, row_id: &str) -> FlowyResult<Option<Arc<RowRevision>>> {
     match self.database_blocks.get_row_rev(row_id).await? {
       None => Ok(None),
@@ -508,8 +560,65 @@ impl DatabaseEditor {
     let row_rev = self.database_blocks.delete_row(row_id).await?;
     tracing::trace!("Did delete row:{:?}", row_rev);
     if let Some(row_rev) = row_rev {
+      self.document_covers.write().await.remove(row_id);
+      if let Some(document_id) = row_rev.document_id.as_ref() {
+        if let Err(err) = self.row_document.delete_document(document_id).await {
+          tracing::error!(
+            "Delete the document of the row:{} failed: {:?}",
+            row_id,
+            err
+          );
+        }
+      }
       self.database_views.did_delete_row(row_rev).await;
     }
+    self.row_activities.delete_row_activities(row_id)?;
+    self.row_comments.delete_row_comments(row_id)?;
+    Ok(())
+  }
+
+  /// Returns the changes of the row's cells, the latest first.
+  pub async fn get_row_activities(&self, row_id: &str) -> FlowyResult<Vec<RowActivityPB>> {
+    let activities = self.row_activities.get_row_activities(row_id)?;
+    Ok(activities.into_iter().map(RowActivityPB::from).collect())
+  }
+
+  /// Returns the comments of the row, the earliest first.
+  pub async fn get_row_comments(&self, row_id: &str) -> FlowyResult<Vec<RowCommentPB>> {
+    let comments = self.row_comments.get_row_comments(row_id)?;
+    Ok(comments.into_iter().map(RowCommentPB::from).collect())
+  }
+
+  pub async fn create_row_comment(
+    &self,
+    params: CreateRowCommentParams,
+  ) -> FlowyResult<RowCommentPB> {
+    if self.get_row_rev(&params.row_id).await?.is_none() {
+      let msg = format!("Row with id:{} not found", &params.row_id);
+      return Err(FlowyError::record_not_found().context(msg));
+    }
+    let user_id = self.user.user_id()?;
+    let comment = RowComment::new(&self.database_id, &params.row_id, params.content, &user_id);
+    self.row_comments.insert(comment.clone())?;
+    self.notify_did_update_row_comments(&params.row_id)?;
+    Ok(comment.into())
+  }
+
+  pub async fn update_row_comment(
+    &self,
+    params: UpdateRowCommentParams,
+  ) -> FlowyResult<RowCommentPB> {
+    let comment = self
+      .row_comments
+      .update_content(&params.comment_id, &params.content)?;
+    self.notify_did_update_row_comments(&comment.row_id)?;
+    Ok(comment.into())
+  }
+
+  pub async fn delete_row_comment(&self, params: DeleteRowCommentParams) -> FlowyResult<()> {
+    let comment = self.row_comments.get_comment(&params.comment_id)?;
+    self.row_comments.delete_comment(&params.comment_id)?;
+    self.notify_did_update_row_comments(&comment.row_id)?;
     Ok(())
   }
 
@@ -540,14 +649,65 @@ impl DatabaseEditor {
         view_id: view_id.to_string(),
         start_row_id: Some(row.id.clone()),
         group_id: None,
+        sub_group_id: None,
         cell_data_by_field_id: Some(cell_data_by_field_id),
       };
 
-      self.create_row(params).await?;
+      let row_pb = self.create_row(params).await?;
+      if let Some(document_id) = row.document_id.as_ref() {
+        let duplicated_document_id = gen_row_document_id();
+        self
+          .row_document
+          .duplicate_document(document_id, &duplicated_document_id)
+          .await?;
+        self
+          .set_row_document_id(&row_pb.id, duplicated_document_id)
+          .await?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Returns the id of the document that is used as the body of the row. The document is
+  /// created if the row doesn't have one yet.
+  /// Deletes the documents of all the rows. It's called when the database is deleted.
+  pub async fn delete_row_documents(&self) -> FlowyResult<()> {
+    let row_revs = self.database_blocks.get_row_revs().await?;
+    for row_rev in row_revs {
+      if let Some(document_id) = row_rev.document_id.as_ref() {
+        self.row_document.delete_document(document_id).await?;
+      }
     }
     Ok(())
   }
 
+  pub async fn get_or_create_row_document(&self, row_id: &str) -> FlowyResult<String> {
+    let row_rev = match self.get_row_rev(row_id).await? {
+      None => {
+        let msg = format!("Row with id:{} not found", row_id);
+        return Err(FlowyError::record_not_found().context(msg));
+      },
+      Some(row_rev) => row_rev,
+    };
+
+    if let Some(document_id) = row_rev.document_id.as_ref() {
+      return Ok(document_id.clone());
+    }
+
+    let document_id = gen_row_document_id();
+    self.row_document.create_document(&document_id).await?;
+    self
+      .set_row_document_id(row_id, document_id.clone())
+      .await?;
+    Ok(document_id)
+  }
+
+  async fn set_row_document_id(&self, row_id: &str, document_id: String) -> FlowyResult<()> {
+    let mut changeset = RowChangeset::new(row_id.to_owned());
+    changeset.document_id = Some(document_id);
+    self.database_blocks.update_row(changeset).await
+  }
+
   /// Returns the cell data that encoded in protobuf.
   pub async fn get_cell(&self, params: &CellIdParams) -> Option<CellPB> {
     let (field_type, cell_bytes) = self.get_type_cell_protobuf(params).await?;
@@ -639,18 +799,31 @@ impl DatabaseEditor {
     field_id: &str,
     cell_changeset: T,
   ) -> FlowyResult<()> {
-    match self.database_pad.read().await.get_field_rev(field_id) {
+    // Release the read lock before updating the formula cells, which read the fields again.
+    let field_rev = self
+      .database_pad
+      .read()
+      .await
+      .get_field_rev(field_id)
+      .map(|(_, field_rev)| field_rev.clone());
+    match field_rev {
       None => {
         let msg = format!("Field with id:{} not found", &field_id);
         Err(FlowyError::internal().context(msg))
       },
-      Some((_, field_rev)) => {
+      Some(field_rev) => {
         tracing::trace!(
           "Cell changeset: id:{} / value:{:?}",
           &field_id,
           cell_changeset
         );
         let old_row_rev = self.get_row_rev(row_id).await?.clone();
+        let cell_id_params = CellIdParams {
+          view_id: self.database_id.clone(),
+          field_id: field_id.to_owned(),
+          row_id: row_id.to_owned(),
+        };
+        let old_display_str = self.get_cell_display_str(&cell_id_params).await;
         let cell_rev = self.get_cell_rev(row_id, field_id).await?;
         // Update the changeset.data property with the return value.
         let type_cell_data = apply_cell_data_changeset(
@@ -666,6 +839,9 @@ impl DatabaseEditor {
           type_cell_data,
         };
         self.database_blocks.update_cell(cell_changeset).await?;
+        let new_display_str = self.get_cell_display_str(&cell_id_params).await;
+        self.record_cell_activity(row_id, field_id, old_display_str, new_display_str);
+        self.update_formula_cells(row_id, field_id).await?;
         self
           .database_views
           .did_update_row(old_row_rev, row_id)
@@ -719,6 +895,84 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  /// Returns the content of the row's primary field. Returns None if the row doesn't exist.
+  pub async fn get_row_name(&self, row_id: &str) -> Option<String> {
+    let row_rev = self.get_row_rev(row_id).await.ok()??;
+    let primary_field_rev = self
+      .database_pad
+      .read()
+      .await
+      .get_field_revs(None)
+      .ok()?
+      .into_iter()
+      .find(|field_rev| field_rev.is_primary);
+    let name = match primary_field_rev {
+      None => "".to_owned(),
+      Some(field_rev) => {
+        let field_type: FieldType = field_rev.ty.into();
+        row_rev
+          .cells
+          .get(&field_rev.id)
+          .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+          .map(|type_cell_data| {
+            stringify_cell_data(
+              type_cell_data.cell_str,
+              &field_type,
+              &field_type,
+              &field_rev,
+            )
+          })
+          .unwrap_or_default()
+      },
+    };
+    Some(name)
+  }
+
+  /// Unlinks the rows of the database with `database_id` from the relation cells. It's called
+  /// after the rows are deleted from that database. Returns the ids of the updated rows.
+  pub async fn remove_related_row_ids(
+    &self,
+    database_id: &str,
+    row_ids: &[String],
+  ) -> FlowyResult<Vec<String>> {
+    let relation_field_revs = self
+      .get_field_revs(None)
+      .await?
+      .into_iter()
+      .filter(|field_rev| {
+        FieldType::from(field_rev.ty).is_relation()
+          && RelationTypeOptionPB::from(field_rev).database_id == database_id
+      })
+      .collect::<Vec<Arc<FieldRevision>>>();
+    if relation_field_revs.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let mut updated_row_ids = vec![];
+    for row_rev in self.database_blocks.get_row_revs().await? {
+      for field_rev in relation_field_revs.iter() {
+        let is_linked = row_rev
+          .cells
+          .get(&field_rev.id)
+          .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+          .filter(|type_cell_data| type_cell_data.is_relation())
+          .and_then(|type_cell_data| RelationCellData::from_cell_str(&type_cell_data.cell_str).ok())
+          .map(|cell_data| cell_data.contains_any(row_ids))
+          .unwrap_or(false);
+        if is_linked {
+          let changeset = RelationCellChangeset::from_remove_row_ids(row_ids.to_vec());
+          self
+            .update_cell_with_changeset(&row_rev.id, &field_rev.id, changeset)
+            .await?;
+          if !updated_row_ids.contains(&row_rev.id) {
+            updated_row_ids.push(row_rev.id.clone());
+          }
+        }
+      }
+    }
+    Ok(updated_row_ids)
+  }
+
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub async fn get_database(&self, view_id: &str) -> FlowyResult<DatabasePB> {
     let pad = self.database_pad.read().await;
@@ -775,6 +1029,26 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  pub async fn get_filter_group(&self, view_id: &str) -> FlowyResult<FilterGroupRevision> {
+    self.database_views.get_filter_group(view_id).await
+  }
+
+  pub async fn create_or_update_filter_group(
+    &self,
+    params: AlterFilterGroupParams,
+  ) -> FlowyResult<()> {
+    self
+      .database_views
+      .create_or_update_filter_group(params)
+      .await?;
+    Ok(())
+  }
+
+  pub async fn delete_filter_group(&self, params: DeleteFilterGroupParams) -> FlowyResult<()> {
+    self.database_views.delete_filter_group(params).await?;
+    Ok(())
+  }
+
   pub async fn get_all_sorts(&self, view_id: &str) -> FlowyResult<Vec<SortPB>> {
     Ok(
       self
@@ -796,6 +1070,31 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  pub async fn get_calculation_values(
+    &self,
+    view_id: &str,
+  ) -> FlowyResult<Vec<CalculationValuePB>> {
+    let values = self
+      .database_views
+      .get_calculation_values(view_id)
+      .await?
+      .into_iter()
+      .map(CalculationValuePB::from)
+      .collect();
+    Ok(values)
+  }
+
+  pub async fn update_calculation(
+    &self,
+    params: UpdateCalculationParams,
+  ) -> FlowyResult<CalculationRevision> {
+    self.database_views.update_calculation(params).await
+  }
+
+  pub async fn delete_calculation(&self, params: DeleteCalculationParams) -> FlowyResult<()> {
+    self.database_views.delete_calculation(params).await
+  }
+
   pub async fn create_or_update_sort(&self, params: AlterSortParams) -> FlowyResult<SortRevision> {
     let sort_rev = self.database_views.create_or_update_sort(params).await?;
     Ok(sort_rev)
@@ -809,6 +1108,37 @@ impl DatabaseEditor {
     self.database_views.delete_group(params).await
   }
 
+  pub async fn update_date_group_condition(
+    &self,
+    params: UpdateDateGroupConditionParams,
+  ) -> FlowyResult<()> {
+    self
+      .database_views
+      .update_date_group_condition(params)
+      .await
+  }
+
+  pub async fn update_number_group_setting(
+    &self,
+    params: UpdateNumberGroupSettingParams,
+  ) -> FlowyResult<()> {
+    self
+      .database_views
+      .update_number_group_setting(params)
+      .await
+  }
+
+  pub async fn update_text_group_setting(
+    &self,
+    params: UpdateTextGroupSettingParams,
+  ) -> FlowyResult<()> {
+    self.database_views.update_text_group_setting(params).await
+  }
+
+  pub async fn set_sub_group_by_field(&self, params: SubGroupByFieldParams) -> FlowyResult<()> {
+    self.database_views.set_sub_group_by_field(params).await
+  }
+
   pub async fn move_row(&self, params: MoveRowParams) -> FlowyResult<()> {
     let MoveRowParams {
       view_id: _,
@@ -843,6 +1173,7 @@ impl DatabaseEditor {
       view_id,
       from_row_id,
       to_group_id,
+      to_sub_group_id,
       to_row_id,
     } = params;
 
@@ -856,6 +1187,7 @@ impl DatabaseEditor {
             &view_id.clone(),
             row_rev,
             to_group_id,
+            to_sub_group_id,
             to_row_id.clone(),
             |row_changeset| {
               to_fut(async move {
@@ -939,6 +1271,20 @@ impl DatabaseEditor {
     }
     drop(database_pad);
 
+    // The duplicated rows own the copies of the original rows' documents.
+    for block in blocks_meta_data.iter_mut() {
+      for row_rev in block.rows.iter_mut() {
+        if let Some(document_id) = row_rev.document_id.clone() {
+          let duplicated_document_id = gen_row_document_id();
+          self
+            .row_document
+            .duplicate_document(&document_id, &duplicated_document_id)
+            .await?;
+          Arc::make_mut(row_rev).document_id = Some(duplicated_document_id);
+        }
+      }
+    }
+
     Ok(BuildDatabaseContext {
       field_revs: duplicated_fields.into_iter().map(Arc::new).collect(),
       block_metas: duplicated_blocks,
@@ -948,6 +1294,12 @@ impl DatabaseEditor {
     })
   }
 
+  /// Returns the view of the database in JSON format, which can be deserialized into
+  /// [DatabaseViewRevision](database_model::DatabaseViewRevision).
+  pub async fn get_database_view_data(&self, view_id: &str) -> FlowyResult<String> {
+    self.database_views.duplicate_database_view(view_id).await
+  }
+
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn load_groups(&self, view_id: &str) -> FlowyResult<RepeatedGroupPB> {
     self.database_views.load_groups(view_id).await
@@ -958,6 +1310,14 @@ impl DatabaseEditor {
     self.database_views.get_group(view_id, group_id).await
   }
 
+  /// Returns the fields that are displayed in the view, in the order they are displayed.
+  pub async fn get_visible_field_revs(
+    &self,
+    view_id: &str,
+  ) -> FlowyResult<Vec<Arc<FieldRevision>>> {
+    self.database_views.get_visible_field_revs(view_id).await
+  }
+
   pub async fn get_layout_setting<T: Into<LayoutRevision>>(
     &self,
     view_id: &str,
@@ -1000,6 +1360,281 @@ impl DatabaseEditor {
     view_editor.v_get_calendar_event(row_id).await
   }
 
+  pub async fn get_calendar_events_in_range(
+    &self,
+    params: CalendarEventRangeRequestParams,
+  ) -> Vec<CalendarEventPB> {
+    match self.database_views.get_view_editor(&params.view_id).await {
+      Ok(view_editor) => view_editor
+        .v_get_calendar_events_in_range(params.start_timestamp, params.end_timestamp)
+        .await
+        .unwrap_or_default(),
+      Err(err) => {
+        tracing::error!("Get calendar events in range failed: {}", err);
+        vec![]
+      },
+    }
+  }
+
+  pub async fn get_timeline_bars(&self, params: TimelineBarRequestParams) -> Vec<TimelineBarPB> {
+    match self.database_views.get_view_editor(&params.view_id).await {
+      Ok(view_editor) => view_editor
+        .v_get_timeline_bars(params.start_timestamp, params.end_timestamp)
+        .await
+        .unwrap_or_default(),
+      Err(err) => {
+        tracing::error!("Get timeline bars failed: {}", err);
+        vec![]
+      },
+    }
+  }
+
+  /// Returns the cards of the gallery in the order of the rows after filtering and sorting. The
+  /// cover of each card is read from the URL field or the first image in the row's document.
+  pub async fn get_gallery_cards(&self, view_id: &str) -> FlowyResult<Vec<GalleryCardPB>> {
+    let gallery_setting = self
+      .get_layout_setting(view_id, LayoutRevision::Gallery)
+      .await?
+      .gallery
+      .ok_or_else(|| FlowyError::record_not_found().context("Gallery layout setting not found"))?;
+
+    let row_revs = self.get_all_row_revs(view_id).await?;
+    let mut cards = Vec::with_capacity(row_revs.len());
+    for row_rev in row_revs {
+      let cover_url = match gallery_setting.cover_source {
+        GalleryCoverSource::None => None,
+        GalleryCoverSource::URLField => gallery_setting
+          .cover_field_id
+          .as_ref()
+          .and_then(|field_id| row_rev.cells.get(field_id))
+          .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+          .and_then(|type_cell_data| URLCellData::from_cell_str(&type_cell_data.cell_str).ok())
+          .map(|url_cell_data| url_cell_data.url)
+          .filter(|url| !url.is_empty()),
+        GalleryCoverSource::DocumentImage => match &row_rev.document_id {
+          None => None,
+          Some(document_id) => match self.get_document_cover(&row_rev.id, document_id).await {
+            Ok(image_src) => image_src,
+            Err(err) => {
+              tracing::error!("Read the cover of the row:{} failed: {}", row_rev.id, err);
+              None
+            },
+          },
+        },
+      };
+      cards.push(GalleryCardPB {
+        row_id: row_rev.id.clone(),
+        cover_url,
+      });
+    }
+    Ok(cards)
+  }
+
+  /// Returns the first image in the row's document. The image is cached until the document is
+  /// changed.
+  async fn get_document_cover(
+    &self,
+    row_id: &str,
+    document_id: &str,
+  ) -> FlowyResult<Option<String>> {
+    let rev_id = self.row_document.document_rev_id(document_id).await?;
+    if let Some(cover) = self.document_covers.read().await.get(row_id) {
+      if cover.document_id == document_id && cover.rev_id == rev_id {
+        return Ok(cover.image_src.clone());
+      }
+    }
+
+    let image_src = self.row_document.first_image_src(document_id).await?;
+    self.document_covers.write().await.insert(
+      row_id.to_owned(),
+      DocumentCover {
+        document_id: document_id.to_owned(),
+        rev_id,
+        image_src: image_src.clone(),
+      },
+    );
+    Ok(image_src)
+  }
+
+  /// Returns the checkpoints of the database in ascending order of the rev_id.
+  pub async fn get_checkpoints(&self) -> FlowyResult<Vec<RevisionCheckpoint>> {
+    self.rev_manager.read_checkpoints().await
+  }
+
+  /// Returns the database at the checkpoint in JSON format.
+  pub async fn read_checkpoint(&self, rev_id: i64) -> FlowyResult<String> {
+    let pad = self
+      .rev_manager
+      .read_checkpoint_object::<DatabaseRevisionSerde>(rev_id)
+      .await?;
+    let value: serde_json::Value =
+      serde_json::from_str(&pad.json_str()?).map_err(internal_error)?;
+    serde_json::to_string_pretty(&value).map_err(internal_error)
+  }
+
+  /// Writes a checkpoint of the database along with the checkpoints of its blocks and its open
+  /// views. The blocks and the views are written first, so restoring the database finds them by
+  /// the timestamp of its checkpoint.
+  pub async fn write_checkpoint(&self) {
+    self.database_blocks.write_checkpoints().await;
+    self.database_views.write_checkpoints().await;
+
+    let pad = self.database_pad.read().await;
+    let rev_id = self.rev_manager.rev_id();
+    let data = Bytes::from(pad.operations_json_str());
+    match self.rev_manager.write_checkpoint(data).await {
+      Ok(_) => self.checkpoint_rev_id.store(rev_id, SeqCst),
+      Err(e) => tracing::error!("Write the checkpoint of database failed: {}", e),
+    }
+  }
+
+  /// Restores the database to the checkpoint, including its rows and the settings of its views.
+  /// The rows and the views are read from their checkpoints that were written with the
+  /// database's. The restoring is saved as new revisions, so it can be synced and undone like any
+  /// other change.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn restore_checkpoint(&self, rev_id: i64) -> FlowyResult<()> {
+    let timestamp = self
+      .rev_manager
+      .read_checkpoints()
+      .await?
+      .into_iter()
+      .find(|checkpoint| checkpoint.rev_id == rev_id)
+      .map(|checkpoint| checkpoint.timestamp)
+      .ok_or_else(|| {
+        FlowyError::record_not_found().context(format!("Can't find the checkpoint: {}", rev_id))
+      })?;
+
+    // Read everything before applying any of it, so a missing checkpoint leaves the database as
+    // it is.
+    let checkpoint = self
+      .rev_manager
+      .read_checkpoint_object::<DatabaseRevisionSerde>(rev_id)
+      .await?;
+    let block_ids = checkpoint
+      .get_block_meta_revs()
+      .iter()
+      .map(|block_meta_rev| block_meta_rev.block_id.clone())
+      .collect::<Vec<String>>();
+    let blocks = self
+      .database_blocks
+      .read_rows_at(timestamp, &block_ids)
+      .await?;
+    let view_ids = self
+      .database_ref_query
+      .get_ref_views(&self.database_id)?
+      .into_iter()
+      .map(|view| view.view_id)
+      .collect::<Vec<String>>();
+    let views = self
+      .database_views
+      .read_checkpoints_at(view_ids, timestamp)
+      .await?;
+
+    let old_field_revs = self.database_pad.read().await.get_fields().to_vec();
+    self
+      .modify_without_checkpoint(|pad| Ok(pad.restore_database(checkpoint)?))
+      .await?;
+    let new_field_revs = self.database_pad.read().await.get_fields().to_vec();
+    self
+      .notify_did_restore_database_fields(&old_field_revs, &new_field_revs)
+      .await?;
+
+    for changeset in self.database_blocks.restore_rows(blocks).await? {
+      self
+        .modify_without_checkpoint(|pad| Ok(pad.update_block_rev(changeset)?))
+        .await?;
+    }
+    self.database_views.restore(views).await?;
+    self.write_checkpoint().await;
+    self.refresh_formula_fields().await?;
+    Ok(())
+  }
+
+  /// Moves or resizes the bar of the row by writing the start and end dates back to the date
+  /// cells. If the timeline uses one field for both dates, the cell holds them as a date range.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn move_timeline_bar(&self, params: MoveTimelineBarParams) -> FlowyResult<()> {
+    let timeline_setting = self
+      .get_layout_setting(&params.view_id, LayoutRevision::Timeline)
+      .await?
+      .timeline
+      .ok_or_else(|| FlowyError::record_not_found().context("Timeline layout setting not found"))?;
+
+    let date_changeset = |timestamp: i64| DateCellChangeset {
+      date: Some(timestamp.to_string()),
+      time: None,
+      include_time: None,
+      is_utc: true,
+      occurrence_override: None,
+      end_date: None,
+      end_time: None,
+      is_range: None,
+      timezone_id: None,
+    };
+
+    if timeline_setting.start_field_id == timeline_setting.end_field_id {
+      let mut cell_changeset = date_changeset(params.start_timestamp);
+      cell_changeset.end_date = Some(params.end_timestamp.to_string());
+      cell_changeset.is_range = Some(true);
+      self
+        .update_cell_with_changeset(
+          &params.row_id,
+          &timeline_setting.start_field_id,
+          cell_changeset,
+        )
+        .await
+    } else {
+      self
+        .update_cell_with_changeset(
+          &params.row_id,
+          &timeline_setting.start_field_id,
+          date_changeset(params.start_timestamp),
+        )
+        .await?;
+      self
+        .update_cell_with_changeset(
+          &params.row_id,
+          &timeline_setting.end_field_id,
+          date_changeset(params.end_timestamp),
+        )
+        .await
+    }
+  }
+
+  /// Reschedules or cancels one occurrence of the repeated date that the calendar is laid out
+  /// by. The override is saved in the date cell of the row.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn update_calendar_event_occurrence(
+    &self,
+    params: UpdateCalendarEventOccurrenceParams,
+  ) -> FlowyResult<()> {
+    let date_field_id = self
+      .get_layout_setting(&params.view_id, LayoutRevision::Calendar)
+      .await?
+      .calendar
+      .map(|calendar_setting| calendar_setting.layout_field_id)
+      .ok_or_else(|| FlowyError::record_not_found().context("Calendar layout setting not found"))?;
+
+    let cell_changeset = DateCellChangeset {
+      date: None,
+      time: None,
+      include_time: None,
+      is_utc: true,
+      occurrence_override: Some(OccurrenceOverride {
+        occurrence_timestamp: params.occurrence_timestamp,
+        timestamp: params.timestamp,
+      }),
+      end_date: None,
+      end_time: None,
+      is_range: None,
+      timezone_id: None,
+    };
+    self
+      .update_cell_with_changeset(&params.row_id, &date_field_id, cell_changeset)
+      .await
+  }
+
   async fn create_row_rev(
     &self,
     cell_data_by_field_id: Option<HashMap<String, String>>,
@@ -1039,7 +1674,197 @@ impl DatabaseEditor {
     Ok(row_pb)
   }
 
+  /// Calculates the formula cells of the new row.
+  async fn insert_formula_cells(&self, row_rev: &mut RowRevision) -> FlowyResult<()> {
+    let field_revs = self.database_pad.read().await.get_field_revs(None)?;
+    let calculator = FormulaCalculator::new(field_revs);
+    let formula_field_ids = calculator.formula_field_ids();
+    if formula_field_ids.is_empty() {
+      return Ok(());
+    }
+
+    for (field_rev, cell_data) in calculator.calculate(row_rev, &formula_field_ids) {
+      let type_cell_data =
+        apply_cell_data_changeset(cell_data.to_string(), None, field_rev.clone(), None)?;
+      row_rev
+        .cells
+        .insert(field_rev.id.clone(), CellRevision::new(type_cell_data));
+    }
+    Ok(())
+  }
+
+  /// Recalculates the formula cells of the row that depend on the updated field.
+  async fn update_formula_cells(&self, row_id: &str, field_id: &str) -> FlowyResult<()> {
+    let field_revs = self.database_pad.read().await.get_field_revs(None)?;
+    let calculator = FormulaCalculator::new(field_revs);
+    let formula_field_ids = calculator.dependent_formula_field_ids(field_id);
+    if formula_field_ids.is_empty() {
+      return Ok(());
+    }
+
+    if let Some((_, row_rev)) = self.database_blocks.get_row_rev(row_id).await? {
+      self
+        .save_formula_cells(&calculator, &row_rev, &formula_field_ids)
+        .await?;
+    }
+    Ok(())
+  }
+
+  /// Records the change of the cell if its display string is changed. The cell was updated
+  /// already, so a failure of recording is logged instead of being returned.
+  fn record_cell_activity(
+    &self,
+    row_id: &str,
+    field_id: &str,
+    old_display_str: String,
+    new_display_str: String,
+  ) {
+    if old_display_str == new_display_str {
+      return;
+    }
+    let user_id = match self.user.user_id() {
+      Ok(user_id) => user_id,
+      Err(err) => {
+        tracing::error!(
+          "Record the activity of the row:{} failed: {:?}",
+          row_id,
+          err
+        );
+        return;
+      },
+    };
+    let activity = RowActivity::new(
+      &self.database_id,
+      row_id,
+      field_id,
+      old_display_str,
+      new_display_str,
+      &user_id,
+    );
+    match self.row_activities.insert(activity) {
+      Ok(activity) => send_notification(row_id, DatabaseNotification::DidUpdateRowActivities)
+        .payload(RowActivityPB::from(activity))
+        .send(),
+      Err(err) => tracing::error!(
+        "Record the activity of the row:{} failed: {:?}",
+        row_id,
+        err
+      ),
+    }
+  }
+
+  fn notify_did_update_row_comments(&self, row_id: &str) -> FlowyResult<()> {
+    let comments = self.row_comments.get_row_comments(row_id)?;
+    send_notification(row_id, DatabaseNotification::DidUpdateRowComments)
+      .payload(RepeatedRowCommentPB::from(comments))
+      .send();
+    Ok(())
+  }
+
+  /// Fills the cells of the system fields from the metadata of the rows. It's called after the
+  /// fields are changed, because the new system fields don't have any cells yet.
+  async fn fill_system_cells(&self) -> FlowyResult<()> {
+    let field_revs = self.database_pad.read().await.get_field_revs(None)?;
+    self.database_blocks.fill_system_cells(&field_revs).await
+  }
+
+  /// Updates the result types of the formula fields and recalculates all the formula cells. It's
+  /// called after the fields are changed, because the expressions reference the fields by name.
+  async fn refresh_formula_fields(&self) -> FlowyResult<()> {
+    let mut field_revs = self.database_pad.read().await.get_field_revs(None)?;
+    if !field_revs
+      .iter()
+      .any(|field_rev| FieldType::from(field_rev.ty).is_formula())
+    {
+      return Ok(());
+    }
+
+    // The result type of a formula depends on the result types of the formulas it references,
+    // so repeat until none of them changes.
+    for _ in 0..field_revs.len() {
+      let mut updated_field_ids = vec![];
+      for field_rev in field_revs.iter() {
+        if !FieldType::from(field_rev.ty).is_formula() {
+          continue;
+        }
+        let mut type_option = FormulaTypeOptionPB::from(field_rev);
+        let result_type = type_option.infer_result_type(&field_revs);
+        if result_type != type_option.result_type {
+          type_option.result_type = result_type;
+          self
+            .modify(|pad| {
+              Ok(pad.modify_field(&field_rev.id, |field| {
+                field.insert_type_option(&type_option);
+                Ok(Some(()))
+              })?)
+            })
+            .await?;
+          updated_field_ids.push(field_rev.id.clone());
+        }
+      }
+      if updated_field_ids.is_empty() {
+        break;
+      }
+      for field_id in updated_field_ids {
+        self.notify_did_update_database_field(&field_id).await?;
+      }
+      field_revs = self.database_pad.read().await.get_field_revs(None)?;
+    }
+
+    let calculator = FormulaCalculator::new(field_revs);
+    let formula_field_ids = calculator.formula_field_ids();
+    for row_rev in self.database_blocks.get_row_revs().await? {
+      let is_changed = self
+        .save_formula_cells(&calculator, &row_rev, &formula_field_ids)
+        .await?;
+      if is_changed {
+        self
+          .database_views
+          .did_update_row(Some(row_rev.clone()), &row_rev.id)
+          .await;
+      }
+    }
+    Ok(())
+  }
+
+  async fn save_formula_cells(
+    &self,
+    calculator: &FormulaCalculator,
+    row_rev: &Arc<RowRevision>,
+    formula_field_ids: &[String],
+  ) -> FlowyResult<bool> {
+    let cells = calculator.calculate(row_rev, formula_field_ids);
+    if cells.is_empty() {
+      return Ok(false);
+    }
+
+    let cell_by_field_id = cells
+      .into_iter()
+      .map(|(field_rev, cell_data)| (field_rev.id.clone(), make_formula_cell(&cell_data)))
+      .collect::<HashMap<String, CellRevision>>();
+    self
+      .database_blocks
+      .update_calculated_cells(&row_rev.id, cell_by_field_id)
+      .await?;
+    Ok(true)
+  }
+
   async fn modify<F>(&self, f: F) -> FlowyResult<()>
+  where
+    F:
+      for<'a> FnOnce(&'a mut DatabaseRevisionPad) -> FlowyResult<Option<DatabaseRevisionChangeset>>,
+  {
+    self.modify_without_checkpoint(f).await?;
+    // The snapshots of the database are not generated automatically, so the checkpoints are
+    // written here instead.
+    let rev_id = self.rev_manager.rev_id();
+    if rev_id - self.checkpoint_rev_id.load(SeqCst) >= AUTO_GEN_SNAPSHOT_PER_10_REVISION {
+      self.write_checkpoint().await;
+    }
+    Ok(())
+  }
+
+  async fn modify_without_checkpoint<F>(&self, f: F) -> FlowyResult<()>
   where
     F:
       for<'a> FnOnce(&'a mut DatabaseRevisionPad) -> FlowyResult<Option<DatabaseRevisionChangeset>>,
@@ -1105,6 +1930,37 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  async fn notify_did_restore_database_fields(
+    &self,
+    old_field_revs: &[Arc<FieldRevision>],
+    new_field_revs: &[Arc<FieldRevision>],
+  ) -> FlowyResult<()> {
+    let deleted_fields = old_field_revs
+      .iter()
+      .filter(|old| !new_field_revs.iter().any(|new| new.id == old.id))
+      .map(|field_rev| FieldIdPB::from(field_rev.id.as_str()))
+      .collect::<Vec<_>>();
+    let mut inserted_fields = vec![];
+    let mut updated_fields = vec![];
+    for (index, field_rev) in new_field_revs.iter().enumerate() {
+      match old_field_revs.iter().find(|old| old.id == field_rev.id) {
+        None => inserted_fields.push(IndexFieldPB::from_field_rev(field_rev, index)),
+        Some(old_field_rev) => {
+          if old_field_rev != field_rev {
+            updated_fields.push(FieldPB::from(field_rev.as_ref().clone()));
+          }
+        },
+      }
+    }
+    let notified_changeset = DatabaseFieldChangesetPB {
+      view_id: self.database_id.clone(),
+      inserted_fields,
+      deleted_fields,
+      updated_fields,
+    };
+    self.notify_did_update_database(notified_changeset).await
+  }
+
   async fn notify_did_update_database(
     &self,
     changeset: DatabaseFieldChangesetPB,
@@ -1,31 +1,40 @@
 use crate::entities::*;
 use crate::notification::{send_notification, DatabaseNotification};
+use crate::services::calculation::{
+  CalculationController, CalculationTaskHandler, CalculationValue,
+};
 use crate::services::cell::{AtomicCellDataCache, TypeCellData};
 use crate::services::database::DatabaseBlockEvent;
 use crate::services::database_view::notifier::DatabaseViewChangedNotifier;
 use crate::services::database_view::trait_impl::*;
 use crate::services::database_view::DatabaseViewChangedReceiverRunner;
-use crate::services::field::{RowSingleCellData, TypeOptionCellDataHandler};
+use crate::services::field::{
+  DateCellData, DateTypeOptionPB, RowSingleCellData, TypeOptionCellDataHandler,
+};
 use crate::services::filter::{
-  FilterChangeset, FilterController, FilterTaskHandler, FilterType, UpdatedFilterType,
+  FilterChangeset, FilterContext, FilterController, FilterTaskHandler, FilterType,
+  UpdatedFilterType,
 };
 use crate::services::group::{
-  default_group_configuration, find_grouping_field, make_group_controller, Group,
-  GroupConfigurationReader, GroupController, MoveGroupRowContext,
+  default_group_configuration, find_grouping_field, make_default_group_controller,
+  make_group_controller, Group, GroupConfigurationReader, GroupController, MoveGroupRowContext,
 };
 use crate::services::row::DatabaseBlockRowRevision;
 use crate::services::sort::{
   DeletedSortType, SortChangeset, SortController, SortTaskHandler, SortType,
 };
+use bytes::Bytes;
 use database_model::{
-  gen_database_filter_id, gen_database_id, gen_database_sort_id, CalendarLayoutSetting,
-  FieldRevision, FieldTypeRevision, FilterRevision, LayoutRevision, RowChangeset, RowRevision,
-  SortRevision,
+  gen_database_calculation_id, gen_database_filter_id, gen_database_id, gen_database_sort_id,
+  CalculationRevision, CalendarLayoutSetting, DateGroupConfigurationRevision, FieldRevision,
+  FieldTypeRevision, FilterGroupRevision, FilterRevision, GalleryCoverSource, GalleryLayoutSetting,
+  GroupConfigurationContentSerde, LayoutRevision, NumberGroupConfigurationRevision, RowChangeset,
+  RowRevision, SortRevision, TextGroupConfigurationRevision, TimelineLayoutSetting,
 };
 use flowy_client_sync::client_database::{
   make_database_view_operations, DatabaseViewRevisionChangeset, DatabaseViewRevisionPad,
 };
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
 use flowy_revision::RevisionManager;
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
@@ -82,8 +91,10 @@ pub struct DatabaseViewEditor {
   rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
   delegate: Arc<dyn DatabaseViewData>,
   group_controller: Arc<RwLock<Box<dyn GroupController>>>,
+  sub_group_controller: Arc<RwLock<Option<Box<dyn GroupController>>>>,
   filter_controller: Arc<FilterController>,
   sort_controller: Arc<RwLock<SortController>>,
+  calculation_controller: Arc<RwLock<CalculationController>>,
   pub notifier: DatabaseViewChangedNotifier,
 }
 
@@ -116,8 +127,19 @@ impl DatabaseViewEditor {
     )
     .await?;
 
+    let sub_group_controller = new_sub_group_controller(
+      user_id.to_owned(),
+      view_id.clone(),
+      view_rev_pad.clone(),
+      rev_manager.clone(),
+      delegate.clone(),
+      group_controller.field_id(),
+    )
+    .await?;
+
     let user_id = user_id.to_owned();
     let group_controller = Arc::new(RwLock::new(group_controller));
+    let sub_group_controller = Arc::new(RwLock::new(sub_group_controller));
     let filter_controller = make_filter_controller(
       &view_id,
       delegate.clone(),
@@ -133,6 +155,17 @@ impl DatabaseViewEditor {
       notifier.clone(),
       filter_controller.clone(),
       view_rev_pad.clone(),
+      cell_data_cache.clone(),
+    )
+    .await;
+
+    let calculation_controller = make_calculation_controller(
+      &view_id,
+      delegate.clone(),
+      notifier.clone(),
+      filter_controller.clone(),
+      group_controller.clone(),
+      view_rev_pad.clone(),
       cell_data_cache,
     )
     .await;
@@ -143,8 +176,10 @@ impl DatabaseViewEditor {
       rev_manager,
       delegate,
       group_controller,
+      sub_group_controller,
       filter_controller,
       sort_controller,
+      calculation_controller,
       notifier,
     })
   }
@@ -188,12 +223,86 @@ impl DatabaseViewEditor {
 
   #[tracing::instrument(name = "close database view editor", level = "trace", skip_all)]
   pub async fn close(&self) {
-    self.rev_manager.generate_snapshot().await;
+    self.v_write_checkpoint().await;
     self.rev_manager.close().await;
     self.sort_controller.write().await.close().await;
+    self.calculation_controller.write().await.close().await;
     self.filter_controller.close().await;
   }
 
+  pub async fn v_write_checkpoint(&self) {
+    let pad = self.pad.read().await;
+    let data = Bytes::from(pad.operations_json_str());
+    if let Err(e) = self.rev_manager.write_checkpoint(data).await {
+      tracing::error!(
+        "Write the checkpoint of view:{} failed: {}",
+        self.view_id,
+        e
+      );
+    }
+  }
+
+  /// Returns the view as it was at the timestamp, or None if the view has no checkpoint by then.
+  pub async fn v_read_checkpoint_at(
+    &self,
+    timestamp: i64,
+  ) -> FlowyResult<Option<DatabaseViewRevisionPad>> {
+    self
+      .rev_manager
+      .read_checkpoint_object_at::<DatabaseViewRevisionSerde>(timestamp)
+      .await
+  }
+
+  /// Restores the settings of the view to the passed-in view, if any, and then groups, filters,
+  /// sorts and calculates the rows again. It's called after the rows of the database are
+  /// restored, so the view is refreshed even if its settings are kept.
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn v_restore(&self, view_pad: Option<DatabaseViewRevisionPad>) -> FlowyResult<()> {
+    if let Some(view_pad) = view_pad {
+      self.modify(|pad| Ok(pad.restore_view(view_pad)?)).await?;
+    }
+
+    let group_controller = new_group_controller(
+      self.user_id.clone(),
+      self.view_id.clone(),
+      self.pad.clone(),
+      self.rev_manager.clone(),
+      self.delegate.clone(),
+    )
+    .await?;
+    let sub_group_controller = new_sub_group_controller(
+      self.user_id.clone(),
+      self.view_id.clone(),
+      self.pad.clone(),
+      self.rev_manager.clone(),
+      self.delegate.clone(),
+      group_controller.field_id(),
+    )
+    .await?;
+    *self.group_controller.write().await = group_controller;
+    *self.sub_group_controller.write().await = sub_group_controller;
+
+    self.filter_controller.did_restore_filters().await;
+    let field_revs = self.delegate.get_field_revs(None).await;
+    let sorts = self.pad.read().await.get_all_sorts(&field_revs);
+    self
+      .sort_controller
+      .write()
+      .await
+      .did_restore_sorts(sorts)
+      .await;
+    self
+      .calculation_controller
+      .read()
+      .await
+      .did_receive_changes()
+      .await;
+
+    self.notify_did_update_setting().await;
+    self.notify_did_group_by_field().await;
+    Ok(())
+  }
+
   pub async fn handle_block_event(&self, event: Cow<'_, DatabaseBlockEvent>) {
     let changeset = match event.into_owned() {
       DatabaseBlockEvent::InsertRow { block_id: _, row } => {
@@ -244,16 +353,23 @@ impl DatabaseViewEditor {
   }
 
   pub async fn v_will_create_row(&self, row_rev: &mut RowRevision, params: &CreateRowParams) {
-    if params.group_id.is_none() {
-      return;
+    if let Some(group_id) = params.group_id.as_ref() {
+      let _ = self
+        .mut_group_controller(|group_controller, field_rev| {
+          group_controller.will_create_row(row_rev, &field_rev, group_id);
+          Ok(())
+        })
+        .await;
+    }
+
+    if let Some(sub_group_id) = params.sub_group_id.as_ref() {
+      let _ = self
+        .mut_sub_group_controller(|sub_group_controller, field_rev| {
+          sub_group_controller.will_create_row(row_rev, &field_rev, sub_group_id);
+          Ok(())
+        })
+        .await;
     }
-    let group_id = params.group_id.as_ref().unwrap();
-    let _ = self
-      .mut_group_controller(|group_controller, field_rev| {
-        group_controller.will_create_row(row_rev, &field_rev, group_id);
-        Ok(())
-      })
-      .await;
   }
 
   pub async fn v_did_create_row(&self, row_pb: &RowPB, params: &CreateRowParams) {
@@ -280,6 +396,26 @@ impl DatabaseViewEditor {
         self.notify_did_update_group_rows(changeset).await;
       },
     }
+
+    if let Some(sub_group_id) = params.sub_group_id.as_ref() {
+      if let Some(sub_group_controller) = self.sub_group_controller.write().await.as_mut() {
+        sub_group_controller.did_create_row(row_pb, sub_group_id);
+      }
+      let inserted_row = InsertedRowPB {
+        row: row_pb.clone(),
+        index: None,
+        is_new: true,
+      };
+      let changeset = GroupRowsNotificationPB::insert(sub_group_id.clone(), vec![inserted_row]);
+      self.notify_did_update_sub_group_rows(changeset).await;
+    }
+
+    self
+      .calculation_controller
+      .read()
+      .await
+      .did_receive_rows_changed()
+      .await;
   }
 
   #[tracing::instrument(level = "trace", skip_all)]
@@ -297,6 +433,24 @@ impl DatabaseViewEditor {
         self.notify_did_update_group_rows(changeset).await;
       }
     }
+
+    let result = self
+      .mut_sub_group_controller(|sub_group_controller, field_rev| {
+        sub_group_controller.did_delete_delete_row(row_rev, &field_rev)
+      })
+      .await;
+    if let Some(result) = result {
+      for changeset in result.row_changesets {
+        self.notify_did_update_sub_group_rows(changeset).await;
+      }
+    }
+
+    self
+      .calculation_controller
+      .read()
+      .await
+      .did_receive_rows_changed()
+      .await;
   }
 
   pub async fn v_did_update_row(
@@ -334,8 +488,34 @@ impl DatabaseViewEditor {
       }
     }
 
+    let result = self
+      .mut_sub_group_controller(|sub_group_controller, field_rev| {
+        sub_group_controller.did_update_group_row(&old_row_rev, row_rev, &field_rev)
+      })
+      .await;
+    if let Some(result) = result {
+      let mut changeset = GroupChangesetPB {
+        view_id: self.view_id.clone(),
+        ..Default::default()
+      };
+      if let Some(inserted_group) = result.inserted_group {
+        changeset.inserted_groups.push(inserted_group);
+      }
+      if let Some(delete_group) = result.deleted_group {
+        changeset.deleted_groups.push(delete_group.group_id);
+      }
+      if !changeset.is_empty() {
+        self.notify_did_update_sub_groups(changeset).await;
+      }
+
+      for changeset in result.row_changesets {
+        self.notify_did_update_sub_group_rows(changeset).await;
+      }
+    }
+
     let filter_controller = self.filter_controller.clone();
     let sort_controller = self.sort_controller.clone();
+    let calculation_controller = self.calculation_controller.clone();
     let row_id = row_rev.id.clone();
     tokio::spawn(async move {
       filter_controller.did_receive_row_changed(&row_id).await;
@@ -344,6 +524,11 @@ impl DatabaseViewEditor {
         .await
         .did_receive_row_changed(&row_id)
         .await;
+      calculation_controller
+        .read()
+        .await
+        .did_receive_rows_changed()
+        .await;
     });
   }
 
@@ -383,6 +568,45 @@ impl DatabaseViewEditor {
       }
     }
   }
+
+  /// Moves the row to the sub group. The cell of the sub grouping field will be updated through
+  /// the `row_changeset`, the same as [Self::v_move_group_row].
+  pub async fn v_move_sub_group_row(
+    &self,
+    row_rev: &RowRevision,
+    row_changeset: &mut RowChangeset,
+    to_sub_group_id: &str,
+    to_row_id: Option<String>,
+  ) {
+    let result = self
+      .mut_sub_group_controller(|sub_group_controller, field_rev| {
+        let move_row_context = MoveGroupRowContext {
+          row_rev,
+          row_changeset,
+          field_rev: field_rev.as_ref(),
+          to_group_id: to_sub_group_id,
+          to_row_id,
+        };
+        sub_group_controller.move_group_row(move_row_context)
+      })
+      .await;
+
+    if let Some(result) = result {
+      if let Some(delete_group) = result.deleted_group {
+        let changeset = GroupChangesetPB {
+          view_id: self.view_id.clone(),
+          deleted_groups: vec![delete_group.group_id],
+          ..Default::default()
+        };
+        self.notify_did_update_sub_groups(changeset).await;
+      }
+
+      for changeset in result.row_changesets {
+        self.notify_did_update_sub_group_rows(changeset).await;
+      }
+    }
+  }
+
   /// Only call once after database view editor initialized
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn v_load_groups(&self) -> FlowyResult<Vec<GroupPB>> {
@@ -395,17 +619,53 @@ impl DatabaseViewEditor {
       .cloned()
       .collect::<Vec<Group>>();
     tracing::trace!("Number of groups: {}", groups.len());
-    Ok(groups.into_iter().map(GroupPB::from).collect())
+    Ok(self.make_group_pbs(groups).await)
   }
 
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn v_get_group(&self, group_id: &str) -> FlowyResult<GroupPB> {
-    match self.group_controller.read().await.get_group(group_id) {
+    let group = self.group_controller.read().await.get_group(group_id);
+    match group {
       None => Err(FlowyError::record_not_found().context("Can't find the group")),
-      Some((_, group)) => Ok(GroupPB::from(group)),
+      Some((_, group)) => Ok(self.make_group_pbs(vec![group]).await.remove(0)),
     }
   }
 
+  /// Returns the groups with their sub groups. The rows of a sub group are the rows of the group
+  /// that are also in the sub group of the sub grouping field.
+  async fn make_group_pbs(&self, groups: Vec<Group>) -> Vec<GroupPB> {
+    let sub_groups = match self.sub_group_controller.read().await.as_ref() {
+      None => vec![],
+      Some(sub_group_controller) => sub_group_controller
+        .groups()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<Group>>(),
+    };
+
+    groups
+      .into_iter()
+      .map(|group| {
+        let sub_groups = sub_groups
+          .iter()
+          .map(|sub_group| GroupPB {
+            rows: group
+              .rows
+              .iter()
+              .filter(|row| sub_group.contains_row(&row.id))
+              .cloned()
+              .collect(),
+            ..GroupPB::from(sub_group.clone())
+          })
+          .collect();
+        GroupPB {
+          sub_groups,
+          ..GroupPB::from(group)
+        }
+      })
+      .collect()
+  }
+
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub async fn v_move_group(&self, params: MoveGroupParams) -> FlowyResult<()> {
     self
@@ -444,6 +704,63 @@ impl DatabaseViewEditor {
     self.group_controller.read().await.field_id().to_string()
   }
 
+  /// Returns the id of the sub grouping field, or None if the view has no sub grouping field.
+  pub async fn sub_group_id(&self) -> Option<String> {
+    self
+      .sub_group_controller
+      .read()
+      .await
+      .as_ref()
+      .map(|sub_group_controller| sub_group_controller.field_id().to_string())
+  }
+
+  /// Groups the rows of each group by the field again, or removes the sub grouping if the
+  /// `field_id` is None.
+  pub async fn v_set_sub_group_by_field(&self, field_id: Option<String>) -> FlowyResult<()> {
+    match field_id {
+      None => {
+        self
+          .modify(|pad| Ok(pad.delete_sub_group_configuration()?))
+          .await?;
+        *self.sub_group_controller.write().await = None;
+      },
+      Some(field_id) => {
+        if self.group_controller.read().await.field_id() == field_id {
+          return Err(
+            FlowyError::invalid_data()
+              .context("The sub grouping field should be different from the grouping field"),
+          );
+        }
+
+        let field_rev = self.get_grouping_field_rev(&field_id).await?;
+        self
+          .modify(|pad| {
+            // Keep the configuration if the rows are already sub grouped by this field
+            if pad
+              .get_all_sub_groups()
+              .iter()
+              .any(|configuration| configuration.field_id == field_rev.id)
+            {
+              return Ok(None);
+            }
+            let configuration = default_group_configuration(&field_rev);
+            let changeset = pad.insert_or_update_sub_group_configuration(
+              &field_rev.id,
+              &field_rev.ty,
+              configuration,
+            )?;
+            Ok(changeset)
+          })
+          .await?;
+        self.v_update_sub_group_setting(&field_id).await?;
+      },
+    }
+
+    self.notify_did_update_setting().await;
+    self.notify_did_group_by_field().await;
+    Ok(())
+  }
+
   /// Initialize new group when grouping by a new field
   ///
   pub async fn v_initialize_new_group(&self, params: InsertGroupParams) -> FlowyResult<()> {
@@ -477,6 +794,135 @@ impl DatabaseViewEditor {
       .await
   }
 
+  /// Saves the new date condition to the group configuration of the field and regroups the rows
+  /// if the view is grouped by this field.
+  pub async fn v_update_date_group_condition(
+    &self,
+    params: UpdateDateGroupConditionParams,
+  ) -> FlowyResult<()> {
+    let field_rev = self.get_grouping_field_rev(&params.field_id).await?;
+    let field_type: FieldType = field_rev.ty.into();
+    if !field_type.is_date() && !field_type.is_timestamp() {
+      return Err(
+        FlowyError::invalid_data().context("The rows can only be grouped by date fields"),
+      );
+    }
+
+    let content = DateGroupConfigurationRevision {
+      hide_empty: false,
+      condition: params.condition,
+    }
+    .to_json()
+    .map_err(internal_error)?;
+    self
+      .update_group_configuration_content(&field_rev, content)
+      .await
+  }
+
+  pub async fn v_update_number_group_setting(
+    &self,
+    params: UpdateNumberGroupSettingParams,
+  ) -> FlowyResult<()> {
+    let field_rev = self.get_grouping_field_rev(&params.field_id).await?;
+    let field_type: FieldType = field_rev.ty.into();
+    if !(field_type.is_number() || field_type.is_auto_increment_id()) {
+      return Err(
+        FlowyError::invalid_data().context("The rows can only be grouped by number fields"),
+      );
+    }
+
+    let content = NumberGroupConfigurationRevision {
+      hide_empty: false,
+      ranges: params.ranges,
+      bucket_width: params.bucket_width,
+    }
+    .to_json()
+    .map_err(internal_error)?;
+    self
+      .update_group_configuration_content(&field_rev, content)
+      .await
+  }
+
+  pub async fn v_update_text_group_setting(
+    &self,
+    params: UpdateTextGroupSettingParams,
+  ) -> FlowyResult<()> {
+    let field_rev = self.get_grouping_field_rev(&params.field_id).await?;
+    let field_type: FieldType = field_rev.ty.into();
+    if !field_type.is_text() {
+      return Err(
+        FlowyError::invalid_data().context("The rows can only be grouped by text fields"),
+      );
+    }
+
+    let content = TextGroupConfigurationRevision {
+      hide_empty: false,
+      case_insensitive: params.case_insensitive,
+    }
+    .to_json()
+    .map_err(internal_error)?;
+    self
+      .update_group_configuration_content(&field_rev, content)
+      .await
+  }
+
+  async fn get_grouping_field_rev(&self, field_id: &str) -> FlowyResult<Arc<FieldRevision>> {
+    self
+      .delegate
+      .get_field_rev(field_id)
+      .await
+      .ok_or_else(|| FlowyError::record_not_found().context("Can't find the grouping field"))
+  }
+
+  /// Saves the content of the field's group configuration and regenerates the groups. The field
+  /// must be the grouping field or the sub grouping field because the view only keeps the
+  /// configurations of these two fields.
+  async fn update_group_configuration_content(
+    &self,
+    field_rev: &Arc<FieldRevision>,
+    content: String,
+  ) -> FlowyResult<()> {
+    let is_grouping_field = self.group_controller.read().await.field_id() == field_rev.id;
+    let is_sub_grouping_field = self.sub_group_id().await.as_ref() == Some(&field_rev.id);
+    if !is_grouping_field && !is_sub_grouping_field {
+      return Err(FlowyError::invalid_data().context("The view is not grouped by the field"));
+    }
+
+    self
+      .modify(|pad| {
+        let configurations = if is_grouping_field {
+          pad.get_all_groups()
+        } else {
+          pad.get_all_sub_groups()
+        };
+        let mut configuration = configurations
+          .into_iter()
+          .find(|configuration| configuration.field_id == field_rev.id)
+          .map(|configuration| (*configuration).clone())
+          .unwrap_or_else(|| default_group_configuration(field_rev));
+        configuration.content = content;
+        let changeset = if is_grouping_field {
+          pad.insert_or_update_group_configuration(&field_rev.id, &field_rev.ty, configuration)?
+        } else {
+          pad.insert_or_update_sub_group_configuration(
+            &field_rev.id,
+            &field_rev.ty,
+            configuration,
+          )?
+        };
+        Ok(changeset)
+      })
+      .await?;
+
+    if is_grouping_field {
+      self.v_update_group_setting(&field_rev.id).await?;
+    } else {
+      self.v_update_sub_group_setting(&field_rev.id).await?;
+      self.notify_did_group_by_field().await;
+    }
+    Ok(())
+  }
+
   pub async fn v_get_setting(&self) -> DatabaseViewSettingPB {
     let field_revs = self.delegate.get_field_revs(None).await;
     make_database_view_setting(&*self.pad.read().await, &field_revs)
@@ -572,63 +1018,146 @@ impl DatabaseViewEditor {
     Ok(())
   }
 
-  pub async fn v_get_all_filters(&self) -> Vec<Arc<FilterRevision>> {
-    let field_revs = self.delegate.get_field_revs(None).await;
-    self.pad.read().await.get_all_filters(&field_revs)
-  }
-
-  pub async fn v_get_filters(&self, filter_type: &FilterType) -> Vec<Arc<FilterRevision>> {
-    let field_type_rev: FieldTypeRevision = filter_type.field_type.clone().into();
+  /// Returns the calculated values of the view and, if the view is a board, of each group.
+  pub async fn v_get_calculation_values(&self) -> Vec<CalculationValue> {
     self
-      .pad
+      .calculation_controller
       .read()
       .await
-      .get_filters(&filter_type.field_id, &field_type_rev)
+      .calculate_all()
+      .await
   }
 
+  /// Sets the calculation of the field. The field's previous calculation will be replaced.
   #[tracing::instrument(level = "trace", skip(self), err)]
-  pub async fn v_insert_filter(&self, params: AlterFilterParams) -> FlowyResult<()> {
-    let filter_type = FilterType::from(&params);
-    let is_exist = params.filter_id.is_some();
-    let filter_id = match params.filter_id {
-      None => gen_database_filter_id(),
-      Some(filter_id) => filter_id,
-    };
-    let filter_rev = FilterRevision {
-      id: filter_id.clone(),
+  pub async fn v_update_calculation(
+    &self,
+    params: UpdateCalculationParams,
+  ) -> FlowyResult<CalculationRevision> {
+    let calculation_id = self
+      .pad
+      .read()
+      .await
+      .get_calculation(&params.field_id, &params.field_type)
+      .map(|calculation| calculation.id.clone())
+      .unwrap_or_else(gen_database_calculation_id);
+    let calculation_rev = CalculationRevision {
+      id: calculation_id,
       field_id: params.field_id.clone(),
       field_type: params.field_type,
-      condition: params.condition,
-      content: params.content,
+      calculation_type: params.calculation_type,
     };
-    let filter_controller = self.filter_controller.clone();
-    let changeset = if is_exist {
-      let old_filter_type = self
-        .delegate
-        .get_field_rev(&params.field_id)
-        .await
-        .map(|field| FilterType::from(&field));
-      self
-        .modify(|pad| {
-          let changeset = pad.update_filter(&params.field_id, filter_rev)?;
-          Ok(changeset)
-        })
-        .await?;
+
+    self
+      .modify(|pad| {
+        let changeset =
+          pad.insert_or_update_calculation(&params.field_id, calculation_rev.clone())?;
+        Ok(changeset)
+      })
+      .await?;
+
+    self
+      .calculation_controller
+      .read()
+      .await
+      .did_receive_changes()
+      .await;
+    self.notify_did_update_setting().await;
+    Ok(calculation_rev)
+  }
+
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_delete_calculation(&self, params: DeleteCalculationParams) -> FlowyResult<()> {
+    self
+      .modify(|pad| {
+        let changeset = pad.delete_calculation(&params.field_id, params.field_type)?;
+        Ok(changeset)
+      })
+      .await?;
+
+    self
+      .calculation_controller
+      .read()
+      .await
+      .did_receive_changes()
+      .await;
+    self.notify_did_update_setting().await;
+    Ok(())
+  }
+
+  pub async fn v_get_all_filters(&self) -> Vec<Arc<FilterRevision>> {
+    let field_revs = self.delegate.get_field_revs(None).await;
+    self.pad.read().await.get_all_filters(&field_revs)
+  }
+
+  pub async fn v_get_filters(&self, filter_type: &FilterType) -> Vec<Arc<FilterRevision>> {
+    let field_type_rev: FieldTypeRevision = filter_type.field_type.clone().into();
+    self
+      .pad
+      .read()
+      .await
+      .get_filters(&filter_type.field_id, &field_type_rev)
+  }
+
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_insert_filter(&self, params: AlterFilterParams) -> FlowyResult<()> {
+    let filter_type = FilterType::from(&params);
+    let group_id = params.group_id.clone();
+    if let Some(group_id) = group_id.as_ref() {
+      if self
+        .v_get_filter_group()
+        .await
+        .find_group(group_id)
+        .is_none()
+      {
+        return Err(
+          FlowyError::record_not_found()
+            .context(format!("Can't find the filter group with id: {}", group_id)),
+        );
+      }
+    }
+    let is_exist = params.filter_id.is_some();
+    let filter_id = match params.filter_id {
+      None => gen_database_filter_id(),
+      Some(filter_id) => filter_id,
+    };
+    let filter_rev = FilterRevision {
+      id: filter_id.clone(),
+      field_id: params.field_id.clone(),
+      field_type: params.field_type,
+      condition: params.condition,
+      content: params.content,
+    };
+    let filter_controller = self.filter_controller.clone();
+    let changeset = if is_exist {
+      let old_filter_type = self
+        .delegate
+        .get_field_rev(&params.field_id)
+        .await
+        .map(|field| FilterType::from(&field));
+      self
+        .modify(|pad| {
+          let changeset = pad.update_filter(&params.field_id, filter_rev, group_id.as_deref())?;
+          Ok(changeset)
+        })
+        .await?;
       filter_controller
-        .did_receive_changes(FilterChangeset::from_update(UpdatedFilterType::new(
-          old_filter_type,
-          filter_type,
-        )))
+        .did_receive_changes(FilterChangeset::from_update(
+          UpdatedFilterType::new(old_filter_type, filter_type).with_filter_id(&filter_id),
+        ))
         .await
     } else {
       self
         .modify(|pad| {
-          let changeset = pad.insert_filter(&params.field_id, filter_rev)?;
+          let changeset = pad.insert_filter(&params.field_id, filter_rev, group_id.as_deref())?;
           Ok(changeset)
         })
         .await?;
       filter_controller
-        .did_receive_changes(FilterChangeset::from_insert(filter_type))
+        .did_receive_changes(FilterChangeset::from_insert(FilterContext::new(
+          &filter_id,
+          filter_type,
+        )))
         .await
     };
     drop(filter_controller);
@@ -636,6 +1165,12 @@ impl DatabaseViewEditor {
     if let Some(changeset) = changeset {
       self.notify_did_update_filter(changeset).await;
     }
+    self
+      .calculation_controller
+      .read()
+      .await
+      .did_receive_rows_changed()
+      .await;
     Ok(())
   }
 
@@ -644,7 +1179,10 @@ impl DatabaseViewEditor {
     let filter_type = params.filter_type;
     let changeset = self
       .filter_controller
-      .did_receive_changes(FilterChangeset::from_delete(filter_type.clone()))
+      .did_receive_changes(FilterChangeset::from_delete(FilterContext::new(
+        &params.filter_id,
+        filter_type.clone(),
+      )))
       .await;
 
     self
@@ -661,9 +1199,130 @@ impl DatabaseViewEditor {
     if changeset.is_some() {
       self.notify_did_update_filter(changeset.unwrap()).await;
     }
+    self
+      .calculation_controller
+      .read()
+      .await
+      .did_receive_rows_changed()
+      .await;
     Ok(())
   }
 
+  pub async fn v_get_filter_group(&self) -> FilterGroupRevision {
+    self.pad.read().await.get_filter_group()
+  }
+
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_insert_filter_group(&self, params: AlterFilterGroupParams) -> FlowyResult<()> {
+    match params.group_id {
+      None => {
+        let group_rev = FilterGroupRevision::new(params.operator);
+        self
+          .modify(|pad| {
+            let changeset =
+              pad.insert_filter_group(group_rev, params.parent_group_id.as_deref())?;
+            Ok(changeset)
+          })
+          .await?;
+      },
+      Some(group_id) => {
+        self
+          .modify(|pad| {
+            let changeset = pad.update_filter_group(&group_id, params.operator)?;
+            Ok(changeset)
+          })
+          .await?;
+      },
+    }
+
+    self
+      .filter_controller
+      .did_receive_filter_group_changes()
+      .await;
+    self
+      .calculation_controller
+      .read()
+      .await
+      .did_receive_rows_changed()
+      .await;
+    self.notify_did_update_setting().await;
+    Ok(())
+  }
+
+  /// Deletes the filter group and the filters that are belong to it. The root filter group
+  /// can't be deleted.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_delete_filter_group(&self, params: DeleteFilterGroupParams) -> FlowyResult<()> {
+    if self.v_get_filter_group().await.id == params.group_id {
+      return Err(FlowyError::internal().context("The root filter group can't be deleted"));
+    }
+
+    let filter_revs = self.pad.read().await.get_filters_in_group(&params.group_id);
+    for filter_rev in filter_revs {
+      let delete_params = DeleteFilterParams {
+        view_id: self.view_id.clone(),
+        filter_type: FilterType::from(filter_rev.as_ref()),
+        filter_id: filter_rev.id.clone(),
+      };
+      self.v_delete_filter(delete_params).await?;
+    }
+
+    self
+      .modify(|pad| {
+        let changeset = pad.delete_filter_group(&params.group_id)?;
+        Ok(changeset)
+      })
+      .await?;
+
+    self
+      .filter_controller
+      .did_receive_filter_group_changes()
+      .await;
+    self
+      .calculation_controller
+      .read()
+      .await
+      .did_receive_rows_changed()
+      .await;
+    self.notify_did_update_setting().await;
+    Ok(())
+  }
+
+  /// Returns the fields that are displayed in the view, in the order they are displayed. The
+  /// gallery displays the fields picked in its layout setting, the other layouts display the
+  /// fields that aren't hidden.
+  pub async fn v_get_visible_field_revs(&self) -> Vec<Arc<FieldRevision>> {
+    let field_revs = self.delegate.get_field_revs(None).await;
+    let layout = self.pad.read().await.layout();
+    let gallery_field_ids = match layout {
+      LayoutRevision::Gallery => self
+        .pad
+        .read()
+        .await
+        .get_layout_setting::<GalleryLayoutSetting>(&layout)
+        .map(|gallery| gallery.visible_field_ids)
+        .unwrap_or_default(),
+      _ => vec![],
+    };
+
+    if gallery_field_ids.is_empty() {
+      field_revs
+        .into_iter()
+        .filter(|field_rev| field_rev.visibility)
+        .collect()
+    } else {
+      gallery_field_ids
+        .iter()
+        .flat_map(|field_id| {
+          field_revs
+            .iter()
+            .find(|field_rev| &field_rev.id == field_id)
+            .cloned()
+        })
+        .collect()
+    }
+  }
+
   /// Returns the current calendar settings
   #[tracing::instrument(level = "debug", skip(self), err)]
   pub async fn v_get_layout_settings(
@@ -692,6 +1351,37 @@ impl DatabaseViewEditor {
           }
         }
       },
+      LayoutRevision::Timeline => {
+        if let Some(timeline) = self
+          .pad
+          .read()
+          .await
+          .get_layout_setting::<TimelineLayoutSetting>(layout_ty)
+        {
+          if self.check_timeline_fields(&timeline).await.is_ok() {
+            layout_setting.timeline = Some(timeline);
+          }
+        }
+      },
+      LayoutRevision::Gallery => {
+        if let Some(mut gallery) = self
+          .pad
+          .read()
+          .await
+          .get_layout_setting::<GalleryLayoutSetting>(layout_ty)
+        {
+          // Skip the visible fields that were deleted
+          let field_revs = self.delegate.get_field_revs(None).await;
+          gallery
+            .visible_field_ids
+            .retain(|field_id| field_revs.iter().any(|field_rev| &field_rev.id == field_id));
+          if self.check_gallery_cover_field(&gallery).await.is_err() {
+            gallery.cover_source = GalleryCoverSource::None;
+            gallery.cover_field_id = None;
+          }
+          layout_setting.gallery = Some(gallery);
+        }
+      },
     }
 
     tracing::debug!("{:?}", layout_setting);
@@ -721,6 +1411,8 @@ impl DatabaseViewEditor {
         let new_field_id = new_calendar_setting.layout_field_id.clone();
         let layout_setting_pb: LayoutSettingPB = LayoutSettingParams {
           calendar: Some(new_calendar_setting),
+          timeline: None,
+          gallery: None,
         }
         .into();
 
@@ -743,6 +1435,93 @@ impl DatabaseViewEditor {
       }
     }
 
+    if let Some(new_timeline_setting) = params.timeline {
+      self.check_timeline_fields(&new_timeline_setting).await?;
+      let layout_ty = LayoutRevision::Timeline;
+      self
+        .modify(|pad| Ok(pad.set_layout_setting(&layout_ty, &new_timeline_setting)?))
+        .await?;
+
+      let layout_setting_pb: LayoutSettingPB = LayoutSettingParams {
+        calendar: None,
+        timeline: Some(new_timeline_setting),
+        gallery: None,
+      }
+      .into();
+      send_notification(&self.view_id, DatabaseNotification::DidUpdateLayoutSettings)
+        .payload(layout_setting_pb)
+        .send();
+    }
+
+    if let Some(new_gallery_setting) = params.gallery {
+      self.check_gallery_cover_field(&new_gallery_setting).await?;
+      let layout_ty = LayoutRevision::Gallery;
+      self
+        .modify(|pad| Ok(pad.set_layout_setting(&layout_ty, &new_gallery_setting)?))
+        .await?;
+
+      let layout_setting_pb: LayoutSettingPB = LayoutSettingParams {
+        calendar: None,
+        timeline: None,
+        gallery: Some(new_gallery_setting),
+      }
+      .into();
+      send_notification(&self.view_id, DatabaseNotification::DidUpdateLayoutSettings)
+        .payload(layout_setting_pb)
+        .send();
+    }
+
+    Ok(())
+  }
+
+  /// The cover field of the gallery must be a URL field if the cover is read from a field.
+  async fn check_gallery_cover_field(&self, setting: &GalleryLayoutSetting) -> FlowyResult<()> {
+    if setting.cover_source != GalleryCoverSource::URLField {
+      return Ok(());
+    }
+
+    let cover_field_id = setting
+      .cover_field_id
+      .as_ref()
+      .ok_or_else(FlowyError::unexpect_gallery_field_type)?;
+    let field_rev = self
+      .delegate
+      .get_field_rev(cover_field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    let field_type: FieldType = field_rev.ty.into();
+    if field_type != FieldType::URL {
+      return Err(FlowyError::unexpect_gallery_field_type());
+    }
+    Ok(())
+  }
+
+  /// The start and end fields of the timeline must be date fields, and the dependency field must
+  /// be a relation field.
+  async fn check_timeline_fields(&self, setting: &TimelineLayoutSetting) -> FlowyResult<()> {
+    for field_id in [&setting.start_field_id, &setting.end_field_id] {
+      let field_rev = self
+        .delegate
+        .get_field_rev(field_id)
+        .await
+        .ok_or_else(FlowyError::field_record_not_found)?;
+      let field_type: FieldType = field_rev.ty.into();
+      if field_type != FieldType::DateTime {
+        return Err(FlowyError::unexpect_timeline_field_type());
+      }
+    }
+
+    if let Some(dependency_field_id) = &setting.dependency_field_id {
+      let field_rev = self
+        .delegate
+        .get_field_rev(dependency_field_id)
+        .await
+        .ok_or_else(FlowyError::field_record_not_found)?;
+      let field_type: FieldType = field_rev.ty.into();
+      if field_type != FieldType::Relation {
+        return Err(FlowyError::unexpect_timeline_field_type());
+      }
+    }
     Ok(())
   }
 
@@ -765,6 +1544,13 @@ impl DatabaseViewEditor {
         .did_update_view_field_type_option(&field_rev)
         .await;
 
+      self
+        .calculation_controller
+        .read()
+        .await
+        .did_receive_changes()
+        .await;
+
       let filter_controller = self.filter_controller.clone();
       let _ = tokio::spawn(async move {
         if let Some(notification) = filter_controller
@@ -788,11 +1574,17 @@ impl DatabaseViewEditor {
   ///
   #[tracing::instrument(level = "debug", skip_all, err)]
   pub async fn v_update_group_setting(&self, field_id: &str) -> FlowyResult<()> {
+    // The rows can't be grouped by the same field twice
+    if self.sub_group_id().await.as_deref() == Some(field_id) {
+      self.v_set_sub_group_by_field(None).await?;
+    }
+
     if let Some(field_rev) = self.delegate.get_field_rev(field_id).await {
       let row_revs = self.delegate.get_row_revs(None).await;
       let configuration_reader = GroupConfigurationReaderImpl {
         pad: self.pad.clone(),
         view_editor_delegate: self.delegate.clone(),
+        is_sub_group: false,
       };
       let new_group_controller = new_group_controller_with_field_rev(
         self.user_id.clone(),
@@ -805,25 +1597,40 @@ impl DatabaseViewEditor {
       )
       .await?;
 
-      let new_groups = new_group_controller
-        .groups()
-        .into_iter()
-        .map(|group| GroupPB::from(group.clone()))
-        .collect();
-
       *self.group_controller.write().await = new_group_controller;
-      let changeset = GroupChangesetPB {
-        view_id: self.view_id.clone(),
-        initial_groups: new_groups,
-        ..Default::default()
+      self.notify_did_group_by_field().await;
+      self
+        .calculation_controller
+        .read()
+        .await
+        .did_receive_changes()
+        .await;
+    }
+    Ok(())
+  }
+
+  /// Regenerates the sub groups with the sub grouping field.
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub async fn v_update_sub_group_setting(&self, field_id: &str) -> FlowyResult<()> {
+    if let Some(field_rev) = self.delegate.get_field_rev(field_id).await {
+      let row_revs = self.delegate.get_row_revs(None).await;
+      let configuration_reader = GroupConfigurationReaderImpl {
+        pad: self.pad.clone(),
+        view_editor_delegate: self.delegate.clone(),
+        is_sub_group: true,
       };
+      let new_sub_group_controller = new_group_controller_with_field_rev(
+        self.user_id.clone(),
+        self.view_id.clone(),
+        self.pad.clone(),
+        self.rev_manager.clone(),
+        field_rev,
+        row_revs,
+        configuration_reader,
+      )
+      .await?;
 
-      debug_assert!(!changeset.is_empty());
-      if !changeset.is_empty() {
-        send_notification(&changeset.view_id, DatabaseNotification::DidGroupByField)
-          .payload(changeset)
-          .send();
-      }
+      *self.sub_group_controller.write().await = Some(new_sub_group_controller);
     }
     Ok(())
   }
@@ -859,17 +1666,16 @@ impl DatabaseViewEditor {
       .unwrap_or_default()
       .into();
 
-    let timestamp = date_cell
-      .into_date_field_cell_data()
-      .unwrap_or_default()
-      .timestamp
-      .unwrap_or_default();
+    let date_cell_data = date_cell.into_date_field_cell_data().unwrap_or_default();
+    let timestamp = date_cell_data.timestamp.unwrap_or_default();
 
     Some(CalendarEventPB {
       row_id: row_id.to_string(),
       date_field_id: date_field.id.clone(),
       title,
       timestamp,
+      occurrence_timestamp: timestamp,
+      end_timestamp: date_cell_data.end_timestamp,
     })
   }
 
@@ -886,7 +1692,7 @@ impl DatabaseViewEditor {
     let text_cells = self.v_get_cells_for_field(&primary_field.id).await.ok()?;
 
     // Date
-    let timestamp_by_row_id = self
+    let date_cell_data_by_row_id = self
       .v_get_cells_for_field(&calendar_setting.layout_field_id)
       .await
       .ok()?
@@ -895,21 +1701,22 @@ impl DatabaseViewEditor {
         let row_id = date_cell.row_id.clone();
 
         // timestamp
-        let timestamp = date_cell
-          .into_date_field_cell_data()
-          .map(|date_cell_data| date_cell_data.timestamp.unwrap_or_default())
-          .unwrap_or_default();
-
-        (row_id, timestamp)
+        let date_cell_data = date_cell.into_date_field_cell_data().unwrap_or_default();
+        (row_id, date_cell_data)
       })
-      .collect::<HashMap<String, i64>>();
+      .collect::<HashMap<String, DateCellData>>();
 
     let mut events: Vec<CalendarEventPB> = vec![];
     for text_cell in text_cells {
       let row_id = text_cell.row_id.clone();
-      let timestamp = timestamp_by_row_id
+      let (timestamp, end_timestamp) = date_cell_data_by_row_id
         .get(&row_id)
-        .cloned()
+        .map(|date_cell_data| {
+          (
+            date_cell_data.timestamp.unwrap_or_default(),
+            date_cell_data.end_timestamp,
+          )
+        })
         .unwrap_or_default();
 
       let title = text_cell
@@ -922,6 +1729,8 @@ impl DatabaseViewEditor {
         date_field_id: calendar_setting.layout_field_id.clone(),
         title,
         timestamp,
+        occurrence_timestamp: timestamp,
+        end_timestamp,
       };
       events.push(event);
     }
@@ -929,6 +1738,210 @@ impl DatabaseViewEditor {
     Some(events)
   }
 
+  /// Returns the events that overlap the range. The repeated dates are expanded into one event
+  /// per occurrence, with the rescheduled and cancelled occurrences applied. Each occurrence of
+  /// a date range lasts as long as the range.
+  pub async fn v_get_calendar_events_in_range(
+    &self,
+    start_timestamp: i64,
+    end_timestamp: i64,
+  ) -> Option<Vec<CalendarEventPB>> {
+    let layout_ty = LayoutRevision::Calendar;
+    let calendar_setting = self
+      .v_get_layout_settings(&layout_ty)
+      .await
+      .ok()?
+      .calendar?;
+
+    // Text
+    let primary_field = self.delegate.get_primary_field_rev().await?;
+    let title_by_row_id = self
+      .v_get_cells_for_field(&primary_field.id)
+      .await
+      .ok()?
+      .into_iter()
+      .map(|text_cell| {
+        let row_id = text_cell.row_id.clone();
+        let title: String = text_cell
+          .into_text_field_cell_data()
+          .unwrap_or_default()
+          .into();
+        (row_id, title)
+      })
+      .collect::<HashMap<String, String>>();
+
+    // Date
+    let date_field = self
+      .delegate
+      .get_field_rev(&calendar_setting.layout_field_id)
+      .await?;
+    let recurrence = date_field
+      .get_type_option::<DateTypeOptionPB>(date_field.ty)
+      .and_then(|type_option| type_option.recurrence);
+    let date_cells = self.v_get_cells_for_field(&date_field.id).await.ok()?;
+
+    let mut events: Vec<CalendarEventPB> = vec![];
+    for date_cell in date_cells {
+      let row_id = date_cell.row_id.clone();
+      let date_cell_data = match date_cell.into_date_field_cell_data() {
+        Some(date_cell_data) => date_cell_data,
+        None => continue,
+      };
+      let timestamp = match date_cell_data.timestamp {
+        Some(timestamp) => timestamp,
+        None => continue,
+      };
+      let title = title_by_row_id.get(&row_id).cloned().unwrap_or_default();
+      let duration = date_cell_data
+        .end_timestamp
+        .map(|end_timestamp| end_timestamp - timestamp);
+      let in_range = |timestamp: i64| {
+        timestamp + duration.unwrap_or_default() >= start_timestamp && timestamp <= end_timestamp
+      };
+      let new_event = |timestamp: i64, occurrence_timestamp: i64| CalendarEventPB {
+        row_id: row_id.clone(),
+        date_field_id: date_field.id.clone(),
+        title: title.clone(),
+        timestamp,
+        occurrence_timestamp,
+        end_timestamp: duration.map(|duration| timestamp + duration),
+      };
+
+      match &recurrence {
+        None => {
+          if in_range(timestamp) {
+            events.push(new_event(timestamp, timestamp));
+          }
+        },
+        Some(recurrence) => {
+          let overrides = &date_cell_data.overrides;
+          // The occurrences that start before the range might last into it.
+          let range_start = start_timestamp - duration.unwrap_or_default();
+          for occurrence in recurrence.occurrences_in_range(timestamp, range_start, end_timestamp) {
+            if overrides
+              .iter()
+              .all(|item| item.occurrence_timestamp != occurrence)
+            {
+              events.push(new_event(occurrence, occurrence));
+            }
+          }
+
+          // The rescheduled occurrences might be moved into the range from outside of it.
+          for item in overrides {
+            if let Some(new_timestamp) = item.timestamp {
+              if in_range(new_timestamp) {
+                events.push(new_event(new_timestamp, item.occurrence_timestamp));
+              }
+            }
+          }
+        },
+      }
+    }
+
+    events.sort_by_key(|event| event.timestamp);
+    Some(events)
+  }
+
+  /// Returns the bars that overlap the date window. The rows without a start date are skipped,
+  /// and a bar ends at its start date if the row doesn't have an end date.
+  pub async fn v_get_timeline_bars(
+    &self,
+    start_timestamp: i64,
+    end_timestamp: i64,
+  ) -> Option<Vec<TimelineBarPB>> {
+    let layout_ty = LayoutRevision::Timeline;
+    let timeline_setting = self
+      .v_get_layout_settings(&layout_ty)
+      .await
+      .ok()?
+      .timeline?;
+
+    // Text
+    let primary_field = self.delegate.get_primary_field_rev().await?;
+    let title_by_row_id = self
+      .v_get_cells_for_field(&primary_field.id)
+      .await
+      .ok()?
+      .into_iter()
+      .map(|text_cell| {
+        let row_id = text_cell.row_id.clone();
+        let title: String = text_cell
+          .into_text_field_cell_data()
+          .unwrap_or_default()
+          .into();
+        (row_id, title)
+      })
+      .collect::<HashMap<String, String>>();
+
+    // Dates
+    let start_cells = self
+      .v_get_cells_for_field(&timeline_setting.start_field_id)
+      .await
+      .ok()?;
+    let end_timestamp_by_row_id =
+      if timeline_setting.start_field_id == timeline_setting.end_field_id {
+        HashMap::new()
+      } else {
+        self
+          .v_get_cells_for_field(&timeline_setting.end_field_id)
+          .await
+          .ok()?
+          .into_iter()
+          .flat_map(|date_cell| {
+            let row_id = date_cell.row_id.clone();
+            let timestamp = date_cell.into_date_field_cell_data()?.timestamp?;
+            Some((row_id, timestamp))
+          })
+          .collect::<HashMap<String, i64>>()
+      };
+
+    // Dependencies
+    let mut dependencies_by_row_id = HashMap::new();
+    if let Some(dependency_field_id) = &timeline_setting.dependency_field_id {
+      for relation_cell in self.v_get_cells_for_field(dependency_field_id).await.ok()? {
+        let row_id = relation_cell.row_id.clone();
+        if let Some(relation_cell_data) = relation_cell.into_relation_field_cell_data() {
+          dependencies_by_row_id.insert(row_id, relation_cell_data.row_ids);
+        }
+      }
+    }
+
+    let mut bars = vec![];
+    for start_cell in start_cells {
+      let row_id = start_cell.row_id.clone();
+      let date_cell_data = match start_cell.into_date_field_cell_data() {
+        Some(date_cell_data) => date_cell_data,
+        None => continue,
+      };
+      let bar_start = match date_cell_data.timestamp {
+        Some(timestamp) => timestamp,
+        None => continue,
+      };
+      let bar_end = if timeline_setting.start_field_id == timeline_setting.end_field_id {
+        date_cell_data.end_timestamp
+      } else {
+        end_timestamp_by_row_id.get(&row_id).cloned()
+      }
+      .unwrap_or(bar_start)
+      .max(bar_start);
+
+      if bar_end < start_timestamp || bar_start > end_timestamp {
+        continue;
+      }
+
+      bars.push(TimelineBarPB {
+        title: title_by_row_id.get(&row_id).cloned().unwrap_or_default(),
+        start_timestamp: bar_start,
+        end_timestamp: bar_end,
+        dependencies: dependencies_by_row_id.remove(&row_id).unwrap_or_default(),
+        row_id,
+      });
+    }
+
+    bars.sort_by_key(|bar| bar.start_timestamp);
+    Some(bars)
+  }
+
   async fn notify_did_update_setting(&self) {
     let setting = self.v_get_setting().await;
     send_notification(&self.view_id, DatabaseNotification::DidUpdateSettings)
@@ -962,6 +1975,45 @@ impl DatabaseViewEditor {
       .send();
   }
 
+  pub async fn notify_did_update_sub_group_rows(&self, payload: GroupRowsNotificationPB) {
+    send_notification(
+      &payload.group_id,
+      DatabaseNotification::DidUpdateSubGroupRow,
+    )
+    .payload(payload)
+    .send();
+  }
+
+  async fn notify_did_update_sub_groups(&self, changeset: GroupChangesetPB) {
+    send_notification(&self.view_id, DatabaseNotification::DidUpdateSubGroups)
+      .payload(changeset)
+      .send();
+  }
+
+  /// Sends all the groups, including their sub groups, as the initial groups.
+  async fn notify_did_group_by_field(&self) {
+    let groups = self
+      .group_controller
+      .read()
+      .await
+      .groups()
+      .into_iter()
+      .cloned()
+      .collect::<Vec<Group>>();
+    let changeset = GroupChangesetPB {
+      view_id: self.view_id.clone(),
+      initial_groups: self.make_group_pbs(groups).await,
+      ..Default::default()
+    };
+
+    debug_assert!(!changeset.is_empty());
+    if !changeset.is_empty() {
+      send_notification(&changeset.view_id, DatabaseNotification::DidGroupByField)
+        .payload(changeset)
+        .send();
+    }
+  }
+
   async fn modify<F>(&self, f: F) -> FlowyResult<()>
   where
     F: for<'a> FnOnce(
@@ -992,6 +2044,17 @@ impl DatabaseViewEditor {
     }
   }
 
+  async fn mut_sub_group_controller<F, T>(&self, f: F) -> Option<T>
+  where
+    F: FnOnce(&mut Box<dyn GroupController>, Arc<FieldRevision>) -> FlowyResult<T>,
+  {
+    let sub_group_field_id = self.sub_group_id().await?;
+    let field_rev = self.delegate.get_field_rev(&sub_group_field_id).await?;
+    let mut write_guard = self.sub_group_controller.write().await;
+    let sub_group_controller = write_guard.as_mut()?;
+    f(sub_group_controller, field_rev).ok()
+  }
+
   #[allow(dead_code)]
   async fn async_mut_group_controller<F, O, T>(&self, f: F) -> Option<T>
   where
@@ -1074,11 +2137,64 @@ async fn new_group_controller(
   let configuration_reader = GroupConfigurationReaderImpl {
     pad: view_rev_pad.clone(),
     view_editor_delegate: delegate.clone(),
+    is_sub_group: false,
   };
   let field_revs = delegate.get_field_revs(None).await;
   let row_revs = delegate.get_row_revs(None).await;
   let layout = view_rev_pad.read().await.layout();
   // Read the group field or find a new group field
+  let configured_field_rev =
+    configuration_reader
+      .get_configuration()
+      .await
+      .and_then(|configuration| {
+        field_revs
+          .iter()
+          .find(|field_rev| field_rev.id == configuration.field_id)
+          .cloned()
+      });
+  let field_rev = match configured_field_rev {
+    Some(field_rev) => field_rev,
+    None => {
+      let field_rev = find_grouping_field(&field_revs, &layout).unwrap();
+      // The primary field is only used as a fallback, so its cells aren't grouped unless the
+      // user picks it as the grouping field.
+      let field_type: FieldType = field_rev.ty.into();
+      if !field_type.can_be_group() {
+        return make_default_group_controller(field_rev, row_revs);
+      }
+      field_rev
+    },
+  };
+
+  new_group_controller_with_field_rev(
+    user_id,
+    view_id,
+    view_rev_pad,
+    rev_manager,
+    field_rev,
+    row_revs,
+    configuration_reader,
+  )
+  .await
+}
+
+/// Returns the [GroupController] of the sub grouping field, or None if the view has no sub
+/// grouping field.
+async fn new_sub_group_controller(
+  user_id: String,
+  view_id: String,
+  view_rev_pad: Arc<RwLock<DatabaseViewRevisionPad>>,
+  rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
+  delegate: Arc<dyn DatabaseViewData>,
+  grouping_field_id: &str,
+) -> FlowyResult<Option<Box<dyn GroupController>>> {
+  let configuration_reader = GroupConfigurationReaderImpl {
+    pad: view_rev_pad.clone(),
+    view_editor_delegate: delegate.clone(),
+    is_sub_group: true,
+  };
+  let field_revs = delegate.get_field_revs(None).await;
   let field_rev = configuration_reader
     .get_configuration()
     .await
@@ -1087,10 +2203,14 @@ async fn new_group_controller(
         .iter()
         .find(|field_rev| field_rev.id == configuration.field_id)
         .cloned()
-    })
-    .unwrap_or_else(|| find_grouping_field(&field_revs, &layout).unwrap());
+    });
+  let field_rev = match field_rev {
+    Some(field_rev) if field_rev.id != grouping_field_id => field_rev,
+    _ => return Ok(None),
+  };
 
-  new_group_controller_with_field_rev(
+  let row_revs = delegate.get_row_revs(None).await;
+  let sub_group_controller = new_group_controller_with_field_rev(
     user_id,
     view_id,
     view_rev_pad,
@@ -1099,7 +2219,8 @@ async fn new_group_controller(
     row_revs,
     configuration_reader,
   )
-  .await
+  .await?;
+  Ok(Some(sub_group_controller))
 }
 
 /// Returns a [GroupController]
@@ -1117,6 +2238,7 @@ async fn new_group_controller_with_field_rev(
     user_id,
     rev_manager,
     view_pad: view_rev_pad,
+    is_sub_group: configuration_reader.is_sub_group,
   };
   make_group_controller(
     view_id,
@@ -1198,6 +2320,42 @@ async fn make_sort_controller(
   sort_controller
 }
 
+async fn make_calculation_controller(
+  view_id: &str,
+  delegate: Arc<dyn DatabaseViewData>,
+  notifier: DatabaseViewChangedNotifier,
+  filter_controller: Arc<FilterController>,
+  group_controller: Arc<RwLock<Box<dyn GroupController>>>,
+  pad: Arc<RwLock<DatabaseViewRevisionPad>>,
+  cell_data_cache: AtomicCellDataCache,
+) -> Arc<RwLock<CalculationController>> {
+  let handler_id = gen_handler_id();
+  let calculation_delegate = DatabaseViewCalculationDelegateImpl {
+    editor_delegate: delegate.clone(),
+    view_revision_pad: pad,
+    filter_controller,
+    group_controller,
+  };
+  let task_scheduler = delegate.get_task_scheduler();
+  let calculation_controller = Arc::new(RwLock::new(CalculationController::new(
+    view_id,
+    &handler_id,
+    calculation_delegate,
+    task_scheduler.clone(),
+    cell_data_cache,
+    notifier,
+  )));
+  task_scheduler
+    .write()
+    .await
+    .register_handler(CalculationTaskHandler::new(
+      handler_id,
+      calculation_controller.clone(),
+    ));
+
+  calculation_controller
+}
+
 fn gen_handler_id() -> String {
   nanoid!(10)
 }
@@ -1,10 +1,13 @@
 #![allow(clippy::while_let_loop)]
 use crate::entities::{
-  AlterFilterParams, AlterSortParams, CreateRowParams, DatabaseViewSettingPB, DeleteFilterParams,
+  AlterFilterGroupParams, AlterFilterParams, AlterSortParams, CreateRowParams,
+  DatabaseViewSettingPB, DeleteCalculationParams, DeleteFilterGroupParams, DeleteFilterParams,
   DeleteGroupParams, DeleteSortParams, GroupPB, InsertGroupParams, LayoutSettingParams,
-  MoveGroupParams, RepeatedGroupPB, RowPB,
+  MoveGroupParams, RepeatedGroupPB, RowPB, SubGroupByFieldParams, UpdateCalculationParams,
+  UpdateDateGroupConditionParams, UpdateNumberGroupSettingParams, UpdateTextGroupSettingParams,
 };
 use crate::manager::DatabaseUser;
+use crate::services::calculation::CalculationValue;
 use crate::services::cell::AtomicCellDataCache;
 use crate::services::database::DatabaseBlockEvent;
 use crate::services::database_view::notifier::*;
@@ -17,7 +20,8 @@ use crate::services::persistence::rev_sqlite::{
   SQLiteDatabaseRevisionSnapshotPersistence, SQLiteDatabaseViewRevisionPersistence,
 };
 use database_model::{
-  FieldRevision, FilterRevision, LayoutRevision, RowChangeset, RowRevision, SortRevision,
+  CalculationRevision, FieldRevision, FilterGroupRevision, FilterRevision, LayoutRevision,
+  RowChangeset, RowRevision, SortRevision,
 };
 use flowy_client_sync::client_database::DatabaseViewRevisionPad;
 use flowy_error::FlowyResult;
@@ -69,6 +73,39 @@ impl DatabaseViews {
     }
   }
 
+  pub async fn write_checkpoints(&self) {
+    for view_editor in self.view_editors.read().await.values() {
+      view_editor.v_write_checkpoint().await;
+    }
+  }
+
+  /// Returns each view with its settings at the timestamp. The settings are None if the view has
+  /// no checkpoint by then.
+  pub async fn read_checkpoints_at(
+    &self,
+    view_ids: Vec<String>,
+    timestamp: i64,
+  ) -> FlowyResult<Vec<(String, Option<DatabaseViewRevisionPad>)>> {
+    let mut views = vec![];
+    for view_id in view_ids {
+      let view_editor = self.get_view_editor(&view_id).await?;
+      let view_pad = view_editor.v_read_checkpoint_at(timestamp).await?;
+      views.push((view_id, view_pad));
+    }
+    Ok(views)
+  }
+
+  pub async fn restore(
+    &self,
+    views: Vec<(String, Option<DatabaseViewRevisionPad>)>,
+  ) -> FlowyResult<()> {
+    for (view_id, view_pad) in views {
+      let view_editor = self.get_view_editor(&view_id).await?;
+      view_editor.v_restore(view_pad).await?;
+    }
+    Ok(())
+  }
+
   pub async fn number_of_views(&self) -> usize {
     self.view_editors.read().await.values().len()
   }
@@ -178,6 +215,24 @@ impl DatabaseViews {
     view_editor.v_delete_filter(params).await
   }
 
+  pub async fn get_filter_group(&self, view_id: &str) -> FlowyResult<FilterGroupRevision> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    Ok(view_editor.v_get_filter_group().await)
+  }
+
+  pub async fn create_or_update_filter_group(
+    &self,
+    params: AlterFilterGroupParams,
+  ) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_insert_filter_group(params).await
+  }
+
+  pub async fn delete_filter_group(&self, params: DeleteFilterGroupParams) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_delete_filter_group(params).await
+  }
+
   pub async fn get_all_sorts(&self, view_id: &str) -> FlowyResult<Vec<Arc<SortRevision>>> {
     let view_editor = self.get_view_editor(view_id).await?;
     Ok(view_editor.v_get_all_sorts().await)
@@ -198,6 +253,24 @@ impl DatabaseViews {
     view_editor.v_delete_sort(params).await
   }
 
+  pub async fn get_calculation_values(&self, view_id: &str) -> FlowyResult<Vec<CalculationValue>> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    Ok(view_editor.v_get_calculation_values().await)
+  }
+
+  pub async fn update_calculation(
+    &self,
+    params: UpdateCalculationParams,
+  ) -> FlowyResult<CalculationRevision> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_update_calculation(params).await
+  }
+
+  pub async fn delete_calculation(&self, params: DeleteCalculationParams) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_delete_calculation(params).await
+  }
+
   pub async fn load_groups(&self, view_id: &str) -> FlowyResult<RepeatedGroupPB> {
     let view_editor = self.get_view_editor(view_id).await?;
     let groups = view_editor.v_load_groups().await?;
@@ -209,6 +282,14 @@ impl DatabaseViews {
     view_editor.v_get_group(group_id).await
   }
 
+  pub async fn get_visible_field_revs(
+    &self,
+    view_id: &str,
+  ) -> FlowyResult<Vec<Arc<FieldRevision>>> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    Ok(view_editor.v_get_visible_field_revs().await)
+  }
+
   pub async fn get_layout_setting(
     &self,
     view_id: &str,
@@ -237,6 +318,35 @@ impl DatabaseViews {
     view_editor.v_delete_group(params).await
   }
 
+  pub async fn update_date_group_condition(
+    &self,
+    params: UpdateDateGroupConditionParams,
+  ) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_update_date_group_condition(params).await
+  }
+
+  pub async fn update_number_group_setting(
+    &self,
+    params: UpdateNumberGroupSettingParams,
+  ) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_update_number_group_setting(params).await
+  }
+
+  pub async fn update_text_group_setting(
+    &self,
+    params: UpdateTextGroupSettingParams,
+  ) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_update_text_group_setting(params).await
+  }
+
+  pub async fn set_sub_group_by_field(&self, params: SubGroupByFieldParams) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_set_sub_group_by_field(params.field_id).await
+  }
+
   pub async fn move_group(&self, params: MoveGroupParams) -> FlowyResult<()> {
     let view_editor = self.get_view_editor(&params.view_id).await?;
     view_editor.v_move_group(params).await?;
@@ -244,13 +354,16 @@ impl DatabaseViews {
   }
 
   /// It may generate a RowChangeset when the Row was moved from one group to another.
-  /// The return value, [RowChangeset], contains the changes made by the groups.
+  /// The return value, [RowChangeset], contains the changes made by the groups. If the
+  /// `to_sub_group_id` is not None, the changeset also contains the change of the sub grouping
+  /// field's cell.
   ///
   pub async fn move_group_row(
     &self,
     view_id: &str,
     row_rev: Arc<RowRevision>,
     to_group_id: String,
+    to_sub_group_id: Option<String>,
     to_row_id: Option<String>,
     recv_row_changeset: impl FnOnce(RowChangeset) -> Fut<()>,
   ) -> FlowyResult<()> {
@@ -265,6 +378,17 @@ impl DatabaseViews {
       )
       .await;
 
+    if let Some(to_sub_group_id) = to_sub_group_id {
+      view_editor
+        .v_move_sub_group_row(
+          &row_rev,
+          &mut row_changeset,
+          &to_sub_group_id,
+          to_row_id.clone(),
+        )
+        .await;
+    }
+
     if !row_changeset.is_empty() {
       recv_row_changeset(row_changeset).await;
     }
@@ -291,6 +415,10 @@ impl DatabaseViews {
     // update the group setting
     if view_editor.group_id().await == field_id {
       view_editor.v_update_group_setting(field_id).await?;
+    } else if view_editor.sub_group_id().await.as_deref() == Some(field_id) {
+      view_editor
+        .v_set_sub_group_by_field(Some(field_id.to_owned()))
+        .await?;
     }
 
     view_editor
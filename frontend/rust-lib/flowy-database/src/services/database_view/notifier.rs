@@ -1,6 +1,10 @@
 #![allow(clippy::while_let_loop)]
-use crate::entities::{ReorderAllRowsPB, ReorderSingleRowPB, RowsVisibilityChangesetPB};
+use crate::entities::{
+  CalculationValuePB, CalculationValuesChangesetPB, ReorderAllRowsPB, ReorderSingleRowPB,
+  RowsVisibilityChangesetPB,
+};
 use crate::notification::{send_notification, DatabaseNotification};
+use crate::services::calculation::CalculationResultNotification;
 use crate::services::filter::FilterResultNotification;
 use crate::services::sort::{ReorderAllRowsResult, ReorderSingleRowResult};
 use async_stream::stream;
@@ -12,6 +16,7 @@ pub enum DatabaseViewChanged {
   FilterNotification(FilterResultNotification),
   ReorderAllRowsNotification(ReorderAllRowsResult),
   ReorderSingleRowNotification(ReorderSingleRowResult),
+  CalculationNotification(CalculationResultNotification),
 }
 
 pub type DatabaseViewChangedNotifier = broadcast::Sender<DatabaseViewChanged>;
@@ -69,6 +74,22 @@ impl DatabaseViewChangedReceiverRunner {
             .payload(reorder_row)
             .send()
           },
+          DatabaseViewChanged::CalculationNotification(notification) => {
+            let changeset = CalculationValuesChangesetPB {
+              view_id: notification.view_id,
+              values: notification
+                .values
+                .into_iter()
+                .map(CalculationValuePB::from)
+                .collect(),
+            };
+            send_notification(
+              &changeset.view_id,
+              DatabaseNotification::DidUpdateCalculations,
+            )
+            .payload(changeset)
+            .send()
+          },
         }
       })
       .await;
@@ -1,14 +1,18 @@
 use crate::entities::{DatabaseViewSettingPB, LayoutSettingPB};
+use crate::services::calculation::CalculationDelegate;
 use crate::services::database_view::{get_cells_for_field, DatabaseViewData};
 use crate::services::field::RowSingleCellData;
-use crate::services::filter::{FilterController, FilterDelegate, FilterType};
-use crate::services::group::{GroupConfigurationReader, GroupConfigurationWriter};
+use crate::services::filter::{FilterContext, FilterController, FilterDelegate, FilterType};
+use crate::services::group::{
+  Group, GroupConfigurationReader, GroupConfigurationWriter, GroupController,
+};
 use crate::services::row::DatabaseBlockRowRevision;
 use crate::services::sort::{SortDelegate, SortType};
 use bytes::Bytes;
 use database_model::{
-  CalendarLayoutSetting, FieldRevision, FieldTypeRevision, FilterRevision,
-  GroupConfigurationRevision, LayoutRevision, RowRevision, SortRevision,
+  CalculationRevision, CalendarLayoutSetting, FieldRevision, FieldTypeRevision,
+  FilterGroupRevision, FilterRevision, GalleryLayoutSetting, GroupConfigurationRevision,
+  LayoutRevision, RowRevision, SortRevision, TimelineLayoutSetting,
 };
 use flowy_client_sync::client_database::{DatabaseViewRevisionChangeset, DatabaseViewRevisionPad};
 use flowy_client_sync::make_operations_from_revisions;
@@ -73,13 +77,20 @@ impl RevisionMergeable for DatabaseViewRevisionMergeable {
 pub(crate) struct GroupConfigurationReaderImpl {
   pub(crate) pad: Arc<RwLock<DatabaseViewRevisionPad>>,
   pub(crate) view_editor_delegate: Arc<dyn DatabaseViewData>,
+  /// Reads the configuration of the sub groups instead of the groups if it's true.
+  pub(crate) is_sub_group: bool,
 }
 
 impl GroupConfigurationReader for GroupConfigurationReaderImpl {
   fn get_configuration(&self) -> Fut<Option<Arc<GroupConfigurationRevision>>> {
     let view_pad = self.pad.clone();
+    let is_sub_group = self.is_sub_group;
     to_fut(async move {
-      let mut groups = view_pad.read().await.get_all_groups();
+      let mut groups = if is_sub_group {
+        view_pad.read().await.get_all_sub_groups()
+      } else {
+        view_pad.read().await.get_all_groups()
+      };
       if groups.is_empty() {
         None
       } else {
@@ -100,6 +111,8 @@ pub(crate) struct GroupConfigurationWriterImpl {
   pub(crate) user_id: String,
   pub(crate) rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
   pub(crate) view_pad: Arc<RwLock<DatabaseViewRevisionPad>>,
+  /// Writes the configuration of the sub groups instead of the groups if it's true.
+  pub(crate) is_sub_group: bool,
 }
 
 impl GroupConfigurationWriter for GroupConfigurationWriterImpl {
@@ -113,12 +126,42 @@ impl GroupConfigurationWriter for GroupConfigurationWriterImpl {
     let rev_manager = self.rev_manager.clone();
     let view_pad = self.view_pad.clone();
     let field_id = field_id.to_owned();
+    let is_sub_group = self.is_sub_group;
 
     to_fut(async move {
-      let changeset = view_pad
-        .write()
-        .await
-        .insert_or_update_group_configuration(&field_id, &field_type, group_configuration)?;
+      let changeset = {
+        let mut view_pad = view_pad.write().await;
+        let configurations = if is_sub_group {
+          view_pad.get_all_sub_groups()
+        } else {
+          view_pad.get_all_groups()
+        };
+        // The group context only changes the groups of the configuration. Keep the content of the
+        // field's configuration because it might be updated after this saving was scheduled.
+        let group_configuration = match configurations
+          .into_iter()
+          .find(|configuration| configuration.field_id == field_id)
+        {
+          None => group_configuration,
+          Some(configuration) => GroupConfigurationRevision {
+            groups: group_configuration.groups,
+            ..(*configuration).clone()
+          },
+        };
+        if is_sub_group {
+          view_pad.insert_or_update_sub_group_configuration(
+            &field_id,
+            &field_type,
+            group_configuration,
+          )?
+        } else {
+          view_pad.insert_or_update_group_configuration(
+            &field_id,
+            &field_type,
+            group_configuration,
+          )?
+        }
+      };
 
       if let Some(changeset) = changeset {
         apply_change(&user_id, rev_manager, changeset).await?;
@@ -156,17 +199,33 @@ pub fn make_database_view_setting(
         .get_layout_setting::<CalendarLayoutSetting>(&layout_type)
         .map(|params| params.into());
     },
+    LayoutRevision::Timeline => {
+      layout_settings.timeline = view_pad
+        .get_layout_setting::<TimelineLayoutSetting>(&layout_type)
+        .map(|params| params.into());
+    },
+    LayoutRevision::Gallery => {
+      layout_settings.gallery = view_pad
+        .get_layout_setting::<GalleryLayoutSetting>(&layout_type)
+        .map(|params| params.into());
+    },
   }
 
   let filters = view_pad.get_all_filters(field_revs);
+  let filter_group = view_pad.get_filter_group();
   let group_configurations = view_pad.get_groups_by_field_revs(field_revs);
+  let sub_group_configurations = view_pad.get_sub_groups_by_field_revs(field_revs);
   let sorts = view_pad.get_all_sorts(field_revs);
+  let calculations = view_pad.get_all_calculations(field_revs);
   DatabaseViewSettingPB {
     current_layout: layout_type.into(),
     layout_setting: layout_settings,
     filters: filters.into(),
+    filter_group: filter_group.into(),
     sorts: sorts.into(),
     group_configurations: group_configurations.into(),
+    sub_group_configurations: sub_group_configurations.into(),
+    calculations: calculations.into(),
   }
 }
 
@@ -176,23 +235,44 @@ pub(crate) struct DatabaseViewFilterDelegateImpl {
 }
 
 impl FilterDelegate for DatabaseViewFilterDelegateImpl {
-  fn get_filter_rev(&self, filter_type: FilterType) -> Fut<Option<Arc<FilterRevision>>> {
+  fn get_filter_rev(&self, filter_ctx: FilterContext) -> Fut<Option<Arc<FilterRevision>>> {
     let pad = self.view_revision_pad.clone();
     to_fut(async move {
+      let filter_type = filter_ctx.filter_type;
       let field_type_rev: FieldTypeRevision = filter_type.field_type.into();
-      let mut filters = pad
+      pad.read().await.get_filter(
+        &filter_type.field_id,
+        &field_type_rev,
+        &filter_ctx.filter_id,
+      )
+    })
+  }
+
+  fn get_filter_revs_of_type(&self, filter_type: FilterType) -> Fut<Vec<Arc<FilterRevision>>> {
+    let pad = self.view_revision_pad.clone();
+    to_fut(async move {
+      let field_type_rev: FieldTypeRevision = filter_type.field_type.into();
+      pad
         .read()
         .await
-        .get_filters(&filter_type.field_id, &field_type_rev);
-      if filters.is_empty() {
-        None
-      } else {
-        debug_assert_eq!(filters.len(), 1);
-        filters.pop()
-      }
+        .get_filters(&filter_type.field_id, &field_type_rev)
+    })
+  }
+
+  fn get_filter_revs(&self) -> Fut<Vec<Arc<FilterRevision>>> {
+    let pad = self.view_revision_pad.clone();
+    let editor_delegate = self.editor_delegate.clone();
+    to_fut(async move {
+      let field_revs = editor_delegate.get_field_revs(None).await;
+      pad.read().await.get_all_filters(&field_revs)
     })
   }
 
+  fn get_filter_group_rev(&self) -> Fut<FilterGroupRevision> {
+    let pad = self.view_revision_pad.clone();
+    to_fut(async move { pad.read().await.get_filter_group() })
+  }
+
   fn get_field_rev(&self, field_id: &str) -> Fut<Option<Arc<FieldRevision>>> {
     self.editor_delegate.get_field_rev(field_id)
   }
@@ -253,3 +333,53 @@ impl SortDelegate for DatabaseViewSortDelegateImpl {
     self.editor_delegate.get_field_revs(field_ids)
   }
 }
+
+pub(crate) struct DatabaseViewCalculationDelegateImpl {
+  pub(crate) editor_delegate: Arc<dyn DatabaseViewData>,
+  pub(crate) view_revision_pad: Arc<RwLock<DatabaseViewRevisionPad>>,
+  pub(crate) filter_controller: Arc<FilterController>,
+  pub(crate) group_controller: Arc<RwLock<Box<dyn GroupController>>>,
+}
+
+impl CalculationDelegate for DatabaseViewCalculationDelegateImpl {
+  fn get_calculation_revs(&self) -> Fut<Vec<Arc<CalculationRevision>>> {
+    let pad = self.view_revision_pad.clone();
+    let editor_delegate = self.editor_delegate.clone();
+    to_fut(async move {
+      let field_revs = editor_delegate.get_field_revs(None).await;
+      pad.read().await.get_all_calculations(&field_revs)
+    })
+  }
+
+  fn get_row_revs(&self) -> Fut<Vec<Arc<RowRevision>>> {
+    let filter_controller = self.filter_controller.clone();
+    let editor_delegate = self.editor_delegate.clone();
+    to_fut(async move {
+      let mut row_revs = editor_delegate.get_row_revs(None).await;
+      filter_controller.filter_row_revs(&mut row_revs).await;
+      row_revs
+    })
+  }
+
+  fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> Fut<Vec<Arc<FieldRevision>>> {
+    self.editor_delegate.get_field_revs(field_ids)
+  }
+
+  fn get_groups(&self) -> Fut<Vec<Group>> {
+    let pad = self.view_revision_pad.clone();
+    let group_controller = self.group_controller.clone();
+    to_fut(async move {
+      // Only the board displays the rows by groups
+      if pad.read().await.layout != LayoutRevision::Board {
+        return vec![];
+      }
+      group_controller
+        .read()
+        .await
+        .groups()
+        .into_iter()
+        .cloned()
+        .collect()
+    })
+  }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::field::{
+    AutoIncrementIdCellData, AutoIncrementIdTypeOptionPB, FieldBuilder, TypeOptionCellDataCompare,
+    TypeOptionCellDataFilter,
+  };
+  use std::cmp::Ordering;
+
+  #[test]
+  fn auto_increment_id_decode_test() {
+    let type_option = AutoIncrementIdTypeOptionPB {
+      prefix: "TASK-".to_owned(),
+    };
+    let field_rev = FieldBuilder::from_field_type(&FieldType::AutoIncrementId).build();
+    let cell_data = type_option
+      .decode_cell_str("12".to_owned(), &FieldType::AutoIncrementId, &field_rev)
+      .unwrap();
+    assert_eq!(cell_data, AutoIncrementIdCellData::new(12));
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "TASK-12");
+
+    let cell_data = type_option
+      .decode_cell_str("".to_owned(), &FieldType::AutoIncrementId, &field_rev)
+      .unwrap();
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "");
+  }
+
+  #[test]
+  fn auto_increment_id_is_read_only_test() {
+    let type_option = AutoIncrementIdTypeOptionPB::default();
+    assert!(type_option.apply_changeset("1".to_owned(), None).is_err());
+  }
+
+  #[test]
+  fn auto_increment_id_filter_and_sort_test() {
+    let type_option = AutoIncrementIdTypeOptionPB::default();
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "2".to_owned(),
+    };
+    let field_type = FieldType::AutoIncrementId;
+    let first = AutoIncrementIdCellData::new(1);
+    let third = AutoIncrementIdCellData::new(3);
+    assert!(type_option.apply_filter(&filter, &field_type, &third));
+    assert!(!type_option.apply_filter(&filter, &field_type, &first));
+    assert_eq!(type_option.apply_cmp(&first, &third), Ordering::Less);
+    assert_eq!(
+      type_option.apply_cmp(&AutoIncrementIdCellData::default(), &first),
+      Ordering::Greater
+    );
+  }
+}
@@ -0,0 +1,139 @@
+use crate::entities::{FieldType, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  default_order, AutoIncrementIdCellData, BoxTypeOptionBuilder, StrCellData, TypeOption,
+  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct AutoIncrementIdTypeOptionBuilder(AutoIncrementIdTypeOptionPB);
+impl_into_box_type_option_builder!(AutoIncrementIdTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(
+  AutoIncrementIdTypeOptionBuilder,
+  AutoIncrementIdTypeOptionPB
+);
+
+impl AutoIncrementIdTypeOptionBuilder {
+  pub fn prefix(mut self, prefix: &str) -> Self {
+    self.0.prefix = prefix.to_owned();
+    self
+  }
+}
+
+impl TypeOptionBuilder for AutoIncrementIdTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::AutoIncrementId
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct AutoIncrementIdTypeOptionPB {
+  /// The text that is displayed before the id. For example, `TASK-` displays `TASK-1`.
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub prefix: String,
+}
+impl_type_option!(AutoIncrementIdTypeOptionPB, FieldType::AutoIncrementId);
+
+impl TypeOption for AutoIncrementIdTypeOptionPB {
+  type CellData = AutoIncrementIdCellData;
+  type CellChangeset = AutoIncrementIdCellChangeset;
+  type CellProtobufType = StrCellData;
+  type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for AutoIncrementIdTypeOptionPB {}
+
+impl TypeOptionCellData for AutoIncrementIdTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    StrCellData(self.decode_cell_data_to_str(cell_data))
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    AutoIncrementIdCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for AutoIncrementIdTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_auto_increment_id() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    match cell_data.0 {
+      None => "".to_owned(),
+      Some(id) => format!("{}{}", self.prefix, id),
+    }
+  }
+}
+
+/// The ids are assigned by the database editor when the rows are created, so any changeset is
+/// rejected.
+pub type AutoIncrementIdCellChangeset = String;
+
+impl CellDataChangeset for AutoIncrementIdTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    Err(FlowyError::invalid_data().context("The id is assigned by the database"))
+  }
+}
+
+impl TypeOptionCellDataFilter for AutoIncrementIdTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_auto_increment_id() {
+      return true;
+    }
+
+    filter.is_visible(&cell_data.to_number_cell_data())
+  }
+}
+
+impl TypeOptionCellDataCompare for AutoIncrementIdTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data.0, other_cell_data.0) {
+      (Some(left), Some(right)) => left.cmp(&right),
+      (Some(_), None) => Ordering::Less,
+      (None, Some(_)) => Ordering::Greater,
+      (None, None) => default_order(),
+    }
+  }
+}
@@ -0,0 +1,45 @@
+use crate::services::cell::{DecodedCellData, FromCellString};
+use crate::services::field::NumberCellData;
+use flowy_error::FlowyResult;
+use rust_decimal::Decimal;
+
+/// The id that is assigned to the row when it's created. It's None if the row was created
+/// before the database recorded the ids.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AutoIncrementIdCellData(pub Option<i64>);
+
+impl AutoIncrementIdCellData {
+  pub fn new(id: i64) -> Self {
+    Self(Some(id))
+  }
+
+  pub fn to_number_cell_data(&self) -> NumberCellData {
+    self
+      .0
+      .map(|id| NumberCellData::from_decimal(Decimal::from(id)))
+      .unwrap_or_default()
+  }
+}
+
+impl FromCellString for AutoIncrementIdCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    Ok(Self(s.trim().parse::<i64>().ok()))
+  }
+}
+
+impl ToString for AutoIncrementIdCellData {
+  fn to_string(&self) -> String {
+    self.0.map(|id| id.to_string()).unwrap_or_default()
+  }
+}
+
+impl DecodedCellData for AutoIncrementIdCellData {
+  type Object = AutoIncrementIdCellData;
+
+  fn is_empty(&self) -> bool {
+    self.0.is_none()
+  }
+}
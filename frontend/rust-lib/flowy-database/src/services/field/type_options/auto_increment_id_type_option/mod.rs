@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod auto_increment_id_tests;
+mod auto_increment_id_type_option;
+mod auto_increment_id_type_option_entities;
+
+pub use auto_increment_id_type_option::*;
+pub use auto_increment_id_type_option_entities::*;
@@ -3,6 +3,16 @@ use chrono::NaiveDateTime;
 
 impl DateFilterPB {
   pub fn is_visible<T: Into<Option<i64>>>(&self, cell_timestamp: T) -> bool {
+    self.is_range_visible(cell_timestamp, None)
+  }
+
+  /// Same as [DateFilterPB::is_visible] except that the cell might hold a date range. The
+  /// conditions other than [DateFilterConditionPB::DateOverlaps] only check the start date.
+  pub fn is_range_visible<T: Into<Option<i64>>>(
+    &self,
+    cell_timestamp: T,
+    cell_end_timestamp: Option<i64>,
+  ) -> bool {
     match cell_timestamp.into() {
       None => DateFilterConditionPB::DateIsEmpty == self.condition,
       Some(timestamp) => {
@@ -18,6 +28,24 @@ impl DateFilterPB {
 
         let cell_time = NaiveDateTime::from_timestamp_opt(timestamp, 0);
         let cell_date = cell_time.map(|time| time.date());
+        if self.condition == DateFilterConditionPB::DateOverlaps {
+          let cell_end_date = cell_end_timestamp
+            .and_then(|end_timestamp| NaiveDateTime::from_timestamp_opt(end_timestamp, 0))
+            .map(|time| time.date())
+            .or(cell_date);
+          let start_date = self
+            .start
+            .and_then(|start| NaiveDateTime::from_timestamp_opt(start, 0))
+            .map(|time| time.date());
+          let end_date = self
+            .end
+            .and_then(|end| NaiveDateTime::from_timestamp_opt(end, 0))
+            .map(|time| time.date());
+          // The open side of the filter's range is unbounded.
+          return start_date.map_or(true, |start_date| cell_end_date >= Some(start_date))
+            && end_date.map_or(true, |end_date| cell_date <= Some(end_date));
+        }
+
         match self.timestamp {
           None => {
             if self.start.is_none() {
@@ -133,6 +161,26 @@ mod tests {
     }
   }
 
+  #[test]
+  fn date_filter_overlaps_test() {
+    let filter = DateFilterPB {
+      condition: DateFilterConditionPB::DateOverlaps,
+      start: Some(1668272685), // 11/13
+      end: Some(1668618285),   // 11/17
+      timestamp: None,
+    };
+
+    for (start, end, visible, msg) in vec![
+      (1668013485, Some(1668272685), true, "11/10 - 11/13"),
+      (1668013485, Some(1668186285), false, "11/10 - 11/12"),
+      (1668013485, Some(1668704685), true, "11/10 - 11/18"),
+      (1668359085, None, true, "11/14"),
+      (1668704685, None, false, "11/18"),
+    ] {
+      assert_eq!(filter.is_range_visible(start, end), visible, "{}", msg);
+    }
+  }
+
   #[test]
   fn date_filter_is_empty_test() {
     let filter = DateFilterPB {
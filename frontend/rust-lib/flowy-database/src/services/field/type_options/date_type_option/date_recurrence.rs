@@ -0,0 +1,272 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of occurrences that are expanded from one date.
+const MAX_OCCURRENCES: usize = 1000;
+
+/// The maximum number of the days, weeks, months or years that are stepped through from the
+/// start date. It stops the expanding of the dates that never occur again, for example, the
+/// dates that are out of the range of the calendar.
+const MAX_STEPS: i64 = 100_000;
+
+/// The maximum interval of the [RecurrenceRulePB].
+pub const MAX_RECURRENCE_INTERVAL: i32 = 1000;
+
+/// [RecurrenceRulePB] describes how a date repeats, similar to the RRULE of the iCalendar.
+/// The occurrences are calculated in UTC.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct RecurrenceRulePB {
+  #[pb(index = 1)]
+  pub frequency: RecurrenceFrequencyPB,
+
+  /// Repeats every `interval` days, weeks, months or years. It's clamped between 1 and
+  /// [MAX_RECURRENCE_INTERVAL].
+  #[pb(index = 2)]
+  pub interval: i32,
+
+  /// The weekdays that the date repeats on if the frequency is weekly. The bits from the lowest
+  /// to the highest are Monday to Sunday. The weekday of the start date is used if it's zero.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub weekdays: i32,
+
+  /// The date doesn't repeat after the end timestamp.
+  #[pb(index = 4, one_of)]
+  #[serde(default)]
+  pub end_timestamp: Option<i64>,
+
+  /// The number of the occurrences, including the start date.
+  #[pb(index = 5, one_of)]
+  #[serde(default)]
+  pub count: Option<i32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum RecurrenceFrequencyPB {
+  Daily = 0,
+  Weekly = 1,
+  Monthly = 2,
+  Yearly = 3,
+}
+
+impl std::default::Default for RecurrenceFrequencyPB {
+  fn default() -> Self {
+    RecurrenceFrequencyPB::Daily
+  }
+}
+
+impl RecurrenceRulePB {
+  /// Returns the rule with the interval clamped between 1 and [MAX_RECURRENCE_INTERVAL].
+  pub fn normalized(mut self) -> Self {
+    self.interval = self.interval.clamp(1, MAX_RECURRENCE_INTERVAL);
+    self
+  }
+
+  /// Returns the timestamps of the occurrences that are within the range. The start timestamp
+  /// is the first occurrence unless it's not on the chosen weekdays.
+  pub fn occurrences_in_range(&self, start: i64, range_start: i64, range_end: i64) -> Vec<i64> {
+    let start_date_time = match NaiveDateTime::from_timestamp_opt(start, 0) {
+      None => return vec![],
+      Some(date_time) => date_time,
+    };
+
+    let mut occurrences = vec![];
+    for (index, occurrence) in self.iter_occurrences(start_date_time).enumerate() {
+      let timestamp = occurrence.timestamp();
+      if timestamp > range_end
+        || self.end_timestamp.map_or(false, |end| timestamp > end)
+        || self.count.map_or(false, |count| index >= count as usize)
+        || occurrences.len() >= MAX_OCCURRENCES
+      {
+        break;
+      }
+
+      if timestamp >= range_start {
+        occurrences.push(timestamp);
+      }
+    }
+    occurrences
+  }
+
+  fn interval(&self) -> i64 {
+    self.interval.clamp(1, MAX_RECURRENCE_INTERVAL) as i64
+  }
+
+  /// Returns the occurrences in ascending order. The iteration stops if the occurrence is out of
+  /// the range of the dates.
+  fn iter_occurrences(&self, start: NaiveDateTime) -> Box<dyn Iterator<Item = NaiveDateTime> + '_> {
+    let interval = self.interval();
+    match self.frequency {
+      RecurrenceFrequencyPB::Daily => Box::new((0..MAX_STEPS).map_while(move |n| {
+        let days = n.checked_mul(interval)?;
+        start.checked_add_signed(Duration::days(days))
+      })),
+      RecurrenceFrequencyPB::Weekly => {
+        let weekdays = match self.weekdays & 0b111_1111 {
+          0 => 1 << start.weekday().num_days_from_monday(),
+          weekdays => weekdays,
+        };
+        // The days are counted from the start date, because the Monday of the first week might be
+        // out of the range of the dates.
+        let weekday = start.weekday().num_days_from_monday() as i64;
+        Box::new(
+          (0..MAX_STEPS)
+            .map_while(move |n| {
+              let monday_offset = n
+                .checked_mul(interval)?
+                .checked_mul(7)?
+                .checked_sub(weekday)?;
+              // Stops if the Monday of the week is out of the range
+              if n > 0 {
+                start.checked_add_signed(Duration::days(monday_offset))?;
+              }
+              Some(monday_offset)
+            })
+            .flat_map(move |monday_offset| {
+              (0..7)
+                .filter(move |day| weekdays & (1 << day) != 0)
+                .filter_map(move |day| {
+                  start.checked_add_signed(Duration::days(monday_offset + day))
+                })
+            })
+            .filter(move |occurrence| *occurrence >= start),
+        )
+      },
+      RecurrenceFrequencyPB::Monthly => Box::new(
+        (0..MAX_STEPS)
+          .map_while(move |n| {
+            let months = (start.month0() as i64).checked_add(n.checked_mul(interval)?)?;
+            let year = start.year().checked_add(i32::try_from(months / 12).ok()?)?;
+            let month = (months % 12) as u32 + 1;
+            // Stops if the month is out of the range
+            NaiveDate::from_ymd_opt(year, month, 1)?;
+            // The months that don't have the day are skipped, for example, the 31st.
+            Some(
+              NaiveDate::from_ymd_opt(year, month, start.day())
+                .map(|date| date.and_time(start.time())),
+            )
+          })
+          .flatten(),
+      ),
+      RecurrenceFrequencyPB::Yearly => Box::new(
+        (0..MAX_STEPS)
+          .map_while(move |n| {
+            let years = i32::try_from(n.checked_mul(interval)?).ok()?;
+            let year = start.year().checked_add(years)?;
+            // Stops if the year is out of the range
+            NaiveDate::from_ymd_opt(year, 1, 1)?;
+            // The years that don't have the 29th of February are skipped.
+            Some(
+              NaiveDate::from_ymd_opt(year, start.month(), start.day())
+                .map(|date| date.and_time(start.time())),
+            )
+          })
+          .flatten(),
+      ),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::field::{RecurrenceFrequencyPB, RecurrenceRulePB, MAX_RECURRENCE_INTERVAL};
+
+  const DAY: i64 = 86400;
+  // Monday, March 14, 2022 09:56:02 UTC
+  const START: i64 = 1647251762;
+
+  fn rule(frequency: RecurrenceFrequencyPB) -> RecurrenceRulePB {
+    RecurrenceRulePB {
+      frequency,
+      interval: 1,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn daily_recurrence_test() {
+    let occurrences =
+      rule(RecurrenceFrequencyPB::Daily).occurrences_in_range(START, START + DAY, START + 3 * DAY);
+    assert_eq!(
+      occurrences,
+      vec![START + DAY, START + 2 * DAY, START + 3 * DAY]
+    );
+  }
+
+  #[test]
+  fn weekly_recurrence_on_weekdays_test() {
+    // Monday and Wednesday
+    let mut rule = rule(RecurrenceFrequencyPB::Weekly);
+    rule.weekdays = 0b101;
+    let occurrences = rule.occurrences_in_range(START, START, START + 13 * DAY);
+    assert_eq!(
+      occurrences,
+      vec![START, START + 2 * DAY, START + 7 * DAY, START + 9 * DAY]
+    );
+  }
+
+  #[test]
+  fn monthly_recurrence_skips_invalid_day_test() {
+    // January 31, 2022 00:00:00 UTC
+    let start = 1643587200;
+    let occurrences =
+      rule(RecurrenceFrequencyPB::Monthly).occurrences_in_range(start, start, start + 90 * DAY);
+    // February 31 and April 31 don't exist
+    assert_eq!(occurrences, vec![start, start + 59 * DAY]);
+  }
+
+  #[test]
+  fn recurrence_with_count_and_end_test() {
+    let mut count_rule = rule(RecurrenceFrequencyPB::Daily);
+    count_rule.count = Some(2);
+    assert_eq!(
+      count_rule.occurrences_in_range(START, START, START + 10 * DAY),
+      vec![START, START + DAY]
+    );
+
+    let mut end_rule = rule(RecurrenceFrequencyPB::Yearly);
+    end_rule.end_timestamp = Some(START + 400 * DAY);
+    assert_eq!(
+      end_rule.occurrences_in_range(START, START, START + 1000 * DAY),
+      vec![START, START + 365 * DAY]
+    );
+  }
+
+  #[test]
+  fn recurrence_with_max_interval_test() {
+    let frequencies = [
+      RecurrenceFrequencyPB::Daily,
+      RecurrenceFrequencyPB::Weekly,
+      RecurrenceFrequencyPB::Monthly,
+      RecurrenceFrequencyPB::Yearly,
+    ];
+    for frequency in frequencies {
+      let mut rule = rule(frequency);
+      rule.interval = i32::MAX;
+      let occurrences = rule.occurrences_in_range(START, START, i64::MAX);
+      assert_eq!(occurrences[0], START);
+      assert!(occurrences.windows(2).all(|pair| pair[0] < pair[1]));
+      assert_eq!(rule.normalized().interval, MAX_RECURRENCE_INTERVAL);
+    }
+
+    // The Monday before the earliest date is out of the range of the dates.
+    let earliest = chrono::NaiveDate::MIN
+      .and_hms_opt(0, 0, 0)
+      .unwrap()
+      .timestamp();
+    let mut weekly_rule = rule(RecurrenceFrequencyPB::Weekly);
+    weekly_rule.weekdays = 0b111_1111;
+    let occurrences = weekly_rule.occurrences_in_range(earliest, earliest, earliest + 2 * DAY);
+    assert_eq!(
+      occurrences,
+      vec![earliest, earliest + DAY, earliest + 2 * DAY]
+    );
+
+    // The occurrences are 1000 years apart
+    let mut rule = rule(RecurrenceFrequencyPB::Yearly);
+    rule.interval = i32::MAX;
+    let occurrences = rule.occurrences_in_range(START, START, START + 1001 * 366 * DAY);
+    assert_eq!(occurrences.len(), 2);
+  }
+}
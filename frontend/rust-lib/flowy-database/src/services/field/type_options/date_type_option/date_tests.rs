@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
   use crate::entities::FieldType;
-  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
 
   use crate::services::field::{
     DateCellChangeset, DateFormat, DateTypeOptionPB, FieldBuilder, TimeFormat, TypeOptionCellData,
@@ -252,6 +252,126 @@ mod tests {
     assert_eq!(china_local_time, "03/14/2022 05:56 PM");
   }
 
+  #[test]
+  fn date_type_option_field_timezone_test() {
+    let mut type_option = DateTypeOptionPB::new();
+    type_option.timezone_id = "Asia/Shanghai".to_string();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+
+    // 09:30 in Shanghai is 01:30 in UTC
+    let changeset = DateCellChangeset {
+      date: Some("1653609600".to_string()),
+      time: Some("09:30".to_string()),
+      is_utc: false,
+      include_time: Some(true),
+      occurrence_override: None,
+      end_date: None,
+      end_time: None,
+      is_range: None,
+      timezone_id: None,
+    };
+    let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_data.timestamp, Some(1653609600 + 5400));
+    assert_eq!(
+      decode_cell_data(cell_str, &type_option, true, &field_rev),
+      "May 27, 2022 09:30"
+    );
+  }
+
+  #[test]
+  fn date_type_option_cell_timezone_test() {
+    let mut type_option = DateTypeOptionPB::new();
+    type_option.timezone_id = "Asia/Shanghai".to_string();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+
+    // The time zone of the cell takes precedence over the time zone of the field.
+    let changeset = DateCellChangeset {
+      date: Some("1653609600".to_string()),
+      time: None,
+      is_utc: false,
+      include_time: Some(true),
+      occurrence_override: None,
+      end_date: None,
+      end_time: None,
+      is_range: None,
+      timezone_id: Some("America/New_York".to_string()),
+    };
+    let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(
+      decode_cell_data(cell_str, &type_option, true, &field_rev),
+      "May 26, 2022 20:00"
+    );
+  }
+
+  #[test]
+  fn date_type_option_date_range_test() {
+    let mut type_option = DateTypeOptionPB::new();
+    type_option.timezone_id = "UTC".to_string();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+
+    let changeset = DateCellChangeset {
+      date: Some("1647251762".to_string()),
+      time: None,
+      is_utc: false,
+      include_time: Some(false),
+      occurrence_override: None,
+      end_date: Some("1653609600".to_string()),
+      end_time: None,
+      is_range: Some(true),
+      timezone_id: None,
+    };
+    let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_data.end_timestamp, Some(1653609600));
+
+    let decoded_data = type_option
+      .decode_cell_str(cell_str.clone(), &FieldType::DateTime, &field_rev)
+      .unwrap();
+    let decoded_data = type_option.convert_to_protobuf(decoded_data);
+    assert!(decoded_data.is_range);
+    assert_eq!(decoded_data.date, "Mar 14, 2022");
+    assert_eq!(decoded_data.end_date, "May 27, 2022");
+
+    // The end date can't be earlier than the start date.
+    let changeset = DateCellChangeset {
+      date: None,
+      time: None,
+      is_utc: false,
+      include_time: None,
+      occurrence_override: None,
+      end_date: Some("1600000000".to_string()),
+      end_time: None,
+      is_range: None,
+      timezone_id: None,
+    };
+    let (_, cell_data) = type_option
+      .apply_changeset(
+        changeset,
+        Some(TypeCellData::new(cell_str.clone(), FieldType::DateTime)),
+      )
+      .unwrap();
+    assert_eq!(cell_data.end_timestamp, Some(1647251762));
+
+    // Turning off the range drops the end date.
+    let changeset = DateCellChangeset {
+      date: None,
+      time: None,
+      is_utc: false,
+      include_time: None,
+      occurrence_override: None,
+      end_date: None,
+      end_time: None,
+      is_range: Some(false),
+      timezone_id: None,
+    };
+    let (_, cell_data) = type_option
+      .apply_changeset(
+        changeset,
+        Some(TypeCellData::new(cell_str, FieldType::DateTime)),
+      )
+      .unwrap();
+    assert_eq!(cell_data.end_timestamp, None);
+  }
+
   fn assert_date<T: ToString>(
     type_option: &DateTypeOptionPB,
     timestamp: T,
@@ -265,6 +385,11 @@ mod tests {
       time: include_time_str,
       is_utc: false,
       include_time: Some(include_time),
+      occurrence_override: None,
+      end_date: None,
+      end_time: None,
+      is_range: None,
+      timezone_id: None,
     };
     let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
 
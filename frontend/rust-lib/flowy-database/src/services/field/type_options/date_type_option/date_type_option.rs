@@ -3,12 +3,13 @@ use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
 use crate::services::field::{
   default_order, BoxTypeOptionBuilder, DateCellChangeset, DateCellData, DateCellDataPB, DateFormat,
-  TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
-  TypeOptionCellDataFilter, TypeOptionTransform,
+  RecurrenceRulePB, TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use bytes::Bytes;
 use chrono::format::strftime::StrftimeItems;
-use chrono::{Local, NaiveDateTime};
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use flowy_derive::ProtoBuf;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
@@ -26,6 +27,17 @@ pub struct DateTypeOptionPB {
 
   #[pb(index = 3)]
   pub include_time: bool,
+
+  /// The dates of the field repeat with the rule if it's set.
+  #[pb(index = 4, one_of)]
+  #[serde(default)]
+  pub recurrence: Option<RecurrenceRulePB>,
+
+  /// The IANA time zone that the dates are displayed and edited in, for example,
+  /// "Europe/Berlin". The local time zone is used if it's empty or unknown.
+  #[pb(index = 5)]
+  #[serde(default)]
+  pub timezone_id: String,
 }
 impl_type_option!(DateTypeOptionPB, FieldType::DateTime);
 
@@ -58,6 +70,13 @@ impl DateTypeOptionPB {
     Self::default()
   }
 
+  /// Returns the type option with the interval of the recurrence rule clamped, see
+  /// [RecurrenceRulePB::normalized].
+  fn normalized(mut self) -> Self {
+    self.recurrence = self.recurrence.map(|recurrence| recurrence.normalized());
+    self
+  }
+
   fn today_desc_from_timestamp(&self, cell_data: DateCellData) -> DateCellDataPB {
     let timestamp = cell_data.timestamp.unwrap_or_default();
     if timestamp == 0 {
@@ -65,30 +84,56 @@ impl DateTypeOptionPB {
     }
 
     let include_time = cell_data.include_time;
-    let native = chrono::NaiveDateTime::from_timestamp_opt(timestamp, 0);
-    if native.is_none() {
-      return DateCellDataPB::default();
+    let timezone = self.timezone(&cell_data.timezone_id);
+    let (date, time) = match self.format_timestamp(timestamp, include_time, &timezone) {
+      None => return DateCellDataPB::default(),
+      Some(date_and_time) => date_and_time,
+    };
+    let (end_date, end_time) = cell_data
+      .end_timestamp
+      .and_then(|end_timestamp| self.format_timestamp(end_timestamp, include_time, &timezone))
+      .unwrap_or_default();
+
+    DateCellDataPB {
+      date,
+      time,
+      include_time,
+      timestamp,
+      end_date,
+      end_time,
+      end_timestamp: cell_data.end_timestamp.unwrap_or_default(),
+      is_range: cell_data.end_timestamp.is_some(),
+      timezone_id: cell_data.timezone_id,
     }
+  }
 
-    // Use the local timezone to calculate the formatted date string. We can use the timezone that
-    // specified by the user in the future.
-    let offset = Local::now().offset().clone();
-    let native = chrono::DateTime::<chrono::Local>::from_utc(native.unwrap(), offset);
+  /// Returns the formatted date and time of the timestamp in the time zone.
+  fn format_timestamp(
+    &self,
+    timestamp: i64,
+    include_time: bool,
+    timezone: &DateTimeZone,
+  ) -> Option<(String, String)> {
+    let native = chrono::NaiveDateTime::from_timestamp_opt(timestamp, 0)?;
+    let date_time = DateTime::<FixedOffset>::from_utc(native, timezone.offset_from_utc(&native));
     let fmt = self.date_format.format_str();
-    let date = format!("{}", native.format_with_items(StrftimeItems::new(fmt)));
+    let date = format!("{}", date_time.format_with_items(StrftimeItems::new(fmt)));
 
     let time = if include_time {
       let fmt = self.time_format.format_str();
-      format!("{}", native.format_with_items(StrftimeItems::new(fmt)))
+      format!("{}", date_time.format_with_items(StrftimeItems::new(fmt)))
     } else {
       "".to_string()
     };
+    Some((date, time))
+  }
 
-    DateCellDataPB {
-      date,
-      time,
-      include_time,
-      timestamp,
+  /// Returns the time zone of the cell, which falls back to the time zone of the field.
+  fn timezone(&self, cell_timezone_id: &str) -> DateTimeZone {
+    if cell_timezone_id.is_empty() {
+      DateTimeZone::from_id(&self.timezone_id)
+    } else {
+      DateTimeZone::from_id(cell_timezone_id)
     }
   }
 
@@ -96,19 +141,19 @@ impl DateTypeOptionPB {
     &self,
     naive_date: NaiveDateTime,
     time_str: &Option<String>,
+    timezone: &DateTimeZone,
   ) -> FlowyResult<i64> {
     if let Some(time_str) = time_str.as_ref() {
       if !time_str.is_empty() {
-        let offset = Local::now().offset().clone();
         let naive_time = chrono::NaiveTime::parse_from_str(time_str, self.time_format.format_str());
 
         return match naive_time {
           Ok(naive_time) => {
-            let naive = chrono::DateTime::<Local>::from_utc(naive_date, offset)
+            let offset = timezone.offset_from_utc(&naive_date);
+            let naive = DateTime::<FixedOffset>::from_utc(naive_date, offset)
               .date_naive()
               .and_time(naive_time);
-            let local = chrono::DateTime::<Local>::from_local(naive, offset);
-            Ok(local.timestamp())
+            Ok(timezone.timestamp_from_local(&naive))
           },
           Err(_e) => {
             let msg = format!("Parse {} failed", time_str);
@@ -120,6 +165,63 @@ impl DateTypeOptionPB {
 
     Ok(naive_date.timestamp())
   }
+
+  fn timestamp_from_changeset(
+    &self,
+    date_timestamp: i64,
+    time: Option<String>,
+    include_time: bool,
+    timezone: &DateTimeZone,
+  ) -> FlowyResult<i64> {
+    match (include_time, time) {
+      (true, Some(time)) => {
+        let time = Some(time.trim().to_uppercase());
+        match NaiveDateTime::from_timestamp_opt(date_timestamp, 0) {
+          Some(naive) => self.timestamp_from_utc_with_time(naive, &time, timezone),
+          None => Ok(date_timestamp),
+        }
+      },
+      _ => Ok(date_timestamp),
+    }
+  }
+}
+
+/// The time zone that the dates are displayed and edited in.
+enum DateTimeZone {
+  Local,
+  Tz(Tz),
+}
+
+impl DateTimeZone {
+  fn from_id(timezone_id: &str) -> Self {
+    match timezone_id.parse::<Tz>() {
+      Ok(tz) => DateTimeZone::Tz(tz),
+      Err(_) => DateTimeZone::Local,
+    }
+  }
+
+  fn offset_from_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+    match self {
+      DateTimeZone::Local => Local.offset_from_utc_datetime(utc).fix(),
+      DateTimeZone::Tz(tz) => tz.offset_from_utc_datetime(utc).fix(),
+    }
+  }
+
+  /// Returns the timestamp of the local date time in the time zone. The earlier one is used if
+  /// the local date time is ambiguous, and it's treated as UTC if it doesn't exist.
+  fn timestamp_from_local(&self, local: &NaiveDateTime) -> i64 {
+    let timestamp = match self {
+      DateTimeZone::Local => Local
+        .from_local_datetime(local)
+        .earliest()
+        .map(|date_time| date_time.timestamp()),
+      DateTimeZone::Tz(tz) => tz
+        .from_local_datetime(local)
+        .earliest()
+        .map(|date_time| date_time.timestamp()),
+    };
+    timestamp.unwrap_or_else(|| local.timestamp())
+  }
 }
 
 impl TypeOptionTransform for DateTypeOptionPB {}
@@ -135,7 +237,9 @@ impl CellDataDecoder for DateTypeOptionPB {
     // It happens when switching from one field to another.
     // For example:
     // FieldType::RichText -> FieldType::DateTime, it will display empty content on the screen.
-    if !decoded_field_type.is_date() {
+    // The timestamp cells are kept when switching the created time or the last edited time
+    // field to a date field.
+    if !decoded_field_type.is_date() && !decoded_field_type.is_timestamp() {
       return Ok(Default::default());
     }
 
@@ -153,38 +257,64 @@ impl CellDataChangeset for DateTypeOptionPB {
     changeset: <Self as TypeOption>::CellChangeset,
     type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    let (timestamp, include_time) = match type_cell_data {
-      None => (None, false),
+    let mut cell_data = match type_cell_data {
+      None => DateCellData::default(),
       Some(type_cell_data) => {
-        let cell_data = DateCellData::from_cell_str(&type_cell_data.cell_str).unwrap_or_default();
-        (cell_data.timestamp, cell_data.include_time)
+        DateCellData::from_cell_str(&type_cell_data.cell_str).unwrap_or_default()
       },
     };
 
-    let include_time = match changeset.include_time {
-      None => include_time,
-      Some(include_time) => include_time,
-    };
-    let timestamp = match changeset.date_timestamp() {
-      None => timestamp,
-      Some(date_timestamp) => match (include_time, changeset.time) {
-        (true, Some(time)) => {
-          let time = Some(time.trim().to_uppercase());
-          let naive = NaiveDateTime::from_timestamp_opt(date_timestamp, 0);
-          if let Some(naive) = naive {
-            Some(self.timestamp_from_utc_with_time(naive, &time)?)
-          } else {
-            Some(date_timestamp)
-          }
-        },
-        _ => Some(date_timestamp),
+    if let Some(include_time) = changeset.include_time {
+      cell_data.include_time = include_time;
+    }
+    if let Some(timezone_id) = &changeset.timezone_id {
+      cell_data.timezone_id = timezone_id.clone();
+    }
+    let timezone = self.timezone(&cell_data.timezone_id);
+
+    // The occurrences are generated from the date, so the overrides of the occurrences are
+    // dropped after the date is changed.
+    if changeset.date_timestamp().is_some() {
+      cell_data.overrides.clear();
+    }
+    if let Some(occurrence_override) = changeset.occurrence_override.clone() {
+      cell_data
+        .overrides
+        .retain(|other| other.occurrence_timestamp != occurrence_override.occurrence_timestamp);
+      cell_data.overrides.push(occurrence_override);
+    }
+
+    if let Some(date_timestamp) = changeset.date_timestamp() {
+      cell_data.timestamp = Some(self.timestamp_from_changeset(
+        date_timestamp,
+        changeset.time.clone(),
+        cell_data.include_time,
+        &timezone,
+      )?);
+    }
+
+    match changeset.is_range {
+      Some(false) => cell_data.end_timestamp = None,
+      Some(true) if cell_data.end_timestamp.is_none() => {
+        cell_data.end_timestamp = cell_data.timestamp;
       },
-    };
+      _ => {},
+    }
+    if let Some(end_date_timestamp) = changeset.end_date_timestamp() {
+      cell_data.end_timestamp = Some(self.timestamp_from_changeset(
+        end_date_timestamp,
+        changeset.end_time.clone(),
+        cell_data.include_time,
+        &timezone,
+      )?);
+    }
 
-    let date_cell_data = DateCellData {
-      timestamp,
-      include_time,
-    };
+    // The range ends at the start date at the earliest.
+    if let (Some(timestamp), Some(end_timestamp)) = (cell_data.timestamp, cell_data.end_timestamp) {
+      cell_data.end_timestamp = Some(end_timestamp.max(timestamp));
+    }
+
+    let date_cell_data = cell_data;
     Ok((date_cell_data.to_string(), date_cell_data))
   }
 }
@@ -200,7 +330,7 @@ impl TypeOptionCellDataFilter for DateTypeOptionPB {
       return true;
     }
 
-    filter.is_visible(cell_data.timestamp)
+    filter.is_range_visible(cell_data.timestamp, cell_data.end_timestamp)
   }
 }
 
@@ -222,9 +352,18 @@ impl TypeOptionCellDataCompare for DateTypeOptionPB {
 #[derive(Default)]
 pub struct DateTypeOptionBuilder(DateTypeOptionPB);
 impl_into_box_type_option_builder!(DateTypeOptionBuilder);
-impl_builder_from_json_str_and_from_bytes!(DateTypeOptionBuilder, DateTypeOptionPB);
 
 impl DateTypeOptionBuilder {
+  pub fn from_protobuf_bytes(bytes: Bytes) -> DateTypeOptionBuilder {
+    let type_option = DateTypeOptionPB::from_protobuf_bytes(bytes);
+    DateTypeOptionBuilder(type_option.normalized())
+  }
+
+  pub fn from_json_str(s: &str) -> DateTypeOptionBuilder {
+    let type_option = DateTypeOptionPB::from_json_str(s);
+    DateTypeOptionBuilder(type_option.normalized())
+  }
+
   pub fn date_format(mut self, date_format: DateFormat) -> Self {
     self.0.date_format = date_format;
     self
@@ -234,6 +373,16 @@ impl DateTypeOptionBuilder {
     self.0.time_format = time_format;
     self
   }
+
+  pub fn recurrence(mut self, recurrence: RecurrenceRulePB) -> Self {
+    self.0.recurrence = Some(recurrence.normalized());
+    self
+  }
+
+  pub fn timezone_id(mut self, timezone_id: &str) -> Self {
+    self.0.timezone_id = timezone_id.to_string();
+    self
+  }
 }
 impl TypeOptionBuilder for DateTypeOptionBuilder {
   fn field_type(&self) -> FieldType {
@@ -25,6 +25,23 @@ pub struct DateCellDataPB {
 
   #[pb(index = 4)]
   pub include_time: bool,
+
+  #[pb(index = 5)]
+  pub end_date: String,
+
+  #[pb(index = 6)]
+  pub end_time: String,
+
+  #[pb(index = 7)]
+  pub end_timestamp: i64,
+
+  #[pb(index = 8)]
+  pub is_range: bool,
+
+  /// The IANA time zone of the cell, for example, "America/New_York". The time zone of the
+  /// field is used if it's empty.
+  #[pb(index = 9)]
+  pub timezone_id: String,
 }
 
 #[derive(Clone, Debug, Default, ProtoBuf)]
@@ -43,6 +60,19 @@ pub struct DateChangesetPB {
 
   #[pb(index = 5)]
   pub is_utc: bool,
+
+  #[pb(index = 6, one_of)]
+  pub end_date: Option<String>,
+
+  #[pb(index = 7, one_of)]
+  pub end_time: Option<String>,
+
+  #[pb(index = 8, one_of)]
+  pub is_range: Option<bool>,
+
+  /// Sets the IANA time zone of the cell. The time zone of the field is used if it's empty.
+  #[pb(index = 9, one_of)]
+  pub timezone_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -51,18 +81,37 @@ pub struct DateCellChangeset {
   pub time: Option<String>,
   pub include_time: Option<bool>,
   pub is_utc: bool,
+  /// Replaces the override of the same occurrence if it exists.
+  #[serde(default)]
+  pub occurrence_override: Option<OccurrenceOverride>,
+  #[serde(default)]
+  pub end_date: Option<String>,
+  #[serde(default)]
+  pub end_time: Option<String>,
+  #[serde(default)]
+  pub is_range: Option<bool>,
+  #[serde(default)]
+  pub timezone_id: Option<String>,
 }
 
 impl DateCellChangeset {
   pub fn date_timestamp(&self) -> Option<i64> {
-    if let Some(date) = &self.date {
-      match date.parse::<i64>() {
-        Ok(date_timestamp) => Some(date_timestamp),
-        Err(_) => None,
-      }
-    } else {
-      None
+    parse_timestamp(&self.date)
+  }
+
+  pub fn end_date_timestamp(&self) -> Option<i64> {
+    parse_timestamp(&self.end_date)
+  }
+}
+
+fn parse_timestamp(date: &Option<String>) -> Option<i64> {
+  if let Some(date) = date {
+    match date.parse::<i64>() {
+      Ok(date_timestamp) => Some(date_timestamp),
+      Err(_) => None,
     }
+  } else {
+    None
   }
 }
 
@@ -85,6 +134,24 @@ impl ToCellChangesetString for DateCellChangeset {
 pub struct DateCellData {
   pub timestamp: Option<i64>,
   pub include_time: bool,
+  /// The overrides of the occurrences if the date repeats.
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub overrides: Vec<OccurrenceOverride>,
+  /// The end of the date range. The cell holds a single date if it's None.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub end_timestamp: Option<i64>,
+  /// The IANA time zone of the cell. The time zone of the field is used if it's empty.
+  #[serde(skip_serializing_if = "String::is_empty")]
+  pub timezone_id: String,
+}
+
+/// [OccurrenceOverride] moves or cancels one occurrence of a repeated date.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OccurrenceOverride {
+  /// The timestamp of the occurrence that is generated by the recurrence rule.
+  pub occurrence_timestamp: i64,
+  /// The new timestamp of the occurrence. The occurrence is cancelled if it's None.
+  pub timestamp: Option<i64>,
 }
 
 impl<'de> serde::Deserialize<'de> for DateCellData {
@@ -110,6 +177,9 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
         Ok(DateCellData {
           timestamp: Some(value),
           include_time: false,
+          overrides: vec![],
+          end_timestamp: None,
+          timezone_id: "".to_string(),
         })
       }
 
@@ -126,6 +196,9 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
       {
         let mut timestamp: Option<i64> = None;
         let mut include_time: Option<bool> = None;
+        let mut overrides: Option<Vec<OccurrenceOverride>> = None;
+        let mut end_timestamp: Option<i64> = None;
+        let mut timezone_id: Option<String> = None;
 
         while let Some(key) = map.next_key()? {
           match key {
@@ -135,6 +208,15 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
             "include_time" => {
               include_time = map.next_value()?;
             },
+            "overrides" => {
+              overrides = map.next_value()?;
+            },
+            "end_timestamp" => {
+              end_timestamp = map.next_value()?;
+            },
+            "timezone_id" => {
+              timezone_id = map.next_value()?;
+            },
             _ => {},
           }
         }
@@ -144,6 +226,9 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
         Ok(DateCellData {
           timestamp,
           include_time,
+          overrides: overrides.unwrap_or_default(),
+          end_timestamp,
+          timezone_id: timezone_id.unwrap_or_default(),
         })
       }
     }
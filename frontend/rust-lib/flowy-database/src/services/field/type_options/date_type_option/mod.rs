@@ -1,8 +1,10 @@
 #![allow(clippy::module_inception)]
 mod date_filter;
+mod date_recurrence;
 mod date_tests;
 mod date_type_option;
 mod date_type_option_entities;
 
+pub use date_recurrence::*;
 pub use date_type_option::*;
 pub use date_type_option_entities::*;
@@ -0,0 +1,224 @@
+use crate::entities::FieldType;
+use crate::services::cell::{stringify_cell_data, FromCellString, TypeCellData};
+use crate::services::field::{
+  AutoIncrementIdCellData, CheckboxCellData, DateCellData, FormulaCellData, FormulaExpression,
+  FormulaTypeOptionPB, NumberTypeOptionPB, RollupCellData, RollupTypeOptionPB,
+};
+use database_model::{CellRevision, FieldRevision, RowRevision};
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Calculates the formula cells of the rows from the other cells of the same row.
+pub struct FormulaCalculator {
+  field_revs: Vec<Arc<FieldRevision>>,
+  expression_by_field_id: HashMap<String, Result<FormulaExpression, String>>,
+}
+
+impl FormulaCalculator {
+  pub fn new(field_revs: Vec<Arc<FieldRevision>>) -> Self {
+    let expression_by_field_id = field_revs
+      .iter()
+      .filter(|field_rev| FieldType::from(field_rev.ty).is_formula())
+      .map(|field_rev| {
+        let expression = FormulaTypeOptionPB::from(field_rev).parse_expression();
+        (field_rev.id.clone(), expression)
+      })
+      .collect();
+    Self {
+      field_revs,
+      expression_by_field_id,
+    }
+  }
+
+  /// Returns the ids of all the formula fields.
+  pub fn formula_field_ids(&self) -> Vec<String> {
+    self
+      .field_revs
+      .iter()
+      .filter(|field_rev| self.expression_by_field_id.contains_key(&field_rev.id))
+      .map(|field_rev| field_rev.id.clone())
+      .collect()
+  }
+
+  /// Returns the ids of the formula fields that reference the field, directly or through other
+  /// formula fields.
+  pub fn dependent_formula_field_ids(&self, field_id: &str) -> Vec<String> {
+    let mut changed_names = HashSet::new();
+    if let Some(field_rev) = self
+      .field_revs
+      .iter()
+      .find(|field_rev| field_rev.id == field_id)
+    {
+      changed_names.insert(field_rev.name.clone());
+    }
+
+    let mut dependent_field_ids: Vec<String> = vec![];
+    loop {
+      let dependents = self
+        .field_revs
+        .iter()
+        .filter(|field_rev| !dependent_field_ids.contains(&field_rev.id))
+        .filter(
+          |field_rev| match self.expression_by_field_id.get(&field_rev.id) {
+            Some(Ok(expression)) => expression
+              .references()
+              .iter()
+              .any(|name| changed_names.contains(name)),
+            _ => false,
+          },
+        )
+        .cloned()
+        .collect::<Vec<_>>();
+      if dependents.is_empty() {
+        return dependent_field_ids;
+      }
+      for field_rev in dependents {
+        changed_names.insert(field_rev.name.clone());
+        dependent_field_ids.push(field_rev.id.clone());
+      }
+    }
+  }
+
+  /// Calculates the cells of the formula fields in the row. The other formula cells keep their
+  /// current values. Returns the cells whose values are changed.
+  pub fn calculate(
+    &self,
+    row_rev: &RowRevision,
+    formula_field_ids: &[String],
+  ) -> Vec<(Arc<FieldRevision>, FormulaCellData)> {
+    let mut state = CalculationState {
+      row_rev,
+      recalculated_field_ids: formula_field_ids.iter().cloned().collect(),
+      result_by_field_id: HashMap::new(),
+      calculating_field_ids: HashSet::new(),
+    };
+
+    let mut changed_cells = vec![];
+    for field_rev in &self.field_revs {
+      if !state.recalculated_field_ids.contains(&field_rev.id) {
+        continue;
+      }
+      let cell_data = match self.calculate_field(field_rev, &mut state) {
+        Ok(cell_data) => cell_data,
+        Err(error) => FormulaCellData::Error(error),
+      };
+      if cell_data != formula_cell_data(row_rev.cells.get(&field_rev.id)) {
+        changed_cells.push((field_rev.clone(), cell_data));
+      }
+    }
+    changed_cells
+  }
+
+  fn calculate_field(
+    &self,
+    field_rev: &Arc<FieldRevision>,
+    state: &mut CalculationState,
+  ) -> Result<FormulaCellData, String> {
+    if let Some(result) = state.result_by_field_id.get(&field_rev.id) {
+      return result.clone();
+    }
+    if !state.calculating_field_ids.insert(field_rev.id.clone()) {
+      return Err("The formula references itself".to_owned());
+    }
+
+    let result = match self.expression_by_field_id.get(&field_rev.id) {
+      None => Ok(FormulaCellData::Empty),
+      Some(Err(error)) => Err(error.clone()),
+      Some(Ok(expression)) => expression.evaluate(|name| self.field_value(name, state)),
+    };
+    state.calculating_field_ids.remove(&field_rev.id);
+    state
+      .result_by_field_id
+      .insert(field_rev.id.clone(), result.clone());
+    result
+  }
+
+  /// Returns the value of the referenced field in the row.
+  fn field_value(
+    &self,
+    name: &str,
+    state: &mut CalculationState,
+  ) -> Result<FormulaCellData, String> {
+    let field_rev = self
+      .field_revs
+      .iter()
+      .find(|field_rev| field_rev.name == name)
+      .ok_or_else(|| format!("Can't find the field: {}", name))?;
+    let cell_rev = state.row_rev.cells.get(&field_rev.id);
+    let field_type: FieldType = field_rev.ty.into();
+    if field_type.is_formula() {
+      if state.recalculated_field_ids.contains(&field_rev.id) {
+        return self.calculate_field(field_rev, state);
+      }
+      return match formula_cell_data(cell_rev) {
+        FormulaCellData::Error(error) => Err(error),
+        cell_data => Ok(cell_data),
+      };
+    }
+
+    // The cell that is not decoded by the current field type is treated as empty.
+    let cell_str = cell_rev
+      .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+      .filter(|type_cell_data| type_cell_data.field_type == field_type)
+      .map(|type_cell_data| type_cell_data.cell_str)
+      .unwrap_or_default();
+    let value = match field_type {
+      FieldType::Number => NumberTypeOptionPB::from(field_rev)
+        .format_cell_data(&cell_str)
+        .ok()
+        .and_then(|cell_data| cell_data.decimal().and_then(|decimal| decimal.to_f64()))
+        .map(FormulaCellData::Number)
+        .unwrap_or(FormulaCellData::Empty),
+      FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+        DateCellData::from_cell_str(&cell_str)
+          .ok()
+          .and_then(|cell_data| cell_data.timestamp)
+          .map(FormulaCellData::Date)
+          .unwrap_or(FormulaCellData::Empty)
+      },
+      FieldType::AutoIncrementId => AutoIncrementIdCellData::from_cell_str(&cell_str)
+        .ok()
+        .and_then(|cell_data| cell_data.0)
+        .map(|id| FormulaCellData::Number(id as f64))
+        .unwrap_or(FormulaCellData::Empty),
+      FieldType::Checkbox => FormulaCellData::Bool(
+        CheckboxCellData::from_cell_str(&cell_str)
+          .map(|cell_data| cell_data.is_check())
+          .unwrap_or(false),
+      ),
+      FieldType::Rollup => {
+        let is_date = RollupTypeOptionPB::from(field_rev).calculation.is_date();
+        match RollupCellData::from_cell_str(&cell_str).map(|cell_data| cell_data.value) {
+          Ok(Some(value)) if is_date => FormulaCellData::Date(value as i64),
+          Ok(Some(value)) => FormulaCellData::Number(value),
+          _ => FormulaCellData::Empty,
+        }
+      },
+      _ => FormulaCellData::Text(stringify_cell_data(
+        cell_str,
+        &field_type,
+        &field_type,
+        field_rev,
+      )),
+    };
+    Ok(value)
+  }
+}
+
+struct CalculationState<'a> {
+  row_rev: &'a RowRevision,
+  recalculated_field_ids: HashSet<String>,
+  result_by_field_id: HashMap<String, Result<FormulaCellData, String>>,
+  /// The formula fields that are being calculated, which are used to detect the circular
+  /// references.
+  calculating_field_ids: HashSet<String>,
+}
+
+fn formula_cell_data(cell_rev: Option<&CellRevision>) -> FormulaCellData {
+  cell_rev
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .filter(|type_cell_data| type_cell_data.is_formula())
+    .and_then(|type_cell_data| FormulaCellData::from_cell_str(&type_cell_data.cell_str).ok())
+    .unwrap_or_default()
+}
@@ -0,0 +1,819 @@
+use crate::entities::FieldType;
+use crate::services::field::FormulaCellData;
+use chrono::{Datelike, Duration, Months, NaiveDateTime};
+use std::cmp::Ordering;
+
+/// The parsed expression of a formula field.
+///
+/// The expression references the other fields of the row by their names, for example,
+/// `prop("Price") * prop("Quantity")`. It supports the arithmetic operators, the `+` also
+/// concatenates the text values, the comparison operators, `and`, `or`, `not` and the
+/// functions listed in [FormulaFunction].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormulaExpression {
+  expr: Expr,
+}
+
+impl FormulaExpression {
+  pub fn parse(s: &str) -> Result<Self, String> {
+    let tokens = tokenize(s)?;
+    if tokens.is_empty() {
+      return Ok(Self {
+        expr: Expr::Value(FormulaCellData::Empty),
+      });
+    }
+
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_or()?;
+    match parser.peek() {
+      None => Ok(Self { expr }),
+      Some(token) => Err(format!("Unexpected token: {:?}", token)),
+    }
+  }
+
+  /// Returns the names of the fields that are referenced by the expression.
+  pub fn references(&self) -> Vec<String> {
+    let mut names = vec![];
+    self.expr.collect_references(&mut names);
+    names
+  }
+
+  /// Evaluates the expression. The `field_value` returns the value of the referenced field.
+  pub fn evaluate<F>(&self, mut field_value: F) -> Result<FormulaCellData, String>
+  where
+    F: FnMut(&str) -> Result<FormulaCellData, String>,
+  {
+    let value = self.expr.evaluate(&mut field_value)?;
+    match value {
+      FormulaCellData::Number(num) if !num.is_finite() => {
+        Err("The result is not a valid number".to_owned())
+      },
+      value => Ok(value),
+    }
+  }
+
+  /// Returns the type of the values that the expression evaluates to. The `field_type` returns
+  /// the type of the referenced field, which is the result type if the field is a formula.
+  pub fn result_type<F>(&self, field_type: F) -> FieldType
+  where
+    F: Fn(&str) -> FieldType,
+  {
+    self.expr.result_type(&field_type)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  Number(f64),
+  Str(String),
+  Ident(String),
+  LeftParen,
+  RightParen,
+  Comma,
+  Plus,
+  Minus,
+  Star,
+  Slash,
+  Percent,
+  Equal,
+  NotEqual,
+  Greater,
+  GreaterOrEqual,
+  Less,
+  LessOrEqual,
+  And,
+  Or,
+  Not,
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, String> {
+  let chars = s.chars().collect::<Vec<char>>();
+  let mut tokens = vec![];
+  let mut index = 0;
+  while index < chars.len() {
+    let c = chars[index];
+    let next = chars.get(index + 1).copied();
+    index += 1;
+    let token = match c {
+      c if c.is_whitespace() => continue,
+      '(' => Token::LeftParen,
+      ')' => Token::RightParen,
+      ',' => Token::Comma,
+      '+' => Token::Plus,
+      '-' => Token::Minus,
+      '*' => Token::Star,
+      '/' => Token::Slash,
+      '%' => Token::Percent,
+      '=' => {
+        if next == Some('=') {
+          index += 1;
+        }
+        Token::Equal
+      },
+      '!' if next == Some('=') => {
+        index += 1;
+        Token::NotEqual
+      },
+      '!' => Token::Not,
+      '>' if next == Some('=') => {
+        index += 1;
+        Token::GreaterOrEqual
+      },
+      '>' => Token::Greater,
+      '<' if next == Some('=') => {
+        index += 1;
+        Token::LessOrEqual
+      },
+      '<' => Token::Less,
+      '&' if next == Some('&') => {
+        index += 1;
+        Token::And
+      },
+      '|' if next == Some('|') => {
+        index += 1;
+        Token::Or
+      },
+      '"' => {
+        let mut value = String::new();
+        loop {
+          match chars.get(index) {
+            None => return Err("Unterminated string".to_owned()),
+            Some('"') => {
+              index += 1;
+              break;
+            },
+            Some('\\') => {
+              match chars.get(index + 1) {
+                Some('n') => value.push('\n'),
+                Some(c) => value.push(*c),
+                None => return Err("Unterminated string".to_owned()),
+              }
+              index += 2;
+            },
+            Some(c) => {
+              value.push(*c);
+              index += 1;
+            },
+          }
+        }
+        Token::Str(value)
+      },
+      c if c.is_ascii_digit() || c == '.' => {
+        let start = index - 1;
+        while index < chars.len() && (chars[index].is_ascii_digit() || chars[index] == '.') {
+          index += 1;
+        }
+        let num = chars[start..index].iter().collect::<String>();
+        match num.parse::<f64>() {
+          Ok(num) => Token::Number(num),
+          Err(_) => return Err(format!("Invalid number: {}", num)),
+        }
+      },
+      c if c.is_alphabetic() || c == '_' => {
+        let start = index - 1;
+        while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+          index += 1;
+        }
+        let ident = chars[start..index].iter().collect::<String>();
+        match ident.as_str() {
+          "and" => Token::And,
+          "or" => Token::Or,
+          "not" => Token::Not,
+          _ => Token::Ident(ident),
+        }
+      },
+      c => return Err(format!("Unexpected character: {}", c)),
+    };
+    tokens.push(token);
+  }
+  Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnaryOp {
+  Negate,
+  Not,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinaryOp {
+  Add,
+  Subtract,
+  Multiply,
+  Divide,
+  Remainder,
+  Equal,
+  NotEqual,
+  Greater,
+  GreaterOrEqual,
+  Less,
+  LessOrEqual,
+  And,
+  Or,
+}
+
+/// The functions that can be called in the expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormulaFunction {
+  If,
+  Concat,
+  Format,
+  ToNumber,
+  Length,
+  Round,
+  Floor,
+  Ceil,
+  Abs,
+  Min,
+  Max,
+  Now,
+  DateAdd,
+  DateSubtract,
+  DateBetween,
+}
+
+impl FormulaFunction {
+  fn from_name(name: &str) -> Option<Self> {
+    let function = match name {
+      "if" => FormulaFunction::If,
+      "concat" => FormulaFunction::Concat,
+      "format" => FormulaFunction::Format,
+      "toNumber" => FormulaFunction::ToNumber,
+      "length" => FormulaFunction::Length,
+      "round" => FormulaFunction::Round,
+      "floor" => FormulaFunction::Floor,
+      "ceil" => FormulaFunction::Ceil,
+      "abs" => FormulaFunction::Abs,
+      "min" => FormulaFunction::Min,
+      "max" => FormulaFunction::Max,
+      "now" => FormulaFunction::Now,
+      "dateAdd" => FormulaFunction::DateAdd,
+      "dateSubtract" => FormulaFunction::DateSubtract,
+      "dateBetween" => FormulaFunction::DateBetween,
+      _ => return None,
+    };
+    Some(function)
+  }
+
+  /// Returns the minimum and the maximum number of the arguments.
+  fn arity(&self) -> (usize, usize) {
+    match self {
+      FormulaFunction::If => (3, 3),
+      FormulaFunction::Concat | FormulaFunction::Min | FormulaFunction::Max => (1, usize::MAX),
+      FormulaFunction::Format
+      | FormulaFunction::ToNumber
+      | FormulaFunction::Length
+      | FormulaFunction::Floor
+      | FormulaFunction::Ceil
+      | FormulaFunction::Abs => (1, 1),
+      FormulaFunction::Round => (1, 2),
+      FormulaFunction::Now => (0, 0),
+      FormulaFunction::DateAdd | FormulaFunction::DateSubtract | FormulaFunction::DateBetween => {
+        (3, 3)
+      },
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+  Value(FormulaCellData),
+  Field(String),
+  Unary(UnaryOp, Box<Expr>),
+  Binary(BinaryOp, Box<Expr>, Box<Expr>),
+  Call(FormulaFunction, Vec<Expr>),
+}
+
+struct Parser {
+  tokens: Vec<Token>,
+  pos: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.pos)
+  }
+
+  fn next(&mut self) -> Option<Token> {
+    let token = self.tokens.get(self.pos).cloned();
+    self.pos += 1;
+    token
+  }
+
+  fn expect(&mut self, expected: Token) -> Result<(), String> {
+    match self.next() {
+      Some(token) if token == expected => Ok(()),
+      Some(token) => Err(format!("Expected {:?} but found {:?}", expected, token)),
+      None => Err(format!("Expected {:?}", expected)),
+    }
+  }
+
+  fn parse_or(&mut self) -> Result<Expr, String> {
+    let mut left = self.parse_and()?;
+    while self.peek() == Some(&Token::Or) {
+      self.next();
+      let right = self.parse_and()?;
+      left = Expr::Binary(BinaryOp::Or, Box::new(left), Box::new(right));
+    }
+    Ok(left)
+  }
+
+  fn parse_and(&mut self) -> Result<Expr, String> {
+    let mut left = self.parse_not()?;
+    while self.peek() == Some(&Token::And) {
+      self.next();
+      let right = self.parse_not()?;
+      left = Expr::Binary(BinaryOp::And, Box::new(left), Box::new(right));
+    }
+    Ok(left)
+  }
+
+  fn parse_not(&mut self) -> Result<Expr, String> {
+    if self.peek() == Some(&Token::Not) {
+      self.next();
+      let expr = self.parse_not()?;
+      return Ok(Expr::Unary(UnaryOp::Not, Box::new(expr)));
+    }
+    self.parse_comparison()
+  }
+
+  fn parse_comparison(&mut self) -> Result<Expr, String> {
+    let left = self.parse_additive()?;
+    let op = match self.peek() {
+      Some(Token::Equal) => BinaryOp::Equal,
+      Some(Token::NotEqual) => BinaryOp::NotEqual,
+      Some(Token::Greater) => BinaryOp::Greater,
+      Some(Token::GreaterOrEqual) => BinaryOp::GreaterOrEqual,
+      Some(Token::Less) => BinaryOp::Less,
+      Some(Token::LessOrEqual) => BinaryOp::LessOrEqual,
+      _ => return Ok(left),
+    };
+    self.next();
+    let right = self.parse_additive()?;
+    Ok(Expr::Binary(op, Box::new(left), Box::new(right)))
+  }
+
+  fn parse_additive(&mut self) -> Result<Expr, String> {
+    let mut left = self.parse_multiplicative()?;
+    loop {
+      let op = match self.peek() {
+        Some(Token::Plus) => BinaryOp::Add,
+        Some(Token::Minus) => BinaryOp::Subtract,
+        _ => return Ok(left),
+      };
+      self.next();
+      let right = self.parse_multiplicative()?;
+      left = Expr::Binary(op, Box::new(left), Box::new(right));
+    }
+  }
+
+  fn parse_multiplicative(&mut self) -> Result<Expr, String> {
+    let mut left = self.parse_unary()?;
+    loop {
+      let op = match self.peek() {
+        Some(Token::Star) => BinaryOp::Multiply,
+        Some(Token::Slash) => BinaryOp::Divide,
+        Some(Token::Percent) => BinaryOp::Remainder,
+        _ => return Ok(left),
+      };
+      self.next();
+      let right = self.parse_unary()?;
+      left = Expr::Binary(op, Box::new(left), Box::new(right));
+    }
+  }
+
+  fn parse_unary(&mut self) -> Result<Expr, String> {
+    if self.peek() == Some(&Token::Minus) {
+      self.next();
+      let expr = self.parse_unary()?;
+      return Ok(Expr::Unary(UnaryOp::Negate, Box::new(expr)));
+    }
+    self.parse_primary()
+  }
+
+  fn parse_primary(&mut self) -> Result<Expr, String> {
+    match self.next() {
+      Some(Token::Number(num)) => Ok(Expr::Value(FormulaCellData::Number(num))),
+      Some(Token::Str(s)) => Ok(Expr::Value(FormulaCellData::Text(s))),
+      Some(Token::LeftParen) => {
+        let expr = self.parse_or()?;
+        self.expect(Token::RightParen)?;
+        Ok(expr)
+      },
+      Some(Token::Ident(ident)) => match ident.as_str() {
+        "true" => Ok(Expr::Value(FormulaCellData::Bool(true))),
+        "false" => Ok(Expr::Value(FormulaCellData::Bool(false))),
+        "prop" => {
+          self.expect(Token::LeftParen)?;
+          let name = match self.next() {
+            Some(Token::Str(name)) => name,
+            _ => return Err("prop() expects the name of a field".to_owned()),
+          };
+          self.expect(Token::RightParen)?;
+          Ok(Expr::Field(name))
+        },
+        _ => {
+          let function = FormulaFunction::from_name(&ident)
+            .ok_or_else(|| format!("Unknown function: {}", ident))?;
+          let args = self.parse_arguments()?;
+          let (min, max) = function.arity();
+          if args.len() < min || args.len() > max {
+            return Err(format!("Wrong number of arguments for {}()", ident));
+          }
+          Ok(Expr::Call(function, args))
+        },
+      },
+      Some(token) => Err(format!("Unexpected token: {:?}", token)),
+      None => Err("Unexpected end of the expression".to_owned()),
+    }
+  }
+
+  fn parse_arguments(&mut self) -> Result<Vec<Expr>, String> {
+    self.expect(Token::LeftParen)?;
+    let mut args = vec![];
+    if self.peek() == Some(&Token::RightParen) {
+      self.next();
+      return Ok(args);
+    }
+    loop {
+      args.push(self.parse_or()?);
+      match self.next() {
+        Some(Token::Comma) => continue,
+        Some(Token::RightParen) => return Ok(args),
+        _ => return Err("Expected , or )".to_owned()),
+      }
+    }
+  }
+}
+
+type FieldValueFn<'a> = dyn FnMut(&str) -> Result<FormulaCellData, String> + 'a;
+
+impl Expr {
+  fn collect_references(&self, names: &mut Vec<String>) {
+    match self {
+      Expr::Value(_) => {},
+      Expr::Field(name) => {
+        if !names.contains(name) {
+          names.push(name.clone());
+        }
+      },
+      Expr::Unary(_, expr) => expr.collect_references(names),
+      Expr::Binary(_, left, right) => {
+        left.collect_references(names);
+        right.collect_references(names);
+      },
+      Expr::Call(_, args) => args.iter().for_each(|arg| arg.collect_references(names)),
+    }
+  }
+
+  fn evaluate(&self, field_value: &mut FieldValueFn) -> Result<FormulaCellData, String> {
+    match self {
+      Expr::Value(value) => Ok(value.clone()),
+      Expr::Field(name) => field_value(name),
+      Expr::Unary(UnaryOp::Negate, expr) => match expr.evaluate(field_value)? {
+        FormulaCellData::Number(num) => Ok(FormulaCellData::Number(-num)),
+        FormulaCellData::Empty => Ok(FormulaCellData::Empty),
+        value => Err(format!("Can't negate {}", value.type_name())),
+      },
+      Expr::Unary(UnaryOp::Not, expr) => Ok(FormulaCellData::Bool(
+        !expr.evaluate(field_value)?.is_truthy(),
+      )),
+      Expr::Binary(BinaryOp::And, left, right) => {
+        let value =
+          left.evaluate(field_value)?.is_truthy() && right.evaluate(field_value)?.is_truthy();
+        Ok(FormulaCellData::Bool(value))
+      },
+      Expr::Binary(BinaryOp::Or, left, right) => {
+        let value =
+          left.evaluate(field_value)?.is_truthy() || right.evaluate(field_value)?.is_truthy();
+        Ok(FormulaCellData::Bool(value))
+      },
+      Expr::Binary(op, left, right) => {
+        let left = left.evaluate(field_value)?;
+        let right = right.evaluate(field_value)?;
+        apply_binary(*op, left, right)
+      },
+      Expr::Call(function, args) => call(*function, args, field_value),
+    }
+  }
+
+  fn result_type(&self, field_type: &dyn Fn(&str) -> FieldType) -> FieldType {
+    match self {
+      Expr::Value(value) => value.field_type(),
+      Expr::Field(name) => match field_type(name) {
+        FieldType::Number => FieldType::Number,
+        FieldType::DateTime => FieldType::DateTime,
+        FieldType::Checkbox => FieldType::Checkbox,
+        _ => FieldType::RichText,
+      },
+      Expr::Unary(UnaryOp::Negate, _) => FieldType::Number,
+      Expr::Unary(UnaryOp::Not, _) => FieldType::Checkbox,
+      Expr::Binary(BinaryOp::Add, left, right) => {
+        let left = left.result_type(field_type);
+        let right = right.result_type(field_type);
+        if left.is_text() || right.is_text() {
+          FieldType::RichText
+        } else {
+          FieldType::Number
+        }
+      },
+      Expr::Binary(BinaryOp::Subtract, _, _)
+      | Expr::Binary(BinaryOp::Multiply, _, _)
+      | Expr::Binary(BinaryOp::Divide, _, _)
+      | Expr::Binary(BinaryOp::Remainder, _, _) => FieldType::Number,
+      Expr::Binary(_, _, _) => FieldType::Checkbox,
+      Expr::Call(function, args) => match function {
+        FormulaFunction::If => args[1].result_type(field_type),
+        FormulaFunction::Concat | FormulaFunction::Format => FieldType::RichText,
+        FormulaFunction::Now | FormulaFunction::DateAdd | FormulaFunction::DateSubtract => {
+          FieldType::DateTime
+        },
+        _ => FieldType::Number,
+      },
+    }
+  }
+}
+
+fn apply_binary(
+  op: BinaryOp,
+  left: FormulaCellData,
+  right: FormulaCellData,
+) -> Result<FormulaCellData, String> {
+  use FormulaCellData::*;
+  match op {
+    BinaryOp::Add => match (&left, &right) {
+      (Number(a), Number(b)) => Ok(Number(a + b)),
+      (Text(_), _) | (_, Text(_)) => Ok(Text(format!(
+        "{}{}",
+        left.to_display_string(),
+        right.to_display_string()
+      ))),
+      (Empty, _) | (_, Empty) => Ok(Empty),
+      _ => Err(format!(
+        "Can't add {} and {}",
+        left.type_name(),
+        right.type_name()
+      )),
+    },
+    BinaryOp::Subtract | BinaryOp::Multiply | BinaryOp::Divide | BinaryOp::Remainder => {
+      match (&left, &right) {
+        (Number(a), Number(b)) => match op {
+          BinaryOp::Subtract => Ok(Number(a - b)),
+          BinaryOp::Multiply => Ok(Number(a * b)),
+          _ if *b == 0.0 => Err("Division by zero".to_owned()),
+          BinaryOp::Divide => Ok(Number(a / b)),
+          _ => Ok(Number(a % b)),
+        },
+        (Empty, _) | (_, Empty) => Ok(Empty),
+        _ => Err(format!(
+          "Can't apply arithmetic to {} and {}",
+          left.type_name(),
+          right.type_name()
+        )),
+      }
+    },
+    _ => {
+      let ordering = match (&left, &right) {
+        (Number(a), Number(b)) => a.partial_cmp(b),
+        (Text(a), Text(b)) => Some(a.cmp(b)),
+        (Date(a), Date(b)) => Some(a.cmp(b)),
+        (Bool(a), Bool(b)) => Some(a.cmp(b)),
+        (Empty, Empty) => Some(Ordering::Equal),
+        _ => None,
+      };
+      let value = match (op, ordering) {
+        (BinaryOp::Equal, ordering) => ordering == Some(Ordering::Equal),
+        (BinaryOp::NotEqual, ordering) => ordering != Some(Ordering::Equal),
+        (_, None) if left.is_empty() || right.is_empty() => false,
+        (_, None) => {
+          return Err(format!(
+            "Can't compare {} and {}",
+            left.type_name(),
+            right.type_name()
+          ))
+        },
+        (BinaryOp::Greater, Some(ordering)) => ordering.is_gt(),
+        (BinaryOp::GreaterOrEqual, Some(ordering)) => ordering.is_ge(),
+        (BinaryOp::Less, Some(ordering)) => ordering.is_lt(),
+        (_, Some(ordering)) => ordering.is_le(),
+      };
+      Ok(Bool(value))
+    },
+  }
+}
+
+fn call(
+  function: FormulaFunction,
+  args: &[Expr],
+  field_value: &mut FieldValueFn,
+) -> Result<FormulaCellData, String> {
+  use FormulaCellData::*;
+  // The branches of the if() are evaluated lazily.
+  if function == FormulaFunction::If {
+    return if args[0].evaluate(field_value)?.is_truthy() {
+      args[1].evaluate(field_value)
+    } else {
+      args[2].evaluate(field_value)
+    };
+  }
+
+  let values = args
+    .iter()
+    .map(|arg| arg.evaluate(field_value))
+    .collect::<Result<Vec<_>, _>>()?;
+  match function {
+    FormulaFunction::If => unreachable!(),
+    FormulaFunction::Concat => Ok(Text(
+      values
+        .iter()
+        .map(|value| value.to_display_string())
+        .collect(),
+    )),
+    FormulaFunction::Format => Ok(Text(values[0].to_display_string())),
+    FormulaFunction::ToNumber => match &values[0] {
+      Number(num) => Ok(Number(*num)),
+      Text(s) => Ok(s.trim().parse::<f64>().map(Number).unwrap_or(Empty)),
+      Bool(b) => Ok(Number(if *b { 1.0 } else { 0.0 })),
+      Date(timestamp) => Ok(Number(*timestamp as f64)),
+      _ => Ok(Empty),
+    },
+    FormulaFunction::Length => Ok(Number(values[0].to_display_string().chars().count() as f64)),
+    FormulaFunction::Round => {
+      let digits = match values.get(1) {
+        None => 0.0,
+        Some(Number(digits)) => digits.trunc(),
+        Some(value) => {
+          return Err(format!(
+            "round() expects a number but got {}",
+            value.type_name()
+          ))
+        },
+      };
+      let factor = 10f64.powf(digits);
+      map_number("round", &values[0], |num| (num * factor).round() / factor)
+    },
+    FormulaFunction::Floor => map_number("floor", &values[0], f64::floor),
+    FormulaFunction::Ceil => map_number("ceil", &values[0], f64::ceil),
+    FormulaFunction::Abs => map_number("abs", &values[0], f64::abs),
+    FormulaFunction::Min | FormulaFunction::Max => {
+      let mut result: Option<f64> = None;
+      for value in &values {
+        match value {
+          Number(num) => {
+            result = Some(match (result, function) {
+              (None, _) => *num,
+              (Some(current), FormulaFunction::Min) => current.min(*num),
+              (Some(current), _) => current.max(*num),
+            })
+          },
+          Empty => {},
+          value => return Err(format!("Expected a number but got {}", value.type_name())),
+        }
+      }
+      Ok(result.map(Number).unwrap_or(Empty))
+    },
+    FormulaFunction::Now => Ok(Date(chrono::Utc::now().timestamp())),
+    FormulaFunction::DateAdd | FormulaFunction::DateSubtract => {
+      let (date, amount, unit) = match (&values[0], &values[1], &values[2]) {
+        (Empty, _, _) | (_, Empty, _) => return Ok(Empty),
+        (Date(date), Number(amount), Text(unit)) => (*date, amount.trunc() as i64, unit),
+        _ => return Err("Expected a date, a number and a unit".to_owned()),
+      };
+      let amount = if function == FormulaFunction::DateAdd {
+        amount
+      } else {
+        amount
+          .checked_neg()
+          .ok_or_else(|| "The date is out of range".to_owned())?
+      };
+      date_add(date, amount, unit).map(Date)
+    },
+    FormulaFunction::DateBetween => match (&values[0], &values[1], &values[2]) {
+      (Empty, _, _) | (_, Empty, _) => Ok(Empty),
+      (Date(left), Date(right), Text(unit)) => {
+        date_between(*left, *right, unit).map(|num| Number(num as f64))
+      },
+      _ => Err("Expected two dates and a unit".to_owned()),
+    },
+  }
+}
+
+fn map_number<F>(name: &str, value: &FormulaCellData, f: F) -> Result<FormulaCellData, String>
+where
+  F: Fn(f64) -> f64,
+{
+  match value {
+    FormulaCellData::Number(num) => Ok(FormulaCellData::Number(f(*num))),
+    FormulaCellData::Empty => Ok(FormulaCellData::Empty),
+    value => Err(format!(
+      "{}() expects a number but got {}",
+      name,
+      value.type_name()
+    )),
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateUnit {
+  Years,
+  Months,
+  Weeks,
+  Days,
+  Hours,
+  Minutes,
+  Seconds,
+}
+
+impl DateUnit {
+  fn from_name(name: &str) -> Result<Self, String> {
+    let unit = match name.trim().to_lowercase().trim_end_matches('s') {
+      "year" => DateUnit::Years,
+      "month" => DateUnit::Months,
+      "week" => DateUnit::Weeks,
+      "day" => DateUnit::Days,
+      "hour" => DateUnit::Hours,
+      "minute" => DateUnit::Minutes,
+      "second" => DateUnit::Seconds,
+      _ => return Err(format!("Unknown date unit: {}", name)),
+    };
+    Ok(unit)
+  }
+
+  fn seconds(&self) -> Option<i64> {
+    match self {
+      DateUnit::Weeks => Some(7 * 24 * 3600),
+      DateUnit::Days => Some(24 * 3600),
+      DateUnit::Hours => Some(3600),
+      DateUnit::Minutes => Some(60),
+      DateUnit::Seconds => Some(1),
+      DateUnit::Years | DateUnit::Months => None,
+    }
+  }
+}
+
+fn naive_date_time(timestamp: i64) -> Result<NaiveDateTime, String> {
+  NaiveDateTime::from_timestamp_opt(timestamp, 0).ok_or_else(|| "Invalid date".to_owned())
+}
+
+/// The longest duration, in seconds, that can be added to a date. chrono's [Duration] panics if
+/// it's longer than this, and adding it to any date gets out of the range of the dates anyway.
+const MAX_DURATION_SECONDS: u64 = (i64::MAX / 1000) as u64;
+
+fn date_add(timestamp: i64, amount: i64, unit: &str) -> Result<i64, String> {
+  let unit = DateUnit::from_name(unit)?;
+  if let Some(seconds) = unit.seconds() {
+    let seconds = amount
+      .checked_mul(seconds)
+      .filter(|seconds| seconds.unsigned_abs() <= MAX_DURATION_SECONDS)
+      .ok_or_else(|| "The date is out of range".to_owned())?;
+    let date_time = naive_date_time(timestamp)?
+      .checked_add_signed(Duration::seconds(seconds))
+      .ok_or_else(|| "The date is out of range".to_owned())?;
+    return Ok(date_time.timestamp());
+  }
+
+  let months = if unit == DateUnit::Years {
+    amount.saturating_mul(12)
+  } else {
+    amount
+  };
+  let date_time = naive_date_time(timestamp)?;
+  let months_abs = u32::try_from(months.unsigned_abs()).map_err(|_| "The date is out of range")?;
+  let date_time = if months >= 0 {
+    date_time.checked_add_months(Months::new(months_abs))
+  } else {
+    date_time.checked_sub_months(Months::new(months_abs))
+  };
+  date_time
+    .map(|date_time| date_time.timestamp())
+    .ok_or_else(|| "The date is out of range".to_owned())
+}
+
+/// Returns the number of the whole units from the `right` date to the `left` date.
+fn date_between(left: i64, right: i64, unit: &str) -> Result<i64, String> {
+  let unit = DateUnit::from_name(unit)?;
+  if let Some(seconds) = unit.seconds() {
+    let difference = left
+      .checked_sub(right)
+      .ok_or_else(|| "The date is out of range".to_owned())?;
+    return Ok(difference / seconds);
+  }
+
+  let left = naive_date_time(left)?;
+  let right = naive_date_time(right)?;
+  let mut months =
+    (left.year() as i64 - right.year() as i64) * 12 + left.month() as i64 - right.month() as i64;
+  // The last month isn't a whole month if the day of the left date hasn't reached the day of
+  // the right date.
+  let left_rest = (left.day(), left.time());
+  let right_rest = (right.day(), right.time());
+  if months > 0 && left_rest < right_rest {
+    months -= 1;
+  } else if months < 0 && left_rest > right_rest {
+    months += 1;
+  }
+  match unit {
+    DateUnit::Years => Ok(months / 12),
+    _ => Ok(months),
+  }
+}
@@ -0,0 +1,122 @@
+use crate::entities::{
+  CheckboxFilterConditionPB, CheckboxFilterPB, DateFilterConditionPB, DateFilterContentPB,
+  DateFilterPB, FieldType, FormulaFilterPB, NumberFilterConditionPB, NumberFilterPB,
+  TextFilterConditionPB, TextFilterPB,
+};
+use crate::services::field::{CheckboxCellData, FormulaCellData, NumberCellData};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+impl FormulaFilterPB {
+  /// Applies the filter of the `result_type` to the cell. The value that doesn't match the
+  /// `result_type` is treated as empty.
+  pub fn is_visible(&self, result_type: &FieldType, cell_data: &FormulaCellData) -> bool {
+    let condition = self.condition as u8;
+    match result_type {
+      FieldType::Number => {
+        let filter = NumberFilterPB {
+          condition: NumberFilterConditionPB::try_from(condition)
+            .unwrap_or(NumberFilterConditionPB::Equal),
+          content: self.content.clone(),
+        };
+        let num_cell_data = match cell_data {
+          FormulaCellData::Number(num) => Decimal::from_f64(*num)
+            .map(NumberCellData::from_decimal)
+            .unwrap_or_default(),
+          _ => NumberCellData::new(),
+        };
+        filter.is_visible(&num_cell_data)
+      },
+      FieldType::DateTime => {
+        let mut filter = DateFilterPB {
+          condition: DateFilterConditionPB::try_from(condition)
+            .unwrap_or(DateFilterConditionPB::DateIs),
+          ..Default::default()
+        };
+        if let Ok(content) = DateFilterContentPB::from_str(&self.content) {
+          filter.start = content.start;
+          filter.end = content.end;
+          filter.timestamp = content.timestamp;
+        }
+        match cell_data {
+          FormulaCellData::Date(timestamp) => filter.is_visible(*timestamp),
+          _ => filter.is_visible(None::<i64>),
+        }
+      },
+      FieldType::Checkbox => {
+        let filter = CheckboxFilterPB {
+          condition: CheckboxFilterConditionPB::try_from(condition)
+            .unwrap_or(CheckboxFilterConditionPB::IsChecked),
+        };
+        let is_check = matches!(cell_data, FormulaCellData::Bool(true));
+        let checkbox_cell_data =
+          CheckboxCellData::from_str(&is_check.to_string()).unwrap_or_default();
+        filter.is_visible(&checkbox_cell_data)
+      },
+      _ => {
+        let filter = TextFilterPB {
+          condition: TextFilterConditionPB::try_from(condition)
+            .unwrap_or(TextFilterConditionPB::Is),
+          content: self.content.clone(),
+        };
+        filter.is_visible(cell_data.to_display_string())
+      },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::entities::{
+    CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, FieldType,
+    FormulaFilterPB, NumberFilterConditionPB, TextFilterConditionPB,
+  };
+  use crate::services::field::FormulaCellData;
+
+  #[test]
+  fn formula_filter_number_test() {
+    let filter = FormulaFilterPB {
+      condition: NumberFilterConditionPB::GreaterThan as u32,
+      content: "10".to_owned(),
+    };
+    for (cell_data, visible) in [
+      (FormulaCellData::Number(12.5), true),
+      (FormulaCellData::Number(10.0), false),
+      (FormulaCellData::Empty, false),
+    ] {
+      assert_eq!(filter.is_visible(&FieldType::Number, &cell_data), visible);
+    }
+  }
+
+  #[test]
+  fn formula_filter_date_test() {
+    let filter = FormulaFilterPB {
+      condition: DateFilterConditionPB::DateAfter as u32,
+      content: DateFilterContentPB {
+        timestamp: Some(1668704685),
+        ..Default::default()
+      }
+      .to_string(),
+    };
+    assert!(filter.is_visible(&FieldType::DateTime, &FormulaCellData::Date(1668963885)));
+    assert!(!filter.is_visible(&FieldType::DateTime, &FormulaCellData::Date(1668359085)));
+  }
+
+  #[test]
+  fn formula_filter_checkbox_and_text_test() {
+    let filter = FormulaFilterPB {
+      condition: CheckboxFilterConditionPB::IsChecked as u32,
+      content: "".to_owned(),
+    };
+    assert!(filter.is_visible(&FieldType::Checkbox, &FormulaCellData::Bool(true)));
+    assert!(!filter.is_visible(&FieldType::Checkbox, &FormulaCellData::Bool(false)));
+
+    let filter = FormulaFilterPB {
+      condition: TextFilterConditionPB::Contains as u32,
+      content: "flowy".to_owned(),
+    };
+    let cell_data = FormulaCellData::Text("AppFlowy".to_owned());
+    assert!(filter.is_visible(&FieldType::RichText, &cell_data));
+  }
+}
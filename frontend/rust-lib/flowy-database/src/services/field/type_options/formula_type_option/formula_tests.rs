@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::FieldType;
+  use crate::services::cell::{make_formula_cell, CellDataChangeset};
+  use crate::services::field::{
+    FieldBuilder, FormulaCalculator, FormulaCellData, FormulaExpression, FormulaTypeOptionBuilder,
+    FormulaTypeOptionPB, NumberTypeOptionBuilder, RichTextTypeOptionBuilder,
+  };
+  use crate::services::row::RowRevisionBuilder;
+  use database_model::FieldRevision;
+  use std::sync::Arc;
+
+  fn evaluate(expression: &str, fields: &[(&str, FormulaCellData)]) -> FormulaCellData {
+    let expression = FormulaExpression::parse(expression).unwrap();
+    expression
+      .evaluate(|name| {
+        fields
+          .iter()
+          .find(|(field_name, _)| *field_name == name)
+          .map(|(_, value)| value.clone())
+          .ok_or_else(|| format!("Can't find the field: {}", name))
+      })
+      .unwrap_or_else(FormulaCellData::Error)
+  }
+
+  #[test]
+  fn formula_arithmetic_test() {
+    let fields = [
+      ("Price", FormulaCellData::Number(2.5)),
+      ("Quantity", FormulaCellData::Number(4.0)),
+    ];
+    assert_eq!(
+      evaluate(r#"prop("Price") * prop("Quantity") + 1"#, &fields),
+      FormulaCellData::Number(11.0)
+    );
+    assert_eq!(
+      evaluate("-(1 + 2) * 3 % 4", &fields),
+      FormulaCellData::Number(-1.0)
+    );
+    assert_eq!(
+      evaluate("round(10 / 3, 2)", &fields),
+      FormulaCellData::Number(3.33)
+    );
+    assert_eq!(
+      evaluate("max(1, 5, 3) - min(4, 2)", &fields),
+      FormulaCellData::Number(3.0)
+    );
+    assert_eq!(
+      evaluate("1 / 0", &fields),
+      FormulaCellData::Error("Division by zero".to_owned())
+    );
+    // The arithmetic on the empty value stays empty.
+    assert_eq!(
+      evaluate(r#"prop("Empty") * 2"#, &[("Empty", FormulaCellData::Empty)]),
+      FormulaCellData::Empty
+    );
+  }
+
+  #[test]
+  fn formula_text_test() {
+    let fields = [
+      ("Name", FormulaCellData::Text("AppFlowy".to_owned())),
+      ("Stars", FormulaCellData::Number(1000.0)),
+    ];
+    assert_eq!(
+      evaluate(r#"prop("Name") + ": " + prop("Stars")"#, &fields),
+      FormulaCellData::Text("AppFlowy: 1000".to_owned())
+    );
+    assert_eq!(
+      evaluate(r#"concat(prop("Name"), "!", true)"#, &fields),
+      FormulaCellData::Text("AppFlowy!Yes".to_owned())
+    );
+    assert_eq!(
+      evaluate(r#"length(prop("Name"))"#, &fields),
+      FormulaCellData::Number(8.0)
+    );
+    assert_eq!(
+      evaluate(r#"toNumber("12.5") * 2"#, &fields),
+      FormulaCellData::Number(25.0)
+    );
+  }
+
+  #[test]
+  fn formula_logic_test() {
+    let fields = [
+      ("Done", FormulaCellData::Bool(true)),
+      ("Score", FormulaCellData::Number(70.0)),
+    ];
+    assert_eq!(
+      evaluate(
+        r#"if(prop("Score") >= 60 and prop("Done"), "Pass", "Fail")"#,
+        &fields
+      ),
+      FormulaCellData::Text("Pass".to_owned())
+    );
+    assert_eq!(
+      evaluate(r#"not prop("Done") || prop("Score") == 70"#, &fields),
+      FormulaCellData::Bool(true)
+    );
+    assert_eq!(
+      evaluate(r#""a" < "b" && 2 != 3"#, &fields),
+      FormulaCellData::Bool(true)
+    );
+    // The branch that is not taken is not evaluated.
+    assert_eq!(
+      evaluate("if(true, 1, 1 / 0)", &fields),
+      FormulaCellData::Number(1.0)
+    );
+  }
+
+  #[test]
+  fn formula_date_test() {
+    // Mar 14, 2022 09:56:02
+    let fields = [
+      ("Start", FormulaCellData::Date(1647251762)),
+      ("End", FormulaCellData::Date(1650016562)),
+    ];
+    assert_eq!(
+      evaluate(r#"dateAdd(prop("Start"), 1, "months")"#, &fields),
+      FormulaCellData::Date(1649930162)
+    );
+    assert_eq!(
+      evaluate(r#"dateSubtract(prop("Start"), 2, "days")"#, &fields),
+      FormulaCellData::Date(1647078962)
+    );
+    assert_eq!(
+      evaluate(
+        r#"dateBetween(prop("End"), prop("Start"), "days")"#,
+        &fields
+      ),
+      FormulaCellData::Number(32.0)
+    );
+    assert_eq!(
+      evaluate(
+        r#"dateBetween(prop("End"), prop("Start"), "months")"#,
+        &fields
+      ),
+      FormulaCellData::Number(1.0)
+    );
+    assert_eq!(
+      evaluate(r#"prop("End") > prop("Start")"#, &fields),
+      FormulaCellData::Bool(true)
+    );
+    assert_eq!(
+      FormulaCellData::Date(1647251762).to_display_string(),
+      "Mar 14, 2022"
+    );
+  }
+
+  #[test]
+  fn formula_date_out_of_range_test() {
+    let fields = [
+      ("Start", FormulaCellData::Date(1647251762)),
+      ("Min", FormulaCellData::Date(i64::MIN)),
+      ("Max", FormulaCellData::Date(i64::MAX)),
+    ];
+    let expressions = [
+      r#"dateAdd(prop("Start"), 100000000000000, "days")"#,
+      r#"dateAdd(prop("Start"), 100000000000000, "weeks")"#,
+      r#"dateSubtract(prop("Start"), 0 - 100000000000000000000, "seconds")"#,
+      r#"dateAdd(prop("Start"), 100000000000000, "years")"#,
+      r#"dateBetween(prop("Max"), prop("Min"), "days")"#,
+    ];
+    for expression in expressions {
+      assert!(matches!(
+        evaluate(expression, &fields),
+        FormulaCellData::Error(_)
+      ));
+    }
+  }
+
+  #[test]
+  fn formula_parse_error_test() {
+    for expression in [
+      "1 +",
+      "unknown(1)",
+      r#"prop(1)"#,
+      r#""unterminated"#,
+      "if(true, 1)",
+    ] {
+      assert!(
+        FormulaExpression::parse(expression).is_err(),
+        "{}",
+        expression
+      );
+    }
+  }
+
+  #[test]
+  fn formula_result_type_test() {
+    let field_revs = vec![
+      Arc::new(
+        FieldBuilder::new(RichTextTypeOptionBuilder::default())
+          .name("Name")
+          .build(),
+      ),
+      Arc::new(
+        FieldBuilder::new(NumberTypeOptionBuilder::default())
+          .name("Price")
+          .build(),
+      ),
+    ];
+    for (expression, result_type) in [
+      (r#"prop("Price") * 2"#, FieldType::Number),
+      (r#"prop("Name") + prop("Price")"#, FieldType::RichText),
+      (r#"prop("Price") > 10"#, FieldType::Checkbox),
+      (r#"dateAdd(now(), 1, "days")"#, FieldType::DateTime),
+      (r#"if(true, prop("Price"), 0)"#, FieldType::Number),
+    ] {
+      let type_option = FormulaTypeOptionPB {
+        expression: expression.to_owned(),
+        ..Default::default()
+      };
+      assert_eq!(type_option.infer_result_type(&field_revs), result_type);
+    }
+  }
+
+  fn formula_field(name: &str, expression: &str) -> Arc<FieldRevision> {
+    let builder = FormulaTypeOptionBuilder::default().expression(expression);
+    Arc::new(FieldBuilder::new(builder).name(name).build())
+  }
+
+  #[test]
+  fn formula_calculator_test() {
+    let price_field = Arc::new(
+      FieldBuilder::new(NumberTypeOptionBuilder::default())
+        .name("Price")
+        .build(),
+    );
+    let total_field = formula_field("Total", r#"prop("Price") * 2"#);
+    let label_field = formula_field("Label", r#""Total: " + prop("Total")"#);
+    let cycle_field = formula_field("Cycle", r#"prop("Cycle") + 1"#);
+    let field_revs = vec![
+      price_field.clone(),
+      total_field.clone(),
+      label_field.clone(),
+      cycle_field.clone(),
+    ];
+
+    let calculator = FormulaCalculator::new(field_revs.clone());
+    assert_eq!(
+      calculator.dependent_formula_field_ids(&price_field.id),
+      vec![total_field.id.clone(), label_field.id.clone()]
+    );
+
+    let mut row_builder = RowRevisionBuilder::new("", field_revs);
+    row_builder.insert_number_cell(&price_field.id, 21);
+    let mut row_rev = row_builder.build();
+    let cells = calculator.calculate(&row_rev, &calculator.formula_field_ids());
+    let values = cells
+      .iter()
+      .map(|(field_rev, cell_data)| (field_rev.name.as_str(), cell_data.clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      values,
+      vec![
+        ("Total", FormulaCellData::Number(42.0)),
+        ("Label", FormulaCellData::Text("Total: 42".to_owned())),
+        (
+          "Cycle",
+          FormulaCellData::Error("The formula references itself".to_owned())
+        ),
+      ]
+    );
+
+    // Only the changed cells are returned after the calculated cells are saved.
+    for (field_rev, cell_data) in cells {
+      row_rev
+        .cells
+        .insert(field_rev.id.clone(), make_formula_cell(&cell_data));
+    }
+    assert!(calculator
+      .calculate(&row_rev, &calculator.formula_field_ids())
+      .is_empty());
+  }
+
+  #[test]
+  fn formula_cell_is_read_only_test() {
+    let type_option = FormulaTypeOptionPB::default();
+    assert!(type_option.apply_changeset("123".to_owned(), None).is_err());
+    // The calculated cell data is rejected too, only the database writes the formula cells.
+    let cell_data = FormulaCellData::Date(0);
+    assert!(type_option
+      .apply_changeset(cell_data.to_string(), None)
+      .is_err());
+  }
+}
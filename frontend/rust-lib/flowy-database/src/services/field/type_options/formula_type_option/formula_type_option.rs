@@ -0,0 +1,189 @@
+use crate::entities::{FieldType, FormulaFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  default_order, BoxTypeOptionBuilder, FormulaCellData, FormulaCellDataPB, FormulaExpression,
+  RollupTypeOptionPB, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct FormulaTypeOptionBuilder(FormulaTypeOptionPB);
+impl_into_box_type_option_builder!(FormulaTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(FormulaTypeOptionBuilder, FormulaTypeOptionPB);
+
+impl FormulaTypeOptionBuilder {
+  pub fn expression(mut self, expression: &str) -> Self {
+    self.0.expression = expression.to_owned();
+    self
+  }
+
+  pub fn result_type(mut self, result_type: FieldType) -> Self {
+    self.0.result_type = result_type;
+    self
+  }
+}
+
+impl TypeOptionBuilder for FormulaTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Formula
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct FormulaTypeOptionPB {
+  #[pb(index = 1)]
+  pub expression: String,
+
+  /// The type of the values that the expression evaluates to. It's inferred from the expression
+  /// and decides which kind of filter applies to the cells.
+  #[pb(index = 2)]
+  #[serde(default)]
+  pub result_type: FieldType,
+}
+impl_type_option!(FormulaTypeOptionPB, FieldType::Formula);
+
+impl FormulaTypeOptionPB {
+  pub fn parse_expression(&self) -> Result<FormulaExpression, String> {
+    FormulaExpression::parse(&self.expression)
+  }
+
+  /// Returns the result type of the expression, which depends on the types of the referenced
+  /// fields.
+  pub fn infer_result_type(&self, field_revs: &[Arc<FieldRevision>]) -> FieldType {
+    match self.parse_expression() {
+      Ok(expression) => expression.result_type(|name| {
+        match field_revs.iter().find(|field_rev| field_rev.name == name) {
+          None => FieldType::RichText,
+          Some(field_rev) => {
+            let field_type: FieldType = field_rev.ty.into();
+            match field_type {
+              FieldType::Formula => FormulaTypeOptionPB::from(field_rev).result_type,
+              FieldType::Rollup if RollupTypeOptionPB::from(field_rev).calculation.is_date() => {
+                FieldType::DateTime
+              },
+              FieldType::Rollup | FieldType::AutoIncrementId => FieldType::Number,
+              FieldType::CreatedTime | FieldType::LastEditedTime => FieldType::DateTime,
+              _ => field_type,
+            }
+          },
+        }
+      }),
+      Err(_) => FieldType::RichText,
+    }
+  }
+}
+
+impl TypeOption for FormulaTypeOptionPB {
+  type CellData = FormulaCellData;
+  type CellChangeset = FormulaCellChangeset;
+  type CellProtobufType = FormulaCellDataPB;
+  type CellFilter = FormulaFilterPB;
+}
+
+impl TypeOptionTransform for FormulaTypeOptionPB {}
+
+impl TypeOptionCellData for FormulaTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    let error = match &cell_data {
+      FormulaCellData::Error(error) => error.clone(),
+      _ => "".to_owned(),
+    };
+    FormulaCellDataPB {
+      result_type: self.result_type.clone(),
+      content: cell_data.to_display_string(),
+      error,
+    }
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    FormulaCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for FormulaTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_formula() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_display_string()
+  }
+}
+
+/// The formula cells are calculated from the other cells of the row, so every changeset is
+/// rejected. The database writes the calculated cells without going through the changesets.
+pub type FormulaCellChangeset = String;
+
+impl CellDataChangeset for FormulaTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    Err(FlowyError::invalid_data().context("The formula cell is calculated from its expression"))
+  }
+}
+
+impl TypeOptionCellDataFilter for FormulaTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_formula() {
+      return true;
+    }
+    filter.is_visible(&self.result_type, cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for FormulaTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data, other_cell_data) {
+      (FormulaCellData::Number(left), FormulaCellData::Number(right)) => {
+        left.partial_cmp(right).unwrap_or_else(default_order)
+      },
+      (FormulaCellData::Text(left), FormulaCellData::Text(right)) => left.cmp(right),
+      (FormulaCellData::Bool(left), FormulaCellData::Bool(right)) => left.cmp(right),
+      (FormulaCellData::Date(left), FormulaCellData::Date(right)) => left.cmp(right),
+      // The cells without a value are placed after the others.
+      (left, right) => match (left.is_empty(), right.is_empty()) {
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        _ => default_order(),
+      },
+    }
+  }
+}
@@ -0,0 +1,133 @@
+use crate::entities::FieldType;
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use crate::services::field::{CHECK, UNCHECK};
+use bytes::Bytes;
+use chrono::NaiveDateTime;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct FormulaCellDataPB {
+  #[pb(index = 1)]
+  pub result_type: FieldType,
+
+  /// The display string of the calculated value.
+  #[pb(index = 2)]
+  pub content: String,
+
+  /// The reason why the expression can't be calculated. It's empty if there is no error.
+  #[pb(index = 3)]
+  pub error: String,
+}
+
+impl DecodedCellData for FormulaCellDataPB {
+  type Object = FormulaCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.content.is_empty()
+  }
+}
+
+pub struct FormulaCellDataParser();
+impl CellProtobufBlobParser for FormulaCellDataParser {
+  type Object = FormulaCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    FormulaCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+/// The calculated value of a formula cell. The value is typed, so the formula cells can be
+/// filtered and sorted like the cells of the number, date, checkbox and text fields.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum FormulaCellData {
+  Empty,
+  Number(f64),
+  Text(String),
+  Bool(bool),
+  /// The timestamp in seconds.
+  Date(i64),
+  Error(String),
+}
+
+impl std::default::Default for FormulaCellData {
+  fn default() -> Self {
+    FormulaCellData::Empty
+  }
+}
+
+impl FormulaCellData {
+  pub fn is_empty(&self) -> bool {
+    match self {
+      FormulaCellData::Empty => true,
+      FormulaCellData::Text(s) => s.is_empty(),
+      _ => false,
+    }
+  }
+
+  pub fn is_truthy(&self) -> bool {
+    match self {
+      FormulaCellData::Empty | FormulaCellData::Error(_) => false,
+      FormulaCellData::Number(num) => *num != 0.0,
+      FormulaCellData::Text(s) => !s.is_empty(),
+      FormulaCellData::Bool(b) => *b,
+      FormulaCellData::Date(_) => true,
+    }
+  }
+
+  /// Returns the field type whose cells hold the same kind of values.
+  pub fn field_type(&self) -> FieldType {
+    match self {
+      FormulaCellData::Number(_) => FieldType::Number,
+      FormulaCellData::Bool(_) => FieldType::Checkbox,
+      FormulaCellData::Date(_) => FieldType::DateTime,
+      _ => FieldType::RichText,
+    }
+  }
+
+  pub fn type_name(&self) -> &'static str {
+    match self {
+      FormulaCellData::Empty => "empty",
+      FormulaCellData::Number(_) => "number",
+      FormulaCellData::Text(_) => "text",
+      FormulaCellData::Bool(_) => "boolean",
+      FormulaCellData::Date(_) => "date",
+      FormulaCellData::Error(_) => "error",
+    }
+  }
+
+  pub fn to_display_string(&self) -> String {
+    match self {
+      FormulaCellData::Empty | FormulaCellData::Error(_) => "".to_owned(),
+      // Rounds the number to hide the error of the floating-point arithmetic, for example,
+      // 0.1 + 0.2.
+      FormulaCellData::Number(num) => format!("{}", (num * 1e10).round() / 1e10),
+      FormulaCellData::Text(s) => s.clone(),
+      FormulaCellData::Bool(true) => CHECK.to_owned(),
+      FormulaCellData::Bool(false) => UNCHECK.to_owned(),
+      FormulaCellData::Date(timestamp) => NaiveDateTime::from_timestamp_opt(*timestamp, 0)
+        .map(|date_time| date_time.format("%b %d, %Y").to_string())
+        .unwrap_or_default(),
+    }
+  }
+}
+
+impl FromCellString for FormulaCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    if s.is_empty() {
+      return Ok(FormulaCellData::Empty);
+    }
+    serde_json::from_str::<FormulaCellData>(s).map_err(internal_error)
+  }
+}
+
+impl ToString for FormulaCellData {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
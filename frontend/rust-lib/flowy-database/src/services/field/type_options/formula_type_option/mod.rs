@@ -0,0 +1,12 @@
+#![allow(clippy::module_inception)]
+mod formula_calculator;
+mod formula_expression;
+mod formula_filter;
+mod formula_tests;
+mod formula_type_option;
+mod formula_type_option_entities;
+
+pub use formula_calculator::*;
+pub use formula_expression::*;
+pub use formula_type_option::*;
+pub use formula_type_option_entities::*;
@@ -1,17 +1,27 @@
+pub mod auto_increment_id_type_option;
 pub mod checkbox_type_option;
 pub mod date_type_option;
+pub mod formula_type_option;
 pub mod number_type_option;
+pub mod relation_type_option;
+pub mod rollup_type_option;
 pub mod selection_type_option;
 pub mod text_type_option;
+pub mod timestamp_type_option;
 mod type_option;
 mod type_option_cell;
 pub mod url_type_option;
 
+pub use auto_increment_id_type_option::*;
 pub use checkbox_type_option::*;
 pub use date_type_option::*;
+pub use formula_type_option::*;
 pub use number_type_option::*;
+pub use relation_type_option::*;
+pub use rollup_type_option::*;
 pub use selection_type_option::*;
 pub use text_type_option::*;
+pub use timestamp_type_option::*;
 pub use type_option::*;
 pub use type_option_cell::*;
 pub use url_type_option::*;
@@ -0,0 +1,11 @@
+use crate::entities::{RelationFilterConditionPB, RelationFilterPB};
+use crate::services::field::RelationCellData;
+
+impl RelationFilterPB {
+  pub fn is_visible(&self, cell_data: &RelationCellData) -> bool {
+    match self.condition {
+      RelationFilterConditionPB::IsEmpty => cell_data.row_ids.is_empty(),
+      RelationFilterConditionPB::IsNotEmpty => !cell_data.row_ids.is_empty(),
+    }
+  }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, RelationFilterConditionPB, RelationFilterPB};
+  use crate::services::cell::{CellDataChangeset, TypeCellData};
+  use crate::services::field::{RelationCellChangeset, RelationCellData, RelationTypeOptionPB};
+
+  fn apply_changeset(
+    type_option: &RelationTypeOptionPB,
+    changeset: RelationCellChangeset,
+    cell_str: Option<String>,
+  ) -> RelationCellData {
+    let type_cell_data = cell_str.map(|cell_str| TypeCellData::new(cell_str, FieldType::Relation));
+    let (_, cell_data) = type_option
+      .apply_changeset(changeset, type_cell_data)
+      .unwrap();
+    cell_data
+  }
+
+  #[test]
+  fn relation_cell_insert_and_remove_test() {
+    let type_option = RelationTypeOptionPB {
+      database_id: "projects".to_owned(),
+    };
+    let cell_data = apply_changeset(
+      &type_option,
+      RelationCellChangeset::from_insert_row_ids(vec!["a".to_owned(), "b".to_owned()]),
+      None,
+    );
+    assert_eq!(cell_data.to_string(), "a,b");
+
+    // The linked rows are not duplicated.
+    let cell_data = apply_changeset(
+      &type_option,
+      RelationCellChangeset::from_insert_row_ids(vec!["b".to_owned(), "c".to_owned()]),
+      Some(cell_data.to_string()),
+    );
+    assert_eq!(cell_data.to_string(), "a,b,c");
+
+    let cell_data = apply_changeset(
+      &type_option,
+      RelationCellChangeset::from_remove_row_ids(vec!["a".to_owned(), "c".to_owned()]),
+      Some(cell_data.to_string()),
+    );
+    assert_eq!(cell_data.row_ids, vec!["b".to_owned()]);
+  }
+
+  #[test]
+  fn relation_filter_test() {
+    let empty_cell_data = RelationCellData::default();
+    let cell_data = RelationCellData::from(vec!["a".to_owned()]);
+
+    let filter = RelationFilterPB {
+      condition: RelationFilterConditionPB::IsEmpty,
+    };
+    assert!(filter.is_visible(&empty_cell_data));
+    assert!(!filter.is_visible(&cell_data));
+
+    let filter = RelationFilterPB {
+      condition: RelationFilterConditionPB::IsNotEmpty,
+    };
+    assert!(!filter.is_visible(&empty_cell_data));
+    assert!(filter.is_visible(&cell_data));
+  }
+}
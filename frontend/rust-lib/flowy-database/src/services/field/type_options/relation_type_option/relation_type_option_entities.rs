@@ -0,0 +1,192 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::{CellIdPB, CellIdParams};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
+  ToCellChangesetString,
+};
+use crate::services::field::SELECTION_IDS_SEPARATOR;
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, ErrorCode, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// The ids of the rows that the cell links to. The rows belong to the database that is
+/// specified by the [RelationTypeOptionPB].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RelationCellData {
+  pub row_ids: Vec<String>,
+}
+
+impl RelationCellData {
+  pub fn contains_any(&self, row_ids: &[String]) -> bool {
+    self.row_ids.iter().any(|row_id| row_ids.contains(row_id))
+  }
+}
+
+impl std::convert::From<Vec<String>> for RelationCellData {
+  fn from(row_ids: Vec<String>) -> Self {
+    let row_ids = row_ids
+      .into_iter()
+      .filter(|row_id| !row_id.is_empty())
+      .collect::<Vec<String>>();
+    Self { row_ids }
+  }
+}
+
+impl FromCellString for RelationCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    let row_ids = s
+      .split(SELECTION_IDS_SEPARATOR)
+      .map(|row_id| row_id.to_owned())
+      .collect::<Vec<String>>();
+    Ok(Self::from(row_ids))
+  }
+}
+
+impl ToString for RelationCellData {
+  /// Returns a string that consists list of row ids, placing a commas separator between each
+  fn to_string(&self) -> String {
+    self.row_ids.join(SELECTION_IDS_SEPARATOR)
+  }
+}
+
+impl DecodedCellData for RelationCellData {
+  type Object = RelationCellData;
+
+  fn is_empty(&self) -> bool {
+    self.row_ids.is_empty()
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationCellDataPB {
+  #[pb(index = 1)]
+  pub row_ids: Vec<String>,
+}
+
+impl From<RelationCellData> for RelationCellDataPB {
+  fn from(data: RelationCellData) -> Self {
+    Self {
+      row_ids: data.row_ids,
+    }
+  }
+}
+
+impl DecodedCellData for RelationCellDataPB {
+  type Object = RelationCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.row_ids.is_empty()
+  }
+}
+
+pub struct RelationCellDataParser();
+impl CellProtobufBlobParser for RelationCellDataParser {
+  type Object = RelationCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    RelationCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RelationCellChangeset {
+  pub inserted_row_ids: Vec<String>,
+  pub removed_row_ids: Vec<String>,
+}
+
+impl RelationCellChangeset {
+  pub fn from_insert_row_ids(row_ids: Vec<String>) -> Self {
+    RelationCellChangeset {
+      inserted_row_ids: row_ids,
+      removed_row_ids: vec![],
+    }
+  }
+
+  pub fn from_remove_row_ids(row_ids: Vec<String>) -> Self {
+    RelationCellChangeset {
+      inserted_row_ids: vec![],
+      removed_row_ids: row_ids,
+    }
+  }
+}
+
+impl FromCellChangesetString for RelationCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    serde_json::from_str::<RelationCellChangeset>(&changeset).map_err(internal_error)
+  }
+}
+
+impl ToCellChangesetString for RelationCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+/// [RelationCellChangesetPB] is used to link or unlink the rows of the target database.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationCellChangesetPB {
+  #[pb(index = 1)]
+  pub cell_identifier: CellIdPB,
+
+  #[pb(index = 2)]
+  pub inserted_row_ids: Vec<String>,
+
+  #[pb(index = 3)]
+  pub removed_row_ids: Vec<String>,
+}
+
+pub struct RelationCellChangesetParams {
+  pub cell_identifier: CellIdParams,
+  pub inserted_row_ids: Vec<String>,
+  pub removed_row_ids: Vec<String>,
+}
+
+impl TryInto<RelationCellChangesetParams> for RelationCellChangesetPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<RelationCellChangesetParams, Self::Error> {
+    let cell_identifier: CellIdParams = self.cell_identifier.try_into()?;
+    let parse_row_ids = |row_ids: Vec<String>| {
+      row_ids
+        .into_iter()
+        .map(|row_id| NotEmptyStr::parse(row_id).map(|row_id| row_id.0))
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|_| ErrorCode::RowIdIsEmpty)
+    };
+    Ok(RelationCellChangesetParams {
+      cell_identifier,
+      inserted_row_ids: parse_row_ids(self.inserted_row_ids)?,
+      removed_row_ids: parse_row_ids(self.removed_row_ids)?,
+    })
+  }
+}
+
+/// A row of the target database that is linked by the relation cell. The name is the content of
+/// the row's primary field.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelatedRowPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedRelatedRowPB {
+  #[pb(index = 1)]
+  pub items: Vec<RelatedRowPB>,
+}
+
+impl std::convert::From<Vec<RelatedRowPB>> for RepeatedRelatedRowPB {
+  fn from(items: Vec<RelatedRowPB>) -> Self {
+    Self { items }
+  }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{
+    insert_checkbox_cell, insert_date_cell, insert_number_cell, CellDataChangeset, CellDataDecoder,
+  };
+  use crate::services::field::{
+    CheckboxTypeOptionBuilder, DateCellData, DateTypeOptionBuilder, FieldBuilder,
+    NumberTypeOptionBuilder, RollupCalculationPB, RollupCellData, RollupTypeOptionPB,
+    TypeOptionCellDataFilter,
+  };
+  use database_model::{CellRevision, FieldRevision};
+
+  fn rollup(calculation: RollupCalculationPB) -> RollupTypeOptionPB {
+    RollupTypeOptionPB {
+      relation_field_id: "relation".to_owned(),
+      target_field_id: "target".to_owned(),
+      calculation,
+    }
+  }
+
+  fn calculate_to_str(
+    calculation: RollupCalculationPB,
+    field_rev: &FieldRevision,
+    cell_revs: &[Option<CellRevision>],
+  ) -> String {
+    let type_option = rollup(calculation);
+    let cell_data = type_option.calculate(field_rev, cell_revs);
+    type_option.decode_cell_data_to_str(cell_data)
+  }
+
+  #[test]
+  fn rollup_number_test() {
+    let field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default())
+      .name("Price")
+      .build();
+    let cell_revs = vec![
+      Some(insert_number_cell(3, &field_rev)),
+      Some(insert_number_cell(1.5, &field_rev)),
+      // The empty cell is counted but not aggregated.
+      None,
+      Some(insert_number_cell(6, &field_rev)),
+    ];
+
+    let assert_calculation = |calculation: RollupCalculationPB, expected: &str| {
+      assert_eq!(
+        calculate_to_str(calculation, &field_rev, &cell_revs),
+        expected
+      );
+    };
+    assert_calculation(RollupCalculationPB::Count, "4");
+    assert_calculation(RollupCalculationPB::Sum, "10.5");
+    assert_calculation(RollupCalculationPB::Average, "3.5");
+    assert_calculation(RollupCalculationPB::Min, "1.5");
+    assert_calculation(RollupCalculationPB::Max, "6");
+    // The number cells can't be aggregated as dates.
+    assert_calculation(RollupCalculationPB::LatestDate, "");
+  }
+
+  #[test]
+  fn rollup_without_linked_rows_test() {
+    let field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default()).build();
+    assert_eq!(
+      calculate_to_str(RollupCalculationPB::Count, &field_rev, &[]),
+      "0"
+    );
+    assert_eq!(
+      calculate_to_str(RollupCalculationPB::Sum, &field_rev, &[]),
+      ""
+    );
+    assert_eq!(
+      calculate_to_str(RollupCalculationPB::PercentChecked, &field_rev, &[]),
+      ""
+    );
+  }
+
+  #[test]
+  fn rollup_date_test() {
+    let field_rev = FieldBuilder::new(DateTypeOptionBuilder::default())
+      .name("Due")
+      .build();
+    let insert_date = |timestamp: i64| {
+      insert_date_cell(
+        DateCellData {
+          timestamp: Some(timestamp),
+          include_time: false,
+          overrides: vec![],
+          end_timestamp: None,
+          timezone_id: "".to_string(),
+        },
+        &field_rev,
+      )
+    };
+    // Mar 14, 2022 and Apr 15, 2022
+    let cell_revs = vec![Some(insert_date(1650016562)), Some(insert_date(1647251762))];
+    assert_eq!(
+      calculate_to_str(RollupCalculationPB::EarliestDate, &field_rev, &cell_revs),
+      "Mar 14, 2022"
+    );
+    assert_eq!(
+      calculate_to_str(RollupCalculationPB::LatestDate, &field_rev, &cell_revs),
+      "Apr 15, 2022"
+    );
+  }
+
+  #[test]
+  fn rollup_percent_checked_test() {
+    let field_rev = FieldBuilder::new(CheckboxTypeOptionBuilder::default())
+      .name("Done")
+      .build();
+    let cell_revs = vec![
+      Some(insert_checkbox_cell(true, &field_rev)),
+      Some(insert_checkbox_cell(false, &field_rev)),
+      None,
+    ];
+    assert_eq!(
+      calculate_to_str(RollupCalculationPB::PercentChecked, &field_rev, &cell_revs),
+      "33.33%"
+    );
+  }
+
+  #[test]
+  fn rollup_cell_is_read_only_test() {
+    let type_option = rollup(RollupCalculationPB::Sum);
+    assert!(type_option.apply_changeset("1".to_owned(), None).is_err());
+
+    let (cell_str, cell_data) = type_option
+      .apply_changeset(RollupCellData::new(2.0).to_string(), None)
+      .unwrap();
+    assert_eq!(cell_data, RollupCellData::new(2.0));
+    assert_eq!(cell_str, RollupCellData::new(2.0).to_string());
+  }
+
+  #[test]
+  fn rollup_filter_test() {
+    let type_option = rollup(RollupCalculationPB::Sum);
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "10".to_owned(),
+    };
+    let field_type = FieldType::Rollup;
+    assert!(type_option.apply_filter(&filter, &field_type, &RollupCellData::new(10.5)));
+    assert!(!type_option.apply_filter(&filter, &field_type, &RollupCellData::new(3.0)));
+    assert!(!type_option.apply_filter(&filter, &field_type, &RollupCellData::default()));
+  }
+}
@@ -0,0 +1,288 @@
+use crate::entities::{FieldType, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  default_order, AutoIncrementIdCellData, BoxTypeOptionBuilder, CheckboxCellData, DateCellData,
+  FormulaCellData, NumberTypeOptionPB, RollupCalculationPB, RollupCellData, RollupCellDataPB,
+  TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use chrono::NaiveDateTime;
+use database_model::{
+  CellRevision, FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer,
+};
+use flowy_derive::ProtoBuf;
+use flowy_error::{FlowyError, FlowyResult};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct RollupTypeOptionBuilder(RollupTypeOptionPB);
+impl_into_box_type_option_builder!(RollupTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(RollupTypeOptionBuilder, RollupTypeOptionPB);
+
+impl RollupTypeOptionBuilder {
+  pub fn relation_field_id(mut self, relation_field_id: &str) -> Self {
+    self.0.relation_field_id = relation_field_id.to_owned();
+    self
+  }
+
+  pub fn target_field_id(mut self, target_field_id: &str) -> Self {
+    self.0.target_field_id = target_field_id.to_owned();
+    self
+  }
+
+  pub fn calculation(mut self, calculation: RollupCalculationPB) -> Self {
+    self.0.calculation = calculation;
+    self
+  }
+}
+
+impl TypeOptionBuilder for RollupTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Rollup
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct RollupTypeOptionPB {
+  /// The relation field of the same database. The rows linked by its cells are aggregated.
+  #[pb(index = 1)]
+  pub relation_field_id: String,
+
+  /// The field of the related database whose values are aggregated.
+  #[pb(index = 2)]
+  pub target_field_id: String,
+
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub calculation: RollupCalculationPB,
+}
+impl_type_option!(RollupTypeOptionPB, FieldType::Rollup);
+
+impl RollupTypeOptionPB {
+  /// Aggregates the cells of the target field. Each item of the `cell_revs` is the cell of one
+  /// linked row.
+  pub fn calculate(
+    &self,
+    target_field_rev: &FieldRevision,
+    cell_revs: &[Option<CellRevision>],
+  ) -> RollupCellData {
+    let values = cell_revs
+      .iter()
+      .map(|cell_rev| RollupValue::from_cell(target_field_rev, cell_rev.as_ref()))
+      .collect::<Vec<RollupValue>>();
+    let numbers = values
+      .iter()
+      .filter_map(|value| match value {
+        RollupValue::Number(num) => Some(*num),
+        _ => None,
+      })
+      .collect::<Vec<f64>>();
+    let dates = values
+      .iter()
+      .filter_map(|value| match value {
+        RollupValue::Date(timestamp) => Some(*timestamp),
+        _ => None,
+      })
+      .collect::<Vec<i64>>();
+
+    let value = match self.calculation {
+      RollupCalculationPB::Count => Some(cell_revs.len() as f64),
+      RollupCalculationPB::Sum if !numbers.is_empty() => Some(numbers.iter().sum()),
+      RollupCalculationPB::Average if !numbers.is_empty() => {
+        Some(numbers.iter().sum::<f64>() / numbers.len() as f64)
+      },
+      RollupCalculationPB::Min => numbers.into_iter().reduce(f64::min),
+      RollupCalculationPB::Max => numbers.into_iter().reduce(f64::max),
+      RollupCalculationPB::EarliestDate => dates.into_iter().min().map(|date| date as f64),
+      RollupCalculationPB::LatestDate => dates.into_iter().max().map(|date| date as f64),
+      RollupCalculationPB::PercentChecked if !values.is_empty() => {
+        let checked = values
+          .iter()
+          .filter(|value| matches!(value, RollupValue::Checked(true)))
+          .count();
+        Some(checked as f64 * 100.0 / values.len() as f64)
+      },
+      _ => None,
+    };
+    RollupCellData { value }
+  }
+}
+
+/// The value of the linked row's cell that can be aggregated.
+enum RollupValue {
+  Empty,
+  Number(f64),
+  Date(i64),
+  Checked(bool),
+}
+
+impl RollupValue {
+  fn from_cell(field_rev: &FieldRevision, cell_rev: Option<&CellRevision>) -> Self {
+    let field_type: FieldType = field_rev.ty.into();
+    // The cell that is not decoded by the current field type is treated as empty.
+    let cell_str = match cell_rev
+      .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+      .filter(|type_cell_data| type_cell_data.field_type == field_type)
+    {
+      None => return RollupValue::Empty,
+      Some(type_cell_data) => type_cell_data.cell_str,
+    };
+
+    match field_type {
+      FieldType::Number => NumberTypeOptionPB::from(field_rev)
+        .format_cell_data(&cell_str)
+        .ok()
+        .and_then(|cell_data| cell_data.decimal().and_then(|decimal| decimal.to_f64()))
+        .map(RollupValue::Number)
+        .unwrap_or(RollupValue::Empty),
+      FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+        DateCellData::from_cell_str(&cell_str)
+          .ok()
+          .and_then(|cell_data| cell_data.timestamp)
+          .map(RollupValue::Date)
+          .unwrap_or(RollupValue::Empty)
+      },
+      FieldType::AutoIncrementId => AutoIncrementIdCellData::from_cell_str(&cell_str)
+        .ok()
+        .and_then(|cell_data| cell_data.0)
+        .map(|id| RollupValue::Number(id as f64))
+        .unwrap_or(RollupValue::Empty),
+      FieldType::Checkbox => RollupValue::Checked(
+        CheckboxCellData::from_cell_str(&cell_str)
+          .map(|cell_data| cell_data.is_check())
+          .unwrap_or(false),
+      ),
+      FieldType::Formula => match FormulaCellData::from_cell_str(&cell_str) {
+        Ok(FormulaCellData::Number(num)) => RollupValue::Number(num),
+        Ok(FormulaCellData::Date(timestamp)) => RollupValue::Date(timestamp),
+        Ok(FormulaCellData::Bool(is_check)) => RollupValue::Checked(is_check),
+        _ => RollupValue::Empty,
+      },
+      FieldType::Rollup => {
+        let is_date = RollupTypeOptionPB::from(field_rev).calculation.is_date();
+        match RollupCellData::from_cell_str(&cell_str).map(|cell_data| cell_data.value) {
+          Ok(Some(value)) if is_date => RollupValue::Date(value as i64),
+          Ok(Some(value)) => RollupValue::Number(value),
+          _ => RollupValue::Empty,
+        }
+      },
+      _ => RollupValue::Empty,
+    }
+  }
+}
+
+impl TypeOption for RollupTypeOptionPB {
+  type CellData = RollupCellData;
+  type CellChangeset = RollupCellChangeset;
+  type CellProtobufType = RollupCellDataPB;
+  type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for RollupTypeOptionPB {}
+
+impl TypeOptionCellData for RollupTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    RollupCellDataPB {
+      content: self.decode_cell_data_to_str(cell_data),
+    }
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    RollupCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for RollupTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_rollup() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    let value = match cell_data.value {
+      None => return "".to_owned(),
+      Some(value) => value,
+    };
+
+    match self.calculation {
+      RollupCalculationPB::EarliestDate | RollupCalculationPB::LatestDate => {
+        NaiveDateTime::from_timestamp_opt(value as i64, 0)
+          .map(|date_time| date_time.format("%b %d, %Y").to_string())
+          .unwrap_or_default()
+      },
+      RollupCalculationPB::PercentChecked => format!("{}%", (value * 100.0).round() / 100.0),
+      // Rounds the number to hide the error of the floating-point arithmetic.
+      _ => format!("{}", (value * 1e10).round() / 1e10),
+    }
+  }
+}
+
+/// The calculated [RollupCellData] in JSON. The rollup cells are calculated from the linked rows,
+/// so any other changeset is rejected.
+pub type RollupCellChangeset = String;
+
+impl CellDataChangeset for RollupTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = serde_json::from_str::<RollupCellData>(&changeset).map_err(|_| {
+      FlowyError::invalid_data().context("The rollup cell is calculated from the related rows")
+    })?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for RollupTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_rollup() {
+      return true;
+    }
+
+    filter.is_visible(&cell_data.to_number_cell_data())
+  }
+}
+
+impl TypeOptionCellDataCompare for RollupTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data.value, other_cell_data.value) {
+      (Some(left), Some(right)) => left.partial_cmp(&right).unwrap_or_else(default_order),
+      // The cells without a value are placed after the others.
+      (Some(_), None) => Ordering::Less,
+      (None, Some(_)) => Ordering::Greater,
+      (None, None) => default_order(),
+    }
+  }
+}
@@ -0,0 +1,109 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use crate::services::field::NumberCellData;
+use bytes::Bytes;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{internal_error, FlowyResult};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// The calculations that aggregate the values of the linked rows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum RollupCalculationPB {
+  /// The number of the linked rows.
+  Count = 0,
+  Sum = 1,
+  Average = 2,
+  Min = 3,
+  Max = 4,
+  EarliestDate = 5,
+  LatestDate = 6,
+  /// The percentage of the linked rows whose checkbox is checked.
+  PercentChecked = 7,
+}
+
+impl std::default::Default for RollupCalculationPB {
+  fn default() -> Self {
+    RollupCalculationPB::Count
+  }
+}
+
+impl RollupCalculationPB {
+  pub fn is_date(&self) -> bool {
+    matches!(
+      self,
+      RollupCalculationPB::EarliestDate | RollupCalculationPB::LatestDate
+    )
+  }
+}
+
+/// The calculated value of the rollup cell. The value is the timestamp if the calculation
+/// returns a date. It's None if there is nothing to aggregate.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RollupCellData {
+  pub value: Option<f64>,
+}
+
+impl RollupCellData {
+  pub fn new(value: f64) -> Self {
+    Self { value: Some(value) }
+  }
+
+  pub fn to_number_cell_data(&self) -> NumberCellData {
+    self
+      .value
+      .and_then(Decimal::from_f64)
+      .map(|decimal| NumberCellData::from_decimal(decimal.normalize()))
+      .unwrap_or_default()
+  }
+}
+
+impl FromCellString for RollupCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    if s.is_empty() {
+      return Ok(Self::default());
+    }
+    serde_json::from_str::<RollupCellData>(s).map_err(internal_error)
+  }
+}
+
+impl ToString for RollupCellData {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+impl DecodedCellData for RollupCellData {
+  type Object = RollupCellData;
+
+  fn is_empty(&self) -> bool {
+    self.value.is_none()
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RollupCellDataPB {
+  /// The calculated value that is formatted by the calculation of the rollup.
+  #[pb(index = 1)]
+  pub content: String,
+}
+
+impl DecodedCellData for RollupCellDataPB {
+  type Object = RollupCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.content.is_empty()
+  }
+}
+
+pub struct RollupCellDataParser();
+impl CellProtobufBlobParser for RollupCellDataParser {
+  type Object = RollupCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    RollupCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
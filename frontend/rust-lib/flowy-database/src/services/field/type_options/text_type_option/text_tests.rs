@@ -26,6 +26,9 @@ mod tests {
     let data = DateCellData {
       timestamp: Some(1647251762),
       include_time: true,
+      overrides: vec![],
+      end_timestamp: None,
+      timezone_id: "".to_string(),
     };
 
     assert_eq!(
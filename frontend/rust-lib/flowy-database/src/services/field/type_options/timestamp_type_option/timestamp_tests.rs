@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{DateFilterConditionPB, DateFilterPB, FieldType};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::field::{
+    FieldBuilder, TimestampTypeOptionPB, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  };
+  use std::cmp::Ordering;
+
+  #[test]
+  fn timestamp_type_option_decode_test() {
+    let type_option = TimestampTypeOptionPB::default();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::CreatedTime).build();
+    let cell_data = type_option
+      .decode_cell_str("1647251762".to_owned(), &FieldType::CreatedTime, &field_rev)
+      .unwrap();
+    assert_eq!(cell_data.timestamp, Some(1647251762));
+    assert_eq!(
+      type_option.decode_cell_data_to_str(cell_data),
+      "Mar 14, 2022"
+    );
+
+    // The cells of the other field types are not displayed.
+    let cell_data = type_option
+      .decode_cell_str("1647251762".to_owned(), &FieldType::Number, &field_rev)
+      .unwrap();
+    assert_eq!(cell_data.timestamp, None);
+  }
+
+  #[test]
+  fn timestamp_cell_is_read_only_test() {
+    let type_option = TimestampTypeOptionPB::default();
+    assert!(type_option
+      .apply_changeset("1647251762".to_owned(), None)
+      .is_err());
+  }
+
+  #[test]
+  fn timestamp_filter_and_sort_test() {
+    let type_option = TimestampTypeOptionPB::default();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::LastEditedTime).build();
+    let decode = |cell_str: &str| {
+      type_option
+        .decode_cell_str(cell_str.to_owned(), &FieldType::LastEditedTime, &field_rev)
+        .unwrap()
+    };
+    let earlier = decode("1647251762");
+    let later = decode("1650016562");
+
+    let filter = DateFilterPB {
+      condition: DateFilterConditionPB::DateAfter,
+      timestamp: Some(1647251762),
+      ..Default::default()
+    };
+    let field_type = FieldType::LastEditedTime;
+    assert!(type_option.apply_filter(&filter, &field_type, &later));
+    assert!(!type_option.apply_filter(&filter, &field_type, &earlier));
+    assert_eq!(type_option.apply_cmp(&earlier, &later), Ordering::Less);
+  }
+}
@@ -0,0 +1,180 @@
+use crate::entities::{DateFilterPB, FieldType};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  default_order, BoxTypeOptionBuilder, DateCellData, DateCellDataPB, DateFormat, DateTypeOptionPB,
+  TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// The type option of the [FieldType::CreatedTime] and the [FieldType::LastEditedTime] fields.
+/// Their cells keep the timestamps of the row, so they are formatted like the date cells but
+/// can't be edited.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct TimestampTypeOptionPB {
+  #[pb(index = 1)]
+  pub date_format: DateFormat,
+
+  #[pb(index = 2)]
+  pub time_format: TimeFormat,
+
+  #[pb(index = 3)]
+  pub include_time: bool,
+}
+impl_type_option!(TimestampTypeOptionPB);
+
+impl TimestampTypeOptionPB {
+  fn date_type_option(&self) -> DateTypeOptionPB {
+    DateTypeOptionPB {
+      date_format: self.date_format,
+      time_format: self.time_format,
+      include_time: self.include_time,
+      recurrence: None,
+      timezone_id: "".to_string(),
+    }
+  }
+}
+
+impl TypeOption for TimestampTypeOptionPB {
+  type CellData = DateCellData;
+  type CellChangeset = TimestampCellChangeset;
+  type CellProtobufType = DateCellDataPB;
+  type CellFilter = DateFilterPB;
+}
+
+impl TypeOptionTransform for TimestampTypeOptionPB {}
+
+impl TypeOptionCellData for TimestampTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    self.date_type_option().convert_to_protobuf(cell_data)
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    let mut cell_data = DateCellData::from_cell_str(&cell_str)?;
+    cell_data.include_time = self.include_time;
+    Ok(cell_data)
+  }
+}
+
+impl CellDataDecoder for TimestampTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_timestamp() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    let cell_data = self.convert_to_protobuf(cell_data);
+    if cell_data.include_time && !cell_data.time.is_empty() {
+      format!("{} {}", cell_data.date, cell_data.time)
+    } else {
+      cell_data.date
+    }
+  }
+}
+
+/// The timestamp cells are maintained by the database editor, so any changeset is rejected.
+pub type TimestampCellChangeset = String;
+
+impl CellDataChangeset for TimestampTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    Err(FlowyError::invalid_data().context("The timestamp cell is maintained by the database"))
+  }
+}
+
+impl TypeOptionCellDataFilter for TimestampTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_timestamp() {
+      return true;
+    }
+
+    filter.is_visible(cell_data.timestamp)
+  }
+}
+
+impl TypeOptionCellDataCompare for TimestampTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data.timestamp, other_cell_data.timestamp) {
+      (Some(left), Some(right)) => left.cmp(&right),
+      (Some(_), None) => Ordering::Greater,
+      (None, Some(_)) => Ordering::Less,
+      (None, None) => default_order(),
+    }
+  }
+}
+
+#[derive(Default)]
+pub struct CreatedTimeTypeOptionBuilder(TimestampTypeOptionPB);
+impl_into_box_type_option_builder!(CreatedTimeTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(CreatedTimeTypeOptionBuilder, TimestampTypeOptionPB);
+
+impl CreatedTimeTypeOptionBuilder {
+  pub fn include_time(mut self, include_time: bool) -> Self {
+    self.0.include_time = include_time;
+    self
+  }
+}
+
+impl TypeOptionBuilder for CreatedTimeTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::CreatedTime
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+#[derive(Default)]
+pub struct LastEditedTimeTypeOptionBuilder(TimestampTypeOptionPB);
+impl_into_box_type_option_builder!(LastEditedTimeTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(LastEditedTimeTypeOptionBuilder, TimestampTypeOptionPB);
+
+impl LastEditedTimeTypeOptionBuilder {
+  pub fn include_time(mut self, include_time: bool) -> Self {
+    self.0.include_time = include_time;
+    self
+  }
+}
+
+impl TypeOptionBuilder for LastEditedTimeTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::LastEditedTime
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
@@ -4,10 +4,11 @@ use crate::services::cell::{
   FromCellChangesetString, FromCellString, TypeCellData,
 };
 use crate::services::field::{
-  CheckboxTypeOptionPB, ChecklistTypeOptionPB, DateTypeOptionPB, MultiSelectTypeOptionPB,
-  NumberTypeOptionPB, RichTextTypeOptionPB, SingleSelectTypeOptionPB, TypeOption,
-  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
-  URLTypeOptionPB,
+  AutoIncrementIdTypeOptionPB, CheckboxTypeOptionPB, ChecklistTypeOptionPB, DateTypeOptionPB,
+  FormulaTypeOptionPB, MultiSelectTypeOptionPB, NumberTypeOptionPB, RelationTypeOptionPB,
+  RichTextTypeOptionPB, RollupTypeOptionPB, SingleSelectTypeOptionPB, TimestampTypeOptionPB,
+  TypeOption, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform, URLTypeOptionPB,
 };
 use crate::services::filter::FilterType;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
@@ -47,6 +48,7 @@ pub trait TypeOptionCellDataHandler {
 
   fn handle_cell_filter(
     &self,
+    filter_id: &str,
     filter_type: &FilterType,
     field_rev: &FieldRevision,
     type_cell_data: TypeCellData,
@@ -249,13 +251,15 @@ where
 
   fn handle_cell_filter(
     &self,
+    filter_id: &str,
     filter_type: &FilterType,
     field_rev: &FieldRevision,
     type_cell_data: TypeCellData,
   ) -> bool {
     let perform_filter = || {
       let filter_cache = self.cell_filter_cache.as_ref()?.read();
-      let cell_filter = filter_cache.get::<<Self as TypeOption>::CellFilter>(filter_type)?;
+      let cell_filter =
+        filter_cache.get::<<Self as TypeOption>::CellFilter>(&filter_id.to_owned())?;
       let cell_data = self
         .get_decoded_cell_data(type_cell_data.cell_str, &filter_type.field_type, field_rev)
         .ok()?;
@@ -425,6 +429,56 @@ impl<'a> TypeOptionCellExt<'a> {
             self.cell_data_cache.clone(),
           )
         }),
+      FieldType::Formula => self
+        .field_rev
+        .get_type_option::<FormulaTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Relation => self
+        .field_rev
+        .get_type_option::<RelationTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Rollup => self
+        .field_rev
+        .get_type_option::<RollupTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::CreatedTime | FieldType::LastEditedTime => self
+        .field_rev
+        .get_type_option::<TimestampTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::AutoIncrementId => self
+        .field_rev
+        .get_type_option::<AutoIncrementIdTypeOptionPB>(field_type.into())
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
     }
   }
 }
@@ -484,6 +538,20 @@ fn get_type_option_transform_handler(
       as Box<dyn TypeOptionTransformHandler>,
     FieldType::Checklist => Box::new(ChecklistTypeOptionPB::from_json_str(type_option_data))
       as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Formula => Box::new(FormulaTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Relation => Box::new(RelationTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::Rollup => Box::new(RollupTypeOptionPB::from_json_str(type_option_data))
+      as Box<dyn TypeOptionTransformHandler>,
+    FieldType::CreatedTime | FieldType::LastEditedTime => {
+      Box::new(TimestampTypeOptionPB::from_json_str(type_option_data))
+        as Box<dyn TypeOptionTransformHandler>
+    },
+    FieldType::AutoIncrementId => {
+      Box::new(AutoIncrementIdTypeOptionPB::from_json_str(type_option_data))
+        as Box<dyn TypeOptionTransformHandler>
+    },
   }
 }
 
@@ -568,4 +636,12 @@ impl RowSingleCellData {
     into_check_list_field_cell_data,
     <CheckboxTypeOptionPB as TypeOption>::CellData
   );
+  into_cell_data!(
+    into_relation_field_cell_data,
+    <RelationTypeOptionPB as TypeOption>::CellData
+  );
+  into_cell_data!(
+    into_auto_increment_id_field_cell_data,
+    <AutoIncrementIdTypeOptionPB as TypeOption>::CellData
+  );
 }
@@ -6,11 +6,13 @@ use crate::services::cell::{
 use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
 use crate::services::field::*;
 use crate::services::filter::{
-  FilterChangeset, FilterResult, FilterResultNotification, FilterType,
+  FilterChangeset, FilterContext, FilterGroup, FilterResult, FilterResultNotification, FilterType,
 };
 use crate::services::row::DatabaseBlockRowRevision;
 use dashmap::DashMap;
-use database_model::{CellRevision, FieldId, FieldRevision, FilterRevision, RowRevision};
+use database_model::{
+  CellRevision, FieldId, FieldRevision, FilterGroupRevision, FilterRevision, RowRevision,
+};
 use flowy_error::FlowyResult;
 use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
 use lib_infra::future::Fut;
@@ -22,7 +24,10 @@ use tokio::sync::RwLock;
 
 type RowId = String;
 pub trait FilterDelegate: Send + Sync + 'static {
-  fn get_filter_rev(&self, filter_type: FilterType) -> Fut<Option<Arc<FilterRevision>>>;
+  fn get_filter_rev(&self, filter_ctx: FilterContext) -> Fut<Option<Arc<FilterRevision>>>;
+  fn get_filter_revs_of_type(&self, filter_type: FilterType) -> Fut<Vec<Arc<FilterRevision>>>;
+  fn get_filter_revs(&self) -> Fut<Vec<Arc<FilterRevision>>>;
+  fn get_filter_group_rev(&self) -> Fut<FilterGroupRevision>;
   fn get_field_rev(&self, field_id: &str) -> Fut<Option<Arc<FieldRevision>>>;
   fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> Fut<Vec<Arc<FieldRevision>>>;
   fn get_blocks(&self) -> Fut<Vec<DatabaseBlockRowRevision>>;
@@ -40,6 +45,7 @@ pub struct FilterController {
   handler_id: String,
   delegate: Box<dyn FilterDelegate>,
   result_by_row_id: DashMap<RowId, FilterResult>,
+  filter_group: RwLock<FilterGroup>,
   cell_data_cache: AtomicCellDataCache,
   cell_filter_cache: AtomicCellFilterCache,
   task_scheduler: Arc<RwLock<TaskDispatcher>>,
@@ -70,12 +76,14 @@ impl FilterController {
       handler_id: handler_id.to_string(),
       delegate: Box::new(delegate),
       result_by_row_id: DashMap::default(),
+      filter_group: RwLock::new(FilterGroup::default()),
       cell_data_cache,
-      cell_filter_cache: AnyTypeCache::<FilterType>::new(),
+      cell_filter_cache: AnyTypeCache::<String>::new(),
       task_scheduler,
       notifier,
     };
     this.refresh_filters(filter_revs).await;
+    this.refresh_filter_group().await;
     this
   }
 
@@ -104,11 +112,15 @@ impl FilterController {
       return;
     }
     let field_rev_by_field_id = self.get_filter_revs_map().await;
+    let filter_revs = self.delegate.get_filter_revs().await;
+    let filter_group = self.filter_group.read().await.clone();
     row_revs.iter().for_each(|row_rev| {
       let _ = filter_row(
         row_rev,
         &self.result_by_row_id,
         &field_rev_by_field_id,
+        &filter_revs,
+        &filter_group,
         &self.cell_data_cache,
         &self.cell_filter_cache,
       );
@@ -118,7 +130,7 @@ impl FilterController {
       self
         .result_by_row_id
         .get(&row_rev.id)
-        .map(|result| result.is_visible())
+        .map(|result| result.is_visible)
         .unwrap_or(false)
     });
   }
@@ -152,12 +164,16 @@ impl FilterController {
   async fn filter_row(&self, row_id: String) -> FlowyResult<()> {
     if let Some((_, row_rev)) = self.delegate.get_row_rev(&row_id).await {
       let field_rev_by_field_id = self.get_filter_revs_map().await;
+      let filter_revs = self.delegate.get_filter_revs().await;
+      let filter_group = self.filter_group.read().await.clone();
       let mut notification =
         FilterResultNotification::new(self.view_id.clone(), row_rev.block_id.clone());
       if let Some((row_id, is_visible)) = filter_row(
         &row_rev,
         &self.result_by_row_id,
         &field_rev_by_field_id,
+        &filter_revs,
+        &filter_group,
         &self.cell_data_cache,
         &self.cell_filter_cache,
       ) {
@@ -182,6 +198,8 @@ impl FilterController {
 
   async fn filter_all_rows(&self) -> FlowyResult<()> {
     let field_rev_by_field_id = self.get_filter_revs_map().await;
+    let filter_revs = self.delegate.get_filter_revs().await;
+    let filter_group = self.filter_group.read().await.clone();
     for block in self.delegate.get_blocks().await.into_iter() {
       // The row_ids contains the row that its visibility was changed.
       let mut visible_rows = vec![];
@@ -192,6 +210,8 @@ impl FilterController {
           row_rev,
           &self.result_by_row_id,
           &field_rev_by_field_id,
+          &filter_revs,
+          &filter_group,
           &self.cell_data_cache,
           &self.cell_filter_cache,
         ) {
@@ -233,60 +253,82 @@ impl FilterController {
     changeset: FilterChangeset,
   ) -> Option<FilterChangesetNotificationPB> {
     let mut notification: Option<FilterChangesetNotificationPB> = None;
-    if let Some(filter_type) = &changeset.insert_filter {
-      if let Some(filter) = self.filter_from_filter_type(filter_type).await {
+    if let Some(filter_ctx) = changeset.insert_filter {
+      if let Some(filter_rev) = self.delegate.get_filter_rev(filter_ctx).await {
         notification = Some(FilterChangesetNotificationPB::from_insert(
           &self.view_id,
-          vec![filter],
+          vec![FilterPB::from(filter_rev.as_ref())],
         ));
-      }
-      if let Some(filter_rev) = self.delegate.get_filter_rev(filter_type.clone()).await {
         self.refresh_filters(vec![filter_rev]).await;
       }
     }
 
     if let Some(updated_filter_type) = changeset.update_filter {
       if let Some(old_filter_type) = updated_filter_type.old {
-        let new_filter = self.filter_from_filter_type(&updated_filter_type.new).await;
-        let old_filter = self.filter_from_filter_type(&old_filter_type).await;
-
-        // Get the filter id
-        let mut filter_id = old_filter.map(|filter| filter.id);
-        if filter_id.is_none() {
-          filter_id = new_filter.as_ref().map(|filter| filter.id.clone());
+        let new_filter_revs = match updated_filter_type.filter_id {
+          Some(filter_id) => self
+            .delegate
+            .get_filter_rev(FilterContext::new(&filter_id, updated_filter_type.new))
+            .await
+            .into_iter()
+            .collect::<Vec<_>>(),
+          None => {
+            self
+              .delegate
+              .get_filter_revs_of_type(updated_filter_type.new)
+              .await
+          },
+        };
+        let old_filter_revs = self.delegate.get_filter_revs_of_type(old_filter_type).await;
+
+        // The filters of the old field type are replaced by the filters of the new field type
+        // if the field type was changed.
+        let mut updated_filters = old_filter_revs
+          .iter()
+          .map(|old_filter_rev| UpdatedFilter {
+            filter_id: old_filter_rev.id.clone(),
+            filter: new_filter_revs
+              .iter()
+              .find(|new_filter_rev| new_filter_rev.id == old_filter_rev.id)
+              .map(|new_filter_rev| FilterPB::from(new_filter_rev.as_ref())),
+          })
+          .collect::<Vec<_>>();
+        for new_filter_rev in new_filter_revs.iter() {
+          if updated_filters
+            .iter()
+            .all(|updated_filter| updated_filter.filter_id != new_filter_rev.id)
+          {
+            updated_filters.push(UpdatedFilter {
+              filter_id: new_filter_rev.id.clone(),
+              filter: Some(FilterPB::from(new_filter_rev.as_ref())),
+            });
+          }
         }
 
-        // Update the corresponding filter in the cache
-        if let Some(filter_rev) = self
-          .delegate
-          .get_filter_rev(updated_filter_type.new.clone())
-          .await
-        {
-          self.refresh_filters(vec![filter_rev]).await;
-        }
+        // Update the corresponding filters in the cache
+        self.refresh_filters(new_filter_revs).await;
 
-        if let Some(filter_id) = filter_id {
+        if !updated_filters.is_empty() {
           notification = Some(FilterChangesetNotificationPB::from_update(
             &self.view_id,
-            vec![UpdatedFilter {
-              filter_id,
-              filter: new_filter,
-            }],
+            updated_filters,
           ));
         }
       }
     }
 
-    if let Some(filter_type) = &changeset.delete_filter {
-      if let Some(filter) = self.filter_from_filter_type(filter_type).await {
+    if let Some(filter_ctx) = changeset.delete_filter {
+      let filter_id = filter_ctx.filter_id.clone();
+      if let Some(filter_rev) = self.delegate.get_filter_rev(filter_ctx).await {
         notification = Some(FilterChangesetNotificationPB::from_delete(
           &self.view_id,
-          vec![filter],
+          vec![FilterPB::from(filter_rev.as_ref())],
         ));
       }
-      self.cell_filter_cache.write().remove(filter_type);
+      self.cell_filter_cache.write().remove(&filter_id);
     }
 
+    self.refresh_filter_group().await;
     self
       .gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
       .await;
@@ -294,12 +336,27 @@ impl FilterController {
     notification
   }
 
-  async fn filter_from_filter_type(&self, filter_type: &FilterType) -> Option<FilterPB> {
+  /// Rebuilds the filter group tree and filters all the rows again.
+  #[tracing::instrument(level = "trace", skip(self))]
+  pub async fn did_receive_filter_group_changes(&self) {
+    self.refresh_filter_group().await;
     self
-      .delegate
-      .get_filter_rev(filter_type.clone())
-      .await
-      .map(|filter| FilterPB::from(filter.as_ref()))
+      .gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
+      .await;
+  }
+
+  /// Reads all the filters of the view again, for example, after the view is restored to a
+  /// checkpoint, and filters all the rows again.
+  pub async fn did_restore_filters(&self) {
+    let filter_revs = self.delegate.get_filter_revs().await;
+    self.refresh_filters(filter_revs).await;
+    self.did_receive_filter_group_changes().await;
+  }
+
+  async fn refresh_filter_group(&self) {
+    let filter_revs = self.delegate.get_filter_revs().await;
+    let group_rev = self.delegate.get_filter_group_rev().await;
+    *self.filter_group.write().await = FilterGroup::new(&group_rev, &filter_revs);
   }
 
   #[tracing::instrument(level = "trace", skip_all)]
@@ -311,46 +368,58 @@ impl FilterController {
         match &filter_type.field_type {
           FieldType::RichText => {
             self.cell_filter_cache.write().insert(
-              &filter_type,
+              &filter_rev.id,
               TextFilterPB::from_filter_rev(filter_rev.as_ref()),
             );
           },
-          FieldType::Number => {
+          FieldType::Number | FieldType::Rollup | FieldType::AutoIncrementId => {
             self.cell_filter_cache.write().insert(
-              &filter_type,
+              &filter_rev.id,
               NumberFilterPB::from_filter_rev(filter_rev.as_ref()),
             );
           },
-          FieldType::DateTime => {
+          FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
             self.cell_filter_cache.write().insert(
-              &filter_type,
+              &filter_rev.id,
               DateFilterPB::from_filter_rev(filter_rev.as_ref()),
             );
           },
           FieldType::SingleSelect | FieldType::MultiSelect => {
             self.cell_filter_cache.write().insert(
-              &filter_type,
+              &filter_rev.id,
               SelectOptionFilterPB::from_filter_rev(filter_rev.as_ref()),
             );
           },
           FieldType::Checkbox => {
             self.cell_filter_cache.write().insert(
-              &filter_type,
+              &filter_rev.id,
               CheckboxFilterPB::from_filter_rev(filter_rev.as_ref()),
             );
           },
           FieldType::URL => {
             self.cell_filter_cache.write().insert(
-              &filter_type,
+              &filter_rev.id,
               TextFilterPB::from_filter_rev(filter_rev.as_ref()),
             );
           },
           FieldType::Checklist => {
             self.cell_filter_cache.write().insert(
-              &filter_type,
+              &filter_rev.id,
               ChecklistFilterPB::from_filter_rev(filter_rev.as_ref()),
             );
           },
+          FieldType::Formula => {
+            self.cell_filter_cache.write().insert(
+              &filter_rev.id,
+              FormulaFilterPB::from_filter_rev(filter_rev.as_ref()),
+            );
+          },
+          FieldType::Relation => {
+            self.cell_filter_cache.write().insert(
+              &filter_rev.id,
+              RelationFilterPB::from_filter_rev(filter_rev.as_ref()),
+            );
+          },
         }
       }
     }
@@ -363,6 +432,8 @@ fn filter_row(
   row_rev: &Arc<RowRevision>,
   result_by_row_id: &DashMap<RowId, FilterResult>,
   field_rev_by_field_id: &HashMap<FieldId, Arc<FieldRevision>>,
+  filter_revs: &[Arc<FilterRevision>],
+  filter_group: &FilterGroup,
   cell_data_cache: &AtomicCellDataCache,
   cell_filter_cache: &AtomicCellFilterCache,
 ) -> Option<(String, bool)> {
@@ -370,20 +441,29 @@ fn filter_row(
   let mut filter_result = result_by_row_id
     .entry(row_rev.id.clone())
     .or_insert_with(FilterResult::default);
-  let old_is_visible = filter_result.is_visible();
 
-  // Iterate each cell of the row to check its visibility
-  for (field_id, field_rev) in field_rev_by_field_id {
-    let filter_type = FilterType::from(field_rev);
-    if !cell_filter_cache.read().contains(&filter_type) {
-      filter_result.visible_by_filter_id.remove(&filter_type);
-      continue;
-    }
+  // Remove the results of the filters that were deleted
+  filter_result.visible_by_filter_id.retain(|filter_id, _| {
+    filter_revs
+      .iter()
+      .any(|filter_rev| &filter_rev.id == filter_id)
+  });
+
+  // Apply each filter to the cell of its field. The results are kept by the filter id because
+  // a field can have more than one filter.
+  for filter_rev in filter_revs {
+    let field_rev = match field_rev_by_field_id.get(&filter_rev.field_id) {
+      Some(field_rev) if cell_filter_cache.read().contains(&filter_rev.id) => field_rev,
+      _ => {
+        filter_result.visible_by_filter_id.remove(&filter_rev.id);
+        continue;
+      },
+    };
 
-    let cell_rev = row_rev.cells.get(field_id);
-    // if the visibility of the cell_rew is changed, which means the visibility of the
-    // row is changed too.
+    let filter_type = FilterType::from(field_rev);
+    let cell_rev = row_rev.cells.get(&filter_rev.field_id);
     if let Some(is_visible) = filter_cell(
+      &filter_rev.id,
       &filter_type,
       field_rev,
       cell_rev,
@@ -392,13 +472,12 @@ fn filter_row(
     ) {
       filter_result
         .visible_by_filter_id
-        .insert(filter_type, is_visible);
+        .insert(filter_rev.id.clone(), is_visible);
     }
   }
 
-  let is_visible = filter_result.is_visible();
-  if old_is_visible != is_visible {
-    Some((row_rev.id.clone(), is_visible))
+  if filter_result.update_visibility(filter_group) {
+    Some((row_rev.id.clone(), filter_result.is_visible))
   } else {
     None
   }
@@ -409,6 +488,7 @@ fn filter_row(
 
 #[tracing::instrument(level = "trace", skip_all, fields(cell_content))]
 fn filter_cell(
+  filter_id: &str,
   filter_type: &FilterType,
   field_rev: &Arc<FieldRevision>,
   cell_rev: Option<&CellRevision>,
@@ -433,7 +513,8 @@ fn filter_cell(
   )
   .get_type_option_cell_data_handler(&filter_type.field_type)?;
 
-  let is_visible = handler.handle_cell_filter(filter_type, field_rev.as_ref(), type_cell_data);
+  let is_visible =
+    handler.handle_cell_filter(filter_id, filter_type, field_rev.as_ref(), type_cell_data);
   Some(is_visible)
 }
 
@@ -1,32 +1,65 @@
 use crate::entities::{
   AlterFilterParams, DatabaseSettingChangesetParams, DeleteFilterParams, FieldType, InsertedRowPB,
 };
-use database_model::{FieldRevision, FieldTypeRevision};
+use database_model::{
+  FieldRevision, FieldTypeRevision, FilterGroupRevision, FilterNodeRevision,
+  FilterOperatorRevision, FilterRevision,
+};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct FilterChangeset {
-  pub(crate) insert_filter: Option<FilterType>,
+  pub(crate) insert_filter: Option<FilterContext>,
   pub(crate) update_filter: Option<UpdatedFilterType>,
-  pub(crate) delete_filter: Option<FilterType>,
+  pub(crate) delete_filter: Option<FilterContext>,
+}
+
+/// Identifies a filter of the view. The [FilterType] locates the filters of the field, and the
+/// filter id picks one of them because a field can have more than one filter.
+#[derive(Debug, Clone)]
+pub struct FilterContext {
+  pub filter_id: String,
+  pub filter_type: FilterType,
+}
+
+impl FilterContext {
+  pub fn new(filter_id: &str, filter_type: FilterType) -> Self {
+    Self {
+      filter_id: filter_id.to_owned(),
+      filter_type,
+    }
+  }
 }
 
 #[derive(Debug)]
 pub struct UpdatedFilterType {
   pub old: Option<FilterType>,
   pub new: FilterType,
+  /// The id of the updated filter. It's None if the field of the filters was updated, which
+  /// updates all the filters of the field.
+  pub filter_id: Option<String>,
 }
 
 impl UpdatedFilterType {
   pub fn new(old: Option<FilterType>, new: FilterType) -> UpdatedFilterType {
-    Self { old, new }
+    Self {
+      old,
+      new,
+      filter_id: None,
+    }
+  }
+
+  pub fn with_filter_id(mut self, filter_id: &str) -> Self {
+    self.filter_id = Some(filter_id.to_owned());
+    self
   }
 }
 
 impl FilterChangeset {
-  pub fn from_insert(filter_type: FilterType) -> Self {
+  pub fn from_insert(filter_ctx: FilterContext) -> Self {
     Self {
-      insert_filter: Some(filter_type),
+      insert_filter: Some(filter_ctx),
       update_filter: None,
       delete_filter: None,
     }
@@ -39,11 +72,11 @@ impl FilterChangeset {
       delete_filter: None,
     }
   }
-  pub fn from_delete(filter_type: FilterType) -> Self {
+  pub fn from_delete(filter_ctx: FilterContext) -> Self {
     Self {
       insert_filter: None,
       update_filter: None,
-      delete_filter: Some(filter_type),
+      delete_filter: Some(filter_ctx),
     }
   }
 }
@@ -53,15 +86,23 @@ impl std::convert::From<&DatabaseSettingChangesetParams> for FilterChangeset {
     let insert_filter = params
       .insert_filter
       .as_ref()
-      .map(|insert_filter_params| FilterType {
-        field_id: insert_filter_params.field_id.clone(),
-        field_type: insert_filter_params.field_type.into(),
+      .and_then(|insert_filter_params| {
+        let filter_type = FilterType {
+          field_id: insert_filter_params.field_id.clone(),
+          field_type: insert_filter_params.field_type.into(),
+        };
+        insert_filter_params
+          .filter_id
+          .as_ref()
+          .map(|filter_id| FilterContext::new(filter_id, filter_type))
       });
 
-    let delete_filter = params
-      .delete_filter
-      .as_ref()
-      .map(|delete_filter_params| delete_filter_params.filter_type.clone());
+    let delete_filter = params.delete_filter.as_ref().map(|delete_filter_params| {
+      FilterContext::new(
+        &delete_filter_params.filter_id,
+        delete_filter_params.filter_type.clone(),
+      )
+    });
     FilterChangeset {
       insert_filter,
       update_filter: None,
@@ -90,6 +131,15 @@ impl std::convert::From<&Arc<FieldRevision>> for FilterType {
   }
 }
 
+impl std::convert::From<&FilterRevision> for FilterType {
+  fn from(rev: &FilterRevision) -> Self {
+    Self {
+      field_id: rev.field_id.clone(),
+      field_type: rev.field_type.into(),
+    }
+  }
+}
+
 impl std::convert::From<&AlterFilterParams> for FilterType {
   fn from(params: &AlterFilterParams) -> Self {
     let field_type: FieldType = params.field_type.into();
@@ -106,6 +156,82 @@ impl std::convert::From<&DeleteFilterParams> for FilterType {
   }
 }
 
+/// [FilterGroup] is the resolved [FilterGroupRevision]. The filter ids of the tree that don't
+/// refer to an existing filter are dropped. The filter results of the row are looked up by the
+/// filter ids, so the filters of the same field are evaluated separately.
+#[derive(Debug, Clone, Default)]
+pub struct FilterGroup {
+  operator: FilterOperatorRevision,
+  children: Vec<FilterNode>,
+}
+
+#[derive(Debug, Clone)]
+enum FilterNode {
+  Filter(String),
+  Group(FilterGroup),
+}
+
+impl FilterGroup {
+  /// Builds the filter group tree. The filters that are not referenced by the tree will be
+  /// appended to the root group.
+  pub fn new(root: &FilterGroupRevision, filter_revs: &[Arc<FilterRevision>]) -> Self {
+    let filter_ids = filter_revs
+      .iter()
+      .map(|filter_rev| filter_rev.id.as_str())
+      .collect::<HashSet<&str>>();
+
+    let mut group = Self::from_group_rev(root, &filter_ids);
+    for filter_rev in filter_revs {
+      if !root.contains_filter(&filter_rev.id) {
+        group
+          .children
+          .push(FilterNode::Filter(filter_rev.id.clone()));
+      }
+    }
+    group
+  }
+
+  fn from_group_rev(group_rev: &FilterGroupRevision, filter_ids: &HashSet<&str>) -> Self {
+    let children = group_rev
+      .children
+      .iter()
+      .filter_map(|child| match child {
+        FilterNodeRevision::Filter { filter_id } => filter_ids
+          .contains(filter_id.as_str())
+          .then(|| FilterNode::Filter(filter_id.clone())),
+        FilterNodeRevision::Group(group_rev) => Some(FilterNode::Group(Self::from_group_rev(
+          group_rev, filter_ids,
+        ))),
+      })
+      .collect();
+
+    Self {
+      operator: group_rev.operator,
+      children,
+    }
+  }
+
+  /// Returns the visibility of the row according to the visibility of each filter. The filters
+  /// that have no result are ignored, and the row is visible if none of the filters apply.
+  pub fn is_visible(&self, visible_by_filter_id: &HashMap<String, bool>) -> bool {
+    self.evaluate(visible_by_filter_id).unwrap_or(true)
+  }
+
+  fn evaluate(&self, visible_by_filter_id: &HashMap<String, bool>) -> Option<bool> {
+    let mut results = self.children.iter().filter_map(|child| match child {
+      FilterNode::Filter(filter_id) => visible_by_filter_id.get(filter_id).cloned(),
+      FilterNode::Group(group) => group.evaluate(visible_by_filter_id),
+    });
+
+    let first = results.next()?;
+    let is_visible = match self.operator {
+      FilterOperatorRevision::And => first && results.all(|is_visible| is_visible),
+      FilterOperatorRevision::Or => first || results.any(|is_visible| is_visible),
+    };
+    Some(is_visible)
+  }
+}
+
 #[derive(Clone, Debug)]
 pub struct FilterResultNotification {
   pub view_id: String,
@@ -1,4 +1,4 @@
-use crate::services::filter::{FilterController, FilterType};
+use crate::services::filter::{FilterController, FilterGroup};
 use flowy_task::{TaskContent, TaskHandler};
 use lib_infra::future::BoxResultFuture;
 use std::collections::HashMap;
@@ -41,20 +41,28 @@ impl TaskHandler for FilterTaskHandler {
   }
 }
 /// Refresh the filter according to the field id.
-#[derive(Default)]
 pub(crate) struct FilterResult {
-  pub(crate) visible_by_filter_id: HashMap<FilterType, bool>,
+  pub(crate) visible_by_filter_id: HashMap<String, bool>,
+  /// The visibility that was calculated by the last call of [FilterResult::update_visibility]
+  pub(crate) is_visible: bool,
 }
 
-impl FilterResult {
-  pub(crate) fn is_visible(&self) -> bool {
-    let mut is_visible = true;
-    for visible in self.visible_by_filter_id.values() {
-      if !is_visible {
-        break;
-      }
-      is_visible = *visible;
+impl std::default::Default for FilterResult {
+  fn default() -> Self {
+    Self {
+      visible_by_filter_id: HashMap::new(),
+      is_visible: true,
     }
-    is_visible
+  }
+}
+
+impl FilterResult {
+  /// Recalculates the visibility of the row with the filter group. Returns true if the
+  /// visibility was changed.
+  pub(crate) fn update_visibility(&mut self, filter_group: &FilterGroup) -> bool {
+    let is_visible = filter_group.is_visible(&self.visible_by_filter_id);
+    let is_changed = self.is_visible != is_visible;
+    self.is_visible = is_visible;
+    is_changed
   }
 }
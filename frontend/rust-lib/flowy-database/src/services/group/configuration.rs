@@ -115,6 +115,19 @@ where
     self.groups_map.get_mut(&self.field_rev.id)
   }
 
+  pub(crate) fn get_field_rev(&self) -> Arc<FieldRevision> {
+    self.field_rev.clone()
+  }
+
+  /// Returns the content of the group configuration. Returns None if the configuration belongs
+  /// to another field or the content can't be deserialized.
+  pub(crate) fn get_setting_content(&self) -> Option<C> {
+    if self.configuration.field_id != self.field_rev.id {
+      return None;
+    }
+    C::from_json(&self.configuration.content).ok()
+  }
+
   pub(crate) fn groups(&self) -> Vec<&Group> {
     self.groups_map.values().collect()
   }
@@ -0,0 +1,501 @@
+use crate::entities::{
+  FieldType, GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB, RowPB,
+};
+use crate::services::cell::insert_date_cell;
+use crate::services::field::{
+  DateCellData, DateCellDataPB, DateCellDataParser, DateFormat, DateTypeOptionPB,
+};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+  GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::{
+  make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroupContext,
+};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, Weekday};
+use database_model::{
+  CellRevision, DateCondition, DateGroupConfigurationRevision, FieldRevision, GroupRevision,
+  RowRevision,
+};
+use flowy_error::FlowyResult;
+
+/// Groups the rows by the [FieldType::DateTime], [FieldType::CreatedTime] and
+/// [FieldType::LastEditedTime] fields. The timestamp fields share the [DateTypeOptionPB] because
+/// the [TimestampTypeOptionPB] is serialized with the same properties.
+pub type DateGroupController = GenericGroupController<
+  DateGroupConfigurationRevision,
+  DateTypeOptionPB,
+  DateGroupGenerator,
+  DateCellDataParser,
+>;
+
+pub type DateGroupContext = GroupContext<DateGroupConfigurationRevision>;
+
+const TODAY: &str = "today";
+const YESTERDAY: &str = "yesterday";
+const TOMORROW: &str = "tomorrow";
+const LAST_7_DAYS: &str = "last_7_days";
+const NEXT_7_DAYS: &str = "next_7_days";
+const LAST_30_DAYS: &str = "last_30_days";
+const NEXT_30_DAYS: &str = "next_30_days";
+
+impl DateGroupController {
+  fn make_group_from_cell(&self, cell_data: &DateCellDataPB) -> Option<GroupRevision> {
+    let condition = self
+      .group_ctx
+      .get_setting_content()
+      .unwrap_or_default()
+      .condition;
+    let date = date_from_timestamp(cell_data.timestamp)?;
+    Some(make_date_group(
+      date,
+      today(),
+      &condition,
+      date_format(&self.type_option),
+    ))
+  }
+
+  /// The cells of the timestamp fields are maintained by the database, so the rows can't be
+  /// moved between their groups.
+  fn is_read_only(field_rev: &FieldRevision) -> bool {
+    let field_type: FieldType = field_rev.ty.into();
+    field_type.is_timestamp()
+  }
+}
+
+impl GroupCustomize for DateGroupController {
+  type CellData = DateCellDataPB;
+
+  fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+    match self.make_group_from_cell(cell_data) {
+      None => false,
+      Some(group_rev) => group_rev.id == content,
+    }
+  }
+
+  fn create_or_delete_group_when_cell_changed(
+    &mut self,
+    row_rev: &RowRevision,
+    old_cell_data: Option<&Self::CellData>,
+    cell_data: &Self::CellData,
+  ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
+    // Insert a new group if there is no group for this date yet
+    let mut inserted_group = None;
+    let group_rev = self.make_group_from_cell(cell_data);
+    let group_id = group_rev.as_ref().map(|group_rev| group_rev.id.clone());
+    if let Some(group_rev) = group_rev {
+      if self.group_ctx.get_group(&group_rev.id).is_none() {
+        let mut new_group = self.group_ctx.add_new_group(group_rev)?;
+        new_group.group.rows.push(RowPB::from(row_rev));
+        inserted_group = Some(new_group);
+      }
+    }
+
+    // Delete the old date group if the row was the only one in it
+    let old_group_id = old_cell_data
+      .and_then(|old_cell_data| self.make_group_from_cell(old_cell_data))
+      .map(|group_rev| group_rev.id)
+      .filter(|old_group_id| Some(old_group_id) != group_id.as_ref());
+    let deleted_group = match old_group_id.and_then(|id| self.group_ctx.get_group(&id)) {
+      Some((_, group)) if group.rows.len() == 1 => Some(group.clone()),
+      _ => None,
+    };
+
+    let deleted_group = match deleted_group {
+      None => None,
+      Some(group) => {
+        self.group_ctx.delete_group(&group.id)?;
+        Some(GroupPB::from(group))
+      },
+    };
+
+    Ok((inserted_group, deleted_group))
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let group_id = self
+      .make_group_from_cell(cell_data)
+      .map(|group_rev| group_rev.id);
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_status_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if Some(&group.id) == group_id.as_ref() {
+        if !group.contains_row(&row_rev.id) {
+          let row_pb = RowPB::from(row_rev);
+          changeset
+            .inserted_rows
+            .push(InsertedRowPB::new(row_pb.clone()));
+          group.add_row(row_pb);
+        }
+      } else if group.contains_row(&row_rev.id) {
+        changeset.deleted_rows.push(row_rev.id.clone());
+        group.remove_row(&row_rev.id);
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(
+    &mut self,
+    row_rev: &RowRevision,
+    _cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if group.contains_row(&row_rev.id) {
+        changeset.deleted_rows.push(row_rev.id.clone());
+        group.remove_row(&row_rev.id);
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut group_changeset = vec![];
+    if Self::is_read_only(context.field_rev) {
+      tracing::warn!("The rows can't be moved between the groups of the timestamp field");
+      return group_changeset;
+    }
+
+    self.group_ctx.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+
+  fn delete_group_when_move_row(
+    &mut self,
+    row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Option<GroupPB> {
+    let field_rev = self.group_ctx.get_field_rev();
+    if Self::is_read_only(&field_rev) {
+      return None;
+    }
+
+    let mut deleted_group = None;
+    let group_id = self.make_group_from_cell(cell_data)?.id;
+    if let Some((_, group)) = self.group_ctx.get_group(&group_id) {
+      if group.rows.len() == 1 && group.contains_row(&row_rev.id) {
+        deleted_group = Some(GroupPB::from(group.clone()));
+      }
+    }
+    if deleted_group.is_some() {
+      let _ = self.group_ctx.delete_group(&group_id);
+    }
+    deleted_group
+  }
+}
+
+impl GroupController for DateGroupController {
+  fn will_create_row(
+    &mut self,
+    row_rev: &mut RowRevision,
+    field_rev: &FieldRevision,
+    group_id: &str,
+  ) {
+    if Self::is_read_only(field_rev) {
+      return;
+    }
+
+    match self.group_ctx.get_group(group_id) {
+      None => tracing::warn!("Can not find the group: {}", group_id),
+      Some((_, group)) => {
+        let cell_rev = insert_date_group_cell(&group.id, field_rev);
+        row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+      },
+    }
+  }
+
+  fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+    if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+      group.add_row(row_pb.clone())
+    }
+  }
+}
+
+pub struct DateGroupGenerator();
+impl GroupGenerator for DateGroupGenerator {
+  type Context = DateGroupContext;
+  type TypeOptionType = DateTypeOptionPB;
+
+  fn generate_groups(
+    field_rev: &FieldRevision,
+    group_ctx: &Self::Context,
+    type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroupContext {
+    let condition = group_ctx
+      .get_setting_content()
+      .unwrap_or_default()
+      .condition;
+    let date_format = date_format(type_option);
+    let today = today();
+
+    // Read all the cells for the grouping field
+    let cells = futures::executor::block_on(group_ctx.get_all_cells());
+    let mut dates = cells
+      .into_iter()
+      .flat_map(|value| value.into_date_field_cell_data())
+      .flat_map(|cell| cell.timestamp)
+      .flat_map(date_from_timestamp)
+      .collect::<Vec<NaiveDate>>();
+    dates.sort();
+
+    // Generate the groups in chronological order
+    let mut group_configs: Vec<GeneratedGroupConfig> = vec![];
+    for date in dates {
+      let group_rev = make_date_group(date, today, &condition, date_format);
+      if group_configs
+        .iter()
+        .all(|config| config.group_rev.id != group_rev.id)
+      {
+        group_configs.push(GeneratedGroupConfig {
+          filter_content: group_rev.id.clone(),
+          group_rev,
+        });
+      }
+    }
+
+    let no_status_group = Some(make_no_status_group(field_rev));
+    GeneratedGroupContext {
+      no_status_group,
+      group_configs,
+    }
+  }
+}
+
+/// Returns the date cell of the row that is moved into the group. The cell will be empty if the
+/// group is the `No status` group.
+pub fn insert_date_group_cell(group_id: &str, field_rev: &FieldRevision) -> CellRevision {
+  let timestamp = if group_id == make_no_status_group(field_rev).id {
+    None
+  } else {
+    date_from_group_id(group_id, today()).and_then(timestamp_from_date)
+  };
+
+  insert_date_cell(
+    DateCellData {
+      timestamp,
+      include_time: false,
+    },
+    field_rev,
+  )
+}
+
+fn make_date_group(
+  date: NaiveDate,
+  today: NaiveDate,
+  condition: &DateCondition,
+  date_format: DateFormat,
+) -> GroupRevision {
+  let format_date = |date: NaiveDate| date.format(date_format.format_str()).to_string();
+  let (group_id, group_name) = match condition {
+    DateCondition::Day => (date.format("%Y-%m-%d").to_string(), format_date(date)),
+    DateCondition::Week => {
+      let week = date.iso_week();
+      let monday =
+        NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon).unwrap_or(date);
+      (
+        format!("{}-W{:02}", week.year(), week.week()),
+        format!("Week of {}", format_date(monday)),
+      )
+    },
+    DateCondition::Month => month_group(date),
+    DateCondition::Year => (date.format("%Y").to_string(), date.format("%Y").to_string()),
+    DateCondition::Relative => {
+      let relative = |id: &str, name: &str| (id.to_owned(), name.to_owned());
+      match (date - today).num_days() {
+        0 => relative(TODAY, "Today"),
+        -1 => relative(YESTERDAY, "Yesterday"),
+        1 => relative(TOMORROW, "Tomorrow"),
+        -7..=-2 => relative(LAST_7_DAYS, "Last 7 days"),
+        2..=7 => relative(NEXT_7_DAYS, "Next 7 days"),
+        -30..=-8 => relative(LAST_30_DAYS, "Last 30 days"),
+        8..=30 => relative(NEXT_30_DAYS, "Next 30 days"),
+        _ => month_group(date),
+      }
+    },
+  };
+  GroupRevision::new(group_id, group_name)
+}
+
+fn month_group(date: NaiveDate) -> (String, String) {
+  (
+    date.format("%Y-%m").to_string(),
+    date.format("%b %Y").to_string(),
+  )
+}
+
+/// Returns a date that belongs to the group. The group id is generated by [make_date_group].
+fn date_from_group_id(group_id: &str, today: NaiveDate) -> Option<NaiveDate> {
+  let date = match group_id {
+    TODAY => today,
+    YESTERDAY => today - Duration::days(1),
+    TOMORROW => today + Duration::days(1),
+    LAST_7_DAYS => today - Duration::days(2),
+    NEXT_7_DAYS => today + Duration::days(2),
+    LAST_30_DAYS => today - Duration::days(8),
+    NEXT_30_DAYS => today + Duration::days(8),
+    _ => return parse_date_group_id(group_id, today),
+  };
+  Some(date)
+}
+
+fn parse_date_group_id(group_id: &str, today: NaiveDate) -> Option<NaiveDate> {
+  // Day: 2022-03-14
+  if let Ok(date) = NaiveDate::parse_from_str(group_id, "%Y-%m-%d") {
+    return Some(date);
+  }
+
+  // Week: 2022-W11
+  if let Some((year, week)) = group_id.split_once("-W") {
+    return NaiveDate::from_isoywd_opt(year.parse().ok()?, week.parse().ok()?, Weekday::Mon);
+  }
+
+  // Month: 2022-03
+  if let Some((year, month)) = group_id.split_once('-') {
+    let first_day = NaiveDate::from_ymd_opt(year.parse().ok()?, month.parse().ok()?, 1)?;
+    // Take the day that is farthest from today. Otherwise, the date might fall into one of the
+    // relative groups.
+    return if first_day > today {
+      last_day_of_month(first_day)
+    } else {
+      Some(first_day)
+    };
+  }
+
+  // Year: 2022
+  if group_id.len() == 4 {
+    return NaiveDate::from_ymd_opt(group_id.parse().ok()?, 1, 1);
+  }
+  None
+}
+
+fn last_day_of_month(first_day: NaiveDate) -> Option<NaiveDate> {
+  let next_month = if first_day.month() == 12 {
+    NaiveDate::from_ymd_opt(first_day.year() + 1, 1, 1)?
+  } else {
+    NaiveDate::from_ymd_opt(first_day.year(), first_day.month() + 1, 1)?
+  };
+  next_month.pred_opt()
+}
+
+fn date_format(type_option: &Option<DateTypeOptionPB>) -> DateFormat {
+  type_option
+    .as_ref()
+    .map(|type_option| type_option.date_format)
+    .unwrap_or_default()
+}
+
+// Use the local timezone to calculate the date, which is the same as the formatted date string of
+// the date cell.
+fn today() -> NaiveDate {
+  Local::now().date_naive()
+}
+
+fn date_from_timestamp(timestamp: i64) -> Option<NaiveDate> {
+  if timestamp == 0 {
+    return None;
+  }
+  let native = NaiveDateTime::from_timestamp_opt(timestamp, 0)?;
+  let offset = *Local::now().offset();
+  Some(chrono::DateTime::<Local>::from_utc(native, offset).date_naive())
+}
+
+fn timestamp_from_date(date: NaiveDate) -> Option<i64> {
+  let native = date.and_hms_opt(0, 0, 0)?;
+  let offset = *Local::now().offset();
+  Some(chrono::DateTime::<Local>::from_local(native, offset).timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::field::DateFormat;
+  use crate::services::group::controller_impls::date_controller::{
+    date_from_group_id, make_date_group,
+  };
+  use chrono::NaiveDate;
+  use database_model::DateCondition;
+
+  #[test]
+  fn date_group_test() {
+    let today = NaiveDate::from_ymd_opt(2022, 11, 17).unwrap();
+    let date = NaiveDate::from_ymd_opt(2022, 3, 14).unwrap();
+    let assert_group = |condition: DateCondition, id: &str, name: &str| {
+      let group_rev = make_date_group(date, today, &condition, DateFormat::ISO);
+      assert_eq!(group_rev.id, id);
+      assert_eq!(group_rev.name, name);
+    };
+
+    assert_group(DateCondition::Day, "2022-03-14", "2022-03-14");
+    assert_group(DateCondition::Week, "2022-W11", "Week of 2022-03-14");
+    assert_group(DateCondition::Month, "2022-03", "Mar 2022");
+    assert_group(DateCondition::Year, "2022", "2022");
+    assert_group(DateCondition::Relative, "2022-03", "Mar 2022");
+  }
+
+  #[test]
+  fn relative_date_group_test() {
+    let today = NaiveDate::from_ymd_opt(2022, 11, 17).unwrap();
+    for (days, id) in [
+      (0, "today"),
+      (-1, "yesterday"),
+      (1, "tomorrow"),
+      (-5, "last_7_days"),
+      (7, "next_7_days"),
+      (-30, "last_30_days"),
+      (12, "next_30_days"),
+      (31, "2022-12"),
+    ] {
+      let date = today + chrono::Duration::days(days);
+      let group_rev = make_date_group(date, today, &DateCondition::Relative, DateFormat::ISO);
+      assert_eq!(group_rev.id, id);
+    }
+  }
+
+  #[test]
+  fn date_from_group_id_test() {
+    let today = NaiveDate::from_ymd_opt(2022, 11, 17).unwrap();
+    for group_id in [
+      "today",
+      "last_7_days",
+      "next_30_days",
+      "2022-03-14",
+      "2022-W11",
+      "2022-03",
+      "2023-01",
+      "2022",
+    ] {
+      // Moving a row into the group must keep the row in that group.
+      let date = date_from_group_id(group_id, today).unwrap();
+      let condition = match group_id {
+        "2022-03-14" => DateCondition::Day,
+        "2022-W11" => DateCondition::Week,
+        "2022" => DateCondition::Year,
+        _ => DateCondition::Relative,
+      };
+      let group_rev = make_date_group(date, today, &condition, DateFormat::ISO);
+      assert_eq!(group_rev.id, group_id);
+    }
+  }
+}
@@ -0,0 +1,401 @@
+use crate::entities::{
+  FieldType, GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB, RowPB,
+};
+use crate::services::cell::{insert_number_cell, CellProtobufBlobParser};
+use crate::services::field::{NumberCellData, NumberFormat, NumberTypeOptionPB};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+  GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::{
+  make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroupContext,
+};
+use bytes::Bytes;
+use database_model::{
+  CellRevision, FieldRevision, GroupRevision, NumberGroupConfigurationRevision,
+  NumberRangeRevision, RowRevision,
+};
+use flowy_error::FlowyResult;
+use rust_decimal::prelude::ToPrimitive;
+
+/// Groups the rows by the [FieldType::Number] or [FieldType::AutoIncrementId] field. The numbers
+/// are grouped into the ranges of the [NumberGroupConfigurationRevision] if there are any.
+/// Otherwise, they are grouped into the buckets of the same width.
+pub type NumberGroupController = GenericGroupController<
+  NumberGroupConfigurationRevision,
+  NumberTypeOptionPB,
+  NumberGroupGenerator,
+  NumberGroupCellDataParser,
+>;
+
+pub type NumberGroupContext = GroupContext<NumberGroupConfigurationRevision>;
+
+impl NumberGroupController {
+  fn make_group_from_cell(&self, cell_data: &NumberCellData) -> Option<GroupRevision> {
+    let setting = self.group_ctx.get_setting_content().unwrap_or_default();
+    make_number_group(number_from_cell(cell_data)?, &setting)
+  }
+
+  /// The ids are assigned by the database, so the rows can't be moved between their groups.
+  fn is_read_only(field_rev: &FieldRevision) -> bool {
+    let field_type: FieldType = field_rev.ty.into();
+    field_type.is_auto_increment_id()
+  }
+
+  /// The groups of the custom ranges are kept even if they are empty.
+  fn is_custom_ranges(&self) -> bool {
+    self
+      .group_ctx
+      .get_setting_content()
+      .map(|setting| !setting.ranges.is_empty())
+      .unwrap_or(false)
+  }
+}
+
+impl GroupCustomize for NumberGroupController {
+  type CellData = NumberCellData;
+
+  fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+    match self.make_group_from_cell(cell_data) {
+      None => false,
+      Some(group_rev) => group_rev.id == content,
+    }
+  }
+
+  fn create_or_delete_group_when_cell_changed(
+    &mut self,
+    row_rev: &RowRevision,
+    old_cell_data: Option<&Self::CellData>,
+    cell_data: &Self::CellData,
+  ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
+    // Insert a new group if there is no group for this number yet
+    let mut inserted_group = None;
+    let group_rev = self.make_group_from_cell(cell_data);
+    let group_id = group_rev.as_ref().map(|group_rev| group_rev.id.clone());
+    if let Some(group_rev) = group_rev {
+      if self.group_ctx.get_group(&group_rev.id).is_none() {
+        let mut new_group = self.group_ctx.add_new_group(group_rev)?;
+        new_group.group.rows.push(RowPB::from(row_rev));
+        inserted_group = Some(new_group);
+      }
+    }
+
+    if self.is_custom_ranges() {
+      return Ok((inserted_group, None));
+    }
+
+    // Delete the old bucket if the row was the only one in it
+    let old_group_id = old_cell_data
+      .and_then(|old_cell_data| self.make_group_from_cell(old_cell_data))
+      .map(|group_rev| group_rev.id)
+      .filter(|old_group_id| Some(old_group_id) != group_id.as_ref());
+    let deleted_group = match old_group_id.and_then(|id| self.group_ctx.get_group(&id)) {
+      Some((_, group)) if group.rows.len() == 1 => Some(group.clone()),
+      _ => None,
+    };
+
+    let deleted_group = match deleted_group {
+      None => None,
+      Some(group) => {
+        self.group_ctx.delete_group(&group.id)?;
+        Some(GroupPB::from(group))
+      },
+    };
+
+    Ok((inserted_group, deleted_group))
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let group_id = self
+      .make_group_from_cell(cell_data)
+      .map(|group_rev| group_rev.id);
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_status_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if Some(&group.id) == group_id.as_ref() {
+        if !group.contains_row(&row_rev.id) {
+          let row_pb = RowPB::from(row_rev);
+          changeset
+            .inserted_rows
+            .push(InsertedRowPB::new(row_pb.clone()));
+          group.add_row(row_pb);
+        }
+      } else if group.contains_row(&row_rev.id) {
+        changeset.deleted_rows.push(row_rev.id.clone());
+        group.remove_row(&row_rev.id);
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(
+    &mut self,
+    row_rev: &RowRevision,
+    _cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if group.contains_row(&row_rev.id) {
+        changeset.deleted_rows.push(row_rev.id.clone());
+        group.remove_row(&row_rev.id);
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut group_changeset = vec![];
+    self.group_ctx.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+
+  fn delete_group_when_move_row(
+    &mut self,
+    row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Option<GroupPB> {
+    if self.is_custom_ranges() {
+      return None;
+    }
+
+    let mut deleted_group = None;
+    let group_id = self.make_group_from_cell(cell_data)?.id;
+    if let Some((_, group)) = self.group_ctx.get_group(&group_id) {
+      if group.rows.len() == 1 && group.contains_row(&row_rev.id) {
+        deleted_group = Some(GroupPB::from(group.clone()));
+      }
+    }
+    if deleted_group.is_some() {
+      let _ = self.group_ctx.delete_group(&group_id);
+    }
+    deleted_group
+  }
+}
+
+impl GroupController for NumberGroupController {
+  fn will_create_row(
+    &mut self,
+    row_rev: &mut RowRevision,
+    field_rev: &FieldRevision,
+    group_id: &str,
+  ) {
+    if Self::is_read_only(field_rev) {
+      return;
+    }
+
+    match self.group_ctx.get_group(group_id) {
+      None => tracing::warn!("Can not find the group: {}", group_id),
+      Some((_, group)) => {
+        let cell_rev = insert_number_group_cell(&group.id, field_rev);
+        row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+      },
+    }
+  }
+
+  fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+    if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+      group.add_row(row_pb.clone())
+    }
+  }
+}
+
+pub struct NumberGroupGenerator();
+impl GroupGenerator for NumberGroupGenerator {
+  type Context = NumberGroupContext;
+  type TypeOptionType = NumberTypeOptionPB;
+
+  fn generate_groups(
+    field_rev: &FieldRevision,
+    group_ctx: &Self::Context,
+    _type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroupContext {
+    let setting = group_ctx.get_setting_content().unwrap_or_default();
+    let group_revs = if setting.ranges.is_empty() {
+      // Read all the cells for the grouping field
+      let cells = futures::executor::block_on(group_ctx.get_all_cells());
+      let mut numbers = cells
+        .into_iter()
+        .flat_map(|value| match value.field_type {
+          FieldType::AutoIncrementId => value
+            .into_auto_increment_id_field_cell_data()
+            .map(|cell| cell.to_number_cell_data()),
+          _ => value
+            .into_number_field_cell_data()
+            .and_then(|cell| parse_number_cell_str(&cell).ok()),
+        })
+        .flat_map(|cell| number_from_cell(&cell))
+        .collect::<Vec<f64>>();
+      numbers.sort_by(|a, b| a.total_cmp(b));
+
+      let mut group_revs: Vec<GroupRevision> = vec![];
+      for number in numbers {
+        if let Some(group_rev) = make_number_group(number, &setting) {
+          if group_revs.iter().all(|other| other.id != group_rev.id) {
+            group_revs.push(group_rev);
+          }
+        }
+      }
+      group_revs
+    } else {
+      setting.ranges.iter().map(make_range_group).collect()
+    };
+
+    let group_configs = group_revs
+      .into_iter()
+      .map(|group_rev| GeneratedGroupConfig {
+        filter_content: group_rev.id.clone(),
+        group_rev,
+      })
+      .collect();
+
+    let no_status_group = Some(make_no_status_group(field_rev));
+    GeneratedGroupContext {
+      no_status_group,
+      group_configs,
+    }
+  }
+}
+
+/// Parses the number cell without dropping the sign of the number, which is different from the
+/// [NumberCellDataParser].
+pub struct NumberGroupCellDataParser();
+impl CellProtobufBlobParser for NumberGroupCellDataParser {
+  type Object = NumberCellData;
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    match String::from_utf8(bytes.to_vec()) {
+      Ok(s) => parse_number_cell_str(&s),
+      Err(_) => Ok(NumberCellData::default()),
+    }
+  }
+}
+
+/// Returns the number cell of the row that is moved into the group. The number will be the start
+/// of the group's range, or empty if the group is the `No status` group.
+pub fn insert_number_group_cell(group_id: &str, field_rev: &FieldRevision) -> CellRevision {
+  match range_from_group_id(group_id) {
+    Some(range) if group_id != make_no_status_group(field_rev).id => {
+      insert_number_cell(range.start, field_rev)
+    },
+    _ => insert_number_cell("", field_rev),
+  }
+}
+
+fn parse_number_cell_str(s: &str) -> FlowyResult<NumberCellData> {
+  let s = s.trim();
+  match s.strip_prefix('-') {
+    None => NumberCellData::from_format_str(s, true, &NumberFormat::Num),
+    Some(s) => NumberCellData::from_format_str(s, false, &NumberFormat::Num),
+  }
+}
+
+fn number_from_cell(cell_data: &NumberCellData) -> Option<f64> {
+  cell_data.decimal().as_ref()?.to_f64()
+}
+
+fn make_number_group(
+  number: f64,
+  setting: &NumberGroupConfigurationRevision,
+) -> Option<GroupRevision> {
+  if !setting.ranges.is_empty() {
+    let range = setting.ranges.iter().find(|range| range.contains(number))?;
+    return Some(make_range_group(range));
+  }
+
+  let width = if setting.bucket_width > 0.0 {
+    setting.bucket_width
+  } else {
+    NumberGroupConfigurationRevision::default().bucket_width
+  };
+  let start = (number / width).floor() * width;
+  Some(make_range_group(&NumberRangeRevision::new(
+    start,
+    start + width,
+  )))
+}
+
+fn make_range_group(range: &NumberRangeRevision) -> GroupRevision {
+  let group_id = format!("{}..{}", range.start, range.end);
+  let group_name = format!("{} - {}", range.start, range.end);
+  GroupRevision::new(group_id, group_name)
+}
+
+fn range_from_group_id(group_id: &str) -> Option<NumberRangeRevision> {
+  let (start, end) = group_id.split_once("..")?;
+  Some(NumberRangeRevision::new(
+    start.parse().ok()?,
+    end.parse().ok()?,
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::group::controller_impls::number_controller::{
+    make_number_group, number_from_cell, parse_number_cell_str, range_from_group_id,
+  };
+  use database_model::{NumberGroupConfigurationRevision, NumberRangeRevision};
+
+  #[test]
+  fn number_bucket_group_test() {
+    let setting = NumberGroupConfigurationRevision::default();
+    for (number, id, name) in [
+      (0.0, "0..10", "0 - 10"),
+      (9.5, "0..10", "0 - 10"),
+      (10.0, "10..20", "10 - 20"),
+      (-0.5, "-10..0", "-10 - 0"),
+    ] {
+      let group_rev = make_number_group(number, &setting).unwrap();
+      assert_eq!(group_rev.id, id);
+      assert_eq!(group_rev.name, name);
+
+      // Moving a row into the group must keep the row in that group.
+      let range = range_from_group_id(&group_rev.id).unwrap();
+      assert_eq!(make_number_group(range.start, &setting).unwrap().id, id);
+    }
+  }
+
+  #[test]
+  fn number_range_group_test() {
+    let setting = NumberGroupConfigurationRevision {
+      ranges: vec![
+        NumberRangeRevision::new(0.0, 10.0),
+        NumberRangeRevision::new(10.0, 100.0),
+      ],
+      ..Default::default()
+    };
+    assert_eq!(make_number_group(3.0, &setting).unwrap().id, "0..10");
+    assert_eq!(make_number_group(10.0, &setting).unwrap().id, "10..100");
+    assert!(make_number_group(100.0, &setting).is_none());
+    assert!(make_number_group(-1.0, &setting).is_none());
+  }
+
+  #[test]
+  fn number_group_cell_parser_test() {
+    for (s, number) in [("$1.00", 1.0), ("-$1.50", -1.5), ("-3", -3.0), ("12", 12.0)] {
+      let cell_data = parse_number_cell_str(s).unwrap();
+      assert_eq!(number_from_cell(&cell_data), Some(number));
+    }
+    assert!(parse_number_cell_str("").unwrap().is_empty());
+  }
+}
@@ -3,7 +3,10 @@ use crate::services::cell::{insert_checkbox_cell, insert_select_option_cell, ins
 use crate::services::field::{SelectOptionCellDataPB, SelectOptionPB, CHECK};
 use crate::services::group::configuration::GroupContext;
 use crate::services::group::controller::MoveGroupRowContext;
-use crate::services::group::{GeneratedGroupConfig, Group};
+use crate::services::group::{
+  insert_date_group_cell, insert_number_group_cell, insert_text_group_cell, GeneratedGroupConfig,
+  Group,
+};
 use database_model::{
   CellRevision, FieldRevision, GroupRevision, RowRevision, SelectOptionGroupConfigurationRevision,
 };
@@ -157,6 +160,18 @@ pub fn make_inserted_cell_rev(group_id: &str, field_rev: &FieldRevision) -> Opti
       let cell_rev = insert_url_cell(group_id.to_owned(), field_rev);
       Some(cell_rev)
     },
+    FieldType::RichText => {
+      let cell_rev = insert_text_group_cell(group_id, field_rev);
+      Some(cell_rev)
+    },
+    FieldType::Number => {
+      let cell_rev = insert_number_group_cell(group_id, field_rev);
+      Some(cell_rev)
+    },
+    FieldType::DateTime => {
+      let cell_rev = insert_date_group_cell(group_id, field_rev);
+      Some(cell_rev)
+    },
     _ => {
       tracing::warn!("Unknown field type: {:?}", field_type);
       None
@@ -0,0 +1,273 @@
+use crate::entities::{GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB, RowPB};
+use crate::services::cell::insert_text_cell;
+use crate::services::field::{RichTextTypeOptionPB, TextCellData, TextCellDataParser};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+  GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::{
+  make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroupContext,
+};
+use database_model::{
+  CellRevision, FieldRevision, GroupRevision, RowRevision, TextGroupConfigurationRevision,
+};
+use flowy_error::FlowyResult;
+
+/// Groups the rows by the distinct values of the [FieldType::RichText] field. The id of the group
+/// is the first text that was put into it, so the texts that only differ in case share the same
+/// group if the grouping is case-insensitive.
+pub type TextGroupController = GenericGroupController<
+  TextGroupConfigurationRevision,
+  RichTextTypeOptionPB,
+  TextGroupGenerator,
+  TextCellDataParser,
+>;
+
+pub type TextGroupContext = GroupContext<TextGroupConfigurationRevision>;
+
+impl TextGroupController {
+  fn is_case_insensitive(&self) -> bool {
+    self
+      .group_ctx
+      .get_setting_content()
+      .map(|setting| setting.case_insensitive)
+      .unwrap_or(false)
+  }
+
+  /// Returns the id of the group that the text belongs to, or None if there is no such group.
+  fn find_group_id(&self, text: &str) -> Option<String> {
+    if text.is_empty() {
+      return None;
+    }
+
+    let case_insensitive = self.is_case_insensitive();
+    let field_id = self.grouping_field_id.clone();
+    self
+      .group_ctx
+      .groups()
+      .into_iter()
+      .filter(|group| group.id != field_id)
+      .find(|group| is_same_text(&group.id, text, case_insensitive))
+      .map(|group| group.id.clone())
+  }
+}
+
+impl GroupCustomize for TextGroupController {
+  type CellData = TextCellData;
+
+  fn placeholder_cell(&self) -> Option<CellRevision> {
+    Some(CellRevision::new("".to_string()))
+  }
+
+  fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+    !cell_data.is_empty() && is_same_text(content, cell_data, self.is_case_insensitive())
+  }
+
+  fn create_or_delete_group_when_cell_changed(
+    &mut self,
+    row_rev: &RowRevision,
+    old_cell_data: Option<&Self::CellData>,
+    cell_data: &Self::CellData,
+  ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
+    // Insert a new group if there is no group for this text yet
+    let mut inserted_group = None;
+    let group_id = self.find_group_id(cell_data);
+    if group_id.is_none() && !cell_data.is_empty() {
+      let group_rev = make_group_from_text(cell_data);
+      let mut new_group = self.group_ctx.add_new_group(group_rev)?;
+      new_group.group.rows.push(RowPB::from(row_rev));
+      inserted_group = Some(new_group);
+    }
+
+    // Delete the old text group if the row was the only one in it
+    let old_group_id = old_cell_data
+      .and_then(|old_cell_data| self.find_group_id(old_cell_data))
+      .filter(|old_group_id| Some(old_group_id) != group_id.as_ref());
+    let deleted_group = match old_group_id.and_then(|id| self.group_ctx.get_group(&id)) {
+      Some((_, group)) if group.rows.len() == 1 => Some(group.clone()),
+      _ => None,
+    };
+
+    let deleted_group = match deleted_group {
+      None => None,
+      Some(group) => {
+        self.group_ctx.delete_group(&group.id)?;
+        Some(GroupPB::from(group))
+      },
+    };
+
+    Ok((inserted_group, deleted_group))
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let group_id = self.find_group_id(cell_data);
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_status_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if Some(&group.id) == group_id.as_ref() {
+        if !group.contains_row(&row_rev.id) {
+          let row_pb = RowPB::from(row_rev);
+          changeset
+            .inserted_rows
+            .push(InsertedRowPB::new(row_pb.clone()));
+          group.add_row(row_pb);
+        }
+      } else if group.contains_row(&row_rev.id) {
+        changeset.deleted_rows.push(row_rev.id.clone());
+        group.remove_row(&row_rev.id);
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(
+    &mut self,
+    row_rev: &RowRevision,
+    _cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if group.contains_row(&row_rev.id) {
+        changeset.deleted_rows.push(row_rev.id.clone());
+        group.remove_row(&row_rev.id);
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut group_changeset = vec![];
+    self.group_ctx.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+
+  fn delete_group_when_move_row(
+    &mut self,
+    row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Option<GroupPB> {
+    let mut deleted_group = None;
+    let group_id = self.find_group_id(cell_data)?;
+    if let Some((_, group)) = self.group_ctx.get_group(&group_id) {
+      if group.rows.len() == 1 && group.contains_row(&row_rev.id) {
+        deleted_group = Some(GroupPB::from(group.clone()));
+      }
+    }
+    if deleted_group.is_some() {
+      let _ = self.group_ctx.delete_group(&group_id);
+    }
+    deleted_group
+  }
+}
+
+impl GroupController for TextGroupController {
+  fn will_create_row(
+    &mut self,
+    row_rev: &mut RowRevision,
+    field_rev: &FieldRevision,
+    group_id: &str,
+  ) {
+    match self.group_ctx.get_group(group_id) {
+      None => tracing::warn!("Can not find the group: {}", group_id),
+      Some((_, group)) => {
+        let cell_rev = insert_text_group_cell(&group.id, field_rev);
+        row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+      },
+    }
+  }
+
+  fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+    if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+      group.add_row(row_pb.clone())
+    }
+  }
+}
+
+pub struct TextGroupGenerator();
+impl GroupGenerator for TextGroupGenerator {
+  type Context = TextGroupContext;
+  type TypeOptionType = RichTextTypeOptionPB;
+
+  fn generate_groups(
+    field_rev: &FieldRevision,
+    group_ctx: &Self::Context,
+    _type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroupContext {
+    let case_insensitive = group_ctx
+      .get_setting_content()
+      .unwrap_or_default()
+      .case_insensitive;
+
+    // Read all the cells for the grouping field
+    let cells = futures::executor::block_on(group_ctx.get_all_cells());
+
+    // Generate the groups in the order of the rows
+    let mut group_configs: Vec<GeneratedGroupConfig> = vec![];
+    for cell in cells
+      .into_iter()
+      .flat_map(|value| value.into_text_field_cell_data())
+      .filter(|cell| !cell.is_empty())
+    {
+      if group_configs
+        .iter()
+        .all(|config| !is_same_text(&config.group_rev.id, &cell, case_insensitive))
+      {
+        let group_rev = make_group_from_text(&cell);
+        group_configs.push(GeneratedGroupConfig {
+          filter_content: group_rev.id.clone(),
+          group_rev,
+        });
+      }
+    }
+
+    let no_status_group = Some(make_no_status_group(field_rev));
+    GeneratedGroupContext {
+      no_status_group,
+      group_configs,
+    }
+  }
+}
+
+/// Returns the text cell of the row that is moved into the group. The cell will be empty if the
+/// group is the `No status` group.
+pub fn insert_text_group_cell(group_id: &str, field_rev: &FieldRevision) -> CellRevision {
+  if group_id == make_no_status_group(field_rev).id {
+    insert_text_cell("".to_owned(), field_rev)
+  } else {
+    insert_text_cell(group_id.to_owned(), field_rev)
+  }
+}
+
+fn make_group_from_text(text: &str) -> GroupRevision {
+  GroupRevision::new(text.to_owned(), text.to_owned())
+}
+
+fn is_same_text(text: &str, other: &str, case_insensitive: bool) -> bool {
+  if case_insensitive {
+    text.to_lowercase() == other.to_lowercase()
+  } else {
+    text == other
+  }
+}
@@ -2,9 +2,10 @@ use crate::entities::FieldType;
 use crate::services::group::configuration::GroupConfigurationReader;
 use crate::services::group::controller::GroupController;
 use crate::services::group::{
-  CheckboxGroupContext, CheckboxGroupController, DefaultGroupController, GroupConfigurationWriter,
-  MultiSelectGroupController, SelectOptionGroupContext, SingleSelectGroupController,
-  URLGroupContext, URLGroupController,
+  CheckboxGroupContext, CheckboxGroupController, DateGroupContext, DateGroupController,
+  DefaultGroupController, GroupConfigurationWriter, MultiSelectGroupController, NumberGroupContext,
+  NumberGroupController, SelectOptionGroupContext, SingleSelectGroupController, TextGroupContext,
+  TextGroupController, URLGroupContext, URLGroupController,
 };
 use database_model::{
   CheckboxGroupConfigurationRevision, DateGroupConfigurationRevision, FieldRevision,
@@ -100,6 +101,39 @@ where
       let controller = URLGroupController::new(&grouping_field_rev, configuration).await?;
       group_controller = Box::new(controller);
     },
+    FieldType::RichText => {
+      let configuration = TextGroupContext::new(
+        view_id,
+        grouping_field_rev.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = TextGroupController::new(&grouping_field_rev, configuration).await?;
+      group_controller = Box::new(controller);
+    },
+    FieldType::Number | FieldType::AutoIncrementId => {
+      let configuration = NumberGroupContext::new(
+        view_id,
+        grouping_field_rev.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = NumberGroupController::new(&grouping_field_rev, configuration).await?;
+      group_controller = Box::new(controller);
+    },
+    FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+      let configuration = DateGroupContext::new(
+        view_id,
+        grouping_field_rev.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = DateGroupController::new(&grouping_field_rev, configuration).await?;
+      group_controller = Box::new(controller);
+    },
     _ => {
       group_controller = Box::new(DefaultGroupController::new(&grouping_field_rev));
     },
@@ -110,6 +144,18 @@ where
   Ok(group_controller)
 }
 
+/// Returns a [DefaultGroupController] that puts all the rows into the default group. It's used
+/// when the view isn't grouped by a field that the user picked.
+pub fn make_default_group_controller(
+  grouping_field_rev: Arc<FieldRevision>,
+  row_revs: Vec<Arc<RowRevision>>,
+) -> FlowyResult<Box<dyn GroupController>> {
+  let mut group_controller: Box<dyn GroupController> =
+    Box::new(DefaultGroupController::new(&grouping_field_rev));
+  group_controller.fill_groups(&row_revs, &grouping_field_rev)?;
+  Ok(group_controller)
+}
+
 #[tracing::instrument(level = "debug", skip_all)]
 pub fn find_grouping_field(
   field_revs: &[Arc<FieldRevision>],
@@ -154,18 +200,20 @@ pub fn default_group_configuration(field_rev: &FieldRevision) -> GroupConfigurat
       TextGroupConfigurationRevision::default(),
     )
     .unwrap(),
-    FieldType::Number => GroupConfigurationRevision::new(
+    FieldType::Number | FieldType::AutoIncrementId => GroupConfigurationRevision::new(
       field_id,
       field_type_rev,
       NumberGroupConfigurationRevision::default(),
     )
     .unwrap(),
-    FieldType::DateTime => GroupConfigurationRevision::new(
-      field_id,
-      field_type_rev,
-      DateGroupConfigurationRevision::default(),
-    )
-    .unwrap(),
+    FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+      GroupConfigurationRevision::new(
+        field_id,
+        field_type_rev,
+        DateGroupConfigurationRevision::default(),
+      )
+      .unwrap()
+    },
 
     FieldType::SingleSelect => GroupConfigurationRevision::new(
       field_id,
@@ -197,6 +245,14 @@ pub fn default_group_configuration(field_rev: &FieldRevision) -> GroupConfigurat
       URLGroupConfigurationRevision::default(),
     )
     .unwrap(),
+    FieldType::Formula | FieldType::Relation | FieldType::Rollup => {
+      GroupConfigurationRevision::new(
+        field_id,
+        field_type_rev,
+        TextGroupConfigurationRevision::default(),
+      )
+      .unwrap()
+    },
   }
 }
 
@@ -1,5 +1,6 @@
 mod util;
 
+pub mod calculation;
 pub mod cell;
 pub mod database;
 pub mod database_view;
@@ -8,5 +9,7 @@ pub mod filter;
 pub mod group;
 pub mod persistence;
 pub mod row;
+pub mod search;
 pub mod setting;
+pub mod share;
 pub mod sort;
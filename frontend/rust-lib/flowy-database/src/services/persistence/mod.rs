@@ -7,6 +7,8 @@ pub mod database_ref;
 pub mod kv;
 pub mod migration;
 pub mod rev_sqlite;
+pub mod row_activity;
+pub mod row_comment;
 
 pub trait DatabaseDBConnection: Send + Sync {
   fn get_db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
@@ -29,6 +29,12 @@ impl SQLiteDatabaseRevisionSnapshotPersistence {
 }
 
 impl RevisionSnapshotPersistence for SQLiteDatabaseRevisionSnapshotPersistence {
+  /// The snapshots of the database, its blocks and its views are written together as the
+  /// database's checkpoints, so they are never generated on their own.
+  fn should_generate_snapshot_from_range(&self, _start_rev_id: i64, _current_rev_id: i64) -> bool {
+    false
+  }
+
   fn write_snapshot(&self, rev_id: i64, data: Vec<u8>) -> FlowyResult<()> {
     let conn = self.pool.get().map_err(internal_error)?;
     let snapshot_id = self.gen_snapshot_id(rev_id);
@@ -92,6 +98,15 @@ impl RevisionSnapshotPersistence for SQLiteDatabaseRevisionSnapshotPersistence {
             .first::<GridSnapshotRecord>(&*conn)?;
     Ok(Some(latest_record.into()))
   }
+
+  fn read_snapshots(&self) -> FlowyResult<Vec<RevisionSnapshotData>> {
+    let conn = self.pool.get().map_err(internal_error)?;
+    let records = dsl::grid_rev_snapshot
+      .filter(dsl::object_id.eq(&self.object_id))
+      .order(dsl::rev_id.asc())
+      .load::<GridSnapshotRecord>(&*conn)?;
+    Ok(records.into_iter().map(|record| record.into()).collect())
+  }
 }
 
 #[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
@@ -0,0 +1,128 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::FlowyResult;
+use flowy_sqlite::{
+  prelude::*,
+  schema::{database_row_activity, database_row_activity::dsl},
+};
+use std::sync::Arc;
+
+diesel::no_arg_sql_function!(
+  last_insert_rowid,
+  diesel::sql_types::Integer,
+  "Returns the id of the last inserted row of the connection"
+);
+
+/// Records the changes of the cells, so the previous values of the cells can be traced after
+/// they are overwritten.
+pub struct RowActivityPersistence {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+impl RowActivityPersistence {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  /// Inserts the activity and returns it with the id that is generated by the database.
+  pub fn insert(&self, mut activity: RowActivity) -> FlowyResult<RowActivity> {
+    let conn = self.database.get_db_connection()?;
+    let _ = diesel::insert_into(database_row_activity::table)
+      .values((
+        database_row_activity::database_id.eq(&activity.database_id),
+        database_row_activity::row_id.eq(&activity.row_id),
+        database_row_activity::field_id.eq(&activity.field_id),
+        database_row_activity::old_value.eq(&activity.old_value),
+        database_row_activity::new_value.eq(&activity.new_value),
+        database_row_activity::user_id.eq(&activity.user_id),
+        database_row_activity::timestamp.eq(activity.timestamp),
+      ))
+      .execute(&*conn)?;
+    activity.id = diesel::select(last_insert_rowid).get_result::<i32>(&*conn)?;
+    Ok(activity)
+  }
+
+  /// Returns the activities of the row, the latest first.
+  pub fn get_row_activities(&self, row_id: &str) -> FlowyResult<Vec<RowActivity>> {
+    let conn = self.database.get_db_connection()?;
+    let activities = dsl::database_row_activity
+      .filter(database_row_activity::row_id.eq(row_id))
+      .order(database_row_activity::id.desc())
+      .load::<RowActivityRecord>(&*conn)?
+      .into_iter()
+      .map(|record| record.into())
+      .collect::<Vec<_>>();
+    Ok(activities)
+  }
+
+  pub fn delete_row_activities(&self, row_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(dsl::database_row_activity.filter(database_row_activity::row_id.eq(row_id)))
+      .execute(&*conn)?;
+    Ok(())
+  }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Associations)]
+#[table_name = "database_row_activity"]
+struct RowActivityRecord {
+  id: i32,
+  database_id: String,
+  row_id: String,
+  field_id: String,
+  old_value: String,
+  new_value: String,
+  user_id: String,
+  timestamp: i64,
+}
+
+/// The change of the cell. The values are the display strings of the cell before and after
+/// the change. The id is generated by the database after the activity is inserted.
+#[derive(Debug, Clone)]
+pub struct RowActivity {
+  pub id: i32,
+  pub database_id: String,
+  pub row_id: String,
+  pub field_id: String,
+  pub old_value: String,
+  pub new_value: String,
+  pub user_id: String,
+  pub timestamp: i64,
+}
+
+impl RowActivity {
+  pub fn new(
+    database_id: &str,
+    row_id: &str,
+    field_id: &str,
+    old_value: String,
+    new_value: String,
+    user_id: &str,
+  ) -> Self {
+    Self {
+      id: 0,
+      database_id: database_id.to_owned(),
+      row_id: row_id.to_owned(),
+      field_id: field_id.to_owned(),
+      old_value,
+      new_value,
+      user_id: user_id.to_owned(),
+      timestamp: chrono::Utc::now().timestamp(),
+    }
+  }
+}
+
+impl std::convert::From<RowActivityRecord> for RowActivity {
+  fn from(record: RowActivityRecord) -> Self {
+    Self {
+      id: record.id,
+      database_id: record.database_id,
+      row_id: record.row_id,
+      field_id: record.field_id,
+      old_value: record.old_value,
+      new_value: record.new_value,
+      user_id: record.user_id,
+      timestamp: record.timestamp,
+    }
+  }
+}
@@ -0,0 +1,143 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::FlowyResult;
+use flowy_sqlite::{
+  prelude::*,
+  schema::{database_row_comment, database_row_comment::dsl},
+};
+use nanoid::nanoid;
+use std::sync::Arc;
+
+/// Stores the comments that are attached to the rows.
+pub struct RowCommentPersistence {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+impl RowCommentPersistence {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  pub fn insert(&self, comment: RowComment) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    let record: RowCommentRecord = comment.into();
+    let _ = diesel::insert_into(database_row_comment::table)
+      .values(record)
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  /// Updates the content of the comment and returns the updated comment.
+  pub fn update_content(&self, comment_id: &str, content: &str) -> FlowyResult<RowComment> {
+    let conn = self.database.get_db_connection()?;
+    let _ =
+      diesel::update(dsl::database_row_comment.filter(database_row_comment::id.eq(comment_id)))
+        .set((
+          database_row_comment::content.eq(content),
+          database_row_comment::modified_at.eq(chrono::Utc::now().timestamp()),
+        ))
+        .execute(&*conn)?;
+    self.get_comment(comment_id)
+  }
+
+  pub fn get_comment(&self, comment_id: &str) -> FlowyResult<RowComment> {
+    let conn = self.database.get_db_connection()?;
+    let record = dsl::database_row_comment
+      .filter(database_row_comment::id.eq(comment_id))
+      .first::<RowCommentRecord>(&*conn)?;
+    Ok(record.into())
+  }
+
+  /// Returns the comments of the row, the earliest first.
+  pub fn get_row_comments(&self, row_id: &str) -> FlowyResult<Vec<RowComment>> {
+    let conn = self.database.get_db_connection()?;
+    let comments = dsl::database_row_comment
+      .filter(database_row_comment::row_id.eq(row_id))
+      .order(database_row_comment::created_at.asc())
+      .load::<RowCommentRecord>(&*conn)?
+      .into_iter()
+      .map(|record| record.into())
+      .collect::<Vec<_>>();
+    Ok(comments)
+  }
+
+  pub fn delete_comment(&self, comment_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(dsl::database_row_comment.filter(database_row_comment::id.eq(comment_id)))
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn delete_row_comments(&self, row_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(dsl::database_row_comment.filter(database_row_comment::row_id.eq(row_id)))
+      .execute(&*conn)?;
+    Ok(())
+  }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "database_row_comment"]
+struct RowCommentRecord {
+  id: String,
+  database_id: String,
+  row_id: String,
+  content: String,
+  user_id: String,
+  created_at: i64,
+  modified_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RowComment {
+  pub id: String,
+  pub database_id: String,
+  pub row_id: String,
+  pub content: String,
+  pub user_id: String,
+  pub created_at: i64,
+  pub modified_at: i64,
+}
+
+impl RowComment {
+  pub fn new(database_id: &str, row_id: &str, content: String, user_id: &str) -> Self {
+    let timestamp = chrono::Utc::now().timestamp();
+    Self {
+      id: nanoid!(10),
+      database_id: database_id.to_owned(),
+      row_id: row_id.to_owned(),
+      content,
+      user_id: user_id.to_owned(),
+      created_at: timestamp,
+      modified_at: timestamp,
+    }
+  }
+}
+
+impl std::convert::From<RowComment> for RowCommentRecord {
+  fn from(comment: RowComment) -> Self {
+    Self {
+      id: comment.id,
+      database_id: comment.database_id,
+      row_id: comment.row_id,
+      content: comment.content,
+      user_id: comment.user_id,
+      created_at: comment.created_at,
+      modified_at: comment.modified_at,
+    }
+  }
+}
+
+impl std::convert::From<RowCommentRecord> for RowComment {
+  fn from(record: RowCommentRecord) -> Self {
+    Self {
+      id: record.id,
+      database_id: record.database_id,
+      row_id: record.row_id,
+      content: record.content,
+      user_id: record.user_id,
+      created_at: record.created_at,
+      modified_at: record.modified_at,
+    }
+  }
+}
@@ -1,12 +1,13 @@
 use crate::services::cell::{
-  insert_checkbox_cell, insert_date_cell, insert_number_cell, insert_select_option_cell,
-  insert_text_cell, insert_url_cell, FromCellString,
+  insert_checkbox_cell, insert_date_cell, insert_number_cell, insert_relation_cell,
+  insert_select_option_cell, insert_text_cell, insert_url_cell, make_system_cell, FromCellString,
 };
 
 use crate::entities::FieldType;
-use crate::services::field::{CheckboxCellData, DateCellData, SelectOptionIds};
+use crate::services::field::{CheckboxCellData, DateCellData, RelationCellData, SelectOptionIds};
 use database_model::{gen_row_id, CellRevision, FieldRevision, RowRevision, DEFAULT_ROW_HEIGHT};
 use indexmap::IndexMap;
+use lib_infra::util::timestamp;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -36,6 +37,8 @@ impl RowRevisionBuilder {
       cell_by_field_id: Default::default(),
       height: DEFAULT_ROW_HEIGHT,
       visibility: true,
+      created_at: timestamp(),
+      auto_increment_id: 0,
     };
 
     let block_id = block_id.to_string();
@@ -78,9 +81,19 @@ impl RowRevisionBuilder {
               builder.insert_select_option_cell(&field_id, ids.into_inner());
             }
           },
+          // The formula and rollup cells are calculated from the other cells.
+          FieldType::Formula | FieldType::Rollup => {},
+          // The system cells are filled from the metadata of the row when it's built.
+          FieldType::CreatedTime | FieldType::LastEditedTime | FieldType::AutoIncrementId => {},
+          FieldType::Relation => {
+            if let Ok(cell_data) = RelationCellData::from_cell_str(&cell_data) {
+              builder.insert_relation_cell(&field_id, cell_data.row_ids);
+            }
+          },
         }
       }
     }
+
     builder
   }
 
@@ -108,7 +121,7 @@ impl RowRevisionBuilder {
     }
   }
 
-  pub fn insert_number_cell(&mut self, field_id: &str, num: i64) {
+  pub fn insert_number_cell<T: ToString>(&mut self, field_id: &str, num: T) {
     match self.field_rev_map.get(&field_id.to_owned()) {
       None => tracing::warn!("Can't find the number field with id: {}", field_id),
       Some(field_rev) => {
@@ -156,6 +169,25 @@ impl RowRevisionBuilder {
     }
   }
 
+  pub fn insert_relation_cell(&mut self, field_id: &str, row_ids: Vec<String>) {
+    match self.field_rev_map.get(&field_id.to_owned()) {
+      None => tracing::warn!("Can't find the relation field with id: {}", field_id),
+      Some(field_rev) => {
+        self.payload.cell_by_field_id.insert(
+          field_id.to_owned(),
+          insert_relation_cell(row_ids, field_rev),
+        );
+      },
+    }
+  }
+
+  /// Sets the auto increment id of the row. The rows that are created by the block editor get the
+  /// next id of the block, so it only needs to be set for the rows that are added to a new
+  /// database, for example, the imported rows.
+  pub fn set_auto_increment_id(&mut self, auto_increment_id: i64) {
+    self.payload.auto_increment_id = auto_increment_id;
+  }
+
   #[allow(dead_code)]
   pub fn height(mut self, height: i32) -> Self {
     self.payload.height = height;
@@ -168,14 +200,27 @@ impl RowRevisionBuilder {
     self
   }
 
+  /// Builds the row and fills the cells of the system fields from the metadata of the row.
   pub fn build(self) -> RowRevision {
-    RowRevision {
+    let mut row_rev = RowRevision {
       id: self.payload.row_id,
       block_id: self.block_id,
       cells: self.payload.cell_by_field_id,
       height: self.payload.height,
       visibility: self.payload.visibility,
+      created_at: self.payload.created_at,
+      last_modified: self.payload.created_at,
+      auto_increment_id: self.payload.auto_increment_id,
+      document_id: None,
+    };
+
+    for field_rev in self.field_rev_map.values() {
+      let field_type: FieldType = field_rev.ty.into();
+      if let Some(cell_rev) = make_system_cell(&field_type, &row_rev) {
+        row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+      }
     }
+    row_rev
   }
 }
 
@@ -184,4 +229,6 @@ pub struct CreateRowRevisionPayload {
   pub cell_by_field_id: IndexMap<String, CellRevision>,
   pub height: i32,
   pub visibility: bool,
+  pub created_at: i64,
+  pub auto_increment_id: i64,
 }
@@ -0,0 +1,3 @@
+mod row_search;
+
+pub use row_search::*;
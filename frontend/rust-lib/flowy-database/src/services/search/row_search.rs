@@ -0,0 +1,161 @@
+use crate::entities::FieldType;
+use crate::services::cell::{AtomicCellDataCache, TypeCellData};
+use crate::services::field::{TypeOptionCellDataHandler, TypeOptionCellExt};
+use database_model::{FieldRevision, RowRevision};
+use std::ops::Range;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowSearchResult {
+  pub row_id: String,
+  pub cells: Vec<CellSearchResult>,
+}
+
+/// The `ranges` are the positions of the matched text in the display string of the cell. They
+/// are measured in UTF-16 code units, which is how the strings are indexed by the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellSearchResult {
+  pub field_id: String,
+  pub ranges: Vec<Range<usize>>,
+}
+
+/// [RowSearch] searches the query in the cells of the rows. Each cell is matched against the
+/// string that is displayed on screen, so the select options, dates and numbers are matched by
+/// their names and formatted strings instead of the raw cell data.
+pub struct RowSearch<'a> {
+  field_revs: &'a [Arc<FieldRevision>],
+  cell_data_cache: Option<AtomicCellDataCache>,
+}
+
+impl<'a> RowSearch<'a> {
+  pub fn new(
+    field_revs: &'a [Arc<FieldRevision>],
+    cell_data_cache: Option<AtomicCellDataCache>,
+  ) -> Self {
+    Self {
+      field_revs,
+      cell_data_cache,
+    }
+  }
+
+  /// Returns the rows that contain the query, in the same order as the `row_revs`. The query is
+  /// matched case-insensitively, and an empty query matches nothing.
+  pub fn search(&self, query: &str, row_revs: &[Arc<RowRevision>]) -> Vec<RowSearchResult> {
+    let query = query.trim();
+    if query.is_empty() {
+      return vec![];
+    }
+
+    // Create the handler for each field once instead of creating it for each cell.
+    let handlers = self
+      .field_revs
+      .iter()
+      .filter_map(|field_rev| {
+        let field_type: FieldType = field_rev.ty.into();
+        let handler = TypeOptionCellExt::new_with_cell_data_cache(
+          field_rev.as_ref(),
+          self.cell_data_cache.clone(),
+        )
+        .get_type_option_cell_data_handler(&field_type)?;
+        Some((field_rev, handler))
+      })
+      .collect::<Vec<(&Arc<FieldRevision>, Box<dyn TypeOptionCellDataHandler>)>>();
+
+    row_revs
+      .iter()
+      .filter_map(|row_rev| {
+        let cells = handlers
+          .iter()
+          .filter_map(|(field_rev, handler)| {
+            let cell_rev = row_rev.cells.get(&field_rev.id)?;
+            let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+            let display_str = handler.stringify_cell_str(
+              type_cell_data.cell_str,
+              &type_cell_data.field_type,
+              field_rev.as_ref(),
+            );
+            let ranges = find_matched_ranges(&display_str, query);
+            if ranges.is_empty() {
+              None
+            } else {
+              Some(CellSearchResult {
+                field_id: field_rev.id.clone(),
+                ranges,
+              })
+            }
+          })
+          .collect::<Vec<CellSearchResult>>();
+
+        if cells.is_empty() {
+          None
+        } else {
+          Some(RowSearchResult {
+            row_id: row_rev.id.clone(),
+            cells,
+          })
+        }
+      })
+      .collect()
+  }
+}
+
+/// Returns the non-overlapping ranges of the `query` in the `text`, ignoring the case. The
+/// ranges are measured in UTF-16 code units.
+pub fn find_matched_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+  let query = query.chars().map(fold_case).collect::<Vec<char>>();
+  if query.is_empty() {
+    return vec![];
+  }
+
+  let chars = text.chars().collect::<Vec<char>>();
+  // offsets[i] is the UTF-16 offset of the i-th char
+  let mut offsets = Vec::with_capacity(chars.len() + 1);
+  let mut offset = 0;
+  for c in &chars {
+    offsets.push(offset);
+    offset += c.len_utf16();
+  }
+  offsets.push(offset);
+
+  let mut ranges = vec![];
+  let mut index = 0;
+  while index + query.len() <= chars.len() {
+    let is_matched = chars[index..index + query.len()]
+      .iter()
+      .zip(query.iter())
+      .all(|(c, q)| fold_case(*c) == *q);
+
+    if is_matched {
+      ranges.push(offsets[index]..offsets[index + query.len()]);
+      index += query.len();
+    } else {
+      index += 1;
+    }
+  }
+  ranges
+}
+
+fn fold_case(c: char) -> char {
+  c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::search::find_matched_ranges;
+
+  #[test]
+  fn find_matched_ranges_test() {
+    assert_eq!(find_matched_ranges("AppFlowy", "flow"), vec![3..7]);
+    assert_eq!(find_matched_ranges("abcABC", "abc"), vec![0..3, 3..6]);
+    assert_eq!(find_matched_ranges("aaa", "aa"), vec![0..2]);
+    assert_eq!(find_matched_ranges("AppFlowy", "notion"), vec![]);
+    assert_eq!(find_matched_ranges("AppFlowy", ""), vec![]);
+  }
+
+  #[test]
+  fn find_matched_ranges_utf16_test() {
+    // The emoji takes two UTF-16 code units
+    assert_eq!(find_matched_ranges("😀 Café", "café"), vec![3..7]);
+    assert_eq!(find_matched_ranges("日本語", "本"), vec![1..2]);
+  }
+}
@@ -17,6 +17,8 @@ impl GridSettingChangesetBuilder {
       delete_group: None,
       alert_sort: None,
       delete_sort: None,
+      insert_filter_group: None,
+      delete_filter_group: None,
     };
     Self { params }
   }
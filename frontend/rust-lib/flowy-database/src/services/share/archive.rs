@@ -0,0 +1,155 @@
+use crate::entities::FieldType;
+use crate::services::cell::{FromCellString, TypeCellData};
+use crate::services::field::{RelationCellData, RelationTypeOptionPB};
+use database_model::{
+  gen_block_id, gen_database_id, gen_row_document_id, gen_row_id, DatabaseBlockMetaRevision,
+  DatabaseBlockRevision, DatabaseViewRevision, FieldRevision, FieldTypeRevision,
+  TypeOptionDataSerializer,
+};
+use flowy_error::{internal_error, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The databases of the views in the workspace archive. Each database is archived once, no
+/// matter how many views are linked to it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatabaseArchive {
+  pub databases: Vec<ArchivedDatabase>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchivedDatabase {
+  pub database_id: String,
+  pub fields: Vec<FieldRevision>,
+  pub block_metas: Vec<DatabaseBlockMetaRevision>,
+  pub blocks: Vec<DatabaseBlockRevision>,
+
+  /// The views of the database in JSON format. Each one can be deserialized into
+  /// [DatabaseViewRevision]. The first one is the base view of the database.
+  pub views: Vec<String>,
+
+  /// The contents of the rows' documents, keyed by the ids of the documents.
+  #[serde(default)]
+  pub row_documents: HashMap<String, String>,
+}
+
+impl DatabaseArchive {
+  /// Replaces the ids of the databases, blocks, rows and rows' documents with new ones, and the
+  /// ids of the views with the ones in the `view_id_map`. The views that are not in the map are
+  /// dropped. The references to the replaced ids are rewritten too, which are the databases of
+  /// the relation fields and the rows of the relation cells.
+  pub fn regenerate_ids(&mut self, view_id_map: &HashMap<String, String>) -> FlowyResult<()> {
+    let database_id_map = self
+      .databases
+      .iter()
+      .map(|database| (database.database_id.clone(), gen_database_id()))
+      .collect::<HashMap<String, String>>();
+    let row_id_map = self
+      .databases
+      .iter()
+      .flat_map(|database| database.blocks.iter())
+      .flat_map(|block| block.rows.iter())
+      .map(|row| (row.id.clone(), gen_row_id()))
+      .collect::<HashMap<String, String>>();
+
+    for database in self.databases.iter_mut() {
+      let database_id = database_id_map[&database.database_id].clone();
+      database.database_id = database_id.clone();
+
+      let relation_field_ids =
+        regenerate_relation_type_options(&mut database.fields, &database_id_map);
+
+      let block_id_map = database
+        .block_metas
+        .iter_mut()
+        .map(|block_meta| {
+          let block_id = gen_block_id();
+          let old_block_id = std::mem::replace(&mut block_meta.block_id, block_id.clone());
+          (old_block_id, block_id)
+        })
+        .collect::<HashMap<String, String>>();
+
+      let mut row_documents = HashMap::new();
+      for block in database.blocks.iter_mut() {
+        let block_id = match block_id_map.get(&block.block_id) {
+          None => gen_block_id(),
+          Some(block_id) => block_id.clone(),
+        };
+        block.block_id = block_id.clone();
+        for row in block.rows.iter_mut() {
+          let row = Arc::make_mut(row);
+          row.id = row_id_map[&row.id].clone();
+          row.block_id = block_id.clone();
+          if let Some(document_id) = row.document_id.take() {
+            if let Some(content) = database.row_documents.remove(&document_id) {
+              let document_id = gen_row_document_id();
+              row_documents.insert(document_id.clone(), content);
+              row.document_id = Some(document_id);
+            }
+          }
+          for field_id in relation_field_ids.iter() {
+            if let Some(cell_rev) = row.cells.get_mut(field_id) {
+              cell_rev.type_cell_data =
+                regenerate_relation_cell(&cell_rev.type_cell_data, &row_id_map);
+            }
+          }
+        }
+      }
+      database.row_documents = row_documents;
+
+      let mut views = vec![];
+      for view in database.views.drain(..) {
+        let mut view_rev = DatabaseViewRevision::from_json(view).map_err(internal_error)?;
+        if let Some(view_id) = view_id_map.get(&view_rev.view_id) {
+          view_rev.view_id = view_id.clone();
+          view_rev.database_id = database_id.clone();
+          view_rev.is_base = views.is_empty();
+          views.push(serde_json::to_string(&view_rev).map_err(internal_error)?);
+        }
+      }
+      database.views = views;
+    }
+    Ok(())
+  }
+}
+
+/// Points the relation fields to the new ids of the databases, and returns the ids of these
+/// fields. The databases that are not in the archive keep their ids.
+fn regenerate_relation_type_options(
+  fields: &mut [FieldRevision],
+  database_id_map: &HashMap<String, String>,
+) -> Vec<String> {
+  let field_type_rev: FieldTypeRevision = FieldType::Relation.into();
+  let mut relation_field_ids = vec![];
+  for field_rev in fields.iter_mut() {
+    if let Some(mut type_option) = field_rev.get_type_option::<RelationTypeOptionPB>(field_type_rev)
+    {
+      if let Some(database_id) = database_id_map.get(&type_option.database_id) {
+        type_option.database_id = database_id.clone();
+        field_rev.insert_type_option_str(&field_type_rev, type_option.json_str());
+      }
+      relation_field_ids.push(field_rev.id.clone());
+    }
+  }
+  relation_field_ids
+}
+
+fn regenerate_relation_cell(type_cell_data: &str, row_id_map: &HashMap<String, String>) -> String {
+  let mut cell_data = match TypeCellData::from_json_str(type_cell_data) {
+    Ok(cell_data) if cell_data.is_relation() => cell_data,
+    _ => return type_cell_data.to_owned(),
+  };
+  match RelationCellData::from_cell_str(&cell_data.cell_str) {
+    Ok(relation) => {
+      let row_ids = relation
+        .row_ids
+        .into_iter()
+        .map(|row_id| row_id_map.get(&row_id).cloned().unwrap_or(row_id))
+        .collect::<Vec<String>>();
+      cell_data.cell_str = RelationCellData::from(row_ids).to_string();
+      cell_data.to_json()
+    },
+    Err(_) => type_cell_data.to_owned(),
+  }
+}
@@ -0,0 +1,39 @@
+use crate::entities::CellIdParams;
+use crate::services::database::DatabaseEditor;
+use flowy_error::{internal_error, FlowyResult};
+
+pub struct CSVExport;
+
+impl CSVExport {
+  /// Exports the rows of the view as CSV. The first record is the header that contains the names
+  /// of the fields that are visible in the view, in the view's order. The rows are filtered and
+  /// sorted by the view, and each cell is exported as the string that is displayed in the cell.
+  pub async fn export_database(
+    &self,
+    view_id: &str,
+    database_editor: &DatabaseEditor,
+  ) -> FlowyResult<String> {
+    let field_revs = database_editor.get_visible_field_revs(view_id).await?;
+    let row_revs = database_editor.get_all_row_revs(view_id).await?;
+
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer
+      .write_record(field_revs.iter().map(|field_rev| &field_rev.name))
+      .map_err(internal_error)?;
+    for row_rev in row_revs {
+      let mut record = Vec::with_capacity(field_revs.len());
+      for field_rev in &field_revs {
+        let params = CellIdParams {
+          view_id: view_id.to_owned(),
+          field_id: field_rev.id.clone(),
+          row_id: row_rev.id.clone(),
+        };
+        record.push(database_editor.get_cell_display_str(&params).await);
+      }
+      writer.write_record(&record).map_err(internal_error)?;
+    }
+
+    let data = writer.into_inner().map_err(internal_error)?;
+    String::from_utf8(data).map_err(internal_error)
+  }
+}
@@ -0,0 +1,276 @@
+use crate::entities::FieldType;
+use crate::services::field::{
+  new_select_option_color, DateCellData, FieldBuilder, MultiSelectTypeOptionBuilder,
+  RichTextTypeOptionBuilder, SelectOptionPB, SingleSelectTypeOptionBuilder,
+  SELECTION_IDS_SEPARATOR,
+};
+use crate::services::row::RowRevisionBuilder;
+use chrono::NaiveDate;
+use database_model::{BuildDatabaseContext, FieldRevision};
+use flowy_client_sync::client_database::DatabaseBuilder;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use lazy_static::lazy_static;
+use regex::Regex;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// The column is imported as a select field only if the number of its distinct values doesn't
+/// exceed this limit.
+const MAX_NUMBER_OF_SELECT_OPTIONS: usize = 20;
+
+/// The formats that are tried when parsing a date. They cover the formats produced by the
+/// [DateFormat](crate::services::field::DateFormat).
+const DATE_FORMATS: [&str; 5] = ["%Y-%m-%d", "%Y/%m/%d", "%m/%d/%Y", "%b %d, %Y", "%b %d,%Y"];
+
+lazy_static! {
+  /// Matches the numbers whose integer part is grouped into thousands by commas, e.g. "1,200.5".
+  static ref THOUSANDS_SEPARATED_NUMBER_REGEX: Regex =
+    Regex::new(r"^[+-]?\d{1,3}(,\d{3})+(\.\d+)?$").unwrap();
+}
+
+pub struct CSVImporter;
+
+impl CSVImporter {
+  /// Builds a database from the CSV content. The first record is the header that contains the
+  /// names of the fields. The first column becomes the primary field, and the types of the other
+  /// fields are inferred from the contents of their columns.
+  pub fn import_csv_from_string(&self, content: &str) -> FlowyResult<BuildDatabaseContext> {
+    let mut reader = csv::ReaderBuilder::new()
+      .flexible(true)
+      .from_reader(content.as_bytes());
+    let header = reader
+      .headers()
+      .map_err(internal_error)?
+      .iter()
+      .map(|name| name.trim().to_owned())
+      .collect::<Vec<String>>();
+    if header.is_empty() {
+      return Err(FlowyError::invalid_data().context("The CSV content doesn't have a header"));
+    }
+
+    let rows = reader
+      .records()
+      .map(|record| {
+        record.map(|record| {
+          record
+            .iter()
+            .map(|cell| cell.trim().to_owned())
+            .collect::<Vec<String>>()
+        })
+      })
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(internal_error)?;
+
+    let mut database_builder = DatabaseBuilder::new();
+    let mut columns = vec![];
+    for (index, name) in header.iter().enumerate() {
+      let cells = rows
+        .iter()
+        .map(|row| row.get(index).map(|cell| cell.as_str()).unwrap_or_default())
+        .collect::<Vec<&str>>();
+      let column = ImportedColumn::new(index, name, &cells);
+      database_builder.add_field(column.field_rev.clone());
+      columns.push(column);
+    }
+
+    let block_id = database_builder.block_id().to_owned();
+    let field_revs = database_builder.field_revs().clone();
+    for row in &rows {
+      let mut row_builder = RowRevisionBuilder::new(&block_id, field_revs.clone());
+      row_builder.set_auto_increment_id(database_builder.next_auto_increment_id());
+      for (column, cell) in columns.iter().zip(row.iter()) {
+        if !cell.is_empty() {
+          column.insert_cell(&mut row_builder, cell);
+        }
+      }
+      database_builder.add_row(row_builder.build());
+    }
+    Ok(database_builder.build())
+  }
+}
+
+struct ImportedColumn {
+  field_rev: FieldRevision,
+  field_type: FieldType,
+  options: Vec<SelectOptionPB>,
+}
+
+impl ImportedColumn {
+  fn new(index: usize, name: &str, cells: &[&str]) -> Self {
+    let name = if name.is_empty() {
+      format!("Field {}", index + 1)
+    } else {
+      name.to_owned()
+    };
+    // The primary field is always a text field.
+    let is_primary = index == 0;
+    let field_type = if is_primary {
+      FieldType::RichText
+    } else {
+      infer_field_type(cells)
+    };
+
+    let mut options: Vec<SelectOptionPB> = vec![];
+    if field_type.is_select_option() {
+      for option_name in distinct_options(cells) {
+        let color = new_select_option_color(&options);
+        options.push(SelectOptionPB::with_color(option_name, color));
+      }
+    }
+
+    let field_builder = match field_type {
+      FieldType::RichText => FieldBuilder::new(RichTextTypeOptionBuilder::default()),
+      FieldType::SingleSelect => FieldBuilder::new(options.iter().fold(
+        SingleSelectTypeOptionBuilder::default(),
+        |builder, option| builder.add_option(option.clone()),
+      )),
+      FieldType::MultiSelect => FieldBuilder::new(options.iter().fold(
+        MultiSelectTypeOptionBuilder::default(),
+        |builder, option| builder.add_option(option.clone()),
+      )),
+      _ => FieldBuilder::from_field_type(&field_type),
+    };
+    let field_rev = field_builder
+      .name(&name)
+      .visibility(true)
+      .primary(is_primary)
+      .build();
+
+    Self {
+      field_rev,
+      field_type,
+      options,
+    }
+  }
+
+  fn insert_cell(&self, row_builder: &mut RowRevisionBuilder, cell: &str) {
+    let field_id = &self.field_rev.id;
+    match self.field_type {
+      FieldType::Number => {
+        if let Some(num) = parse_number(cell) {
+          row_builder.insert_number_cell(field_id, num);
+        }
+      },
+      FieldType::DateTime => {
+        if let Some(timestamp) = parse_date(cell) {
+          let date_cell_data = DateCellData {
+            timestamp: Some(timestamp),
+            include_time: false,
+            overrides: vec![],
+            end_timestamp: None,
+            timezone_id: "".to_string(),
+          };
+          row_builder.insert_date_cell(field_id, date_cell_data);
+        }
+      },
+      FieldType::Checkbox => {
+        row_builder.insert_checkbox_cell(field_id, parse_checkbox(cell).unwrap_or(false))
+      },
+      FieldType::URL => row_builder.insert_url_cell(field_id, cell.to_owned()),
+      FieldType::SingleSelect | FieldType::MultiSelect => {
+        let option_ids = split_options(cell)
+          .filter_map(|name| self.options.iter().find(|option| option.name == name))
+          .map(|option| option.id.clone())
+          .collect::<Vec<String>>();
+        row_builder.insert_select_option_cell(field_id, option_ids);
+      },
+      _ => row_builder.insert_text_cell(field_id, cell.to_owned()),
+    }
+  }
+}
+
+/// Infers the type of the field from the non-empty cells of the column. The column is a text
+/// field if its cells don't share any other type.
+fn infer_field_type(cells: &[&str]) -> FieldType {
+  let values = cells
+    .iter()
+    .filter(|cell| !cell.is_empty())
+    .copied()
+    .collect::<Vec<&str>>();
+  if values.is_empty() {
+    return FieldType::RichText;
+  }
+
+  if values.iter().all(|value| parse_checkbox(value).is_some()) {
+    return FieldType::Checkbox;
+  }
+  if values.iter().all(|value| parse_number(value).is_some()) {
+    return FieldType::Number;
+  }
+  if values.iter().all(|value| parse_date(value).is_some()) {
+    return FieldType::DateTime;
+  }
+  if values.iter().all(|value| is_url(value)) {
+    return FieldType::URL;
+  }
+
+  // The values of a select field are expected to repeat.
+  let number_of_options = distinct_options(&values).len();
+  if number_of_options <= MAX_NUMBER_OF_SELECT_OPTIONS && number_of_options < values.len() {
+    if values.iter().any(|value| split_options(value).count() > 1) {
+      return FieldType::MultiSelect;
+    }
+    return FieldType::SingleSelect;
+  }
+  FieldType::RichText
+}
+
+fn parse_checkbox(value: &str) -> Option<bool> {
+  match value.to_lowercase().as_str() {
+    "yes" | "true" => Some(true),
+    "no" | "false" => Some(false),
+    _ => None,
+  }
+}
+
+/// Parses the number, ignoring the currency symbols and the thousands separators.
+/// Parses the number, ignoring the currency symbols. The commas are only ignored if they separate
+/// the thousands, so "1,5" isn't a number.
+fn parse_number(value: &str) -> Option<Decimal> {
+  let value = value
+    .chars()
+    .filter(|c| !matches!(c, '$' | '€' | '£' | '¥'))
+    .collect::<String>();
+  let value = value.trim();
+  if THOUSANDS_SEPARATED_NUMBER_REGEX.is_match(value) {
+    Decimal::from_str(&value.replace(',', "")).ok()
+  } else {
+    Decimal::from_str(value).ok()
+  }
+}
+
+/// Returns the timestamp of the date.
+fn parse_date(value: &str) -> Option<i64> {
+  if let Ok(date_time) = chrono::DateTime::parse_from_rfc3339(value) {
+    return Some(date_time.timestamp());
+  }
+  DATE_FORMATS
+    .iter()
+    .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+    .and_then(|date| date.and_hms_opt(0, 0, 0))
+    .map(|date_time| date_time.timestamp())
+}
+
+fn is_url(value: &str) -> bool {
+  url::Url::parse(value)
+    .map(|url| matches!(url.scheme(), "http" | "https"))
+    .unwrap_or(false)
+}
+
+fn split_options(value: &str) -> impl Iterator<Item = &str> {
+  value
+    .split(SELECTION_IDS_SEPARATOR)
+    .map(|name| name.trim())
+    .filter(|name| !name.is_empty())
+}
+
+/// Returns the distinct options of the cells in the order of their first appearance.
+fn distinct_options<'a>(cells: &[&'a str]) -> Vec<&'a str> {
+  let mut options = vec![];
+  for option in cells.iter().copied().flat_map(split_options) {
+    if !options.contains(&option) {
+      options.push(option);
+    }
+  }
+  options
+}
@@ -0,0 +1,4 @@
+mod archive;
+pub mod csv;
+
+pub use archive::*;
@@ -166,6 +166,15 @@ impl SortController {
       .await;
   }
 
+  /// Replaces the sorts, for example, with the sorts of the view that is restored to a
+  /// checkpoint, and sorts the rows again.
+  pub async fn did_restore_sorts(&mut self, sorts: Vec<Arc<SortRevision>>) {
+    self.sorts = sorts;
+    self
+      .gen_task(SortEvent::SortDidChanged, QualityOfService::Background)
+      .await;
+  }
+
   pub async fn did_update_view_field_type_option(&self, _field_rev: &FieldRevision) {
     //
   }
@@ -1,7 +1,10 @@
 use crate::entities::FieldType;
 use crate::services::field::*;
 use crate::services::row::RowRevisionBuilder;
-use database_model::{BuildDatabaseContext, CalendarLayoutSetting, LayoutRevision, LayoutSetting};
+use database_model::{
+  BuildDatabaseContext, CalendarLayoutSetting, GalleryCoverSource, GalleryLayoutSetting,
+  LayoutRevision, LayoutSetting, TimelineLayoutSetting,
+};
 use flowy_client_sync::client_database::DatabaseBuilder;
 
 pub fn make_default_grid() -> BuildDatabaseContext {
@@ -66,6 +69,7 @@ pub fn make_default_board() -> BuildDatabaseContext {
       database_builder.block_id(),
       database_builder.field_revs().clone(),
     );
+    row_builder.set_auto_increment_id(database_builder.next_auto_increment_id());
     row_builder.insert_select_option_cell(&single_select_field_id, vec![to_do_option.id.clone()]);
     let data = format!("Card {}", i + 1);
     row_builder.insert_text_cell(&text_field_id, data);
@@ -111,6 +115,77 @@ pub fn make_default_calendar() -> BuildDatabaseContext {
   database_builder.build()
 }
 
+pub fn make_default_timeline() -> BuildDatabaseContext {
+  let mut database_builder = DatabaseBuilder::new();
+  // text
+  let text_field = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+    .name("Title")
+    .visibility(true)
+    .primary(true)
+    .build();
+  database_builder.add_field(text_field);
+
+  // start date
+  let start_field = FieldBuilder::new(DateTypeOptionBuilder::default())
+    .name("Start")
+    .visibility(true)
+    .build();
+  let start_field_id = start_field.id.clone();
+  database_builder.add_field(start_field);
+
+  // end date
+  let end_field = FieldBuilder::new(DateTypeOptionBuilder::default())
+    .name("End")
+    .visibility(true)
+    .build();
+  let end_field_id = end_field.id.clone();
+  database_builder.add_field(end_field);
+
+  let timeline_layout_setting = TimelineLayoutSetting::new(start_field_id, end_field_id);
+  let mut layout_setting = LayoutSetting::new();
+  let timeline_setting = serde_json::to_string(&timeline_layout_setting).unwrap();
+  layout_setting.insert(LayoutRevision::Timeline, timeline_setting);
+  database_builder.set_layout_setting(layout_setting);
+  database_builder.build()
+}
+
+pub fn make_default_gallery() -> BuildDatabaseContext {
+  let mut database_builder = DatabaseBuilder::new();
+  // text
+  let text_field = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+    .name("Title")
+    .visibility(true)
+    .primary(true)
+    .build();
+  let text_field_id = text_field.id.clone();
+  database_builder.add_field(text_field);
+
+  // cover
+  let cover_field = FieldBuilder::new(URLTypeOptionBuilder::default())
+    .name("Cover")
+    .visibility(true)
+    .build();
+  let cover_field_id = cover_field.id.clone();
+  database_builder.add_field(cover_field);
+
+  // tags
+  let tags_field = FieldBuilder::new(MultiSelectTypeOptionBuilder::default())
+    .name("Tags")
+    .visibility(true)
+    .build();
+  let tags_field_id = tags_field.id.clone();
+  database_builder.add_field(tags_field);
+
+  let mut gallery_layout_setting = GalleryLayoutSetting::new(vec![text_field_id, tags_field_id]);
+  gallery_layout_setting.cover_source = GalleryCoverSource::URLField;
+  gallery_layout_setting.cover_field_id = Some(cover_field_id);
+  let mut layout_setting = LayoutSetting::new();
+  let gallery_setting = serde_json::to_string(&gallery_layout_setting).unwrap();
+  layout_setting.insert(LayoutRevision::Gallery, gallery_setting);
+  database_builder.set_layout_setting(layout_setting);
+  database_builder.build()
+}
+
 #[allow(dead_code)]
 pub fn make_default_board_2() -> BuildDatabaseContext {
   let mut database_builder = DatabaseBuilder::new();
@@ -160,6 +235,7 @@ pub fn make_default_board_2() -> BuildDatabaseContext {
       database_builder.block_id(),
       database_builder.field_revs().clone(),
     );
+    row_builder.set_auto_increment_id(database_builder.next_auto_increment_id());
     row_builder.insert_select_option_cell(&single_select_field_id, vec![to_do_option.id.clone()]);
     match i {
       0 => {
@@ -189,6 +265,7 @@ pub fn make_default_board_2() -> BuildDatabaseContext {
       database_builder.block_id(),
       database_builder.field_revs().clone(),
     );
+    row_builder.set_auto_increment_id(database_builder.next_auto_increment_id());
     row_builder.insert_select_option_cell(&single_select_field_id, vec![doing_option.id.clone()]);
     match i {
       0 => {
@@ -219,6 +296,7 @@ pub fn make_default_board_2() -> BuildDatabaseContext {
       database_builder.block_id(),
       database_builder.field_revs().clone(),
     );
+    row_builder.set_auto_increment_id(database_builder.next_auto_increment_id());
     row_builder.insert_select_option_cell(&single_select_field_id, vec![done_option.id.clone()]);
     match i {
       0 => {
@@ -28,6 +28,8 @@ async fn grid_update_row() {
     row_id: row_rev.id.clone(),
     height: None,
     visibility: None,
+    last_modified: None,
+    document_id: None,
     cell_by_field_id: Default::default(),
   };
   let row_count = test.row_revs.len();
@@ -81,6 +81,7 @@ impl DatabaseRowTest {
           view_id: self.editor.database_id.clone(),
           start_row_id: None,
           group_id: None,
+          sub_group_id: None,
           cell_data_by_field_id: None,
         };
         let row_order = self.editor.create_row(params).await.unwrap();
@@ -282,6 +283,61 @@ impl DatabaseRowTest {
         assert_eq!(cell_data.content, expected);
         // assert_eq!(cell_data.url, expected);
       },
+      FieldType::Formula => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<FormulaCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.content, expected);
+      },
+      FieldType::Relation => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<RelationCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.row_ids.join(SELECTION_IDS_SEPARATOR), expected);
+      },
+      FieldType::Rollup => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<RollupCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.content, expected);
+      },
+      FieldType::CreatedTime | FieldType::LastEditedTime => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<DateCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.date, expected);
+      },
+      FieldType::AutoIncrementId => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<TextCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.as_str(), expected);
+      },
     }
   }
 }
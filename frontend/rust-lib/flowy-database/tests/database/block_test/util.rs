@@ -44,6 +44,11 @@ impl DatabaseRowTestBuilder {
       time: None,
       is_utc: true,
       include_time: Some(false),
+      occurrence_override: None,
+      end_date: None,
+      end_time: None,
+      is_range: None,
+      timezone_id: None,
     })
     .unwrap();
     let date_field = self.field_rev_with_type(&FieldType::DateTime);
@@ -0,0 +1,157 @@
+use crate::database::calculation_test::script::CalculationScript::*;
+use crate::database::calculation_test::script::DatabaseCalculationTest;
+use database_model::CalculationTypeRevision;
+use flowy_database::entities::{CheckboxFilterConditionPB, FieldType};
+
+#[tokio::test]
+async fn calculate_number_field_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  // The numbers are 1, 2, 3, 14, 5 and one empty cell
+  let scripts = vec![
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationTypeRevision::Sum,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::Number,
+      expected: "25",
+    },
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationTypeRevision::Average,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::Number,
+      expected: "5",
+    },
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationTypeRevision::Median,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::Number,
+      expected: "3",
+    },
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationTypeRevision::Max,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::Number,
+      expected: "14",
+    },
+    // The field's calculation is replaced instead of being added
+    AssertCalculationCount(1),
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calculate_count_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  let scripts = vec![
+    UpdateCalculation {
+      field_type: FieldType::RichText,
+      calculation_type: CalculationTypeRevision::CountEmpty,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::RichText,
+      expected: "1",
+    },
+    // A, C, DA and AE
+    UpdateCalculation {
+      field_type: FieldType::RichText,
+      calculation_type: CalculationTypeRevision::CountUnique,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::RichText,
+      expected: "4",
+    },
+    UpdateCalculation {
+      field_type: FieldType::RichText,
+      calculation_type: CalculationTypeRevision::Count,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::RichText,
+      expected: "6",
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calculate_checkbox_and_date_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  let scripts = vec![
+    UpdateCalculation {
+      field_type: FieldType::Checkbox,
+      calculation_type: CalculationTypeRevision::PercentChecked,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::Checkbox,
+      expected: "50",
+    },
+    UpdateCalculation {
+      field_type: FieldType::DateTime,
+      calculation_type: CalculationTypeRevision::EarliestDate,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::DateTime,
+      expected: "1647251762",
+    },
+    UpdateCalculation {
+      field_type: FieldType::DateTime,
+      calculation_type: CalculationTypeRevision::LatestDate,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::DateTime,
+      expected: "1671938394",
+    },
+    AssertCalculationCount(2),
+    DeleteCalculation {
+      field_type: FieldType::Checkbox,
+    },
+    AssertCalculationCount(1),
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calculate_filtered_rows_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  let scripts = vec![
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationTypeRevision::Sum,
+    },
+    // Only the checked rows are visible, whose numbers are 1, 2 and 5
+    CreateCheckboxFilter {
+      condition: CheckboxFilterConditionPB::IsChecked,
+    },
+    AssertCalculationValue {
+      field_type: FieldType::Number,
+      expected: "8",
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calculate_after_updating_cell_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  let scripts = vec![
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationTypeRevision::Sum,
+    },
+    UpdateNumberCell {
+      row_index: 4,
+      content: "10",
+    },
+    AssertCalculationChanged {
+      field_type: FieldType::Number,
+      expected: "35",
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
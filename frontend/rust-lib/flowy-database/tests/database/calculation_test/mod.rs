@@ -0,0 +1,2 @@
+mod calculation_test;
+mod script;
@@ -0,0 +1,167 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use database_model::CalculationTypeRevision;
+use flowy_database::entities::{
+  AlterFilterParams, AlterFilterPayloadPB, CheckboxFilterConditionPB, CheckboxFilterPB,
+  DeleteCalculationParams, FieldType, UpdateCalculationParams,
+};
+use flowy_database::services::database_view::DatabaseViewChanged;
+use std::time::Duration;
+use tokio::sync::broadcast::Receiver;
+
+pub enum CalculationScript {
+  UpdateCalculation {
+    field_type: FieldType,
+    calculation_type: CalculationTypeRevision,
+  },
+  DeleteCalculation {
+    field_type: FieldType,
+  },
+  CreateCheckboxFilter {
+    condition: CheckboxFilterConditionPB,
+  },
+  UpdateNumberCell {
+    row_index: usize,
+    content: &'static str,
+  },
+  AssertCalculationValue {
+    field_type: FieldType,
+    expected: &'static str,
+  },
+  AssertCalculationCount(usize),
+  AssertCalculationChanged {
+    field_type: FieldType,
+    expected: &'static str,
+  },
+}
+
+pub struct DatabaseCalculationTest {
+  inner: DatabaseEditorTest,
+  recv: Option<Receiver<DatabaseViewChanged>>,
+}
+
+impl DatabaseCalculationTest {
+  pub async fn new() -> Self {
+    let editor_test = DatabaseEditorTest::new_grid().await;
+    Self {
+      inner: editor_test,
+      recv: None,
+    }
+  }
+
+  pub async fn run_scripts(&mut self, scripts: Vec<CalculationScript>) {
+    for script in scripts {
+      self.run_script(script).await;
+    }
+  }
+
+  pub async fn run_script(&mut self, script: CalculationScript) {
+    match script {
+      CalculationScript::UpdateCalculation {
+        field_type,
+        calculation_type,
+      } => {
+        let field_rev = self.get_first_field_rev(field_type).clone();
+        let params = UpdateCalculationParams {
+          view_id: self.view_id.clone(),
+          field_id: field_rev.id.clone(),
+          field_type: field_rev.ty,
+          calculation_type,
+        };
+        self.editor.update_calculation(params).await.unwrap();
+      },
+      CalculationScript::DeleteCalculation { field_type } => {
+        let field_rev = self.get_first_field_rev(field_type).clone();
+        let params = DeleteCalculationParams {
+          view_id: self.view_id.clone(),
+          field_id: field_rev.id.clone(),
+          field_type: field_rev.ty,
+        };
+        self.editor.delete_calculation(params).await.unwrap();
+      },
+      CalculationScript::CreateCheckboxFilter { condition } => {
+        let field_rev = self.get_first_field_rev(FieldType::Checkbox);
+        let payload =
+          AlterFilterPayloadPB::new(&self.view_id, field_rev, CheckboxFilterPB { condition });
+        let params: AlterFilterParams = payload.try_into().unwrap();
+        self.editor.create_or_update_filter(params).await.unwrap();
+      },
+      CalculationScript::UpdateNumberCell { row_index, content } => {
+        self.recv = Some(
+          self
+            .editor
+            .subscribe_view_changed(&self.view_id)
+            .await
+            .unwrap(),
+        );
+        let field_id = self.get_first_field_rev(FieldType::Number).id.clone();
+        let row_id = self.row_revs[row_index].id.clone();
+        self
+          .update_cell(&field_id, row_id, content.to_owned())
+          .await;
+      },
+      CalculationScript::AssertCalculationValue {
+        field_type,
+        expected,
+      } => {
+        let field_id = self.get_first_field_rev(field_type).id.clone();
+        let values = self
+          .editor
+          .get_calculation_values(&self.view_id)
+          .await
+          .unwrap();
+        let value = values
+          .into_iter()
+          .find(|value| value.field_id == field_id && value.group_id.is_none())
+          .unwrap();
+        assert_eq!(value.value, expected);
+      },
+      CalculationScript::AssertCalculationCount(count) => {
+        let values = self
+          .editor
+          .get_calculation_values(&self.view_id)
+          .await
+          .unwrap();
+        assert_eq!(values.len(), count);
+      },
+      CalculationScript::AssertCalculationChanged {
+        field_type,
+        expected,
+      } => {
+        let field_id = self.get_first_field_rev(field_type).id.clone();
+        let mut receiver = self.recv.take().unwrap();
+        let changed_value = tokio::time::timeout(Duration::from_secs(2), async {
+          loop {
+            if let DatabaseViewChanged::CalculationNotification(notification) =
+              receiver.recv().await.unwrap()
+            {
+              if let Some(value) = notification
+                .values
+                .into_iter()
+                .find(|value| value.field_id == field_id && value.group_id.is_none())
+              {
+                return value.value;
+              }
+            }
+          }
+        })
+        .await
+        .unwrap();
+        assert_eq!(changed_value, expected);
+      },
+    }
+  }
+}
+
+impl std::ops::Deref for DatabaseCalculationTest {
+  type Target = DatabaseEditorTest;
+
+  fn deref(&self) -> &Self::Target {
+    &self.inner
+  }
+}
+
+impl std::ops::DerefMut for DatabaseCalculationTest {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.inner
+  }
+}
@@ -1,11 +1,12 @@
 use crate::database::cell_test::script::CellScript::*;
 use crate::database::cell_test::script::DatabaseCellTest;
 use crate::database::field_test::util::make_date_cell_string;
-use flowy_database::entities::{CellChangesetPB, FieldType};
+use flowy_database::entities::{CellChangesetPB, CellIdParams, CreateRowParams, FieldType};
 use flowy_database::services::cell::ToCellChangesetString;
 use flowy_database::services::field::selection_type_option::SelectOptionCellChangeset;
 use flowy_database::services::field::{
-  ChecklistTypeOptionPB, MultiSelectTypeOptionPB, SingleSelectTypeOptionPB,
+  ChecklistTypeOptionPB, FormulaCellData, MultiSelectTypeOptionPB, RelationCellChangeset,
+  RelationTypeOptionPB, RollupCalculationPB, RollupTypeOptionPB, SingleSelectTypeOptionPB,
 };
 
 #[tokio::test]
@@ -43,6 +44,15 @@ async fn grid_cell_update() {
         },
         FieldType::Checkbox => "1".to_string(),
         FieldType::URL => "1".to_string(),
+        // The formula and rollup cells are calculated and the system cells are maintained by the
+        // database, so the changeset is rejected even if it's valid cell data.
+        FieldType::Formula => FormulaCellData::Number(1.0).to_string(),
+        FieldType::Rollup
+        | FieldType::CreatedTime
+        | FieldType::LastEditedTime
+        | FieldType::AutoIncrementId => "1".to_string(),
+        FieldType::Relation => RelationCellChangeset::from_insert_row_ids(vec![row_rev.id.clone()])
+          .to_cell_changeset_str(),
       };
 
       scripts.push(UpdateCell {
@@ -52,7 +62,7 @@ async fn grid_cell_update() {
           field_id: field_rev.id.clone(),
           type_cell_data: data,
         },
-        is_err: false,
+        is_err: field_type.is_formula() || field_type.is_rollup() || field_type.is_system(),
       });
     }
   }
@@ -101,3 +111,234 @@ async fn url_cell_date_test() {
     }
   }
 }
+
+#[tokio::test]
+async fn formula_cell_recalculate_test() {
+  let test = DatabaseCellTest::new().await;
+  let number_field = test.get_first_field_rev(FieldType::Number).clone();
+  let formula_field = test.get_first_field_rev(FieldType::Formula).clone();
+  let row_id = test.row_revs.first().unwrap().id.clone();
+  test
+    .editor
+    .update_cell_with_changeset(&row_id, &number_field.id, "21".to_owned())
+    .await
+    .unwrap();
+
+  let cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: formula_field.id.clone(),
+    row_id,
+  };
+  assert_eq!(test.editor.get_cell_display_str(&cell_id).await, "42");
+}
+
+#[tokio::test]
+async fn relation_cell_unlink_deleted_row_test() {
+  let test = DatabaseCellTest::new().await;
+  let database_id = test.editor.database_id.clone();
+  let relation_field = test.get_first_field_rev(FieldType::Relation).clone();
+  test
+    .editor
+    .modify_field_rev(&test.view_id, &relation_field.id, |field_rev| {
+      field_rev.insert_type_option(&RelationTypeOptionPB {
+        database_id: database_id.clone(),
+      });
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+
+  // Link the first row to the second and the third row of the same database.
+  let row_ids = test
+    .row_revs
+    .iter()
+    .map(|row_rev| row_rev.id.clone())
+    .collect::<Vec<String>>();
+  let changeset =
+    RelationCellChangeset::from_insert_row_ids(vec![row_ids[1].clone(), row_ids[2].clone()]);
+  test
+    .editor
+    .update_cell_with_changeset(&row_ids[0], &relation_field.id, changeset)
+    .await
+    .unwrap();
+
+  let cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: relation_field.id.clone(),
+    row_id: row_ids[0].clone(),
+  };
+  let related_rows = test
+    .sdk
+    .database_manager
+    .get_related_rows(&cell_id)
+    .await
+    .unwrap();
+  let names = related_rows
+    .into_iter()
+    .map(|row| row.name)
+    .collect::<Vec<String>>();
+  assert_eq!(names, vec!["".to_owned(), "C".to_owned()]);
+
+  test.editor.delete_row(&row_ids[2]).await.unwrap();
+  test
+    .sdk
+    .database_manager
+    .did_delete_rows(&database_id, vec![row_ids[2].clone()])
+    .await
+    .unwrap();
+  assert_eq!(test.editor.get_cell_display_str(&cell_id).await, row_ids[1]);
+}
+
+#[tokio::test]
+async fn rollup_cell_refresh_test() {
+  let test = DatabaseCellTest::new().await;
+  let database_id = test.editor.database_id.clone();
+  let manager = test.sdk.database_manager.clone();
+  let number_field = test.get_first_field_rev(FieldType::Number).clone();
+  let relation_field = test.get_first_field_rev(FieldType::Relation).clone();
+  let rollup_field = test.get_first_field_rev(FieldType::Rollup).clone();
+  test
+    .editor
+    .modify_field_rev(&test.view_id, &relation_field.id, |field_rev| {
+      field_rev.insert_type_option(&RelationTypeOptionPB {
+        database_id: database_id.clone(),
+      });
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+  test
+    .editor
+    .modify_field_rev(&test.view_id, &rollup_field.id, |field_rev| {
+      field_rev.insert_type_option(&RollupTypeOptionPB {
+        relation_field_id: relation_field.id.clone(),
+        target_field_id: number_field.id.clone(),
+        calculation: RollupCalculationPB::Sum,
+      });
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+
+  // Link the first row to the second and the third row, whose numbers are 2 and 3.
+  let row_ids = test
+    .row_revs
+    .iter()
+    .map(|row_rev| row_rev.id.clone())
+    .collect::<Vec<String>>();
+  let changeset =
+    RelationCellChangeset::from_insert_row_ids(vec![row_ids[1].clone(), row_ids[2].clone()]);
+  test
+    .editor
+    .update_cell_with_changeset(&row_ids[0], &relation_field.id, changeset)
+    .await
+    .unwrap();
+  manager
+    .did_update_rows(&database_id, vec![row_ids[0].clone()])
+    .await
+    .unwrap();
+
+  let cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: rollup_field.id.clone(),
+    row_id: row_ids[0].clone(),
+  };
+  assert_eq!(test.editor.get_cell_display_str(&cell_id).await, "5");
+
+  // The rollup is recalculated after the linked row is updated.
+  test
+    .editor
+    .update_cell_with_changeset(&row_ids[1], &number_field.id, "10".to_owned())
+    .await
+    .unwrap();
+  manager
+    .did_update_rows(&database_id, vec![row_ids[1].clone()])
+    .await
+    .unwrap();
+  assert_eq!(test.editor.get_cell_display_str(&cell_id).await, "13");
+
+  // The rollup is recalculated after the linked row is deleted.
+  test.editor.delete_row(&row_ids[2]).await.unwrap();
+  manager
+    .did_delete_rows(&database_id, vec![row_ids[2].clone()])
+    .await
+    .unwrap();
+  assert_eq!(test.editor.get_cell_display_str(&cell_id).await, "10");
+}
+
+#[tokio::test]
+async fn system_cells_test() {
+  let test = DatabaseCellTest::new().await;
+  let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+  let created_time_field = test.get_first_field_rev(FieldType::CreatedTime).clone();
+  let last_edited_time_field = test.get_first_field_rev(FieldType::LastEditedTime).clone();
+  let id_field = test.get_first_field_rev(FieldType::AutoIncrementId).clone();
+  let cell_id = |row_id: &str, field_id: &str| CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: field_id.to_owned(),
+    row_id: row_id.to_owned(),
+  };
+
+  // The mocked rows are built with the system values, so they are numbered from one.
+  let first_row_id = test.row_revs[0].id.clone();
+  assert_eq!(
+    test
+      .editor
+      .get_cell_display_str(&cell_id(&first_row_id, &id_field.id))
+      .await,
+    "1"
+  );
+  assert!(!test
+    .editor
+    .get_cell_display_str(&cell_id(&first_row_id, &created_time_field.id))
+    .await
+    .is_empty());
+
+  // The created time and the id are assigned when the row is created, so the new row gets the
+  // next id.
+  let row_pb = test
+    .editor
+    .create_row(CreateRowParams {
+      view_id: test.view_id.clone(),
+      start_row_id: None,
+      group_id: None,
+      sub_group_id: None,
+      cell_data_by_field_id: None,
+    })
+    .await
+    .unwrap();
+  let row_rev = test.editor.get_row_rev(&row_pb.id).await.unwrap().unwrap();
+  let next_id = test.row_revs.len() as i64 + 1;
+  assert!(row_rev.created_at > 0);
+  assert_eq!(row_rev.auto_increment_id, next_id);
+  assert_eq!(
+    test
+      .editor
+      .get_cell_display_str(&cell_id(&row_pb.id, &id_field.id))
+      .await,
+    next_id.to_string()
+  );
+  assert!(!test
+    .editor
+    .get_cell_display_str(&cell_id(&row_pb.id, &created_time_field.id))
+    .await
+    .is_empty());
+
+  // Editing the row refreshes its last edited time.
+  let row_id = test.row_revs[0].id.clone();
+  let last_edited_time_cell_id = cell_id(&row_id, &last_edited_time_field.id);
+  let old_last_modified = test.row_revs[0].last_modified;
+  assert!(old_last_modified > 0);
+  test
+    .editor
+    .update_cell_with_changeset(&row_id, &text_field.id, "hello".to_owned())
+    .await
+    .unwrap();
+  let row_rev = test.editor.get_row_rev(&row_id).await.unwrap().unwrap();
+  assert!(row_rev.last_modified >= old_last_modified);
+  assert!(!test
+    .editor
+    .get_cell_display_str(&last_edited_time_cell_id)
+    .await
+    .is_empty());
+}
@@ -37,8 +37,19 @@ impl DatabaseEditorTest {
     Self::new(LayoutTypePB::Calendar).await
   }
 
+  pub async fn new_timeline() -> Self {
+    Self::new(LayoutTypePB::Timeline).await
+  }
+
+  pub async fn new_gallery() -> Self {
+    Self::new(LayoutTypePB::Gallery).await
+  }
+
   pub async fn new(layout: LayoutTypePB) -> Self {
-    let sdk = FlowySDKTest::default();
+    Self::new_with_sdk(layout, FlowySDKTest::default()).await
+  }
+
+  pub async fn new_with_sdk(layout: LayoutTypePB, sdk: FlowySDKTest) -> Self {
     let _ = sdk.init_user().await;
     let test = match layout {
       LayoutTypePB::Grid => {
@@ -56,6 +67,16 @@ impl DatabaseEditorTest {
         let view_data: Bytes = build_context.into();
         ViewTest::new_calendar_view(&sdk, view_data.to_vec()).await
       },
+      LayoutTypePB::Timeline => {
+        let build_context = make_test_timeline();
+        let view_data: Bytes = build_context.into();
+        ViewTest::new_timeline_view(&sdk, view_data.to_vec()).await
+      },
+      LayoutTypePB::Gallery => {
+        let build_context = make_test_gallery();
+        let view_data: Bytes = build_context.into();
+        ViewTest::new_gallery_view(&sdk, view_data.to_vec()).await
+      },
     };
 
     let editor = sdk
@@ -65,6 +65,11 @@ pub fn make_date_cell_string(s: &str) -> String {
     time: None,
     is_utc: true,
     include_time: Some(false),
+    occurrence_override: None,
+    end_date: None,
+    end_time: None,
+    is_range: None,
+    timezone_id: None,
   })
   .unwrap()
 }
@@ -0,0 +1,157 @@
+use crate::database::filter_test::script::FilterScript::*;
+use crate::database::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use database_model::ROOT_FILTER_GROUP_ID;
+use flowy_database::entities::{
+  AlterFilterPayloadPB, CheckboxFilterConditionPB, FieldType, FilterOperatorPB,
+  NumberFilterConditionPB, NumberFilterPB, SelectOptionConditionPB, TextFilterConditionPB,
+  TextFilterPB,
+};
+
+#[tokio::test]
+async fn grid_filter_or_operator_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    UpdateFilterGroup {
+      group_id: ROOT_FILTER_GROUP_ID.to_owned(),
+      operator: FilterOperatorPB::Or,
+      changed: None,
+    },
+    CreateCheckboxFilter {
+      condition: CheckboxFilterConditionPB::IsChecked,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: 3,
+      }),
+    },
+    CreateNumberFilter {
+      condition: NumberFilterConditionPB::Equal,
+      content: "14".to_string(),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 1,
+        hiding_num_of_rows: 0,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected: 4 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_switch_operator_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateCheckboxFilter {
+      condition: CheckboxFilterConditionPB::IsChecked,
+      changed: None,
+    },
+    CreateNumberFilter {
+      condition: NumberFilterConditionPB::Equal,
+      content: "14".to_string(),
+      changed: None,
+    },
+    AssertNumberOfVisibleRows { expected: 0 },
+    UpdateFilterGroup {
+      group_id: ROOT_FILTER_GROUP_ID.to_owned(),
+      operator: FilterOperatorPB::Or,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 4,
+        hiding_num_of_rows: 0,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected: 4 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_nested_group_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  test
+    .run_scripts(vec![
+      CreateCheckboxFilter {
+        condition: CheckboxFilterConditionPB::IsChecked,
+        changed: None,
+      },
+      CreateFilterGroup {
+        parent_group_id: None,
+        operator: FilterOperatorPB::Or,
+      },
+    ])
+    .await;
+  let group_id = test.get_last_filter_group_id().await;
+
+  // Checked AND (number is 1 OR text is "AE")
+  let field_rev = test.get_first_field_rev(FieldType::Number);
+  let number_filter = NumberFilterPB {
+    condition: NumberFilterConditionPB::Equal,
+    content: "1".to_string(),
+  };
+  let mut number_payload = AlterFilterPayloadPB::new(&test.view_id(), field_rev, number_filter);
+  number_payload.group_id = Some(group_id.clone());
+
+  let field_rev = test.get_first_field_rev(FieldType::RichText);
+  let text_filter = TextFilterPB {
+    condition: TextFilterConditionPB::Is,
+    content: "AE".to_string(),
+  };
+  let mut text_payload = AlterFilterPayloadPB::new(&test.view_id(), field_rev, text_filter);
+  text_payload.group_id = Some(group_id.clone());
+
+  let scripts = vec![
+    AssertNumberOfVisibleRows { expected: 3 },
+    InsertFilter {
+      payload: number_payload,
+    },
+    AssertNumberOfVisibleRows { expected: 1 },
+    InsertFilter {
+      payload: text_payload,
+    },
+    AssertNumberOfVisibleRows { expected: 2 },
+    AssertFilterCount { count: 3 },
+    DeleteFilterGroup {
+      group_id,
+      changed: None,
+    },
+    AssertFilterCount { count: 1 },
+    AssertNumberOfVisibleRows { expected: 3 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_or_operator_same_field_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let field_rev = test.get_first_field_rev(FieldType::SingleSelect);
+  let mut options = test.get_single_select_type_option(&field_rev.id).options;
+  let first_option = options.remove(0);
+  let second_option = options.remove(0);
+
+  // Status is the first option OR Status is the second option
+  let scripts = vec![
+    UpdateFilterGroup {
+      group_id: ROOT_FILTER_GROUP_ID.to_owned(),
+      operator: FilterOperatorPB::Or,
+      changed: None,
+    },
+    CreateSingleSelectFilter {
+      condition: SelectOptionConditionPB::OptionIs,
+      option_ids: vec![first_option.id],
+      changed: None,
+    },
+    AssertNumberOfVisibleRows { expected: 2 },
+    CreateSingleSelectFilter {
+      condition: SelectOptionConditionPB::OptionIs,
+      option_ids: vec![second_option.id],
+      changed: None,
+    },
+    AssertFilterCount { count: 2 },
+    AssertNumberOfVisibleRows { expected: 4 },
+    UpdateFilterGroup {
+      group_id: ROOT_FILTER_GROUP_ID.to_owned(),
+      operator: FilterOperatorPB::And,
+      changed: None,
+    },
+    AssertNumberOfVisibleRows { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
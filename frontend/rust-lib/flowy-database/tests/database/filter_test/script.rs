@@ -7,10 +7,10 @@ use std::time::Duration;
 use bytes::Bytes;
 use futures::TryFutureExt;
 use tokio::sync::broadcast::Receiver;
-use flowy_database::entities::{AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, LayoutTypePB, DatabaseSettingChangesetParams, DatabaseViewSettingPB, RowPB, TextFilterConditionPB, FieldType, NumberFilterConditionPB, CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, SelectOptionConditionPB, TextFilterPB, NumberFilterPB, CheckboxFilterPB, DateFilterPB, SelectOptionFilterPB, CellChangesetPB, FilterPB, ChecklistFilterConditionPB, ChecklistFilterPB};
+use flowy_database::entities::{AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, LayoutTypePB, DatabaseSettingChangesetParams, DatabaseViewSettingPB, RowPB, TextFilterConditionPB, FieldType, NumberFilterConditionPB, CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, SelectOptionConditionPB, TextFilterPB, NumberFilterPB, CheckboxFilterPB, DateFilterPB, SelectOptionFilterPB, CellChangesetPB, FilterPB, ChecklistFilterConditionPB, ChecklistFilterPB, AlterFilterGroupParams, DeleteFilterGroupParams, FilterOperatorPB};
 use flowy_database::services::field::{SelectOptionCellChangeset, SelectOptionIds};
 use flowy_database::services::setting::GridSettingChangesetBuilder;
-use database_model::{FieldRevision, FieldTypeRevision};
+use database_model::{FieldRevision, FieldTypeRevision, FilterGroupRevision, FilterNodeRevision};
 use flowy_sqlite::schema::view_table::dsl::view_table;
 use flowy_database::services::cell::insert_select_option_cell;
 use flowy_database::services::filter::FilterType;
@@ -92,6 +92,19 @@ pub enum FilterScript {
     AssertNumberOfVisibleRows {
         expected: usize,
     },
+    CreateFilterGroup {
+        parent_group_id: Option<String>,
+        operator: FilterOperatorPB,
+    },
+    UpdateFilterGroup {
+        group_id: String,
+        operator: FilterOperatorPB,
+        changed: Option<FilterRowChanged>,
+    },
+    DeleteFilterGroup {
+        group_id: String,
+        changed: Option<FilterRowChanged>,
+    },
     #[allow(dead_code)]
     AssertGridSetting {
         expected_setting: DatabaseViewSettingPB,
@@ -121,6 +134,18 @@ impl DatabaseFilterTest {
         self.editor.get_all_filters(&self.view_id).await.unwrap()
     }
 
+    pub async fn get_filter_group(&self) -> FilterGroupRevision {
+        self.editor.get_filter_group(&self.view_id).await.unwrap()
+    }
+
+    /// Returns the id of the last filter group that was inserted into the root group
+    pub async fn get_last_filter_group_id(&self) -> String {
+        self.get_filter_group().await.children.iter().rev().find_map(|child| match child {
+            FilterNodeRevision::Filter { .. } => None,
+            FilterNodeRevision::Group(group) => Some(group.id.clone()),
+        }).unwrap()
+    }
+
     pub async fn run_scripts(&mut self, scripts: Vec<FilterScript>) {
         for script in scripts {
             self.run_script(script).await;
@@ -166,7 +191,8 @@ impl DatabaseFilterTest {
                     filter_id: Some(filter.id),
                     field_type: filter.field_type.into(),
                     condition: condition as u8,
-                    content
+                    content,
+                    group_id: None,
                 };
                 self.editor.create_or_update_filter(params).await.unwrap();
             }
@@ -261,6 +287,22 @@ impl DatabaseFilterTest {
                 let grid = self.editor.get_database(&self.view_id()).await.unwrap();
                 assert_eq!(grid.rows.len(), expected);
             }
+            FilterScript::CreateFilterGroup { parent_group_id, operator } => {
+                let params = AlterFilterGroupParams { view_id: self.view_id(), group_id: None, parent_group_id, operator: operator.into() };
+                self.editor.create_or_update_filter_group(params).await.unwrap();
+            }
+            FilterScript::UpdateFilterGroup { group_id, operator, changed } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let params = AlterFilterGroupParams { view_id: self.view_id(), group_id: Some(group_id), parent_group_id: None, operator: operator.into() };
+                self.editor.create_or_update_filter_group(params).await.unwrap();
+            }
+            FilterScript::DeleteFilterGroup { group_id, changed } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let params = DeleteFilterGroupParams { view_id: self.view_id(), group_id };
+                self.editor.delete_filter_group(params).await.unwrap();
+            }
             FilterScript::Wait { millisecond } => {
                 tokio::time::sleep(Duration::from_millis(millisecond)).await;
             }
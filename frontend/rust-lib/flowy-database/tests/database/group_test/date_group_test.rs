@@ -0,0 +1,182 @@
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+use flowy_database::entities::{DateCondition, GroupPB};
+use flowy_database::services::field::DateFormat;
+
+#[tokio::test]
+async fn group_by_date_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let date_field = test.get_date_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: date_field.id.clone(),
+    },
+    // The rows are grouped by month because all the dates are more than 30 days ago.
+    AssertGroupCount(3),
+    // no status group
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 0,
+    },
+    // Mar 2022
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+    // Nov 2022
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_date_with_day_condition_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let date_field = test.get_date_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: date_field.id.clone(),
+    },
+    UpdateDateGroupCondition {
+      field_id: date_field.id.clone(),
+      condition: DateCondition::Day,
+    },
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 1,
+    },
+    UpdateDateGroupCondition {
+      field_id: date_field.id.clone(),
+      condition: DateCondition::Year,
+    },
+    AssertGroupCount(2),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 5,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_move_date_row_to_other_group_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let date_field = test.get_date_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: date_field.id.clone(),
+    },
+    UpdateDateGroupCondition {
+      field_id: date_field.id.clone(),
+      condition: DateCondition::Day,
+    },
+    MoveRow {
+      from_group_index: 1,
+      from_row_index: 0,
+      to_group_index: 3,
+      to_row_index: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 2,
+    },
+    // Regroup the rows to check the date cell of the moved row is updated
+    GroupByField {
+      field_id: date_field.id.clone(),
+    },
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_move_last_date_row_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let date_field = test.get_date_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: date_field.id.clone(),
+    },
+    UpdateDateGroupCondition {
+      field_id: date_field.id.clone(),
+      condition: DateCondition::Day,
+    },
+    // When moving the only row of the group to another group, the group will be removed
+    MoveRow {
+      from_group_index: 2,
+      from_row_index: 0,
+      to_group_index: 1,
+      to_row_index: 0,
+    },
+    AssertGroupCount(3),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 4,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_regroup_after_updating_date_format_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let date_field = test.get_date_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: date_field.id.clone(),
+    },
+    UpdateDateGroupCondition {
+      field_id: date_field.id.clone(),
+      condition: DateCondition::Day,
+    },
+    AssertGroup {
+      group_index: 1,
+      expected_group: GroupPB {
+        group_id: "2022-03-14".to_owned(),
+        desc: "2022/03/14".to_owned(),
+        ..Default::default()
+      },
+    },
+    UpdateDateFormat {
+      field_id: date_field.id.clone(),
+      date_format: DateFormat::ISO,
+    },
+    AssertGroup {
+      group_index: 1,
+      expected_group: GroupPB {
+        group_id: "2022-03-14".to_owned(),
+        desc: "2022-03-14".to_owned(),
+        ..Default::default()
+      },
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
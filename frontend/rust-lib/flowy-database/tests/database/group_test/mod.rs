@@ -1,3 +1,7 @@
+mod date_group_test;
+mod number_group_test;
 mod script;
+mod sub_group_test;
 mod test;
+mod text_group_test;
 mod url_group_test;
@@ -0,0 +1,204 @@
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+use database_model::NumberRangeRevision;
+use flowy_database::entities::GroupPB;
+
+#[tokio::test]
+async fn group_by_number_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let number_field = test.get_number_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: number_field.id.clone(),
+    },
+    // The numbers are grouped into the buckets of 10 by default
+    AssertGroupCount(2),
+    // no status group
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 1,
+    },
+    AssertGroup {
+      group_index: 1,
+      expected_group: GroupPB {
+        group_id: "0..10".to_owned(),
+        desc: "0 - 10".to_owned(),
+        ..Default::default()
+      },
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 4,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_number_bucket_width_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let number_field = test.get_number_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: number_field.id.clone(),
+    },
+    UpdateNumberGroupSetting {
+      field_id: number_field.id.clone(),
+      ranges: vec![],
+      bucket_width: 2.0,
+    },
+    // 0..2, 2..4, 4..6
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 1,
+    },
+    // The group 0..2 is removed after moving its only row to the group 4..6
+    MoveRow {
+      from_group_index: 1,
+      from_row_index: 0,
+      to_group_index: 3,
+      to_row_index: 0,
+    },
+    AssertGroupCount(3),
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+    // Regroup the rows to check the number cell of the moved row is updated
+    GroupByField {
+      field_id: number_field.id.clone(),
+    },
+    AssertGroupCount(3),
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_number_ranges_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let number_field = test.get_number_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: number_field.id.clone(),
+    },
+    UpdateNumberGroupSetting {
+      field_id: number_field.id.clone(),
+      ranges: vec![
+        NumberRangeRevision::new(0.0, 2.0),
+        NumberRangeRevision::new(2.0, 100.0),
+        NumberRangeRevision::new(100.0, 200.0),
+      ],
+      bucket_width: 0.0,
+    },
+    // The groups of the ranges are generated even if they are empty
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 3,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 0,
+    },
+    // The group 0..2 is kept after moving its only row to the group 100..200
+    MoveRow {
+      from_group_index: 1,
+      from_row_index: 0,
+      to_group_index: 3,
+      to_row_index: 0,
+    },
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 1,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_update_number_cell_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let number_field = test.get_number_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: number_field.id.clone(),
+    },
+    // A new group is inserted for the number
+    UpdateGroupedCellWithData {
+      from_group_index: 1,
+      row_index: 0,
+      cell_data: "25".to_string(),
+    },
+    AssertGroupCount(3),
+    AssertGroup {
+      group_index: 2,
+      expected_group: GroupPB {
+        group_id: "20..30".to_owned(),
+        desc: "20 - 30".to_owned(),
+        ..Default::default()
+      },
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_auto_increment_id_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let id_field = test.get_auto_increment_id_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: id_field.id.clone(),
+    },
+    UpdateNumberGroupSetting {
+      field_id: id_field.id.clone(),
+      ranges: vec![],
+      bucket_width: 2.0,
+    },
+    // The ids of the rows are 1 to 5: 0..2, 2..4, 4..6
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
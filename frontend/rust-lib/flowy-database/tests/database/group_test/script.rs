@@ -1,14 +1,17 @@
 use crate::database::database_editor::DatabaseEditorTest;
-use database_model::{FieldRevision, RowChangeset};
+use database_model::{FieldRevision, NumberRangeRevision, RowChangeset};
 use flowy_database::entities::{
-  CreateRowParams, FieldType, GroupPB, MoveGroupParams, MoveGroupRowParams, RowPB,
+  CreateRowParams, DateCondition, FieldType, GroupPB, MoveGroupParams, MoveGroupRowParams, RowPB,
+  SubGroupByFieldParams, UpdateDateGroupConditionParams, UpdateNumberGroupSettingParams,
+  UpdateTextGroupSettingParams,
 };
 use flowy_database::services::cell::{
-  delete_select_option_cell, insert_select_option_cell, insert_url_cell,
+  delete_select_option_cell, insert_number_cell, insert_select_option_cell, insert_text_cell,
+  insert_url_cell,
 };
 use flowy_database::services::field::{
-  edit_single_select_type_option, SelectOptionPB, SelectTypeOptionSharedAction,
-  SingleSelectTypeOptionPB,
+  edit_field_type_option, edit_single_select_type_option, DateFormat, DateTypeOptionPB,
+  SelectOptionPB, SelectTypeOptionSharedAction, SingleSelectTypeOptionPB,
 };
 use std::sync::Arc;
 
@@ -60,6 +63,41 @@ pub enum GroupScript {
   GroupByField {
     field_id: String,
   },
+  UpdateDateGroupCondition {
+    field_id: String,
+    condition: DateCondition,
+  },
+  UpdateDateFormat {
+    field_id: String,
+    date_format: DateFormat,
+  },
+  UpdateNumberGroupSetting {
+    field_id: String,
+    ranges: Vec<NumberRangeRevision>,
+    bucket_width: f64,
+  },
+  UpdateTextGroupSetting {
+    field_id: String,
+    case_insensitive: bool,
+  },
+  SetSubGroupByField {
+    field_id: Option<String>,
+  },
+  AssertSubGroupCount {
+    group_index: usize,
+    count: usize,
+  },
+  AssertSubGroupRowCount {
+    group_index: usize,
+    sub_group_index: usize,
+    row_count: usize,
+  },
+  MoveRowToSubGroup {
+    from_group_index: usize,
+    from_row_index: usize,
+    to_group_index: usize,
+    to_sub_group_index: usize,
+  },
 }
 
 pub struct DatabaseGroupTest {
@@ -109,6 +147,7 @@ impl DatabaseGroupTest {
           view_id: self.view_id.clone(),
           from_row_id: from_row.id.clone(),
           to_group_id: to_group.group_id.clone(),
+          to_sub_group_id: None,
           to_row_id: Some(to_row.id.clone()),
         };
 
@@ -130,6 +169,7 @@ impl DatabaseGroupTest {
           view_id: self.view_id.clone(),
           start_row_id: None,
           group_id: Some(group.group_id.clone()),
+          sub_group_id: None,
           cell_data_by_field_id: None,
         };
         let _ = self.editor.create_row(params).await.unwrap();
@@ -195,6 +235,8 @@ impl DatabaseGroupTest {
         let field_type: FieldType = field_rev.ty.into();
         let cell_rev = match field_type {
           FieldType::URL => insert_url_cell(cell_data, &field_rev),
+          FieldType::RichText => insert_text_cell(cell_data, &field_rev),
+          FieldType::Number => insert_number_cell(cell_data, &field_rev),
           _ => {
             panic!("Unsupported group field type");
           },
@@ -243,6 +285,100 @@ impl DatabaseGroupTest {
           .await
           .unwrap();
       },
+      GroupScript::UpdateDateGroupCondition {
+        field_id,
+        condition,
+      } => {
+        let params = UpdateDateGroupConditionParams {
+          view_id: self.view_id.clone(),
+          field_id,
+          condition: condition.into(),
+        };
+        self
+          .editor
+          .update_date_group_condition(params)
+          .await
+          .unwrap();
+      },
+      GroupScript::UpdateDateFormat {
+        field_id,
+        date_format,
+      } => {
+        edit_field_type_option(
+          &self.view_id,
+          &field_id,
+          self.editor.clone(),
+          |type_option: &mut DateTypeOptionPB| type_option.date_format = date_format,
+        )
+        .await
+        .unwrap();
+      },
+      GroupScript::UpdateNumberGroupSetting {
+        field_id,
+        ranges,
+        bucket_width,
+      } => {
+        let params = UpdateNumberGroupSettingParams {
+          view_id: self.view_id.clone(),
+          field_id,
+          ranges,
+          bucket_width,
+        };
+        self
+          .editor
+          .update_number_group_setting(params)
+          .await
+          .unwrap();
+      },
+      GroupScript::UpdateTextGroupSetting {
+        field_id,
+        case_insensitive,
+      } => {
+        let params = UpdateTextGroupSettingParams {
+          view_id: self.view_id.clone(),
+          field_id,
+          case_insensitive,
+        };
+        self.editor.update_text_group_setting(params).await.unwrap();
+      },
+      GroupScript::SetSubGroupByField { field_id } => {
+        let params = SubGroupByFieldParams {
+          view_id: self.view_id.clone(),
+          field_id,
+        };
+        self.editor.set_sub_group_by_field(params).await.unwrap();
+      },
+      GroupScript::AssertSubGroupCount { group_index, count } => {
+        let group = self.group_at_index(group_index).await;
+        assert_eq!(count, group.sub_groups.len());
+      },
+      GroupScript::AssertSubGroupRowCount {
+        group_index,
+        sub_group_index,
+        row_count,
+      } => {
+        let group = self.group_at_index(group_index).await;
+        let sub_group = group.sub_groups.get(sub_group_index).unwrap();
+        assert_eq!(row_count, sub_group.rows.len());
+      },
+      GroupScript::MoveRowToSubGroup {
+        from_group_index,
+        from_row_index,
+        to_group_index,
+        to_sub_group_index,
+      } => {
+        let from_row = self.row_at_index(from_group_index, from_row_index).await;
+        let to_group = self.group_at_index(to_group_index).await;
+        let to_sub_group = to_group.sub_groups.get(to_sub_group_index).unwrap();
+        let params = MoveGroupRowParams {
+          view_id: self.view_id.clone(),
+          from_row_id: from_row.id,
+          to_group_id: to_group.group_id.clone(),
+          to_sub_group_id: Some(to_sub_group.group_id.clone()),
+          to_row_id: None,
+        };
+        self.editor.move_group_row(params).await.unwrap();
+      },
     }
   }
 
@@ -271,6 +407,19 @@ impl DatabaseGroupTest {
     field
   }
 
+  pub async fn get_auto_increment_id_field(&self) -> Arc<FieldRevision> {
+    self
+      .inner
+      .field_revs
+      .iter()
+      .find(|field_rev| {
+        let field_type: FieldType = field_rev.ty.into();
+        field_type.is_auto_increment_id()
+      })
+      .unwrap()
+      .clone()
+  }
+
   pub async fn get_single_select_field(&self) -> Arc<FieldRevision> {
     self
       .inner
@@ -299,6 +448,58 @@ impl DatabaseGroupTest {
     .unwrap();
   }
 
+  pub async fn get_date_field(&self) -> Arc<FieldRevision> {
+    self
+      .inner
+      .field_revs
+      .iter()
+      .find(|field_rev| {
+        let field_type: FieldType = field_rev.ty.into();
+        field_type.is_date()
+      })
+      .unwrap()
+      .clone()
+  }
+
+  pub async fn get_number_field(&self) -> Arc<FieldRevision> {
+    self
+      .inner
+      .field_revs
+      .iter()
+      .find(|field_rev| {
+        let field_type: FieldType = field_rev.ty.into();
+        field_type.is_number()
+      })
+      .unwrap()
+      .clone()
+  }
+
+  pub async fn get_text_field(&self) -> Arc<FieldRevision> {
+    self
+      .inner
+      .field_revs
+      .iter()
+      .find(|field_rev| {
+        let field_type: FieldType = field_rev.ty.into();
+        field_type.is_text()
+      })
+      .unwrap()
+      .clone()
+  }
+
+  pub async fn get_checkbox_field(&self) -> Arc<FieldRevision> {
+    self
+      .inner
+      .field_revs
+      .iter()
+      .find(|field_rev| {
+        let field_type: FieldType = field_rev.ty.into();
+        field_type.is_checkbox()
+      })
+      .unwrap()
+      .clone()
+  }
+
   pub async fn get_url_field(&self) -> Arc<FieldRevision> {
     self
       .inner
@@ -0,0 +1,102 @@
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+
+#[tokio::test]
+async fn sub_group_by_checkbox_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let scripts = vec![
+    SetSubGroupByField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    AssertGroupCount(4),
+    // The sub groups are the check and uncheck groups
+    AssertSubGroupCount {
+      group_index: 1,
+      count: 2,
+    },
+    AssertSubGroupRowCount {
+      group_index: 1,
+      sub_group_index: 0,
+      row_count: 2,
+    },
+    AssertSubGroupRowCount {
+      group_index: 1,
+      sub_group_index: 1,
+      row_count: 0,
+    },
+    AssertSubGroupRowCount {
+      group_index: 2,
+      sub_group_index: 0,
+      row_count: 0,
+    },
+    AssertSubGroupRowCount {
+      group_index: 2,
+      sub_group_index: 1,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sub_group_remove_sub_grouping_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let scripts = vec![
+    SetSubGroupByField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    SetSubGroupByField { field_id: None },
+    AssertSubGroupCount {
+      group_index: 1,
+      count: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sub_group_move_row_to_other_sub_group_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let scripts = vec![
+    SetSubGroupByField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    // Move the unchecked row of the second group to the checked sub group of the first group
+    MoveRowToSubGroup {
+      from_group_index: 2,
+      from_row_index: 0,
+      to_group_index: 1,
+      to_sub_group_index: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+    AssertSubGroupRowCount {
+      group_index: 1,
+      sub_group_index: 0,
+      row_count: 3,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 1,
+    },
+    // Regroup the rows to check both cells of the moved row are updated
+    SetSubGroupByField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    AssertSubGroupRowCount {
+      group_index: 1,
+      sub_group_index: 0,
+      row_count: 3,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -0,0 +1,126 @@
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+use flowy_database::entities::GroupPB;
+
+#[tokio::test]
+async fn group_by_text_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let text_field = test.get_text_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: text_field.id.clone(),
+    },
+    // no status group + A, B, C, DA, AE
+    AssertGroupCount(6),
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 0,
+    },
+    AssertGroup {
+      group_index: 1,
+      expected_group: GroupPB {
+        group_id: "A".to_owned(),
+        desc: "A".to_owned(),
+        ..Default::default()
+      },
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_update_text_cell_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let text_field = test.get_text_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: text_field.id.clone(),
+    },
+    // The group B is removed and the group a is inserted
+    UpdateGroupedCellWithData {
+      from_group_index: 2,
+      row_index: 0,
+      cell_data: "a".to_string(),
+    },
+    AssertGroupCount(6),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+    // The group A and the group a are merged after grouping case-insensitively
+    UpdateTextGroupSetting {
+      field_id: text_field.id.clone(),
+      case_insensitive: true,
+    },
+    AssertGroupCount(5),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_update_text_cell_case_insensitive_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let text_field = test.get_text_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: text_field.id.clone(),
+    },
+    UpdateTextGroupSetting {
+      field_id: text_field.id.clone(),
+      case_insensitive: true,
+    },
+    // The row joins the group A and the group B is removed
+    UpdateGroupedCellWithData {
+      from_group_index: 2,
+      row_index: 0,
+      cell_data: "a".to_string(),
+    },
+    AssertGroupCount(5),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_move_text_row_to_other_group_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let text_field = test.get_text_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: text_field.id.clone(),
+    },
+    // Move the only row of the group C to the group A. The group C will be removed
+    MoveRow {
+      from_group_index: 3,
+      from_row_index: 0,
+      to_group_index: 1,
+      to_row_index: 0,
+    },
+    AssertGroupCount(5),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+    // Regroup the rows to check the text cell of the moved row is updated
+    GroupByField {
+      field_id: text_field.id.clone(),
+    },
+    AssertGroupCount(5),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
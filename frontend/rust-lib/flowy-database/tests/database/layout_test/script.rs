@@ -1,11 +1,70 @@
 use crate::database::database_editor::DatabaseEditorTest;
-use database_model::{CalendarLayoutSetting, FieldRevision, LayoutRevision};
-use flowy_database::entities::FieldType;
+use database_model::{
+  CalendarLayoutSetting, FieldRevision, GalleryCoverSource, LayoutRevision, SortCondition,
+};
+use flowy_database::entities::{
+  AlterSortParams, CalendarEventRangeRequestParams, CellIdParams, FieldType, LayoutSettingParams,
+  LayoutTypePB, MoveTimelineBarParams, TimelineBarPB, TimelineBarRequestParams,
+  UpdateCalendarEventOccurrenceParams,
+};
+use flowy_database::services::field::{
+  edit_field_type_option, DateCellChangeset, DateTypeOptionPB, RecurrenceRulePB,
+};
+use flowy_document::entities::DocumentVersionPB;
+use flowy_test::FlowySDKTest;
 use std::sync::Arc;
 
 pub enum LayoutScript {
-  AssertCalendarLayoutSetting { expected: CalendarLayoutSetting },
+  AssertCalendarLayoutSetting {
+    expected: CalendarLayoutSetting,
+  },
   GetCalendarEvents,
+  SetDateRecurrence {
+    recurrence: Option<RecurrenceRulePB>,
+  },
+  SetEventEndDate {
+    title: String,
+    end_timestamp: i64,
+  },
+  UpdateEventOccurrence {
+    title: String,
+    occurrence_timestamp: i64,
+    timestamp: Option<i64>,
+  },
+  AssertEventsInRange {
+    start_timestamp: i64,
+    end_timestamp: i64,
+    expected: Vec<(&'static str, i64)>,
+  },
+  AssertTimelineBars {
+    start_timestamp: i64,
+    end_timestamp: i64,
+    expected: Vec<(&'static str, i64, i64)>,
+  },
+  AssertTimelineDependencies {
+    title: &'static str,
+    expected: Vec<&'static str>,
+  },
+  MoveTimelineBar {
+    title: &'static str,
+    start_timestamp: i64,
+    end_timestamp: i64,
+  },
+  AssertGalleryCards {
+    expected: Vec<(&'static str, Option<&'static str>)>,
+  },
+  SetGalleryCover {
+    cover_source: GalleryCoverSource,
+    field_type: FieldType,
+    is_err: bool,
+  },
+  SetRowDocument {
+    title: &'static str,
+    markdown: &'static str,
+  },
+  SortGalleryByTitle {
+    condition: SortCondition,
+  },
 }
 
 pub struct DatabaseLayoutTest {
@@ -18,6 +77,23 @@ impl DatabaseLayoutTest {
     Self { database_test }
   }
 
+  pub async fn new_timeline() -> Self {
+    let database_test = DatabaseEditorTest::new_timeline().await;
+    Self { database_test }
+  }
+
+  pub async fn new_gallery() -> Self {
+    let database_test = DatabaseEditorTest::new_gallery().await;
+    Self { database_test }
+  }
+
+  /// The covers of the gallery cards can only be read from the node-based documents.
+  pub async fn new_gallery_with_documents() -> Self {
+    let sdk = FlowySDKTest::new(DocumentVersionPB::V1);
+    let database_test = DatabaseEditorTest::new_with_sdk(LayoutTypePB::Gallery, sdk).await;
+    Self { database_test }
+  }
+
   pub async fn run_scripts(&mut self, scripts: Vec<LayoutScript>) {
     for script in scripts {
       self.run_script(script).await;
@@ -31,6 +107,34 @@ impl DatabaseLayoutTest {
       .clone()
   }
 
+  async fn get_row_id_by_title(&self, title: &str) -> String {
+    self
+      .database_test
+      .editor
+      .get_all_calendar_events(&self.database_test.view_id)
+      .await
+      .into_iter()
+      .find(|event| event.title == title)
+      .unwrap()
+      .row_id
+  }
+
+  async fn get_timeline_bars(
+    &self,
+    start_timestamp: i64,
+    end_timestamp: i64,
+  ) -> Vec<TimelineBarPB> {
+    self
+      .database_test
+      .editor
+      .get_timeline_bars(TimelineBarRequestParams {
+        view_id: self.database_test.view_id.clone(),
+        start_timestamp,
+        end_timestamp,
+      })
+      .await
+  }
+
   pub async fn run_script(&mut self, script: LayoutScript) {
     match script {
       LayoutScript::AssertCalendarLayoutSetting { expected } => {
@@ -81,6 +185,279 @@ impl DatabaseLayoutTest {
           }
         }
       },
+      LayoutScript::SetDateRecurrence { recurrence } => {
+        let date_field = self.get_first_date_field().await;
+        edit_field_type_option(
+          &self.database_test.view_id,
+          &date_field.id,
+          self.database_test.editor.clone(),
+          |type_option: &mut DateTypeOptionPB| type_option.recurrence = recurrence,
+        )
+        .await
+        .unwrap();
+      },
+      LayoutScript::SetEventEndDate {
+        title,
+        end_timestamp,
+      } => {
+        let row_id = self.get_row_id_by_title(&title).await;
+        let date_field = self.get_first_date_field().await;
+        let cell_changeset = DateCellChangeset {
+          date: None,
+          time: None,
+          include_time: None,
+          is_utc: true,
+          occurrence_override: None,
+          end_date: Some(end_timestamp.to_string()),
+          end_time: None,
+          is_range: Some(true),
+          timezone_id: None,
+        };
+        self
+          .database_test
+          .editor
+          .update_cell(row_id, date_field.id.clone(), cell_changeset)
+          .await
+          .unwrap();
+      },
+      LayoutScript::UpdateEventOccurrence {
+        title,
+        occurrence_timestamp,
+        timestamp,
+      } => {
+        let view_id = self.database_test.view_id.clone();
+        let row_id = self.get_row_id_by_title(&title).await;
+        self
+          .database_test
+          .editor
+          .update_calendar_event_occurrence(UpdateCalendarEventOccurrenceParams {
+            view_id,
+            row_id,
+            occurrence_timestamp,
+            timestamp,
+          })
+          .await
+          .unwrap();
+      },
+      LayoutScript::AssertEventsInRange {
+        start_timestamp,
+        end_timestamp,
+        mut expected,
+      } => {
+        let mut events = self
+          .database_test
+          .editor
+          .get_calendar_events_in_range(CalendarEventRangeRequestParams {
+            view_id: self.database_test.view_id.clone(),
+            start_timestamp,
+            end_timestamp,
+          })
+          .await
+          .into_iter()
+          .map(|event| (event.title, event.timestamp))
+          .collect::<Vec<_>>();
+        events.sort();
+        expected.sort();
+        let expected = expected
+          .into_iter()
+          .map(|(title, timestamp)| (title.to_string(), timestamp))
+          .collect::<Vec<_>>();
+        assert_eq!(events, expected);
+      },
+      LayoutScript::AssertTimelineBars {
+        start_timestamp,
+        end_timestamp,
+        expected,
+      } => {
+        let bars = self
+          .get_timeline_bars(start_timestamp, end_timestamp)
+          .await
+          .into_iter()
+          .map(|bar| (bar.title, bar.start_timestamp, bar.end_timestamp))
+          .collect::<Vec<_>>();
+        let expected = expected
+          .into_iter()
+          .map(|(title, start, end)| (title.to_string(), start, end))
+          .collect::<Vec<_>>();
+        assert_eq!(bars, expected);
+      },
+      LayoutScript::AssertTimelineDependencies { title, expected } => {
+        let bars = self.get_timeline_bars(0, i64::MAX).await;
+        let bar = bars.iter().find(|bar| bar.title == title).unwrap();
+        let dependencies = bar
+          .dependencies
+          .iter()
+          .map(|row_id| {
+            bars
+              .iter()
+              .find(|bar| &bar.row_id == row_id)
+              .unwrap()
+              .title
+              .as_str()
+          })
+          .collect::<Vec<_>>();
+        assert_eq!(dependencies, expected);
+      },
+      LayoutScript::MoveTimelineBar {
+        title,
+        start_timestamp,
+        end_timestamp,
+      } => {
+        let row_id = self
+          .get_timeline_bars(0, i64::MAX)
+          .await
+          .into_iter()
+          .find(|bar| bar.title == title)
+          .unwrap()
+          .row_id;
+        self
+          .database_test
+          .editor
+          .move_timeline_bar(MoveTimelineBarParams {
+            view_id: self.database_test.view_id.clone(),
+            row_id,
+            start_timestamp,
+            end_timestamp,
+          })
+          .await
+          .unwrap();
+      },
+      LayoutScript::AssertGalleryCards { expected } => {
+        let view_id = self.database_test.view_id.clone();
+        let title_field_id = self
+          .database_test
+          .get_first_field_rev(FieldType::RichText)
+          .id
+          .clone();
+        let cards = self
+          .database_test
+          .editor
+          .get_gallery_cards(&view_id)
+          .await
+          .unwrap();
+        let mut actual = vec![];
+        for card in cards {
+          let title = self
+            .database_test
+            .editor
+            .get_cell_display_str(&CellIdParams {
+              view_id: view_id.clone(),
+              field_id: title_field_id.clone(),
+              row_id: card.row_id,
+            })
+            .await;
+          actual.push((title, card.cover_url));
+        }
+        let expected = expected
+          .into_iter()
+          .map(|(title, cover_url)| (title.to_string(), cover_url.map(|url| url.to_string())))
+          .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+      },
+      LayoutScript::SetRowDocument { title, markdown } => {
+        let view_id = self.database_test.view_id.clone();
+        let title_field_id = self
+          .database_test
+          .get_first_field_rev(FieldType::RichText)
+          .id
+          .clone();
+        let mut row_id = None;
+        for row_rev in self.database_test.row_revs.iter() {
+          let cell_title = self
+            .database_test
+            .editor
+            .get_cell_display_str(&CellIdParams {
+              view_id: view_id.clone(),
+              field_id: title_field_id.clone(),
+              row_id: row_rev.id.clone(),
+            })
+            .await;
+          if cell_title == title {
+            row_id = Some(row_rev.id.clone());
+          }
+        }
+
+        // Replaces the empty document of the row with the one that is made from the Markdown
+        let document_id = self
+          .database_test
+          .editor
+          .get_or_create_row_document(&row_id.unwrap())
+          .await
+          .unwrap();
+        let document_manager = self.database_test.sdk.document_manager.clone();
+        document_manager
+          .delete_document(&document_id)
+          .await
+          .unwrap();
+        document_manager
+          .create_document_from_markdown(&document_id, markdown)
+          .await
+          .unwrap();
+      },
+      LayoutScript::SetGalleryCover {
+        cover_source,
+        field_type,
+        is_err,
+      } => {
+        let view_id = self.database_test.view_id.clone();
+        let cover_field_id = self
+          .database_test
+          .get_first_field_rev(field_type)
+          .id
+          .clone();
+        let mut gallery = self
+          .database_test
+          .editor
+          .get_layout_setting(&view_id, LayoutRevision::Gallery)
+          .await
+          .unwrap()
+          .gallery
+          .unwrap();
+        gallery.cover_source = cover_source;
+        gallery.cover_field_id = Some(cover_field_id);
+        let result = self
+          .database_test
+          .editor
+          .set_layout_setting(
+            &view_id,
+            LayoutSettingParams {
+              gallery: Some(gallery.clone()),
+              ..Default::default()
+            },
+          )
+          .await;
+        assert_eq!(result.is_err(), is_err);
+        if !is_err {
+          let new_gallery = self
+            .database_test
+            .editor
+            .get_layout_setting(&view_id, LayoutRevision::Gallery)
+            .await
+            .unwrap()
+            .gallery
+            .unwrap();
+          assert_eq!(new_gallery, gallery);
+        }
+      },
+      LayoutScript::SortGalleryByTitle { condition } => {
+        let field_rev = self
+          .database_test
+          .get_first_field_rev(FieldType::RichText)
+          .clone();
+        let params = AlterSortParams {
+          view_id: self.database_test.view_id.clone(),
+          field_id: field_rev.id.clone(),
+          sort_id: None,
+          field_type: field_rev.ty,
+          condition: condition.into(),
+        };
+        self
+          .database_test
+          .editor
+          .create_or_update_sort(params)
+          .await
+          .unwrap();
+      },
     }
   }
 }
@@ -1,6 +1,22 @@
 use crate::database::layout_test::script::DatabaseLayoutTest;
 use crate::database::layout_test::script::LayoutScript::*;
-use database_model::CalendarLayoutSetting;
+use crate::database::mock_data::TIMELINE_START;
+use database_model::{CalendarLayoutSetting, GalleryCoverSource, SortCondition};
+use flowy_database::entities::FieldType;
+use flowy_database::services::field::{RecurrenceFrequencyPB, RecurrenceRulePB};
+
+const DAY: i64 = 86400;
+// The date of the row "A"
+const A_TIMESTAMP: i64 = 1678090778;
+
+fn daily_recurrence(count: i32) -> Option<RecurrenceRulePB> {
+  Some(RecurrenceRulePB {
+    frequency: RecurrenceFrequencyPB::Daily,
+    interval: 1,
+    count: Some(count),
+    ..Default::default()
+  })
+}
 
 #[tokio::test]
 async fn calendar_initial_layout_setting_test() {
@@ -19,3 +35,225 @@ async fn calendar_get_events_test() {
   let scripts = vec![GetCalendarEvents];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn calendar_get_events_in_range_test() {
+  let mut test = DatabaseLayoutTest::new_calendar().await;
+  let scripts = vec![AssertEventsInRange {
+    start_timestamp: A_TIMESTAMP - 2 * DAY,
+    end_timestamp: A_TIMESTAMP,
+    expected: vec![("A", A_TIMESTAMP), ("B", A_TIMESTAMP - 2 * DAY)],
+  }];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calendar_get_repeated_events_in_range_test() {
+  let mut test = DatabaseLayoutTest::new_calendar().await;
+  let scripts = vec![
+    SetDateRecurrence {
+      recurrence: daily_recurrence(3),
+    },
+    // The date of the row "B" is two days before the row "A"
+    AssertEventsInRange {
+      start_timestamp: A_TIMESTAMP,
+      end_timestamp: A_TIMESTAMP + 3 * DAY,
+      expected: vec![
+        ("A", A_TIMESTAMP),
+        ("A", A_TIMESTAMP + DAY),
+        ("A", A_TIMESTAMP + 2 * DAY),
+        ("B", A_TIMESTAMP),
+      ],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calendar_override_repeated_event_occurrence_test() {
+  let mut test = DatabaseLayoutTest::new_calendar().await;
+  let scripts = vec![
+    SetDateRecurrence {
+      recurrence: daily_recurrence(3),
+    },
+    // Cancel the second occurrence
+    UpdateEventOccurrence {
+      title: "A".to_string(),
+      occurrence_timestamp: A_TIMESTAMP + DAY,
+      timestamp: None,
+    },
+    // Move the third occurrence one hour earlier
+    UpdateEventOccurrence {
+      title: "A".to_string(),
+      occurrence_timestamp: A_TIMESTAMP + 2 * DAY,
+      timestamp: Some(A_TIMESTAMP + 2 * DAY - 3600),
+    },
+    AssertEventsInRange {
+      start_timestamp: A_TIMESTAMP + DAY,
+      end_timestamp: A_TIMESTAMP + 3 * DAY,
+      expected: vec![("A", A_TIMESTAMP + 2 * DAY - 3600)],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calendar_get_multi_day_events_in_range_test() {
+  let mut test = DatabaseLayoutTest::new_calendar().await;
+  let scripts = vec![
+    SetEventEndDate {
+      title: "A".to_string(),
+      end_timestamp: A_TIMESTAMP + 3 * DAY,
+    },
+    // The event that starts before the range lasts into it
+    AssertEventsInRange {
+      start_timestamp: A_TIMESTAMP + 2 * DAY,
+      end_timestamp: A_TIMESTAMP + 2 * DAY + 3600,
+      expected: vec![("A", A_TIMESTAMP)],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn timeline_get_bars_in_window_test() {
+  let mut test = DatabaseLayoutTest::new_timeline().await;
+  let scripts = vec![AssertTimelineBars {
+    start_timestamp: TIMELINE_START,
+    end_timestamp: TIMELINE_START + 4 * DAY,
+    expected: vec![
+      ("A", TIMELINE_START, TIMELINE_START + 2 * DAY),
+      ("B", TIMELINE_START + 3 * DAY, TIMELINE_START + 5 * DAY),
+    ],
+  }];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn timeline_bar_dependencies_test() {
+  let mut test = DatabaseLayoutTest::new_timeline().await;
+  let scripts = vec![
+    AssertTimelineDependencies {
+      title: "B",
+      expected: vec!["A"],
+    },
+    AssertTimelineDependencies {
+      title: "A",
+      expected: vec![],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn timeline_move_and_resize_bar_test() {
+  let mut test = DatabaseLayoutTest::new_timeline().await;
+  let scripts = vec![
+    // Move the bar of C, which ends on its start date, into the window
+    MoveTimelineBar {
+      title: "C",
+      start_timestamp: TIMELINE_START + DAY,
+      end_timestamp: TIMELINE_START + DAY,
+    },
+    // Resize the bar of A
+    MoveTimelineBar {
+      title: "A",
+      start_timestamp: TIMELINE_START,
+      end_timestamp: TIMELINE_START + 6 * DAY,
+    },
+    AssertTimelineBars {
+      start_timestamp: TIMELINE_START,
+      end_timestamp: TIMELINE_START + 2 * DAY,
+      expected: vec![
+        ("A", TIMELINE_START, TIMELINE_START + 6 * DAY),
+        ("C", TIMELINE_START + DAY, TIMELINE_START + DAY),
+      ],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn gallery_cards_with_url_cover_test() {
+  let mut test = DatabaseLayoutTest::new_gallery().await;
+  let scripts = vec![AssertGalleryCards {
+    expected: vec![
+      ("A", Some("https://appflowy.io/a.png")),
+      ("B", Some("https://appflowy.io/b.png")),
+      ("C", None),
+    ],
+  }];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn gallery_cards_follow_sort_test() {
+  let mut test = DatabaseLayoutTest::new_gallery().await;
+  let scripts = vec![
+    SortGalleryByTitle {
+      condition: SortCondition::Descending,
+    },
+    AssertGalleryCards {
+      expected: vec![
+        ("C", None),
+        ("B", Some("https://appflowy.io/b.png")),
+        ("A", Some("https://appflowy.io/a.png")),
+      ],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn gallery_cards_with_document_cover_test() {
+  let mut test = DatabaseLayoutTest::new_gallery_with_documents().await;
+  let scripts = vec![
+    SetGalleryCover {
+      cover_source: GalleryCoverSource::DocumentImage,
+      field_type: FieldType::URL,
+      is_err: false,
+    },
+    SetRowDocument {
+      title: "B",
+      markdown: "# B\n\n![cover](https://appflowy.io/cover.png)",
+    },
+    AssertGalleryCards {
+      expected: vec![
+        ("A", None),
+        ("B", Some("https://appflowy.io/cover.png")),
+        ("C", None),
+      ],
+    },
+    // The cover is read from the cache
+    AssertGalleryCards {
+      expected: vec![
+        ("A", None),
+        ("B", Some("https://appflowy.io/cover.png")),
+        ("C", None),
+      ],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn gallery_update_cover_source_test() {
+  let mut test = DatabaseLayoutTest::new_gallery().await;
+  let scripts = vec![
+    // The cover can't be read from a text field
+    SetGalleryCover {
+      cover_source: GalleryCoverSource::URLField,
+      field_type: FieldType::RichText,
+      is_err: true,
+    },
+    SetGalleryCover {
+      cover_source: GalleryCoverSource::None,
+      field_type: FieldType::URL,
+      is_err: false,
+    },
+    AssertGalleryCards {
+      expected: vec![("A", None), ("B", None), ("C", None)],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
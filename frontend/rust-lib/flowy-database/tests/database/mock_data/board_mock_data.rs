@@ -102,6 +102,56 @@ pub fn make_test_board() -> BuildDatabaseContext {
           .build();
         database_builder.add_field(checklist_field);
       },
+      FieldType::Formula => {
+        let formula = FormulaTypeOptionBuilder::default()
+          .expression(r#"prop("Price") * 2"#)
+          .result_type(FieldType::Number);
+        let formula_field = FieldBuilder::new(formula)
+          .name("Total")
+          .visibility(true)
+          .build();
+        database_builder.add_field(formula_field);
+      },
+      FieldType::Relation => {
+        let relation = RelationTypeOptionBuilder::default();
+        let relation_field = FieldBuilder::new(relation)
+          .name("Related")
+          .visibility(true)
+          .build();
+        database_builder.add_field(relation_field);
+      },
+      FieldType::Rollup => {
+        let rollup = RollupTypeOptionBuilder::default();
+        let rollup_field = FieldBuilder::new(rollup)
+          .name("Rollup")
+          .visibility(true)
+          .build();
+        database_builder.add_field(rollup_field);
+      },
+      FieldType::CreatedTime => {
+        let created_time = CreatedTimeTypeOptionBuilder::default();
+        let created_time_field = FieldBuilder::new(created_time)
+          .name("Created time")
+          .visibility(true)
+          .build();
+        database_builder.add_field(created_time_field);
+      },
+      FieldType::LastEditedTime => {
+        let last_edited_time = LastEditedTimeTypeOptionBuilder::default();
+        let last_edited_time_field = FieldBuilder::new(last_edited_time)
+          .name("Last edited time")
+          .visibility(true)
+          .build();
+        database_builder.add_field(last_edited_time_field);
+      },
+      FieldType::AutoIncrementId => {
+        let auto_increment_id = AutoIncrementIdTypeOptionBuilder::default();
+        let auto_increment_id_field = FieldBuilder::new(auto_increment_id)
+          .name("ID")
+          .visibility(true)
+          .build();
+        database_builder.add_field(auto_increment_id_field);
+      },
     }
   }
 
@@ -110,6 +160,7 @@ pub fn make_test_board() -> BuildDatabaseContext {
     let block_id = database_builder.block_id().to_owned();
     let field_revs = database_builder.field_revs().clone();
     let mut row_builder = DatabaseRowTestBuilder::new(block_id.clone(), field_revs);
+    row_builder.set_auto_increment_id(database_builder.next_auto_increment_id());
     match i {
       0 => {
         for field_type in FieldType::iter() {
@@ -47,6 +47,7 @@ pub fn make_test_calendar() -> BuildDatabaseContext {
     let block_id = database_builder.block_id().to_owned();
     let field_revs = database_builder.field_revs().clone();
     let mut row_builder = DatabaseRowTestBuilder::new(block_id.clone(), field_revs);
+    row_builder.set_auto_increment_id(database_builder.next_auto_increment_id());
     match i {
       0 => {
         for field_type in FieldType::iter() {
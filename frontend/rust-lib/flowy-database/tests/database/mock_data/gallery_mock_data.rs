@@ -0,0 +1,57 @@
+use database_model::{
+  BuildDatabaseContext, GalleryCoverSource, GalleryLayoutSetting, LayoutRevision, LayoutSetting,
+};
+use flowy_client_sync::client_database::DatabaseBuilder;
+use flowy_database::services::field::{
+  FieldBuilder, RichTextTypeOptionBuilder, URLTypeOptionBuilder,
+};
+use flowy_database::services::row::RowRevisionBuilder;
+
+// Gallery unit test mock data
+pub fn make_test_gallery() -> BuildDatabaseContext {
+  let mut database_builder = DatabaseBuilder::new();
+  // text
+  let text_field = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+    .name("Title")
+    .visibility(true)
+    .primary(true)
+    .build();
+  let text_field_id = text_field.id.clone();
+  database_builder.add_field(text_field);
+
+  // cover
+  let cover_field = FieldBuilder::new(URLTypeOptionBuilder::default())
+    .name("Cover")
+    .visibility(true)
+    .build();
+  let cover_field_id = cover_field.id.clone();
+  database_builder.add_field(cover_field);
+
+  let mut gallery_layout_setting = GalleryLayoutSetting::new(vec![text_field_id.clone()]);
+  gallery_layout_setting.cover_source = GalleryCoverSource::URLField;
+  gallery_layout_setting.cover_field_id = Some(cover_field_id.clone());
+  let mut layout_setting = LayoutSetting::new();
+  let gallery_setting = serde_json::to_string(&gallery_layout_setting).unwrap();
+  layout_setting.insert(LayoutRevision::Gallery, gallery_setting);
+  database_builder.set_layout_setting(layout_setting);
+
+  let rows = vec![
+    ("A", Some("https://appflowy.io/a.png")),
+    ("B", Some("https://appflowy.io/b.png")),
+    ("C", None),
+  ];
+  for (title, cover) in rows {
+    let block_id = database_builder.block_id().to_owned();
+    let field_revs = database_builder.field_revs().clone();
+    let mut row_builder = RowRevisionBuilder::new(&block_id, field_revs);
+    row_builder.set_auto_increment_id(database_builder.next_auto_increment_id());
+    row_builder.insert_text_cell(&text_field_id, title.to_string());
+    if let Some(cover) = cover {
+      row_builder.insert_url_cell(&cover_field_id, cover.to_string());
+    }
+    let row = row_builder.build();
+    database_builder.add_row(row);
+  }
+
+  database_builder.build()
+}
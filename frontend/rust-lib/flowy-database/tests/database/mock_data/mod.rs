@@ -1,10 +1,14 @@
 mod board_mock_data;
 mod calendar_mock_data;
+mod gallery_mock_data;
 mod grid_mock_data;
+mod timeline_mock_data;
 
 pub use board_mock_data::*;
 pub use calendar_mock_data::*;
+pub use gallery_mock_data::*;
 pub use grid_mock_data::*;
+pub use timeline_mock_data::*;
 
 pub const GOOGLE: &str = "Google";
 pub const FACEBOOK: &str = "Facebook";
@@ -0,0 +1,105 @@
+use database_model::{BuildDatabaseContext, LayoutRevision, LayoutSetting, TimelineLayoutSetting};
+use flowy_client_sync::client_database::DatabaseBuilder;
+use flowy_database::services::field::{
+  DateCellData, DateTypeOptionBuilder, FieldBuilder, RelationTypeOptionBuilder,
+  RichTextTypeOptionBuilder,
+};
+use flowy_database::services::row::RowRevisionBuilder;
+
+pub const TIMELINE_START: i64 = 1678090778;
+const DAY: i64 = 86400;
+
+// Timeline unit test mock data
+pub fn make_test_timeline() -> BuildDatabaseContext {
+  let mut database_builder = DatabaseBuilder::new();
+  // text
+  let text_field = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+    .name("Title")
+    .visibility(true)
+    .primary(true)
+    .build();
+  let text_field_id = text_field.id.clone();
+  database_builder.add_field(text_field);
+
+  // start date
+  let start_field = FieldBuilder::new(DateTypeOptionBuilder::default())
+    .name("Start")
+    .visibility(true)
+    .build();
+  let start_field_id = start_field.id.clone();
+  database_builder.add_field(start_field);
+
+  // end date
+  let end_field = FieldBuilder::new(DateTypeOptionBuilder::default())
+    .name("End")
+    .visibility(true)
+    .build();
+  let end_field_id = end_field.id.clone();
+  database_builder.add_field(end_field);
+
+  // dependencies
+  let dependency_field = FieldBuilder::new(RelationTypeOptionBuilder::default())
+    .name("Blocked by")
+    .visibility(true)
+    .build();
+  let dependency_field_id = dependency_field.id.clone();
+  database_builder.add_field(dependency_field);
+
+  let mut timeline_layout_setting =
+    TimelineLayoutSetting::new(start_field_id.clone(), end_field_id.clone());
+  timeline_layout_setting.dependency_field_id = Some(dependency_field_id.clone());
+  let mut layout_setting = LayoutSetting::new();
+  let timeline_setting = serde_json::to_string(&timeline_layout_setting).unwrap();
+  layout_setting.insert(LayoutRevision::Timeline, timeline_setting);
+  database_builder.set_layout_setting(layout_setting);
+
+  // A: day 0 - day 2
+  // B: day 3 - day 5, depends on A
+  // C: day 10
+  // D: no dates
+  let rows = vec![
+    ("A", Some(TIMELINE_START), Some(TIMELINE_START + 2 * DAY)),
+    (
+      "B",
+      Some(TIMELINE_START + 3 * DAY),
+      Some(TIMELINE_START + 5 * DAY),
+    ),
+    ("C", Some(TIMELINE_START + 10 * DAY), None),
+    ("D", None, None),
+  ];
+  let mut first_row_id: Option<String> = None;
+  for (title, start, end) in rows {
+    let block_id = database_builder.block_id().to_owned();
+    let field_revs = database_builder.field_revs().clone();
+    let mut row_builder = RowRevisionBuilder::new(&block_id, field_revs);
+    row_builder.set_auto_increment_id(database_builder.next_auto_increment_id());
+    row_builder.insert_text_cell(&text_field_id, title.to_string());
+    if let Some(start) = start {
+      row_builder.insert_date_cell(&start_field_id, date_cell_data(start));
+    }
+    if let Some(end) = end {
+      row_builder.insert_date_cell(&end_field_id, date_cell_data(end));
+    }
+    if title == "B" {
+      row_builder.insert_relation_cell(
+        &dependency_field_id,
+        first_row_id.clone().into_iter().collect(),
+      );
+    }
+
+    let row = row_builder.build();
+    if first_row_id.is_none() {
+      first_row_id = Some(row.id.clone());
+    }
+    database_builder.add_row(row);
+  }
+
+  database_builder.build()
+}
+
+fn date_cell_data(timestamp: i64) -> DateCellData {
+  DateCellData {
+    timestamp: Some(timestamp),
+    ..Default::default()
+  }
+}
@@ -1,4 +1,5 @@
 mod block_test;
+mod calculation_test;
 mod cell_test;
 mod database_editor;
 mod database_ref_test;
@@ -6,6 +7,10 @@ mod field_test;
 mod filter_test;
 mod group_test;
 mod layout_test;
+mod row_activity_test;
+mod row_document_test;
+mod search_test;
+mod share_test;
 mod snapshot_test;
 mod sort_test;
 
@@ -0,0 +1 @@
+mod row_activity_test;
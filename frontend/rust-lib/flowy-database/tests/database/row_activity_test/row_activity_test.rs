@@ -0,0 +1,108 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use flowy_database::entities::{
+  CreateRowCommentParams, DeleteRowCommentParams, FieldType, UpdateRowCommentParams,
+};
+
+#[tokio::test]
+async fn update_cell_records_row_activity_test() {
+  let mut test = DatabaseEditorTest::new_grid().await;
+  let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+  let row_id = test.row_revs[0].id.clone();
+  test.update_text_cell(row_id.clone(), "B").await;
+  test.update_text_cell(row_id.clone(), "C").await;
+
+  // The latest activity comes first
+  let activities = test.editor.get_row_activities(&row_id).await.unwrap();
+  assert_eq!(activities.len(), 2);
+  assert_eq!(activities[0].field_id, text_field.id);
+  assert_eq!(activities[0].old_value, "B");
+  assert_eq!(activities[0].new_value, "C");
+  assert_eq!(activities[1].old_value, "A");
+  assert_eq!(activities[1].new_value, "B");
+}
+
+#[tokio::test]
+async fn update_cell_with_same_value_test() {
+  let mut test = DatabaseEditorTest::new_grid().await;
+  let row_id = test.row_revs[0].id.clone();
+  test.update_text_cell(row_id.clone(), "A").await;
+
+  let activities = test.editor.get_row_activities(&row_id).await.unwrap();
+  assert!(activities.is_empty());
+}
+
+#[tokio::test]
+async fn update_number_cell_records_display_value_test() {
+  let mut test = DatabaseEditorTest::new_grid().await;
+  let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+  let row_id = test.row_revs[0].id.clone();
+  test
+    .update_cell(&number_field_id, row_id.clone(), "2".to_owned())
+    .await;
+
+  let activities = test.editor.get_row_activities(&row_id).await.unwrap();
+  assert_eq!(activities.len(), 1);
+  assert_eq!(activities[0].old_value, "$1");
+  assert_eq!(activities[0].new_value, "$2");
+}
+
+#[tokio::test]
+async fn row_comment_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let row_id = test.row_revs[0].id.clone();
+  let comment = test
+    .editor
+    .create_row_comment(CreateRowCommentParams {
+      view_id: test.view_id.clone(),
+      row_id: row_id.clone(),
+      content: "Hello".to_owned(),
+    })
+    .await
+    .unwrap();
+  assert_eq!(comment.content, "Hello");
+
+  let updated_comment = test
+    .editor
+    .update_row_comment(UpdateRowCommentParams {
+      view_id: test.view_id.clone(),
+      comment_id: comment.id.clone(),
+      content: "Hello world".to_owned(),
+    })
+    .await
+    .unwrap();
+  assert_eq!(updated_comment.id, comment.id);
+
+  let comments = test.editor.get_row_comments(&row_id).await.unwrap();
+  assert_eq!(comments.len(), 1);
+  assert_eq!(comments[0].content, "Hello world");
+
+  test
+    .editor
+    .delete_row_comment(DeleteRowCommentParams {
+      view_id: test.view_id.clone(),
+      comment_id: comment.id,
+    })
+    .await
+    .unwrap();
+  let comments = test.editor.get_row_comments(&row_id).await.unwrap();
+  assert!(comments.is_empty());
+}
+
+#[tokio::test]
+async fn delete_row_removes_comments_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let row_id = test.row_revs[0].id.clone();
+  test
+    .editor
+    .create_row_comment(CreateRowCommentParams {
+      view_id: test.view_id.clone(),
+      row_id: row_id.clone(),
+      content: "Hello".to_owned(),
+    })
+    .await
+    .unwrap();
+
+  test.editor.delete_row(&row_id).await.unwrap();
+  let comments = test.editor.get_row_comments(&row_id).await.unwrap();
+  assert!(comments.is_empty());
+}
@@ -0,0 +1 @@
+mod row_document_test;
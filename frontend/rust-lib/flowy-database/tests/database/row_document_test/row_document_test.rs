@@ -0,0 +1,100 @@
+use crate::database::database_editor::DatabaseEditorTest;
+
+#[tokio::test]
+async fn row_document_is_created_lazily_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let row_id = test.row_revs[0].id.clone();
+  assert!(test.row_revs[0].document_id.is_none());
+
+  let document_id = test
+    .editor
+    .get_or_create_row_document(&row_id)
+    .await
+    .unwrap();
+  let row_rev = test.editor.get_row_rev(&row_id).await.unwrap().unwrap();
+  assert_eq!(row_rev.document_id, Some(document_id.clone()));
+
+  // Returns the existing document after the document is created
+  let same_document_id = test
+    .editor
+    .get_or_create_row_document(&row_id)
+    .await
+    .unwrap();
+  assert_eq!(document_id, same_document_id);
+}
+
+#[tokio::test]
+async fn duplicate_row_with_document_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let row_id = test.row_revs[0].id.clone();
+  let document_id = test
+    .editor
+    .get_or_create_row_document(&row_id)
+    .await
+    .unwrap();
+
+  test
+    .editor
+    .duplicate_row(&test.view_id, &row_id)
+    .await
+    .unwrap();
+
+  // The duplicated row is inserted after the original row
+  let row_revs = test.get_row_revs().await;
+  assert_eq!(row_revs.len(), test.row_revs.len() + 1);
+  let duplicated_document_id = row_revs[1].document_id.clone().unwrap();
+  assert_ne!(duplicated_document_id, document_id);
+}
+
+#[tokio::test]
+async fn duplicate_database_with_row_documents_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let row_id = test.row_revs[0].id.clone();
+  let document_id = test
+    .editor
+    .get_or_create_row_document(&row_id)
+    .await
+    .unwrap();
+
+  let context = test.editor.duplicate_database(&test.view_id).await.unwrap();
+  let duplicated_row_revs = context
+    .blocks
+    .iter()
+    .flat_map(|block| block.rows.iter())
+    .collect::<Vec<_>>();
+  let document_ids = duplicated_row_revs
+    .iter()
+    .flat_map(|row_rev| row_rev.document_id.clone())
+    .collect::<Vec<String>>();
+  assert_eq!(document_ids.len(), 1);
+  assert_ne!(document_ids[0], document_id);
+}
+
+#[tokio::test]
+async fn delete_database_with_row_documents_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let row_id = test.row_revs[0].id.clone();
+  let document_id = test
+    .editor
+    .get_or_create_row_document(&row_id)
+    .await
+    .unwrap();
+  let document_manager = test.sdk.document_manager.clone();
+  document_manager
+    .open_document_editor(&document_id)
+    .await
+    .unwrap();
+
+  test
+    .sdk
+    .database_manager
+    .delete_database_view(&test.view_id)
+    .await
+    .unwrap();
+
+  // The document of the row is deleted with the database
+  assert!(document_manager
+    .open_document_editor(&document_id)
+    .await
+    .is_err());
+}
@@ -0,0 +1,62 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use crate::database::mock_data::COMPLETED;
+use flowy_database::entities::FieldType;
+
+#[tokio::test]
+async fn search_rows_by_text_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+  let results = test.editor.search_rows(&test.view_id, "ae").await.unwrap();
+
+  // The text of the last two rows is "AE"
+  let row_ids = results
+    .iter()
+    .map(|result| result.row_id.clone())
+    .collect::<Vec<String>>();
+  assert_eq!(
+    row_ids,
+    vec![test.row_revs[4].id.clone(), test.row_revs[5].id.clone()]
+  );
+  for result in results {
+    let cell = result
+      .cells
+      .iter()
+      .find(|cell| cell.field_id == text_field.id)
+      .unwrap();
+    assert_eq!(cell.ranges, vec![0..2]);
+  }
+}
+
+#[tokio::test]
+async fn search_rows_by_select_option_name_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let single_select_field = test.get_first_field_rev(FieldType::SingleSelect).clone();
+  let results = test
+    .editor
+    .search_rows(&test.view_id, &COMPLETED.to_lowercase())
+    .await
+    .unwrap();
+
+  // The option of the third and fourth rows is Completed
+  let row_ids = results
+    .iter()
+    .filter(|result| {
+      result
+        .cells
+        .iter()
+        .any(|cell| cell.field_id == single_select_field.id)
+    })
+    .map(|result| result.row_id.clone())
+    .collect::<Vec<String>>();
+  assert_eq!(
+    row_ids,
+    vec![test.row_revs[2].id.clone(), test.row_revs[3].id.clone()]
+  );
+}
+
+#[tokio::test]
+async fn search_rows_with_empty_query_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let results = test.editor.search_rows(&test.view_id, "  ").await.unwrap();
+  assert!(results.is_empty());
+}
@@ -0,0 +1,81 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use flowy_database::entities::{CellIdParams, FieldChangesetParams};
+use flowy_database::services::share::csv::CSVExport;
+
+#[tokio::test]
+async fn export_grid_as_csv_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let data = CSVExport
+    .export_database(&test.view_id, &test.editor)
+    .await
+    .unwrap();
+
+  let mut reader = csv::Reader::from_reader(data.as_bytes());
+  let header = reader
+    .headers()
+    .unwrap()
+    .iter()
+    .map(|name| name.to_owned())
+    .collect::<Vec<String>>();
+  let field_names = test
+    .field_revs
+    .iter()
+    .map(|field_rev| field_rev.name.clone())
+    .collect::<Vec<String>>();
+  assert_eq!(header, field_names);
+
+  let records = reader
+    .records()
+    .map(|record| record.unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(records.len(), test.row_revs.len());
+  for (record, row_rev) in records.iter().zip(test.row_revs.iter()) {
+    for (index, field_rev) in test.field_revs.iter().enumerate() {
+      let params = CellIdParams {
+        view_id: test.view_id.clone(),
+        field_id: field_rev.id.clone(),
+        row_id: row_rev.id.clone(),
+      };
+      let display_str = test.editor.get_cell_display_str(&params).await;
+      assert_eq!(&record[index], display_str);
+    }
+  }
+}
+
+#[tokio::test]
+async fn export_grid_as_csv_skip_hidden_fields_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let hidden_field_rev = test.field_revs[1].clone();
+  test
+    .editor
+    .update_field(FieldChangesetParams {
+      field_id: hidden_field_rev.id.clone(),
+      view_id: test.view_id.clone(),
+      visibility: Some(false),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+
+  let data = CSVExport
+    .export_database(&test.view_id, &test.editor)
+    .await
+    .unwrap();
+  let mut reader = csv::Reader::from_reader(data.as_bytes());
+  let header = reader
+    .headers()
+    .unwrap()
+    .iter()
+    .map(|name| name.to_owned())
+    .collect::<Vec<String>>();
+  let field_names = test
+    .field_revs
+    .iter()
+    .filter(|field_rev| field_rev.id != hidden_field_rev.id)
+    .map(|field_rev| field_rev.name.clone())
+    .collect::<Vec<String>>();
+  assert_eq!(header, field_names);
+  assert!(reader
+    .records()
+    .all(|record| record.unwrap().len() == field_names.len()));
+}
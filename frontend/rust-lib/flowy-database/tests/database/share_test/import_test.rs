@@ -0,0 +1,61 @@
+use flowy_database::entities::FieldType;
+use flowy_database::services::share::csv::CSVImporter;
+
+#[test]
+fn import_csv_infer_field_types_test() {
+  let data = r#"Name,Price,Date,Done,Link,Status,Tags,Notes
+Write the docs,$10.50,2022/03/14,Yes,https://appflowy.io,Planned,"Docs, Rust",first
+Fix the bug,3,2022/03/15,No,,Planned,Rust,second
+Release,"1,200",2022/03/16,no,https://github.com,Completed,Docs,third
+"#;
+  let build_context = CSVImporter.import_csv_from_string(data).unwrap();
+  let field_types = build_context
+    .field_revs
+    .iter()
+    .map(|field_rev| field_rev.ty.into())
+    .collect::<Vec<FieldType>>();
+  assert_eq!(
+    field_types,
+    vec![
+      FieldType::RichText,
+      FieldType::Number,
+      FieldType::DateTime,
+      FieldType::Checkbox,
+      FieldType::URL,
+      FieldType::SingleSelect,
+      FieldType::MultiSelect,
+      FieldType::RichText,
+    ]
+  );
+  assert!(build_context.field_revs[0].is_primary);
+
+  let rows = &build_context.blocks[0].rows;
+  assert_eq!(rows.len(), 3);
+  // The empty cells are not imported.
+  let link_field_id = &build_context.field_revs[4].id;
+  assert!(rows[1].cells.get(link_field_id).is_none());
+}
+
+#[test]
+fn import_csv_number_with_comma_test() {
+  let data = r#"Name,Price,Ratio
+Apple,"1,200.5","1,5"
+Banana,"12,000","2,25"
+"#;
+  let build_context = CSVImporter.import_csv_from_string(data).unwrap();
+  let field_types = build_context
+    .field_revs
+    .iter()
+    .map(|field_rev| field_rev.ty.into())
+    .collect::<Vec<FieldType>>();
+  // The commas of the ratios are decimal separators, not thousands separators.
+  assert_eq!(
+    field_types,
+    vec![FieldType::RichText, FieldType::Number, FieldType::RichText]
+  );
+}
+
+#[test]
+fn import_csv_without_header_test() {
+  assert!(CSVImporter.import_csv_from_string("").is_err());
+}
@@ -0,0 +1,2 @@
+mod export_test;
+mod import_test;
@@ -1,7 +1,8 @@
 use crate::database::database_editor::DatabaseEditorTest;
 
-use database_model::FieldRevision;
+use database_model::{FieldRevision, SortCondition};
 use flowy_client_sync::client_database::{DatabaseOperations, DatabaseRevisionPad};
+use flowy_database::entities::{AlterSortParams, CreateRowParams};
 use flowy_revision::{RevisionSnapshotData, REVISION_WRITE_INTERVAL_IN_MILLIS};
 use revision_model::Revision;
 use std::time::Duration;
@@ -9,6 +10,7 @@ use tokio::time::sleep;
 
 pub enum SnapshotScript {
   WriteSnapshot,
+  WriteCheckpoint,
   #[allow(dead_code)]
   AssertSnapshot {
     rev_id: i64,
@@ -24,6 +26,29 @@ pub enum SnapshotScript {
   DeleteField {
     field_rev: FieldRevision,
   },
+  AssertCheckpointExists {
+    rev_id: i64,
+  },
+  AssertCheckpointContent {
+    rev_id: i64,
+    expected: String,
+  },
+  RestoreCheckpoint {
+    rev_id: i64,
+  },
+  CreateEmptyRow,
+  InsertSort {
+    field_rev: FieldRevision,
+  },
+  AssertFieldCount {
+    expected: usize,
+  },
+  AssertRowCount {
+    expected: usize,
+  },
+  AssertSortCount {
+    expected: usize,
+  },
 }
 
 pub struct DatabaseSnapshotTest {
@@ -68,6 +93,11 @@ impl DatabaseSnapshotTest {
         rev_manager.generate_snapshot().await;
         self.current_snapshot = rev_manager.read_snapshot(None).await.unwrap();
       },
+      SnapshotScript::WriteCheckpoint => {
+        self.editor.write_checkpoint().await;
+        let rev_id = rev_manager.rev_id();
+        self.current_snapshot = rev_manager.read_snapshot(Some(rev_id)).await.unwrap();
+      },
       SnapshotScript::AssertSnapshot { rev_id, expected } => {
         let snapshot = rev_manager.read_snapshot(Some(rev_id)).await.unwrap();
         assert_eq!(snapshot, expected);
@@ -85,6 +115,52 @@ impl DatabaseSnapshotTest {
       SnapshotScript::DeleteField { field_rev } => {
         self.editor.delete_field(&field_rev.id).await.unwrap();
       },
+      SnapshotScript::AssertCheckpointExists { rev_id } => {
+        let checkpoints = self.editor.get_checkpoints().await.unwrap();
+        assert!(checkpoints
+          .iter()
+          .any(|checkpoint| checkpoint.rev_id == rev_id));
+      },
+      SnapshotScript::AssertCheckpointContent { rev_id, expected } => {
+        let content = self.editor.read_checkpoint(rev_id).await.unwrap();
+        let content: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(&expected).unwrap();
+        assert_eq!(content, expected);
+      },
+      SnapshotScript::RestoreCheckpoint { rev_id } => {
+        self.editor.restore_checkpoint(rev_id).await.unwrap();
+      },
+      SnapshotScript::CreateEmptyRow => {
+        let params = CreateRowParams {
+          view_id: self.view_id.clone(),
+          start_row_id: None,
+          group_id: None,
+          sub_group_id: None,
+          cell_data_by_field_id: None,
+        };
+        self.editor.create_row(params).await.unwrap();
+      },
+      SnapshotScript::InsertSort { field_rev } => {
+        let params = AlterSortParams {
+          view_id: self.view_id.clone(),
+          field_id: field_rev.id.clone(),
+          sort_id: None,
+          field_type: field_rev.ty,
+          condition: SortCondition::Ascending.into(),
+        };
+        self.editor.create_or_update_sort(params).await.unwrap();
+      },
+      SnapshotScript::AssertFieldCount { expected } => {
+        let field_revs = self.editor.get_field_revs(None).await.unwrap();
+        assert_eq!(field_revs.len(), expected);
+      },
+      SnapshotScript::AssertRowCount { expected } => {
+        assert_eq!(self.get_row_revs().await.len(), expected);
+      },
+      SnapshotScript::AssertSortCount { expected } => {
+        let sorts = self.editor.get_all_sorts(&self.view_id).await.unwrap();
+        assert_eq!(sorts.len(), expected);
+      },
     }
   }
 }
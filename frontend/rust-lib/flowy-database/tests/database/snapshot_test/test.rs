@@ -45,3 +45,63 @@ async fn snapshot_multi_version_test() {
     }])
     .await;
 }
+
+#[tokio::test]
+async fn snapshot_read_checkpoint_test() {
+  let mut test = DatabaseSnapshotTest::new().await;
+  let original_content = test.grid_pad().await.json_str().unwrap();
+  let scripts = vec![WriteSnapshot];
+  test.run_scripts(scripts).await;
+  let checkpoint_rev_id = test.current_snapshot.clone().unwrap().rev_id;
+
+  let (_, field_rev) = create_text_field(&test.grid_id());
+  let scripts = vec![
+    CreateField { field_rev },
+    WriteSnapshot,
+    AssertCheckpointExists {
+      rev_id: checkpoint_rev_id,
+    },
+    AssertCheckpointContent {
+      rev_id: checkpoint_rev_id,
+      expected: original_content,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn snapshot_restore_checkpoint_test() {
+  let mut test = DatabaseSnapshotTest::new().await;
+  let original_field_count = test.field_count;
+  let original_row_count = test.row_revs.len();
+  test.run_scripts(vec![WriteCheckpoint]).await;
+  let checkpoint_rev_id = test.current_snapshot.clone().unwrap().rev_id;
+
+  let (_, field_rev) = create_text_field(&test.grid_id());
+  let scripts = vec![
+    CreateField {
+      field_rev: field_rev.clone(),
+    },
+    CreateEmptyRow,
+    InsertSort { field_rev },
+    AssertFieldCount {
+      expected: original_field_count + 1,
+    },
+    AssertRowCount {
+      expected: original_row_count + 1,
+    },
+    AssertSortCount { expected: 1 },
+    RestoreCheckpoint {
+      rev_id: checkpoint_rev_id,
+    },
+    // The rows and the sorts are restored along with the fields.
+    AssertFieldCount {
+      expected: original_field_count,
+    },
+    AssertRowCount {
+      expected: original_row_count,
+    },
+    AssertSortCount { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -0,0 +1,77 @@
+use crate::editor::document::Document;
+use crate::entities::ExportType;
+use flowy_error::FlowyResult;
+use lib_ot::codec::editor_node::{node_plain_text, TEXT_NODE_TYPE};
+use lib_ot::codec::markdown::markdown_node_encoder;
+use lib_ot::core::NodeData;
+
+impl Document {
+  /// Exports the document in the format of the [ExportType]. The [ExportType::Link] exports
+  /// the content of the document in JSON format.
+  pub fn export(&self, export_type: &ExportType) -> FlowyResult<String> {
+    match export_type {
+      ExportType::Text => Ok(document_to_text(self)),
+      ExportType::Markdown => Ok(document_to_markdown(self)),
+      ExportType::Link => self.get_content(false),
+    }
+  }
+
+  /// Returns the top level nodes of the document. Usually, it's the `editor` node.
+  pub fn get_root_node_data(&self) -> Vec<NodeData> {
+    let tree = self.get_tree();
+    tree
+      .get_children_ids(tree.root_node_id())
+      .into_iter()
+      .filter_map(|node_id| tree.get_node_data(node_id))
+      .collect()
+  }
+}
+
+pub fn document_to_markdown(document: &Document) -> String {
+  markdown_node_encoder(&document.get_root_node_data())
+}
+
+/// Returns the text of the document. Each text node occupies its own line and the nested
+/// nodes are indented.
+pub fn document_to_text(document: &Document) -> String {
+  let mut lines = vec![];
+  write_text_lines(&document.get_root_node_data(), 0, &mut lines);
+  lines.join("\n")
+}
+
+fn write_text_lines(nodes: &[NodeData], depth: usize, lines: &mut Vec<String>) {
+  for node in nodes {
+    if node.node_type == TEXT_NODE_TYPE {
+      let indent = "  ".repeat(depth);
+      for line in node_plain_text(node).split('\n') {
+        lines.push(format!("{}{}", indent, line));
+      }
+      write_text_lines(&node.children, depth + 1, lines);
+    } else {
+      write_text_lines(&node.children, depth, lines);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::editor::document::Document;
+  use crate::editor::document_export::{document_to_markdown, document_to_text};
+
+  const DOCUMENT: &str = r#"{"document":{"type":"editor","children":[{"type":"text","attributes":{"subtype":"heading","heading":"h2"},"delta":[{"insert":"Hello"}]},{"type":"text","attributes":{"subtype":"bulleted-list"},"delta":[{"insert":"bold","attributes":{"bold":true}},{"insert":" world"}],"children":[{"type":"text","attributes":{"subtype":"checkbox","checkbox":true},"delta":[{"insert":"done"}]}]},{"type":"text"}]}}"#;
+
+  #[test]
+  fn document_export_markdown_test() {
+    let document: Document = serde_json::from_str(DOCUMENT).unwrap();
+    assert_eq!(
+      document_to_markdown(&document),
+      "## Hello\n\n* **bold** world\n  - [x] done\n"
+    );
+  }
+
+  #[test]
+  fn document_export_text_test() {
+    let document: Document = serde_json::from_str(DOCUMENT).unwrap();
+    assert_eq!(document_to_text(&document), "Hello\nbold world\n  done\n");
+  }
+}
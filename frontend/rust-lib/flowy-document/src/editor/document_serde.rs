@@ -1,6 +1,7 @@
 use crate::editor::document::Document;
 use bytes::Bytes;
 use flowy_error::FlowyResult;
+use lib_ot::codec::markdown::markdown_node_decoder;
 use lib_ot::core::{
   AttributeHashMap, Body, Changeset, Extension, NodeData, NodeId, NodeOperation, NodeTree,
   NodeTreeContext, Path, Selection, Transaction,
@@ -88,6 +89,40 @@ pub fn make_transaction_from_document_content(content: &str) -> FlowyResult<Tran
   Ok(document_transaction.into())
 }
 
+/// Returns the transaction that creates the document from the Markdown. The transaction can be
+/// saved as the initial revision of the document.
+pub fn make_transaction_from_markdown(markdown: &str) -> Transaction {
+  let document_node = DocumentNode::from(markdown_node_decoder(markdown));
+  let document_operation = DocumentOperation::Insert {
+    path: 0_usize.into(),
+    nodes: vec![document_node],
+  };
+  let mut document_transaction = DocumentTransaction::default();
+  document_transaction.operations.push(document_operation);
+  document_transaction.into()
+}
+
+/// Returns the source of the first image in the document content, searching the nodes in
+/// depth-first order.
+pub fn first_image_src_from_document_content(content: &str) -> FlowyResult<Option<String>> {
+  let document_node: DocumentNode =
+    serde_json::from_str::<DocumentContentDeserializer>(content)?.document;
+  Ok(first_image_src(&document_node))
+}
+
+fn first_image_src(node: &DocumentNode) -> Option<String> {
+  if node.node_type == "image" {
+    if let Some(image_src) = node
+      .attributes
+      .get("image_src")
+      .and_then(|value| value.str_value())
+    {
+      return Some(image_src);
+    }
+  }
+  node.children.iter().find_map(first_image_src)
+}
+
 pub struct DocumentContentSerde {}
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -350,7 +385,9 @@ impl<'a> Serialize for DocumentContentSerializer<'a> {
 #[cfg(test)]
 mod tests {
   use crate::editor::document::Document;
-  use crate::editor::document_serde::DocumentTransaction;
+  use crate::editor::document_serde::{
+    first_image_src_from_document_content, make_transaction_from_markdown, DocumentTransaction,
+  };
   use crate::editor::initial_read_me;
 
   #[test]
@@ -358,6 +395,21 @@ mod tests {
     let _ = initial_read_me();
   }
 
+  #[test]
+  fn document_first_image_src_test() {
+    let content = r#"{"document":{"type":"editor","children":[{"type":"text","delta":[{"insert":"Hello"}]},{"type":"text","children":[{"type":"image","attributes":{"image_src":"https://appflowy.io/first.png"}}]},{"type":"image","attributes":{"image_src":"https://appflowy.io/second.png"}}]}}"#;
+    assert_eq!(
+      first_image_src_from_document_content(content).unwrap(),
+      Some("https://appflowy.io/first.png".to_owned())
+    );
+
+    let content = r#"{"document":{"type":"editor","children":[{"type":"text"}]}}"#;
+    assert_eq!(
+      first_image_src_from_document_content(content).unwrap(),
+      None
+    );
+  }
+
   #[test]
   fn transaction_deserialize_update_text_operation_test() {
     // bold
@@ -396,6 +448,16 @@ mod tests {
     let _ = serde_json::to_string_pretty(&document).unwrap();
   }
 
+  #[test]
+  fn document_from_markdown_test() {
+    let transaction = make_transaction_from_markdown("# Hello\n\n- [ ] AppFlowy");
+    let document = Document::from_transaction(transaction).unwrap();
+    assert_eq!(
+      document.get_content(false).unwrap(),
+      r#"{"document":{"type":"editor","children":[{"type":"text","attributes":{"subtype":"heading","heading":"h1"},"delta":[{"insert":"Hello"}]},{"type":"text","attributes":{"subtype":"checkbox","checkbox":false},"delta":[{"insert":"AppFlowy"}]}]}}"#
+    );
+  }
+
   // #[test]
   // fn document_operation_compose_test() {
   //     let json = include_str!("./test.json");
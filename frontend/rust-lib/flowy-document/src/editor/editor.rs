@@ -2,14 +2,15 @@ use crate::editor::document::{Document, DocumentRevisionSerde};
 use crate::editor::document_serde::DocumentTransaction;
 use crate::editor::make_transaction_from_revisions;
 use crate::editor::queue::{Command, CommandSender, DocumentQueue};
+use crate::entities::ExportType;
 use crate::{DocumentEditor, DocumentUser};
 use bytes::Bytes;
 use flowy_error::{internal_error, FlowyError, FlowyResult};
-use flowy_revision::{RevisionCloudService, RevisionManager};
+use flowy_revision::{RevisionCheckpoint, RevisionCloudService, RevisionManager};
 use flowy_sqlite::ConnectionPool;
 use lib_infra::async_trait::async_trait;
 use lib_infra::future::FutureResult;
-use lib_ot::core::Transaction;
+use lib_ot::core::{Path, Transaction, TransactionBuilder};
 use lib_ws::WSConnectState;
 use std::any::Any;
 use std::sync::Arc;
@@ -64,6 +65,16 @@ impl AppFlowyDocumentEditor {
     Ok(content)
   }
 
+  pub async fn export_document(&self, export_type: ExportType) -> FlowyResult<String> {
+    let (ret, rx) = oneshot::channel::<FlowyResult<String>>();
+    let _ = self
+      .command_sender
+      .send(Command::ExportDocument { export_type, ret })
+      .await;
+    let data = rx.await.map_err(internal_error)??;
+    Ok(data)
+  }
+
   pub async fn duplicate_document(&self) -> FlowyResult<String> {
     let transaction = self.document_transaction().await?;
     let document = Document::from_transaction(transaction)?;
@@ -75,6 +86,30 @@ impl AppFlowyDocumentEditor {
     let revisions = self.rev_manager.load_revisions().await?;
     make_transaction_from_revisions(&revisions)
   }
+
+  pub async fn read_checkpoint_document(&self, rev_id: i64) -> FlowyResult<String> {
+    let document = self
+      .rev_manager
+      .read_checkpoint_object::<DocumentRevisionSerde>(rev_id)
+      .await?;
+    document.get_content(true)
+  }
+
+  /// Replaces the top level nodes of the document with the ones of the checkpoint.
+  pub async fn restore_checkpoint_document(&self, rev_id: i64) -> FlowyResult<()> {
+    let checkpoint = self
+      .rev_manager
+      .read_checkpoint_object::<DocumentRevisionSerde>(rev_id)
+      .await?;
+    let document = Document::from_transaction(self.document_transaction().await?)?;
+    let tree = document.get_tree();
+    let number_of_nodes = tree.get_children_ids(tree.root_node_id()).len();
+    let transaction = TransactionBuilder::new()
+      .delete_nodes_at_path(tree, &Path::from(vec![0]), number_of_nodes)
+      .insert_nodes_at_path(0, checkpoint.get_root_node_data())
+      .build();
+    self.apply_transaction(transaction).await
+  }
 }
 
 fn spawn_edit_queue(
@@ -101,11 +136,31 @@ impl DocumentEditor for Arc<AppFlowyDocumentEditor> {
     FutureResult::new(async move { this.get_content(false).await })
   }
 
+  fn export_as(&self, export_type: ExportType) -> FutureResult<String, FlowyError> {
+    let this = self.clone();
+    FutureResult::new(async move { this.export_document(export_type).await })
+  }
+
   fn duplicate(&self) -> FutureResult<String, FlowyError> {
     let this = self.clone();
     FutureResult::new(async move { this.duplicate_document().await })
   }
 
+  fn checkpoints(&self) -> FutureResult<Vec<RevisionCheckpoint>, FlowyError> {
+    let rev_manager = self.rev_manager.clone();
+    FutureResult::new(async move { rev_manager.read_checkpoints().await })
+  }
+
+  fn read_checkpoint(&self, rev_id: i64) -> FutureResult<String, FlowyError> {
+    let this = self.clone();
+    FutureResult::new(async move { this.read_checkpoint_document(rev_id).await })
+  }
+
+  fn restore_checkpoint(&self, rev_id: i64) -> FutureResult<(), FlowyError> {
+    let this = self.clone();
+    FutureResult::new(async move { this.restore_checkpoint_document(rev_id).await })
+  }
+
   fn receive_ws_data(&self, _data: ServerRevisionWSData) -> FutureResult<(), FlowyError> {
     FutureResult::new(async move { Ok(()) })
   }
@@ -125,3 +180,10 @@ impl DocumentEditor for Arc<AppFlowyDocumentEditor> {
     self
   }
 }
+
+#[cfg(feature = "flowy_unit_test")]
+impl AppFlowyDocumentEditor {
+  pub fn rev_manager(&self) -> Arc<RevisionManager<Arc<ConnectionPool>>> {
+    self.rev_manager.clone()
+  }
+}
@@ -1,10 +1,12 @@
 #![allow(clippy::module_inception)]
 mod document;
+mod document_export;
 mod document_serde;
 mod editor;
 mod queue;
 
 pub use document::*;
+pub use document_export::*;
 pub use document_serde::*;
 pub use editor::*;
 
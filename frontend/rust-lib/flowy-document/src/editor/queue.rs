@@ -1,5 +1,6 @@
 #![allow(clippy::while_let_loop)]
 use crate::editor::document::Document;
+use crate::entities::ExportType;
 use crate::DocumentUser;
 use async_stream::stream;
 use bytes::Bytes;
@@ -75,6 +76,10 @@ impl DocumentQueue {
         let content = self.document.read().await.get_content(pretty)?;
         let _ = ret.send(Ok(content));
       },
+      Command::ExportDocument { export_type, ret } => {
+        let data = self.document.read().await.export(&export_type)?;
+        let _ = ret.send(Ok(data));
+      },
     }
     Ok(())
   }
@@ -104,4 +109,8 @@ pub enum Command {
     pretty: bool,
     ret: Ret<String>,
   },
+  ExportDocument {
+    export_type: ExportType,
+    ret: Ret<String>,
+  },
 }
@@ -38,7 +38,7 @@ pub(crate) async fn export_handler(
 ) -> DataResult<ExportDataPB, FlowyError> {
   let params: ExportParams = data.into_inner().try_into()?;
   let editor = manager.open_document_editor(&params.view_id).await?;
-  let document_data = editor.export().await?;
+  let document_data = editor.export_as(params.export_type.clone()).await?;
   data_result_ok(ExportDataPB {
     data: document_data,
     export_type: params.export_type,
@@ -1,5 +1,8 @@
-use crate::editor::{initial_document_content, AppFlowyDocumentEditor, DocumentRevisionMergeable};
-use crate::entities::{DocumentVersionPB, EditParams};
+use crate::editor::{
+  initial_document_content, make_transaction_from_markdown, make_transaction_from_revisions,
+  AppFlowyDocumentEditor, Document, DocumentRevisionMergeable,
+};
+use crate::entities::{DocumentVersionPB, EditParams, ExportType};
 use crate::old_editor::editor::{DeltaDocumentEditor, DeltaDocumentRevisionMergeable};
 use crate::old_editor::snapshot::DeltaDocumentSnapshotPersistence;
 use crate::services::rev_sqlite::{
@@ -13,9 +16,10 @@ use document_model::document::DocumentId;
 use flowy_client_sync::client_document::initial_delta_document_content;
 use flowy_error::FlowyResult;
 use flowy_revision::{
-  RevisionCloudService, RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration,
-  RevisionWebSocket,
+  RevisionCheckpoint, RevisionCloudService, RevisionManager, RevisionPersistence,
+  RevisionPersistenceConfiguration, RevisionWebSocket,
 };
+use flowy_revision_persistence::RevisionDiskCache;
 use flowy_sqlite::ConnectionPool;
 use lib_infra::async_trait::async_trait;
 use lib_infra::future::FutureResult;
@@ -48,9 +52,36 @@ pub trait DocumentEditor: Send + Sync {
   /// editor data format.
   fn export(&self) -> FutureResult<String, FlowyError>;
 
+  /// Exports the document content in the format of the [ExportType]. The editors that don't
+  /// support the [ExportType] export the content in their own data format.
+  fn export_as(&self, _export_type: ExportType) -> FutureResult<String, FlowyError> {
+    self.export()
+  }
+
   /// Duplicate the document inner data into String
   fn duplicate(&self) -> FutureResult<String, FlowyError>;
 
+  /// Returns the checkpoints of the document in ascending order of the rev_id. The editors that
+  /// don't keep the snapshots of the document have no checkpoints.
+  fn checkpoints(&self) -> FutureResult<Vec<RevisionCheckpoint>, FlowyError> {
+    FutureResult::new(async { Ok(vec![]) })
+  }
+
+  /// Returns the document content at the checkpoint. The content is encoded in the
+  /// corresponding editor data format.
+  fn read_checkpoint(&self, rev_id: i64) -> FutureResult<String, FlowyError> {
+    FutureResult::new(async move {
+      Err(FlowyError::record_not_found().context(format!("Can't find the checkpoint: {}", rev_id)))
+    })
+  }
+
+  /// Restores the document to the checkpoint by saving the changes as a new revision.
+  fn restore_checkpoint(&self, rev_id: i64) -> FutureResult<(), FlowyError> {
+    FutureResult::new(async move {
+      Err(FlowyError::record_not_found().context(format!("Can't find the checkpoint: {}", rev_id)))
+    })
+  }
+
   fn receive_ws_data(&self, data: ServerRevisionWSData) -> FutureResult<(), FlowyError>;
 
   fn receive_ws_state(&self, state: &WSConnectState);
@@ -162,6 +193,74 @@ impl DocumentManager {
     Ok(())
   }
 
+  /// Creates the document from the Markdown. Only the node-based documents (V1) can be imported
+  /// from Markdown, the delta-based documents (V0) return an error.
+  pub async fn create_document_from_markdown<T: AsRef<str>>(
+    &self,
+    doc_id: T,
+    markdown: &str,
+  ) -> FlowyResult<()> {
+    if self.config.version != DocumentVersionPB::V1 {
+      return Err(FlowyError::internal().context("Importing Markdown requires the V1 document"));
+    }
+    let doc_id = doc_id.as_ref();
+    let transaction = make_transaction_from_markdown(markdown);
+    let revision = Revision::initial_revision(doc_id, Bytes::from(transaction.to_bytes()?));
+    self.create_document(doc_id, vec![revision]).await
+  }
+
+  /// Closes the editor of the document and removes the document's revisions from the disk.
+  #[tracing::instrument(level = "trace", skip(self, doc_id), err)]
+  pub async fn delete_document<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<()> {
+    let doc_id = doc_id.as_ref();
+    self.close_document_editor(doc_id).await?;
+    let user_id = self.user.user_id()?;
+    let db_pool = self.persistence.database.db_pool()?;
+    match self.config.version {
+      DocumentVersionPB::V0 => SQLiteDeltaDocumentRevisionPersistence::new(&user_id, db_pool)
+        .delete_revision_records(doc_id, None)?,
+      DocumentVersionPB::V1 => SQLiteDocumentRevisionPersistence::new(&user_id, db_pool)
+        .delete_revision_records(doc_id, None)?,
+    }
+    Ok(())
+  }
+
+  /// Returns the content of the document without opening its editor. The content is built from
+  /// the revisions on the disk and encoded in the same way as [DocumentEditor::duplicate]. Only
+  /// the node-based documents (V1) can be read.
+  pub fn read_document_content<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<String> {
+    let revisions = self
+      .make_document_disk_cache()?
+      .read_revision_records(doc_id.as_ref(), None)?
+      .into_iter()
+      .map(|record| record.revision)
+      .collect::<Vec<_>>();
+    let document = Document::from_transaction(make_transaction_from_revisions(&revisions)?)?;
+    let json = serde_json::to_string(&document)?;
+    Ok(json)
+  }
+
+  /// Returns the rev_id of the latest revision of the document on the disk. It changes when the
+  /// document is edited, so it's used to tell whether the data read from the document is stale.
+  pub fn read_document_rev_id<T: AsRef<str>>(&self, doc_id: T) -> FlowyResult<i64> {
+    let rev_id = self
+      .make_document_disk_cache()?
+      .read_latest_rev_id(doc_id.as_ref())?;
+    Ok(rev_id.unwrap_or_default())
+  }
+
+  fn make_document_disk_cache(&self) -> FlowyResult<SQLiteDocumentRevisionPersistence> {
+    if self.config.version != DocumentVersionPB::V1 {
+      return Err(
+        FlowyError::internal()
+          .context("Reading the document from the disk requires the V1 document"),
+      );
+    }
+    let user_id = self.user.user_id()?;
+    let db_pool = self.persistence.database.db_pool()?;
+    Ok(SQLiteDocumentRevisionPersistence::new(&user_id, db_pool))
+  }
+
   pub async fn receive_ws_data(&self, data: Bytes) {
     let result: Result<ServerRevisionWSData, serde_json::Error> =
       ServerRevisionWSData::try_from(data);
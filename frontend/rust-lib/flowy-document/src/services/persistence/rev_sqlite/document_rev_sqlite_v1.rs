@@ -94,6 +94,13 @@ impl SQLiteDocumentRevisionPersistence {
       pool,
     }
   }
+
+  /// Returns the rev_id of the latest revision of the document, or None if the document has no
+  /// revisions.
+  pub fn read_latest_rev_id(&self, object_id: &str) -> FlowyResult<Option<i64>> {
+    let conn = self.pool.get().map_err(internal_error)?;
+    DocumentRevisionSql::read_latest_rev_id(object_id, &conn)
+  }
 }
 
 struct DocumentRevisionSql {}
@@ -163,6 +170,17 @@ impl DocumentRevisionSql {
     Ok(records)
   }
 
+  fn read_latest_rev_id(
+    object_id: &str,
+    conn: &SqliteConnection,
+  ) -> Result<Option<i64>, FlowyError> {
+    let rev_id = dsl::document_rev_table
+      .filter(dsl::document_id.eq(object_id))
+      .select(diesel::dsl::max(dsl::rev_id))
+      .first::<Option<i64>>(conn)?;
+    Ok(rev_id)
+  }
+
   fn read_with_range(
     user_id: &str,
     object_id: &str,
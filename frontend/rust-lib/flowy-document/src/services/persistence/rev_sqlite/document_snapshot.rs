@@ -71,6 +71,15 @@ impl RevisionSnapshotPersistence for SQLiteDocumentRevisionSnapshotPersistence {
             .first::<DocumentSnapshotRecord>(&*conn)?;
     Ok(Some(latest_record.into()))
   }
+
+  fn read_snapshots(&self) -> FlowyResult<Vec<RevisionSnapshotData>> {
+    let conn = self.pool.get().map_err(internal_error)?;
+    let records = dsl::document_rev_snapshot
+      .filter(dsl::object_id.eq(&self.object_id))
+      .order(dsl::rev_id.asc())
+      .load::<DocumentSnapshotRecord>(&*conn)?;
+    Ok(records.into_iter().map(|record| record.into()).collect())
+  }
 }
 
 #[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
@@ -1,11 +1,14 @@
 use flowy_document::editor::{AppFlowyDocumentEditor, Document, DocumentTransaction};
 
 use flowy_document::entities::DocumentVersionPB;
+use flowy_revision::REVISION_WRITE_INTERVAL_IN_MILLIS;
 use flowy_test::helper::ViewTest;
 use flowy_test::FlowySDKTest;
 use lib_ot::core::{Changeset, NodeDataBuilder, NodeOperation, Path, Transaction};
 use lib_ot::text_delta::DeltaTextOperations;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
 
 pub enum EditScript {
   InsertText {
@@ -26,6 +29,8 @@ pub enum EditScript {
   Delete {
     path: Path,
   },
+  WriteCheckpoint,
+  RestoreLastCheckpoint,
   AssertContent {
     expected: &'static str,
   },
@@ -46,9 +51,21 @@ impl DocumentEditorTest {
     let _ = sdk.init_user().await;
 
     let test = ViewTest::new_document_view(&sdk).await;
+    Self::open(sdk, &test.view.id).await
+  }
+
+  pub async fn new_from_markdown(markdown: &str) -> Self {
+    let sdk = FlowySDKTest::new(DocumentVersionPB::V1);
+    let _ = sdk.init_user().await;
+
+    let test = ViewTest::new_document_view_from_markdown(&sdk, markdown).await;
+    Self::open(sdk, &test.view.id).await
+  }
+
+  async fn open(sdk: FlowySDKTest, view_id: &str) -> Self {
     let document_editor = sdk
       .document_manager
-      .open_document_editor(&test.view.id)
+      .open_document_editor(view_id)
       .await
       .unwrap();
     let editor = match document_editor
@@ -112,6 +129,19 @@ impl DocumentEditorTest {
           .await
           .unwrap();
       },
+      EditScript::WriteCheckpoint => {
+        sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
+        self.editor.rev_manager().generate_snapshot().await;
+      },
+      EditScript::RestoreLastCheckpoint => {
+        let checkpoints = self.editor.rev_manager().read_checkpoints().await.unwrap();
+        let checkpoint = checkpoints.last().unwrap();
+        self
+          .editor
+          .restore_checkpoint_document(checkpoint.rev_id)
+          .await
+          .unwrap();
+      },
       EditScript::AssertContent { expected } => {
         //
         let content = self.editor.get_content(false).await.unwrap();
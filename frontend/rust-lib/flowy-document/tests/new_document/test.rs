@@ -162,3 +162,42 @@ async fn document_delete_node_test() {
 
   DocumentEditorTest::new().await.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn document_import_markdown_test() {
+  let markdown = "# AppFlowy\n\n**bold** text\n\n- [x] done";
+  let scripts = vec![AssertContent {
+    expected: r#"{"document":{"type":"editor","children":[{"type":"text","attributes":{"subtype":"heading","heading":"h1"},"delta":[{"insert":"AppFlowy"}]},{"type":"text","delta":[{"insert":"bold","attributes":{"bold":true}},{"insert":" text"}]},{"type":"text","attributes":{"subtype":"checkbox","checkbox":true},"delta":[{"insert":"done"}]}]}}"#,
+  }];
+  DocumentEditorTest::new_from_markdown(markdown)
+    .await
+    .run_scripts(scripts)
+    .await;
+}
+
+#[tokio::test]
+async fn document_restore_checkpoint_test() {
+  let scripts = vec![
+    UpdateText {
+      path: vec![0, 0].into(),
+      delta: DeltaTextOperationBuilder::new()
+        .insert("Hello world")
+        .build(),
+    },
+    WriteCheckpoint,
+    UpdateText {
+      path: vec![0, 0].into(),
+      delta: DeltaTextOperationBuilder::new().retain(5).delete(6).build(),
+    },
+    InsertText {
+      path: vec![0, 1].into(),
+      delta: DeltaTextOperationBuilder::new().insert("AppFlowy").build(),
+    },
+    RestoreLastCheckpoint,
+    AssertContent {
+      expected: r#"{"document":{"type":"editor","children":[{"type":"text","delta":[{"insert":"Hello world"}]}]}}"#,
+    },
+  ];
+
+  DocumentEditorTest::new().await.run_scripts(scripts).await;
+}
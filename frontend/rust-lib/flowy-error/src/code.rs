@@ -189,6 +189,30 @@ pub enum ErrorCode {
 
   #[error("Only the date type can be used in calendar")]
   UnexpectedCalendarFieldType = 61,
+
+  #[error("Filter group id is empty")]
+  FilterGroupIdIsEmpty = 62,
+
+  #[error("Comment id is empty")]
+  CommentIdIsEmpty = 63,
+
+  #[error("The field type is not supported by the timeline")]
+  UnexpectedTimelineFieldType = 64,
+
+  #[error("The field type is not supported by the gallery")]
+  UnexpectedGalleryFieldType = 65,
+
+  #[error("The database is encrypted, unlock it with the passphrase")]
+  DatabaseLocked = 66,
+
+  #[error("The passphrase of the database is invalid")]
+  DatabasePassphraseInvalid = 67,
+
+  #[error("The database encryption is not supported")]
+  DatabaseEncryptionNotSupported = 68,
+
+  #[error("The version of the workspace archive is not supported")]
+  WorkspaceArchiveVersionNotSupported = 69,
 }
 
 impl ErrorCode {
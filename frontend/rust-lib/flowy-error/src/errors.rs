@@ -86,6 +86,14 @@ impl FlowyError {
     unexpect_calendar_field_type,
     ErrorCode::UnexpectedCalendarFieldType
   );
+  static_flowy_error!(
+    unexpect_timeline_field_type,
+    ErrorCode::UnexpectedTimelineFieldType
+  );
+  static_flowy_error!(
+    unexpect_gallery_field_type,
+    ErrorCode::UnexpectedGalleryFieldType
+  );
 }
 
 impl std::convert::From<ErrorCode> for FlowyError {
@@ -1,4 +1,5 @@
-use crate::FlowyError;
+use crate::{ErrorCode, FlowyError};
+use flowy_sqlite::DatabaseErrorKind;
 
 impl std::convert::From<flowy_sqlite::Error> for FlowyError {
   fn from(error: flowy_sqlite::Error) -> Self {
@@ -6,6 +7,16 @@ impl std::convert::From<flowy_sqlite::Error> for FlowyError {
   }
 }
 
+impl std::convert::From<flowy_sqlite::DatabaseError> for FlowyError {
+  fn from(error: flowy_sqlite::DatabaseError) -> Self {
+    match error.kind() {
+      DatabaseErrorKind::InvalidDatabaseKey => ErrorCode::DatabasePassphraseInvalid.into(),
+      DatabaseErrorKind::EncryptionNotSupported => ErrorCode::DatabaseEncryptionNotSupported.into(),
+      _ => FlowyError::internal().context(error),
+    }
+  }
+}
+
 impl std::convert::From<::r2d2::Error> for FlowyError {
   fn from(error: r2d2::Error) -> Self {
     FlowyError::internal().context(error)
@@ -0,0 +1,55 @@
+use crate::{entities::parser::workspace::WorkspaceIdentify, errors::ErrorCode};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportWorkspacePayloadPB {
+  #[pb(index = 1)]
+  pub workspace_id: String,
+
+  /// The path of the archive file. The file is replaced if it exists.
+  #[pb(index = 2)]
+  pub path: String,
+}
+
+pub struct ExportWorkspaceParams {
+  pub workspace_id: String,
+  pub path: String,
+}
+
+impl TryInto<ExportWorkspaceParams> for ExportWorkspacePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ExportWorkspaceParams, Self::Error> {
+    let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?;
+    if self.path.trim().is_empty() {
+      return Err(ErrorCode::UnexpectedEmptyString);
+    }
+    Ok(ExportWorkspaceParams {
+      workspace_id: workspace_id.0,
+      path: self.path,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ImportWorkspacePayloadPB {
+  /// The path of the archive file.
+  #[pb(index = 1)]
+  pub path: String,
+}
+
+pub struct ImportWorkspaceParams {
+  pub path: String,
+}
+
+impl TryInto<ImportWorkspaceParams> for ImportWorkspacePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ImportWorkspaceParams, Self::Error> {
+    if self.path.trim().is_empty() {
+      return Err(ErrorCode::UnexpectedEmptyString);
+    }
+    Ok(ImportWorkspaceParams { path: self.path })
+  }
+}
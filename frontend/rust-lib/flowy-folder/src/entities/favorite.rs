@@ -0,0 +1,22 @@
+use crate::entities::view::ViewPB;
+use crate::impl_def_and_def_mut;
+use flowy_derive::ProtoBuf;
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RecentViewPB {
+  #[pb(index = 1)]
+  pub view: ViewPB,
+
+  /// The timestamp of the last time the view was opened
+  #[pb(index = 2)]
+  pub accessed_at: i64,
+}
+
+/// The recent views, the most recently accessed first.
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedRecentViewPB {
+  #[pb(index = 1)]
+  pub items: Vec<RecentViewPB>,
+}
+
+impl_def_and_def_mut!(RepeatedRecentViewPB, RecentViewPB);
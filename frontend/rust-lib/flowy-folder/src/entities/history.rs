@@ -0,0 +1,169 @@
+use crate::{entities::parser::view::ViewIdentify, errors::ErrorCode};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_revision::{CheckpointDiffChunk, RevisionCheckpoint};
+use std::convert::TryInto;
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RevisionCheckpointPB {
+  #[pb(index = 1)]
+  pub rev_id: i64,
+
+  #[pb(index = 2)]
+  pub timestamp: i64,
+}
+
+impl std::convert::From<RevisionCheckpoint> for RevisionCheckpointPB {
+  fn from(checkpoint: RevisionCheckpoint) -> Self {
+    Self {
+      rev_id: checkpoint.rev_id,
+      timestamp: checkpoint.timestamp,
+    }
+  }
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedRevisionCheckpointPB {
+  #[pb(index = 1)]
+  pub items: Vec<RevisionCheckpointPB>,
+}
+
+impl std::convert::From<Vec<RevisionCheckpoint>> for RepeatedRevisionCheckpointPB {
+  fn from(checkpoints: Vec<RevisionCheckpoint>) -> Self {
+    Self {
+      items: checkpoints
+        .into_iter()
+        .map(|checkpoint| checkpoint.into())
+        .collect(),
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ViewCheckpointIdPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub rev_id: i64,
+}
+
+pub struct ViewCheckpointIdParams {
+  pub view_id: String,
+  pub rev_id: i64,
+}
+
+impl TryInto<ViewCheckpointIdParams> for ViewCheckpointIdPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ViewCheckpointIdParams, Self::Error> {
+    let view_id = ViewIdentify::parse(self.view_id)?.0;
+    Ok(ViewCheckpointIdParams {
+      view_id,
+      rev_id: self.rev_id,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct FolderCheckpointIdPB {
+  #[pb(index = 1)]
+  pub rev_id: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct DiffViewCheckpointsPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub from_rev_id: i64,
+
+  #[pb(index = 3)]
+  pub to_rev_id: i64,
+}
+
+pub struct DiffViewCheckpointsParams {
+  pub view_id: String,
+  pub from_rev_id: i64,
+  pub to_rev_id: i64,
+}
+
+impl TryInto<DiffViewCheckpointsParams> for DiffViewCheckpointsPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<DiffViewCheckpointsParams, Self::Error> {
+    let view_id = ViewIdentify::parse(self.view_id)?.0;
+    Ok(DiffViewCheckpointsParams {
+      view_id,
+      from_rev_id: self.from_rev_id,
+      to_rev_id: self.to_rev_id,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct DiffFolderCheckpointsPB {
+  #[pb(index = 1)]
+  pub from_rev_id: i64,
+
+  #[pb(index = 2)]
+  pub to_rev_id: i64,
+}
+
+/// The content of the object at the checkpoint. The document is encoded in its JSON format, the
+/// database and the folder are encoded in pretty JSON.
+#[derive(Default, ProtoBuf)]
+pub struct CheckpointContentPB {
+  #[pb(index = 1)]
+  pub rev_id: i64,
+
+  #[pb(index = 2)]
+  pub content: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ProtoBuf_Enum)]
+pub enum CheckpointDiffTypePB {
+  Equal = 0,
+  Insert = 1,
+  Delete = 2,
+}
+
+impl std::default::Default for CheckpointDiffTypePB {
+  fn default() -> Self {
+    CheckpointDiffTypePB::Equal
+  }
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct CheckpointDiffChunkPB {
+  #[pb(index = 1)]
+  pub ty: CheckpointDiffTypePB,
+
+  #[pb(index = 2)]
+  pub text: String,
+}
+
+impl std::convert::From<CheckpointDiffChunk> for CheckpointDiffChunkPB {
+  fn from(chunk: CheckpointDiffChunk) -> Self {
+    let (ty, text) = match chunk {
+      CheckpointDiffChunk::Equal(text) => (CheckpointDiffTypePB::Equal, text),
+      CheckpointDiffChunk::Insert(text) => (CheckpointDiffTypePB::Insert, text),
+      CheckpointDiffChunk::Delete(text) => (CheckpointDiffTypePB::Delete, text),
+    };
+    Self { ty, text }
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct RepeatedCheckpointDiffChunkPB {
+  #[pb(index = 1)]
+  pub items: Vec<CheckpointDiffChunkPB>,
+}
+
+impl std::convert::From<Vec<CheckpointDiffChunk>> for RepeatedCheckpointDiffChunkPB {
+  fn from(chunks: Vec<CheckpointDiffChunk>) -> Self {
+    Self {
+      items: chunks.into_iter().map(|chunk| chunk.into()).collect(),
+    }
+  }
+}
@@ -1,10 +1,16 @@
 pub mod app;
+pub mod archive;
+pub mod favorite;
+pub mod history;
 mod parser;
 pub mod trash;
 pub mod view;
 pub mod workspace;
 
 pub use app::*;
+pub use archive::*;
+pub use favorite::*;
+pub use history::*;
 pub use trash::*;
 pub use view::*;
 pub use workspace::*;
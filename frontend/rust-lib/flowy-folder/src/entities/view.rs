@@ -92,6 +92,8 @@ pub enum ViewLayoutTypePB {
   Grid = 3,
   Board = 4,
   Calendar = 5,
+  Timeline = 6,
+  Gallery = 7,
 }
 
 impl std::default::Default for ViewLayoutTypePB {
@@ -107,6 +109,8 @@ impl std::convert::From<ViewLayoutTypeRevision> for ViewLayoutTypePB {
       ViewLayoutTypeRevision::Board => ViewLayoutTypePB::Board,
       ViewLayoutTypeRevision::Document => ViewLayoutTypePB::Document,
       ViewLayoutTypeRevision::Calendar => ViewLayoutTypePB::Calendar,
+      ViewLayoutTypeRevision::Timeline => ViewLayoutTypePB::Timeline,
+      ViewLayoutTypeRevision::Gallery => ViewLayoutTypePB::Gallery,
     }
   }
 }
@@ -118,6 +122,8 @@ impl std::convert::From<ViewLayoutTypePB> for ViewLayoutTypeRevision {
       ViewLayoutTypePB::Board => ViewLayoutTypeRevision::Board,
       ViewLayoutTypePB::Document => ViewLayoutTypeRevision::Document,
       ViewLayoutTypePB::Calendar => ViewLayoutTypeRevision::Calendar,
+      ViewLayoutTypePB::Timeline => ViewLayoutTypeRevision::Timeline,
+      ViewLayoutTypePB::Gallery => ViewLayoutTypeRevision::Gallery,
     }
   }
 }
@@ -215,6 +221,8 @@ pub fn data_format_from_layout(layout: &ViewLayoutTypePB) -> ViewDataFormatPB {
     ViewLayoutTypePB::Grid => ViewDataFormatPB::DatabaseFormat,
     ViewLayoutTypePB::Board => ViewDataFormatPB::DatabaseFormat,
     ViewLayoutTypePB::Calendar => ViewDataFormatPB::DatabaseFormat,
+    ViewLayoutTypePB::Timeline => ViewDataFormatPB::DatabaseFormat,
+    ViewLayoutTypePB::Gallery => ViewDataFormatPB::DatabaseFormat,
   }
 }
 
@@ -306,6 +314,7 @@ impl TryInto<UpdateViewParams> for UpdateViewPayloadPB {
 pub enum MoveFolderItemType {
   MoveApp = 0,
   MoveView = 1,
+  MoveFavorite = 2,
 }
 
 impl std::default::Default for MoveFolderItemType {
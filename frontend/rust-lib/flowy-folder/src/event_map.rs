@@ -8,7 +8,8 @@ use crate::{
   errors::FlowyError,
   manager::FolderManager,
   services::{
-    app::event_handler::*, trash::event_handler::*, view::event_handler::*,
+    app::event_handler::*, archive::event_handler::*, favorite::event_handler::*,
+    history::event_handler::*, trash::event_handler::*, view::event_handler::*,
     workspace::event_handler::*,
   },
 };
@@ -25,6 +26,12 @@ pub trait WorkspaceDeps: WorkspaceUser + WorkspaceDatabase {}
 pub trait WorkspaceUser: Send + Sync {
   fn user_id(&self) -> Result<String, FlowyError>;
   fn token(&self) -> Result<String, FlowyError>;
+
+  /// Returns the user's settings in JSON, which are exported along with the workspace.
+  fn get_settings(&self) -> Result<Option<String>, FlowyError>;
+
+  /// Replaces the user's settings with the ones imported along with the workspace.
+  fn set_settings(&self, settings: String) -> Result<(), FlowyError>;
 }
 
 pub trait WorkspaceDatabase: Send + Sync {
@@ -55,7 +62,9 @@ pub fn init(folder: Arc<FolderManager>) -> AFPlugin {
     )
     .event(FolderEvent::ReadWorkspaces, read_workspaces_handler)
     .event(FolderEvent::OpenWorkspace, open_workspace_handler)
-    .event(FolderEvent::ReadWorkspaceApps, read_workspace_apps_handler);
+    .event(FolderEvent::ReadWorkspaceApps, read_workspace_apps_handler)
+    .event(FolderEvent::ExportWorkspace, export_workspace_handler)
+    .event(FolderEvent::ImportWorkspace, import_workspace_handler);
 
   // App
   plugin = plugin
@@ -83,6 +92,48 @@ pub fn init(folder: Arc<FolderManager>) -> AFPlugin {
     .event(FolderEvent::RestoreAllTrash, restore_all_trash_handler)
     .event(FolderEvent::DeleteAllTrash, delete_all_trash_handler);
 
+  // Favorite
+  plugin = plugin
+    .event(FolderEvent::AddFavorite, add_favorite_handler)
+    .event(FolderEvent::RemoveFavorite, remove_favorite_handler)
+    .event(FolderEvent::ReadFavorites, read_favorites_handler)
+    .event(FolderEvent::ReadRecentViews, read_recent_views_handler);
+
+  // History
+  plugin = plugin
+    .event(
+      FolderEvent::GetViewCheckpoints,
+      get_view_checkpoints_handler,
+    )
+    .event(
+      FolderEvent::ReadViewCheckpoint,
+      read_view_checkpoint_handler,
+    )
+    .event(
+      FolderEvent::DiffViewCheckpoints,
+      diff_view_checkpoints_handler,
+    )
+    .event(
+      FolderEvent::RestoreViewCheckpoint,
+      restore_view_checkpoint_handler,
+    )
+    .event(
+      FolderEvent::GetFolderCheckpoints,
+      get_folder_checkpoints_handler,
+    )
+    .event(
+      FolderEvent::ReadFolderCheckpoint,
+      read_folder_checkpoint_handler,
+    )
+    .event(
+      FolderEvent::DiffFolderCheckpoints,
+      diff_folder_checkpoints_handler,
+    )
+    .event(
+      FolderEvent::RestoreFolderCheckpoint,
+      restore_folder_checkpoint_handler,
+    );
+
   plugin
 }
 
@@ -113,6 +164,14 @@ pub enum FolderEvent {
   #[event(input = "WorkspaceIdPB", output = "RepeatedAppPB")]
   ReadWorkspaceApps = 5,
 
+  /// Export the workspace, the data of its views and the user's settings into an archive file
+  #[event(input = "ExportWorkspacePayloadPB")]
+  ExportWorkspace = 6,
+
+  /// Import the workspace from an archive file. Everything is recreated under new ids
+  #[event(input = "ImportWorkspacePayloadPB", output = "WorkspacePB")]
+  ImportWorkspace = 7,
+
   /// Create a new app
   #[event(input = "CreateAppPayloadPB", output = "AppPB")]
   CreateApp = 101,
@@ -184,6 +243,63 @@ pub enum FolderEvent {
   /// Delete all the trash from the disk
   #[event()]
   DeleteAllTrash = 304,
+
+  /// Return the checkpoints of the view's data. Each checkpoint is a point in the history of
+  /// the data that can be read or restored.
+  #[event(input = "ViewIdPB", output = "RepeatedRevisionCheckpointPB")]
+  GetViewCheckpoints = 400,
+
+  /// Return the view's data at the checkpoint
+  #[event(input = "ViewCheckpointIdPB", output = "CheckpointContentPB")]
+  ReadViewCheckpoint = 401,
+
+  /// Return the changes of the view's data between two checkpoints
+  #[event(
+    input = "DiffViewCheckpointsPB",
+    output = "RepeatedCheckpointDiffChunkPB"
+  )]
+  DiffViewCheckpoints = 402,
+
+  /// Restore the view's data to the checkpoint. The restoring is saved as a new revision
+  #[event(input = "ViewCheckpointIdPB")]
+  RestoreViewCheckpoint = 403,
+
+  /// Return the checkpoints of the folder
+  #[event(output = "RepeatedRevisionCheckpointPB")]
+  GetFolderCheckpoints = 410,
+
+  /// Return the folder at the checkpoint
+  #[event(input = "FolderCheckpointIdPB", output = "CheckpointContentPB")]
+  ReadFolderCheckpoint = 411,
+
+  /// Return the changes of the folder between two checkpoints
+  #[event(
+    input = "DiffFolderCheckpointsPB",
+    output = "RepeatedCheckpointDiffChunkPB"
+  )]
+  DiffFolderCheckpoints = 412,
+
+  /// Restore the folder to the checkpoint. The restoring is saved as a new revision
+  #[event(input = "FolderCheckpointIdPB")]
+  RestoreFolderCheckpoint = 413,
+
+  /// Add the view to the end of the favorites. The favorites are reordered by the MoveItem event
+  /// with the MoveFavorite type
+  #[event(input = "ViewIdPB")]
+  AddFavorite = 500,
+
+  /// Remove the view from the favorites
+  #[event(input = "ViewIdPB")]
+  RemoveFavorite = 501,
+
+  /// Return the views of the favorites in order
+  #[event(output = "RepeatedViewPB")]
+  ReadFavorites = 502,
+
+  /// Return the recently opened views, the most recently opened first. A view is recorded as
+  /// opened by the SetLatestView event
+  #[event(output = "RepeatedRecentViewPB")]
+  ReadRecentViews = 510,
 }
 
 pub trait FolderCouldServiceV1: Send + Sync {
@@ -1,5 +1,7 @@
 use crate::entities::view::ViewDataFormatPB;
-use crate::entities::{ViewLayoutTypePB, ViewPB, WorkspacePB};
+use crate::entities::{
+  RecentViewPB, RepeatedRecentViewPB, RepeatedViewPB, ViewLayoutTypePB, ViewPB, WorkspacePB,
+};
 use crate::services::folder_editor::FolderRevisionMergeable;
 use crate::{
   entities::workspace::RepeatedWorkspacePB,
@@ -7,7 +9,15 @@ use crate::{
   event_map::{FolderCouldServiceV1, WorkspaceDatabase, WorkspaceUser},
   notification::{send_notification, FolderNotification},
   services::{
-    folder_editor::FolderEditor, persistence::FolderPersistence, set_current_workspace,
+    archive::{
+      regenerate_workspace_ids, remove_trash_from_workspace, workspace_views, ArchivedViewData,
+      WorkspaceArchive, WORKSPACE_ARCHIVE_VERSION,
+    },
+    favorite::{notify_favorites_changed, read_favorite_views},
+    folder_editor::FolderEditor,
+    persistence::FolderPersistence,
+    send_workspace_notification, set_current_workspace,
+    view::recent_views::{clear_recent_views, read_recent_views},
     AppController, TrashController, ViewController, WorkspaceController,
   },
 };
@@ -15,16 +25,20 @@ use bytes::Bytes;
 use flowy_document::editor::initial_read_me;
 use flowy_error::FlowyError;
 use flowy_revision::{
-  RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration, RevisionWebSocket,
+  RevisionCheckpoint, RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration,
+  RevisionWebSocket,
 };
-use folder_model::user_default;
+use folder_model::{user_default, FavoriteRevision, ViewDataFormatRevision};
 use lazy_static::lazy_static;
 use lib_infra::future::FutureResult;
+use lib_infra::util::timestamp;
 
 use crate::services::persistence::rev_sqlite::{
   SQLiteFolderRevisionPersistence, SQLiteFolderRevisionSnapshotPersistence,
 };
-use crate::services::{clear_current_workspace, get_current_workspace};
+use crate::services::{
+  clear_current_workspace, get_current_workspace, notify_apps_changed, notify_trash_changed,
+};
 use flowy_client_sync::client_folder::FolderPad;
 use std::convert::TryFrom;
 use std::{collections::HashMap, fmt::Formatter, sync::Arc};
@@ -240,10 +254,221 @@ impl FolderManager {
     self.initialize(user_id, token).await
   }
 
+  pub async fn get_folder_checkpoints(&self) -> FlowyResult<Vec<RevisionCheckpoint>> {
+    self.get_folder_editor().await?.get_checkpoints().await
+  }
+
+  pub async fn read_folder_checkpoint(&self, rev_id: i64) -> FlowyResult<String> {
+    self
+      .get_folder_editor()
+      .await?
+      .read_checkpoint(rev_id)
+      .await
+  }
+
+  /// Restores the folder to the checkpoint. The workspaces, apps, views and trash are all
+  /// restored, so the apps of the current workspace and the trash get notified.
+  pub async fn restore_folder_checkpoint(&self, rev_id: i64) -> FlowyResult<()> {
+    self
+      .get_folder_editor()
+      .await?
+      .restore_checkpoint(rev_id)
+      .await?;
+    let user_id = self.user.user_id()?;
+    let workspace_id = get_current_workspace(&user_id)?;
+    let trash_controller = self.trash_controller.clone();
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        notify_apps_changed(&workspace_id, trash_controller, &transaction)?;
+        notify_trash_changed(transaction.read_trash(None)?);
+        Ok(())
+      })
+      .await
+  }
+
+  /// Appends the view to the end of the favorites. The favorites are saved in the folder, so they
+  /// get synced like the rest of the folder.
+  pub async fn add_favorite(&self, view_id: &str) -> FlowyResult<()> {
+    let trash_controller = self.trash_controller.clone();
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        let view_rev = transaction.read_view(view_id)?;
+        transaction.create_favorite(FavoriteRevision {
+          id: view_rev.id,
+          create_time: timestamp(),
+        })?;
+        notify_favorites_changed(trash_controller, &transaction)
+      })
+      .await
+  }
+
+  pub async fn remove_favorite(&self, view_id: &str) -> FlowyResult<()> {
+    let trash_controller = self.trash_controller.clone();
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        transaction.delete_favorites(vec![view_id.to_owned()])?;
+        notify_favorites_changed(trash_controller, &transaction)
+      })
+      .await
+  }
+
+  pub async fn move_favorite(&self, view_id: &str, from: usize, to: usize) -> FlowyResult<()> {
+    let trash_controller = self.trash_controller.clone();
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        transaction.move_favorite(view_id, from, to)?;
+        notify_favorites_changed(trash_controller, &transaction)
+      })
+      .await
+  }
+
+  /// Returns the views of the favorites in the order they were added, or reordered to. The views
+  /// in the trash are left out.
+  pub async fn get_favorites(&self) -> FlowyResult<RepeatedViewPB> {
+    let trash_controller = self.trash_controller.clone();
+    let view_revs = self
+      .persistence
+      .begin_transaction(|transaction| read_favorite_views(trash_controller, &transaction))
+      .await?;
+    Ok(view_revs.into())
+  }
+
+  /// Returns the recently opened views, the most recently opened first. The recent views are
+  /// kept locally, so they are not synced. The views in the trash are left out.
+  pub async fn get_recent_views(&self) -> FlowyResult<RepeatedRecentViewPB> {
+    let recent_views = read_recent_views();
+    let trash_controller = self.trash_controller.clone();
+    let items = self
+      .persistence
+      .begin_transaction(|transaction| {
+        let trash_ids = trash_controller.read_trash_ids(&transaction)?;
+        let mut items = vec![];
+        for recent_view in recent_views {
+          if trash_ids.contains(&recent_view.view_id) {
+            continue;
+          }
+          if let Ok(view_rev) = transaction.read_view(&recent_view.view_id) {
+            if !trash_ids.contains(&view_rev.app_id) {
+              items.push(RecentViewPB {
+                view: view_rev.into(),
+                accessed_at: recent_view.accessed_at,
+              });
+            }
+          }
+        }
+        Ok(items)
+      })
+      .await?;
+    Ok(RepeatedRecentViewPB { items })
+  }
+
+  /// Exports the workspace into the archive file at `path`. The apps and views in the trash are
+  /// left out.
+  pub async fn export_workspace(&self, workspace_id: &str, path: &str) -> FlowyResult<()> {
+    let user_id = self.user.user_id()?;
+    let workspace_rev = self
+      .persistence
+      .begin_transaction(|transaction| {
+        let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+        let mut workspace_rev = transaction
+          .read_workspaces(&user_id, Some(workspace_id.to_owned()))?
+          .pop()
+          .ok_or_else(|| {
+            FlowyError::record_not_found().context(format!("Can't find workspace {}", workspace_id))
+          })?;
+        remove_trash_from_workspace(&mut workspace_rev, &trash_ids);
+        Ok(workspace_rev)
+      })
+      .await?;
+
+    let mut view_ids_by_format: Vec<(ViewDataFormatRevision, Vec<String>)> = vec![];
+    for view_rev in workspace_views(&workspace_rev) {
+      match view_ids_by_format
+        .iter_mut()
+        .find(|(data_format, _)| data_format == &view_rev.data_format)
+      {
+        None => view_ids_by_format.push((view_rev.data_format.clone(), vec![view_rev.id.clone()])),
+        Some((_, view_ids)) => view_ids.push(view_rev.id.clone()),
+      }
+    }
+
+    let mut view_data = vec![];
+    for (data_format, view_ids) in view_ids_by_format {
+      let processor = self
+        .view_controller
+        .get_data_processor(data_format.clone())?;
+      let content = processor.export_views(view_ids).await?;
+      view_data.push(ArchivedViewData {
+        data_format,
+        content,
+      });
+    }
+
+    let archive = WorkspaceArchive {
+      version: WORKSPACE_ARCHIVE_VERSION,
+      exported_at: timestamp(),
+      workspace: workspace_rev,
+      view_data,
+      user_settings: self.user.get_settings()?,
+    };
+    std::fs::write(path, archive.to_json()?)?;
+    Ok(())
+  }
+
+  /// Imports the workspace from the archive file at `path`. Everything in the archive gets new
+  /// ids, so the imported workspace never conflicts with the existing ones.
+  pub async fn import_workspace(&self, path: &str) -> FlowyResult<WorkspacePB> {
+    let json = std::fs::read_to_string(path)?;
+    let mut archive = WorkspaceArchive::from_json(&json)?;
+    let view_id_map = regenerate_workspace_ids(&mut archive.workspace);
+    for view_data in archive.view_data {
+      let processor = self
+        .view_controller
+        .get_data_processor(view_data.data_format)?;
+      processor
+        .import_views(view_data.content, view_id_map.clone())
+        .await?;
+    }
+
+    let user_id = self.user.user_id()?;
+    let workspace_rev = archive.workspace;
+    let workspaces = self
+      .persistence
+      .begin_transaction(|transaction| {
+        transaction.create_workspace(&user_id, workspace_rev.clone())?;
+        transaction.read_workspaces(&user_id, None)
+      })
+      .await?
+      .into_iter()
+      .map(|workspace_rev| workspace_rev.into())
+      .collect();
+
+    if let Some(settings) = archive.user_settings {
+      self.user.set_settings(settings)?;
+    }
+    let repeated_workspace = RepeatedWorkspacePB { items: workspaces };
+    send_workspace_notification(FolderNotification::DidCreateWorkspace, repeated_workspace);
+    Ok(workspace_rev.into())
+  }
+
+  async fn get_folder_editor(&self) -> FlowyResult<Arc<FolderEditor>> {
+    match self.folder_editor.read().await.clone() {
+      None => Err(
+        FlowyError::internal().context("FolderEditor should be initialized after user login in."),
+      ),
+      Some(editor) => Ok(editor),
+    }
+  }
+
   /// Called when the current user logout
   ///
   pub async fn clear(&self, user_id: &str) {
     self.view_controller.clear_latest_view();
+    clear_recent_views();
     clear_current_workspace(user_id);
     *self.folder_editor.write().await = None;
   }
@@ -295,6 +520,9 @@ pub trait ViewDataProcessor {
   /// the backend
   fn close_view(&self, view_id: &str) -> FutureResult<(), FlowyError>;
 
+  /// Deletes the data of the view. It's called when the view is deleted from the trash.
+  fn delete_view(&self, view_id: &str) -> FutureResult<(), FlowyError>;
+
   /// Gets the data of the this view.
   /// For example, the data can be used to duplicate the view.
   fn get_view_data(&self, view: &ViewPB) -> FutureResult<Bytes, FlowyError>;
@@ -324,6 +552,32 @@ pub trait ViewDataProcessor {
   ) -> FutureResult<(), FlowyError>;
 
   fn data_types(&self) -> Vec<ViewDataFormatPB>;
+
+  /// Returns the checkpoints of the view's data in ascending order of the rev_id.
+  fn get_view_checkpoints(
+    &self,
+    view_id: &str,
+  ) -> FutureResult<Vec<RevisionCheckpoint>, FlowyError>;
+
+  /// Returns the data of the view at the checkpoint.
+  fn read_view_checkpoint(&self, view_id: &str, rev_id: i64) -> FutureResult<String, FlowyError>;
+
+  /// Restores the view's data to the checkpoint. The restoring is saved as a new revision, so it
+  /// syncs like any other edit.
+  fn restore_view_checkpoint(&self, view_id: &str, rev_id: i64) -> FutureResult<(), FlowyError>;
+
+  /// Exports the data of the views into the content of the workspace archive. The views are
+  /// exported at once, so the data shared between them is only exported once.
+  fn export_views(&self, view_ids: Vec<String>) -> FutureResult<String, FlowyError>;
+
+  /// Creates the data of the views from the content that was exported by
+  /// [ViewDataProcessor::export_views]. The `view_id_map` maps the ids of the exported views to
+  /// the ids of the new views.
+  fn import_views(
+    &self,
+    content: String,
+    view_id_map: HashMap<String, String>,
+  ) -> FutureResult<(), FlowyError>;
 }
 
 pub type ViewDataProcessorMap =
@@ -27,6 +27,10 @@ pub(crate) enum FolderNotification {
   DidMoveViewToTrash = 33,
   /// Trigger when the number of trash is changed
   DidUpdateTrash = 34,
+  /// Trigger when the favorites are added, removed or reordered
+  DidUpdateFavorites = 35,
+  /// Trigger when a view is opened, which moves it to the front of the recent views
+  DidUpdateRecentViews = 36,
 }
 
 impl std::default::Default for FolderNotification {
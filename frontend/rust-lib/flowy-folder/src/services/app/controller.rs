@@ -227,7 +227,7 @@ async fn handle_trash_event(
   skip(workspace_id, trash_controller, transaction),
   err
 )]
-fn notify_apps_changed<'a>(
+pub(crate) fn notify_apps_changed<'a>(
   workspace_id: &str,
   trash_controller: Arc<TrashController>,
   transaction: &'a (dyn FolderPersistenceTransaction + 'a),
@@ -0,0 +1,35 @@
+use crate::{
+  entities::{
+    archive::{
+      ExportWorkspaceParams, ExportWorkspacePayloadPB, ImportWorkspaceParams,
+      ImportWorkspacePayloadPB,
+    },
+    workspace::WorkspacePB,
+  },
+  errors::FlowyError,
+  manager::FolderManager,
+};
+use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn export_workspace_handler(
+  data: AFPluginData<ExportWorkspacePayloadPB>,
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> Result<(), FlowyError> {
+  let params: ExportWorkspaceParams = data.into_inner().try_into()?;
+  folder
+    .export_workspace(&params.workspace_id, &params.path)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn import_workspace_handler(
+  data: AFPluginData<ImportWorkspacePayloadPB>,
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> DataResult<WorkspacePB, FlowyError> {
+  let params: ImportWorkspaceParams = data.into_inner().try_into()?;
+  let workspace = folder.import_workspace(&params.path).await?;
+  data_result_ok(workspace)
+}
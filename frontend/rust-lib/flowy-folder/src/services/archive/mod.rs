@@ -0,0 +1,4 @@
+pub(crate) mod event_handler;
+mod workspace_archive;
+
+pub use workspace_archive::*;
@@ -0,0 +1,190 @@
+//! The workspace archive is a single JSON file that contains everything needed to recreate a
+//! workspace:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "exported_at": 1680000000,
+//!   "workspace": { "id": "...", "name": "...", "apps": [{ "id": "...", "belongings": [] }] },
+//!   "view_data": [{ "data_format": 2, "content": "..." }],
+//!   "user_settings": "{\"theme\":\"Default\", ...}"
+//! }
+//! ```
+//!
+//! * `version` is the [WORKSPACE_ARCHIVE_VERSION] of the format. It's increased whenever the
+//! format changes incompatibly, and the archives with a newer version are rejected.
+//! * `workspace` is the [WorkspaceRevision] as it's stored in the folder, which contains the
+//! apps and their views. The apps and views in the trash are left out.
+//! * `view_data` contains the data of the views, one entry for each [ViewDataFormatRevision].
+//! The `content` is opaque to the folder. It's produced by the
+//! [ViewDataProcessor::export_views] of the format and consumed by its
+//! [ViewDataProcessor::import_views]. The documents are a JSON object that maps the ids of the
+//! views to the contents of the documents. The databases are a JSON `DatabaseArchive`, which
+//! contains each database once along with all the views linked to it and the rows' documents.
+//! * `user_settings` is the user's appearance settings in JSON. It's optional.
+//!
+//! The ids in the archive are the ones of the exported workspace. The importer generates new
+//! ids for everything and rewrites the references between them, so the same archive can be
+//! imported more than once.
+//!
+//! [ViewDataProcessor::export_views]: crate::manager::ViewDataProcessor::export_views
+//! [ViewDataProcessor::import_views]: crate::manager::ViewDataProcessor::import_views
+use crate::errors::{ErrorCode, FlowyError, FlowyResult};
+use folder_model::{
+  gen_app_id, gen_view_id, gen_workspace_id, ViewDataFormatRevision, ViewRevision,
+  WorkspaceRevision,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const WORKSPACE_ARCHIVE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceArchive {
+  pub version: u32,
+  pub exported_at: i64,
+  pub workspace: WorkspaceRevision,
+  pub view_data: Vec<ArchivedViewData>,
+  #[serde(default)]
+  pub user_settings: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedViewData {
+  pub data_format: ViewDataFormatRevision,
+  pub content: String,
+}
+
+/// Only the version is read first, so the archives of the newer versions are rejected with the
+/// right error even if they can't be deserialized.
+#[derive(Deserialize)]
+struct ArchiveVersion {
+  version: u32,
+}
+
+impl WorkspaceArchive {
+  pub fn from_json(json: &str) -> FlowyResult<Self> {
+    let archive_version: ArchiveVersion =
+      serde_json::from_str(json).map_err(|e| FlowyError::invalid_data().context(e))?;
+    if archive_version.version > WORKSPACE_ARCHIVE_VERSION {
+      return Err(
+        FlowyError::from(ErrorCode::WorkspaceArchiveVersionNotSupported).context(format!(
+          "The archive version is {}, but the supported version is {}",
+          archive_version.version, WORKSPACE_ARCHIVE_VERSION
+        )),
+      );
+    }
+    serde_json::from_str(json).map_err(|e| FlowyError::invalid_data().context(e))
+  }
+
+  pub fn to_json(&self) -> FlowyResult<String> {
+    serde_json::to_string(self).map_err(|e| FlowyError::internal().context(e))
+  }
+}
+
+/// Removes the apps and views that are in the trash from the workspace.
+pub(crate) fn remove_trash_from_workspace(workspace: &mut WorkspaceRevision, trash_ids: &[String]) {
+  workspace
+    .apps
+    .retain(|app_rev| !trash_ids.contains(&app_rev.id));
+  for app_rev in workspace.apps.iter_mut() {
+    remove_trash_from_views(&mut app_rev.belongings, trash_ids);
+  }
+}
+
+fn remove_trash_from_views(views: &mut Vec<ViewRevision>, trash_ids: &[String]) {
+  views.retain(|view_rev| !trash_ids.contains(&view_rev.id));
+  for view_rev in views.iter_mut() {
+    remove_trash_from_views(&mut view_rev.belongings, trash_ids);
+  }
+}
+
+/// Returns the views of the workspace, including the nested ones.
+pub(crate) fn workspace_views(workspace: &WorkspaceRevision) -> Vec<&ViewRevision> {
+  fn collect<'a>(views: &'a [ViewRevision], output: &mut Vec<&'a ViewRevision>) {
+    for view_rev in views {
+      output.push(view_rev);
+      collect(&view_rev.belongings, output);
+    }
+  }
+
+  let mut views = vec![];
+  for app_rev in workspace.apps.iter() {
+    collect(&app_rev.belongings, &mut views);
+  }
+  views
+}
+
+/// Replaces the ids of the workspace, apps and views with new ones, and points the apps and views
+/// to the new ids of their parents. Returns the map from the old ids of the views to the new ones.
+pub(crate) fn regenerate_workspace_ids(
+  workspace: &mut WorkspaceRevision,
+) -> HashMap<String, String> {
+  fn regenerate_view_ids(
+    views: &mut [ViewRevision],
+    parent_id: &str,
+    view_id_map: &mut HashMap<String, String>,
+  ) {
+    for view_rev in views.iter_mut() {
+      let view_id = gen_view_id();
+      let old_view_id = std::mem::replace(&mut view_rev.id, view_id.clone());
+      view_id_map.insert(old_view_id, view_id);
+      view_rev.app_id = parent_id.to_owned();
+      regenerate_view_ids(&mut view_rev.belongings, &view_rev.id, view_id_map);
+    }
+  }
+
+  let mut view_id_map = HashMap::new();
+  workspace.id = gen_workspace_id();
+  for app_rev in workspace.apps.iter_mut() {
+    app_rev.id = gen_app_id();
+    app_rev.workspace_id = workspace.id.clone();
+    regenerate_view_ids(&mut app_rev.belongings, &app_rev.id, &mut view_id_map);
+  }
+  view_id_map
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::archive::workspace_archive::{
+    regenerate_workspace_ids, workspace_views, WorkspaceArchive, WORKSPACE_ARCHIVE_VERSION,
+  };
+  use flowy_error::ErrorCode;
+  use folder_model::user_default;
+
+  #[test]
+  fn regenerate_workspace_ids_test() {
+    let mut workspace = user_default::create_default_workspace();
+    let original = workspace.clone();
+    let view_id_map = regenerate_workspace_ids(&mut workspace);
+
+    assert_ne!(workspace.id, original.id);
+    for (app_rev, original_app_rev) in workspace.apps.iter().zip(original.apps.iter()) {
+      assert_ne!(app_rev.id, original_app_rev.id);
+      assert_eq!(app_rev.workspace_id, workspace.id);
+      for (view_rev, original_view_rev) in app_rev
+        .belongings
+        .iter()
+        .zip(original_app_rev.belongings.iter())
+      {
+        assert_eq!(view_id_map[&original_view_rev.id], view_rev.id);
+        assert_eq!(view_rev.app_id, app_rev.id);
+        assert_eq!(view_rev.name, original_view_rev.name);
+      }
+    }
+    assert_eq!(view_id_map.len(), workspace_views(&original).len());
+  }
+
+  #[test]
+  fn reject_newer_archive_version_test() {
+    let json = format!(
+      r#"{{"version":{},"format":"unknown"}}"#,
+      WORKSPACE_ARCHIVE_VERSION + 1
+    );
+    let error = WorkspaceArchive::from_json(&json).unwrap_err();
+    assert_eq!(
+      error.code,
+      ErrorCode::WorkspaceArchiveVersionNotSupported.value()
+    );
+  }
+}
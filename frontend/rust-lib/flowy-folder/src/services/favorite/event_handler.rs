@@ -0,0 +1,46 @@
+use crate::{
+  entities::{
+    favorite::RepeatedRecentViewPB,
+    view::{RepeatedViewPB, ViewIdPB},
+  },
+  errors::FlowyError,
+  manager::FolderManager,
+};
+use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
+use std::sync::Arc;
+
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn add_favorite_handler(
+  data: AFPluginData<ViewIdPB>,
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> Result<(), FlowyError> {
+  let view_id: ViewIdPB = data.into_inner();
+  folder.add_favorite(&view_id.value).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn remove_favorite_handler(
+  data: AFPluginData<ViewIdPB>,
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> Result<(), FlowyError> {
+  let view_id: ViewIdPB = data.into_inner();
+  folder.remove_favorite(&view_id.value).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(folder), err)]
+pub(crate) async fn read_favorites_handler(
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> DataResult<RepeatedViewPB, FlowyError> {
+  let favorites = folder.get_favorites().await?;
+  data_result_ok(favorites)
+}
+
+#[tracing::instrument(level = "debug", skip(folder), err)]
+pub(crate) async fn read_recent_views_handler(
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> DataResult<RepeatedRecentViewPB, FlowyError> {
+  let recent_views = folder.get_recent_views().await?;
+  data_result_ok(recent_views)
+}
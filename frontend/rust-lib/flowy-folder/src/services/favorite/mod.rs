@@ -0,0 +1,42 @@
+pub(crate) mod event_handler;
+
+use crate::{
+  entities::view::RepeatedViewPB,
+  errors::FlowyResult,
+  notification::{send_anonymous_notification, FolderNotification},
+  services::{persistence::FolderPersistenceTransaction, TrashController},
+};
+use folder_model::ViewRevision;
+use std::sync::Arc;
+
+/// Returns the views of the favorites in order. The favorites whose views are in the trash, or
+/// whose apps are in the trash, are left out, and so are the ones whose views no longer exist.
+pub(crate) fn read_favorite_views<'a>(
+  trash_controller: Arc<TrashController>,
+  transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> FlowyResult<Vec<ViewRevision>> {
+  let trash_ids = trash_controller.read_trash_ids(transaction)?;
+  let mut view_revs = vec![];
+  for favorite_rev in transaction.read_favorites()? {
+    if trash_ids.contains(&favorite_rev.id) {
+      continue;
+    }
+    if let Ok(view_rev) = transaction.read_view(&favorite_rev.id) {
+      if !trash_ids.contains(&view_rev.app_id) {
+        view_revs.push(view_rev);
+      }
+    }
+  }
+  Ok(view_revs)
+}
+
+pub(crate) fn notify_favorites_changed<'a>(
+  trash_controller: Arc<TrashController>,
+  transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> FlowyResult<()> {
+  let favorites: RepeatedViewPB = read_favorite_views(trash_controller, transaction)?.into();
+  send_anonymous_notification(FolderNotification::DidUpdateFavorites)
+    .payload(favorites)
+    .send();
+  Ok(())
+}
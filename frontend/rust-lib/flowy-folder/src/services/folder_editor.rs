@@ -3,10 +3,10 @@ use bytes::Bytes;
 use flowy_client_sync::client_folder::{FolderChangeset, FolderOperations, FolderPad};
 use flowy_client_sync::make_operations_from_revisions;
 use flowy_client_sync::util::recover_operation_from_revisions;
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
 use flowy_revision::{
-  RevisionCloudService, RevisionManager, RevisionMergeable, RevisionObjectDeserializer,
-  RevisionObjectSerializer, RevisionWebSocket,
+  RevisionCheckpoint, RevisionCloudService, RevisionManager, RevisionMergeable,
+  RevisionObjectDeserializer, RevisionObjectSerializer, RevisionWebSocket,
 };
 use flowy_sqlite::ConnectionPool;
 use lib_infra::future::FutureResult;
@@ -101,6 +101,34 @@ impl FolderEditor {
     Ok(())
   }
 
+  pub async fn get_checkpoints(&self) -> FlowyResult<Vec<RevisionCheckpoint>> {
+    self.rev_manager.read_checkpoints().await
+  }
+
+  /// Returns the folder at the checkpoint in JSON format.
+  pub async fn read_checkpoint(&self, rev_id: i64) -> FlowyResult<String> {
+    let checkpoint = self
+      .rev_manager
+      .read_checkpoint_object::<FolderRevisionSerde>(rev_id)
+      .await?;
+    let value: serde_json::Value =
+      serde_json::from_str(&checkpoint.to_json()?).map_err(internal_error)?;
+    serde_json::to_string_pretty(&value).map_err(internal_error)
+  }
+
+  /// Restores the folder to the checkpoint. The restoring is saved as a new revision.
+  pub async fn restore_checkpoint(&self, rev_id: i64) -> FlowyResult<()> {
+    let checkpoint = self
+      .rev_manager
+      .read_checkpoint_object::<FolderRevisionSerde>(rev_id)
+      .await?;
+    let change = self.folder.write().restore_folder(checkpoint)?;
+    if let Some(change) = change {
+      self.apply_change(change)?;
+    }
+    Ok(())
+  }
+
   #[allow(dead_code)]
   pub fn folder_json(&self) -> FlowyResult<String> {
     let json = self.folder.read().to_json()?;
@@ -0,0 +1,99 @@
+use crate::{
+  entities::history::{
+    CheckpointContentPB, DiffFolderCheckpointsPB, DiffViewCheckpointsPB, DiffViewCheckpointsParams,
+    FolderCheckpointIdPB, RepeatedCheckpointDiffChunkPB, RepeatedRevisionCheckpointPB,
+    ViewCheckpointIdPB, ViewCheckpointIdParams,
+  },
+  entities::view::ViewIdPB,
+  errors::FlowyError,
+  manager::FolderManager,
+  services::ViewController,
+};
+use flowy_revision::diff_checkpoint_content;
+use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
+use std::{convert::TryInto, sync::Arc};
+
+pub(crate) async fn get_view_checkpoints_handler(
+  data: AFPluginData<ViewIdPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<RepeatedRevisionCheckpointPB, FlowyError> {
+  let view_id: ViewIdPB = data.into_inner();
+  let checkpoints = controller.get_view_checkpoints(&view_id.value).await?;
+  data_result_ok(checkpoints.into())
+}
+
+pub(crate) async fn read_view_checkpoint_handler(
+  data: AFPluginData<ViewCheckpointIdPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<CheckpointContentPB, FlowyError> {
+  let params: ViewCheckpointIdParams = data.into_inner().try_into()?;
+  let content = controller
+    .read_view_checkpoint(&params.view_id, params.rev_id)
+    .await?;
+  data_result_ok(CheckpointContentPB {
+    rev_id: params.rev_id,
+    content,
+  })
+}
+
+pub(crate) async fn diff_view_checkpoints_handler(
+  data: AFPluginData<DiffViewCheckpointsPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<RepeatedCheckpointDiffChunkPB, FlowyError> {
+  let params: DiffViewCheckpointsParams = data.into_inner().try_into()?;
+  let old = controller
+    .read_view_checkpoint(&params.view_id, params.from_rev_id)
+    .await?;
+  let new = controller
+    .read_view_checkpoint(&params.view_id, params.to_rev_id)
+    .await?;
+  data_result_ok(diff_checkpoint_content(&old, &new).into())
+}
+
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn restore_view_checkpoint_handler(
+  data: AFPluginData<ViewCheckpointIdPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> Result<(), FlowyError> {
+  let params: ViewCheckpointIdParams = data.into_inner().try_into()?;
+  controller
+    .restore_view_checkpoint(&params.view_id, params.rev_id)
+    .await?;
+  Ok(())
+}
+
+pub(crate) async fn get_folder_checkpoints_handler(
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> DataResult<RepeatedRevisionCheckpointPB, FlowyError> {
+  let checkpoints = folder.get_folder_checkpoints().await?;
+  data_result_ok(checkpoints.into())
+}
+
+pub(crate) async fn read_folder_checkpoint_handler(
+  data: AFPluginData<FolderCheckpointIdPB>,
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> DataResult<CheckpointContentPB, FlowyError> {
+  let rev_id = data.into_inner().rev_id;
+  let content = folder.read_folder_checkpoint(rev_id).await?;
+  data_result_ok(CheckpointContentPB { rev_id, content })
+}
+
+pub(crate) async fn diff_folder_checkpoints_handler(
+  data: AFPluginData<DiffFolderCheckpointsPB>,
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> DataResult<RepeatedCheckpointDiffChunkPB, FlowyError> {
+  let params = data.into_inner();
+  let old = folder.read_folder_checkpoint(params.from_rev_id).await?;
+  let new = folder.read_folder_checkpoint(params.to_rev_id).await?;
+  data_result_ok(diff_checkpoint_content(&old, &new).into())
+}
+
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn restore_folder_checkpoint_handler(
+  data: AFPluginData<FolderCheckpointIdPB>,
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> Result<(), FlowyError> {
+  let rev_id = data.into_inner().rev_id;
+  folder.restore_folder_checkpoint(rev_id).await?;
+  Ok(())
+}
@@ -4,7 +4,10 @@ pub(crate) use view::controller::*;
 pub(crate) use workspace::controller::*;
 
 pub(crate) mod app;
+pub mod archive;
+pub(crate) mod favorite;
 pub mod folder_editor;
+pub(crate) mod history;
 pub(crate) mod persistence;
 pub(crate) mod trash;
 pub(crate) mod view;
@@ -13,7 +13,7 @@ use flowy_client_sync::client_folder::{FolderOperationsBuilder, FolderPad};
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_revision_persistence::{RevisionDiskCache, RevisionState, SyncRecord};
 use flowy_sqlite::ConnectionPool;
-use folder_model::{AppRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+use folder_model::{AppRevision, FavoriteRevision, TrashRevision, ViewRevision, WorkspaceRevision};
 use revision_model::Revision;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -48,6 +48,11 @@ pub trait FolderPersistenceTransaction {
   fn create_trash(&self, trashes: Vec<TrashRevision>) -> FlowyResult<()>;
   fn read_trash(&self, trash_id: Option<String>) -> FlowyResult<Vec<TrashRevision>>;
   fn delete_trash(&self, trash_ids: Option<Vec<String>>) -> FlowyResult<()>;
+
+  fn create_favorite(&self, favorite_rev: FavoriteRevision) -> FlowyResult<()>;
+  fn read_favorites(&self) -> FlowyResult<Vec<FavoriteRevision>>;
+  fn delete_favorites(&self, view_ids: Vec<String>) -> FlowyResult<()>;
+  fn move_favorite(&self, view_id: &str, from: usize, to: usize) -> FlowyResult<()>;
 }
 
 pub struct FolderPersistence {
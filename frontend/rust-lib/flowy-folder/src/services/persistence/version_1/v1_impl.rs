@@ -8,7 +8,7 @@ use crate::services::persistence::{
 };
 use flowy_error::FlowyResult;
 use flowy_sqlite::DBConnection;
-use folder_model::{AppRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+use folder_model::{AppRevision, FavoriteRevision, TrashRevision, ViewRevision, WorkspaceRevision};
 
 /// V1Transaction is deprecated since version 0.0.2 version
 pub struct V1Transaction<'a>(pub &'a DBConnection);
@@ -133,6 +133,23 @@ impl<'a> FolderPersistenceTransaction for V1Transaction<'a> {
       },
     }
   }
+
+  // The favorites were introduced after V1 was deprecated, so V1 has no favorites.
+  fn create_favorite(&self, _favorite_rev: FavoriteRevision) -> FlowyResult<()> {
+    Ok(())
+  }
+
+  fn read_favorites(&self) -> FlowyResult<Vec<FavoriteRevision>> {
+    Ok(vec![])
+  }
+
+  fn delete_favorites(&self, _view_ids: Vec<String>) -> FlowyResult<()> {
+    Ok(())
+  }
+
+  fn move_favorite(&self, _view_id: &str, _from: usize, _to: usize) -> FlowyResult<()> {
+    Ok(())
+  }
 }
 
 // https://www.reddit.com/r/rust/comments/droxdg/why_arent_traits_impld_for_boxdyn_trait/
@@ -219,4 +236,20 @@ where
   fn delete_trash(&self, trash_ids: Option<Vec<String>>) -> FlowyResult<()> {
     (**self).delete_trash(trash_ids)
   }
+
+  fn create_favorite(&self, favorite_rev: FavoriteRevision) -> FlowyResult<()> {
+    (**self).create_favorite(favorite_rev)
+  }
+
+  fn read_favorites(&self) -> FlowyResult<Vec<FavoriteRevision>> {
+    (**self).read_favorites()
+  }
+
+  fn delete_favorites(&self, view_ids: Vec<String>) -> FlowyResult<()> {
+    (**self).delete_favorites(view_ids)
+  }
+
+  fn move_favorite(&self, view_id: &str, from: usize, to: usize) -> FlowyResult<()> {
+    (**self).move_favorite(view_id, from, to)
+  }
 }
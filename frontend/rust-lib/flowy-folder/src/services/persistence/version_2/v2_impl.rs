@@ -3,7 +3,7 @@ use crate::services::{
   persistence::{AppChangeset, FolderPersistenceTransaction, ViewChangeset, WorkspaceChangeset},
 };
 use flowy_error::{FlowyError, FlowyResult};
-use folder_model::{AppRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+use folder_model::{AppRevision, FavoriteRevision, TrashRevision, ViewRevision, WorkspaceRevision};
 use std::sync::Arc;
 
 impl FolderPersistenceTransaction for FolderEditor {
@@ -157,6 +157,32 @@ impl FolderPersistenceTransaction for FolderEditor {
     }
     Ok(())
   }
+
+  fn create_favorite(&self, favorite_rev: FavoriteRevision) -> FlowyResult<()> {
+    if let Some(change) = self.folder.write().create_favorite(favorite_rev)? {
+      self.apply_change(change)?;
+    }
+    Ok(())
+  }
+
+  fn read_favorites(&self) -> FlowyResult<Vec<FavoriteRevision>> {
+    let favorites = self.folder.read().read_favorites()?;
+    Ok(favorites)
+  }
+
+  fn delete_favorites(&self, view_ids: Vec<String>) -> FlowyResult<()> {
+    if let Some(change) = self.folder.write().delete_favorites(view_ids)? {
+      self.apply_change(change)?;
+    }
+    Ok(())
+  }
+
+  fn move_favorite(&self, view_id: &str, from: usize, to: usize) -> FlowyResult<()> {
+    if let Some(change) = self.folder.write().move_favorite(view_id, from, to)? {
+      self.apply_change(change)?;
+    }
+    Ok(())
+  }
 }
 
 impl<T> FolderPersistenceTransaction for Arc<T>
@@ -242,4 +268,20 @@ where
   fn delete_trash(&self, trash_ids: Option<Vec<String>>) -> FlowyResult<()> {
     (**self).delete_trash(trash_ids)
   }
+
+  fn create_favorite(&self, favorite_rev: FavoriteRevision) -> FlowyResult<()> {
+    (**self).create_favorite(favorite_rev)
+  }
+
+  fn read_favorites(&self) -> FlowyResult<Vec<FavoriteRevision>> {
+    (**self).read_favorites()
+  }
+
+  fn delete_favorites(&self, view_ids: Vec<String>) -> FlowyResult<()> {
+    (**self).delete_favorites(view_ids)
+  }
+
+  fn move_favorite(&self, view_id: &str, from: usize, to: usize) -> FlowyResult<()> {
+    (**self).move_favorite(view_id, from, to)
+  }
 }
@@ -228,7 +228,7 @@ impl TrashController {
 }
 
 #[tracing::instrument(level = "debug", skip(repeated_trash), fields(n_trash))]
-fn notify_trash_changed<T: Into<RepeatedTrashPB>>(repeated_trash: T) {
+pub(crate) fn notify_trash_changed<T: Into<RepeatedTrashPB>>(repeated_trash: T) {
   let repeated_trash = repeated_trash.into();
   tracing::Span::current().record("n_trash", repeated_trash.len());
   send_anonymous_notification(FolderNotification::DidUpdateTrash)
@@ -10,11 +10,14 @@ use crate::{
   event_map::{FolderCouldServiceV1, WorkspaceUser},
   notification::{send_notification, FolderNotification},
   services::{
+    favorite::notify_favorites_changed,
     persistence::{FolderPersistence, FolderPersistenceTransaction, ViewChangeset},
+    view::recent_views::{record_recent_view, remove_recent_views},
     TrashController, TrashEvent,
   },
 };
 use bytes::Bytes;
+use flowy_revision::RevisionCheckpoint;
 use flowy_sqlite::kv::KV;
 use folder_model::{gen_view_id, ViewRevision};
 use futures::{FutureExt, StreamExt};
@@ -177,6 +180,7 @@ impl ViewController {
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub(crate) fn set_latest_view(&self, view_id: &str) -> Result<(), FlowyError> {
     KV::set_str(LATEST_VIEW_ID, view_id.to_owned());
+    record_recent_view(view_id);
     Ok(())
   }
 
@@ -192,6 +196,33 @@ impl ViewController {
     Ok(())
   }
 
+  pub(crate) async fn get_view_checkpoints(
+    &self,
+    view_id: &str,
+  ) -> Result<Vec<RevisionCheckpoint>, FlowyError> {
+    let processor = self.get_data_processor_from_view_id(view_id).await?;
+    processor.get_view_checkpoints(view_id).await
+  }
+
+  pub(crate) async fn read_view_checkpoint(
+    &self,
+    view_id: &str,
+    rev_id: i64,
+  ) -> Result<String, FlowyError> {
+    let processor = self.get_data_processor_from_view_id(view_id).await?;
+    processor.read_view_checkpoint(view_id, rev_id).await
+  }
+
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub(crate) async fn restore_view_checkpoint(
+    &self,
+    view_id: &str,
+    rev_id: i64,
+  ) -> Result<(), FlowyError> {
+    let processor = self.get_data_processor_from_view_id(view_id).await?;
+    processor.restore_view_checkpoint(view_id, rev_id).await
+  }
+
   #[tracing::instrument(level = "debug", skip(self), err)]
   pub(crate) async fn move_view_to_trash(&self, view_id: &str) -> Result<(), FlowyError> {
     if let Some(latest_view_id) = KV::get_str(LATEST_VIEW_ID) {
@@ -384,7 +415,7 @@ impl ViewController {
   }
 
   #[inline]
-  fn get_data_processor<T: Into<ViewDataFormatPB>>(
+  pub(crate) fn get_data_processor<T: Into<ViewDataFormatPB>>(
     &self,
     data_type: T,
   ) -> FlowyResult<Arc<dyn ViewDataProcessor + Send + Sync>> {
@@ -415,7 +446,7 @@ async fn handle_trash_event(
             notify_views_changed(&view_rev.app_id, trash_can.clone(), &transaction)?;
             notify_dart(view_rev.into(), FolderNotification::DidDeleteView);
           }
-          Ok(())
+          notify_favorites_changed(trash_can.clone(), &transaction)
         })
         .await;
       let _ = ret.send(result).await;
@@ -428,7 +459,7 @@ async fn handle_trash_event(
             notify_views_changed(&view_rev.app_id, trash_can.clone(), &transaction)?;
             notify_dart(view_rev.into(), FolderNotification::DidRestoreView);
           }
-          Ok(())
+          notify_favorites_changed(trash_can.clone(), &transaction)
         })
         .await;
       let _ = ret.send(result).await;
@@ -448,6 +479,13 @@ async fn handle_trash_event(
             for notify_id in notify_ids {
               notify_views_changed(&notify_id, trash_can.clone(), &transaction)?;
             }
+            let view_ids = views
+              .iter()
+              .map(|view| view.id.clone())
+              .collect::<Vec<String>>();
+            remove_recent_views(&view_ids);
+            transaction.delete_favorites(view_ids)?;
+            notify_favorites_changed(trash_can.clone(), &transaction)?;
             Ok(views)
           })
           .await?;
@@ -456,7 +494,7 @@ async fn handle_trash_event(
           let data_type = view.data_format.clone().into();
           match get_data_processor(data_processors.clone(), &data_type) {
             Ok(processor) => {
-              processor.close_view(&view.id).await?;
+              processor.delete_view(&view.id).await?;
             },
             Err(e) => tracing::error!("{}", e),
           }
@@ -1,5 +1,6 @@
 use crate::entities::view::{MoveFolderItemParams, MoveFolderItemPayloadPB, MoveFolderItemType};
 use crate::manager::FolderManager;
+use crate::notification::{send_anonymous_notification, FolderNotification};
 use crate::services::{notify_workspace_setting_did_change, AppController};
 use crate::{
   entities::{
@@ -77,6 +78,10 @@ pub(crate) async fn set_latest_view_handler(
   let view_id: ViewIdPB = data.into_inner();
   controller.set_latest_view(&view_id.value)?;
   notify_workspace_setting_did_change(&folder, &view_id).await?;
+  let recent_views = folder.get_recent_views().await?;
+  send_anonymous_notification(FolderNotification::DidUpdateRecentViews)
+    .payload(recent_views)
+    .send();
   Ok(())
 }
 
@@ -94,6 +99,7 @@ pub(crate) async fn move_item_handler(
   data: AFPluginData<MoveFolderItemPayloadPB>,
   view_controller: AFPluginState<Arc<ViewController>>,
   app_controller: AFPluginState<Arc<AppController>>,
+  folder: AFPluginState<Arc<FolderManager>>,
 ) -> Result<(), FlowyError> {
   let params: MoveFolderItemParams = data.into_inner().try_into()?;
   match params.ty {
@@ -107,6 +113,11 @@ pub(crate) async fn move_item_handler(
         .move_view(&params.item_id, params.from, params.to)
         .await?;
     },
+    MoveFolderItemType::MoveFavorite => {
+      folder
+        .move_favorite(&params.item_id, params.from, params.to)
+        .await?;
+    },
   }
   Ok(())
 }
@@ -0,0 +1,65 @@
+use flowy_sqlite::kv::KV;
+use lib_infra::util::timestamp;
+use serde::{Deserialize, Serialize};
+
+const RECENT_VIEWS: &str = "recent_views";
+
+/// The number of the recent views that are kept. The least recently accessed view is dropped
+/// when a new one is accessed.
+pub(crate) const MAX_RECENT_VIEWS: usize = 20;
+
+/// The recent views are kept locally instead of in the folder, so they don't get synced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RecentView {
+  pub view_id: String,
+  pub accessed_at: i64,
+}
+
+/// Returns the recent views, the most recently accessed first.
+pub(crate) fn read_recent_views() -> Vec<RecentView> {
+  match KV::get_str(RECENT_VIEWS) {
+    None => vec![],
+    Some(s) => match serde_json::from_str(&s) {
+      Ok(recent_views) => recent_views,
+      Err(e) => {
+        tracing::error!("Deserialize recent views failed: {:?}", e);
+        vec![]
+      },
+    },
+  }
+}
+
+/// Moves the view to the front of the recent views and updates its access time.
+pub(crate) fn record_recent_view(view_id: &str) {
+  let mut recent_views = read_recent_views();
+  recent_views.retain(|recent_view| recent_view.view_id != view_id);
+  recent_views.insert(
+    0,
+    RecentView {
+      view_id: view_id.to_owned(),
+      accessed_at: timestamp(),
+    },
+  );
+  recent_views.truncate(MAX_RECENT_VIEWS);
+  save_recent_views(&recent_views);
+}
+
+pub(crate) fn remove_recent_views(view_ids: &[String]) {
+  let mut recent_views = read_recent_views();
+  let len = recent_views.len();
+  recent_views.retain(|recent_view| !view_ids.contains(&recent_view.view_id));
+  if recent_views.len() != len {
+    save_recent_views(&recent_views);
+  }
+}
+
+pub(crate) fn clear_recent_views() {
+  let _ = KV::remove(RECENT_VIEWS);
+}
+
+fn save_recent_views(recent_views: &[RecentView]) {
+  match serde_json::to_string(recent_views) {
+    Ok(s) => KV::set_str(RECENT_VIEWS, s),
+    Err(e) => tracing::error!("Serialize recent views failed: {:?}", e),
+  }
+}
@@ -253,7 +253,7 @@ pub async fn notify_workspace_setting_did_change(
 /// The [CURRENT_WORKSPACE] represents as the current workspace that opened by the
 /// user. Only one workspace can be opened at a time.
 const CURRENT_WORKSPACE: &str = "current-workspace";
-fn send_workspace_notification<T: ToBytes>(ty: FolderNotification, payload: T) {
+pub(crate) fn send_workspace_notification<T: ToBytes>(ty: FolderNotification, payload: T) {
   send_notification(CURRENT_WORKSPACE, ty)
     .payload(payload)
     .send();
@@ -2,7 +2,7 @@ use crate::script::{invalid_workspace_name_test_case, FolderScript::*, FolderTes
 use flowy_folder::entities::view::ViewDataFormatPB;
 use flowy_folder::entities::workspace::CreateWorkspacePayloadPB;
 use flowy_revision_persistence::RevisionState;
-use flowy_test::{event_builder::*, FlowySDKTest};
+use flowy_test::{event_builder::*, helper::root_dir, FlowySDKTest};
 
 #[tokio::test]
 async fn workspace_read_all() {
@@ -62,6 +62,39 @@ async fn workspace_create_with_apps() {
   test.run_scripts(vec![ReadApp(app.id)]).await;
 }
 
+#[tokio::test]
+async fn workspace_export_and_import() {
+  let mut test = FolderTest::new().await;
+  let workspace = test.workspace.clone();
+  let path = format!("{}/{}.json", root_dir(), workspace.id);
+  test
+    .run_scripts(vec![
+      ExportWorkspace { path: path.clone() },
+      ImportWorkspace { path },
+    ])
+    .await;
+
+  let imported_workspace = test.workspace.clone();
+  assert_ne!(imported_workspace.id, workspace.id);
+  assert_eq!(imported_workspace.name, workspace.name);
+  assert_eq!(imported_workspace.apps.items.len(), 1);
+
+  let imported_app = &imported_workspace.apps.items[0];
+  assert_ne!(imported_app.id, test.app.id);
+  assert_eq!(imported_app.name, test.app.name);
+  assert_eq!(imported_app.belongings.items.len(), 1);
+
+  let imported_view = imported_app.belongings.items[0].clone();
+  assert_ne!(imported_view.id, test.view.id);
+  assert_eq!(imported_view.name, test.view.name);
+  test
+    .run_scripts(vec![
+      ReadView(imported_view.id.clone()),
+      AssertView(imported_view),
+    ])
+    .await;
+}
+
 #[tokio::test]
 async fn workspace_create_with_invalid_name() {
   for (name, code) in invalid_workspace_name_test_case() {
@@ -269,6 +302,111 @@ async fn view_delete_all_permanent() {
   assert_eq!(test.trash.len(), 0);
 }
 
+#[tokio::test]
+async fn favorite_add_and_remove() {
+  let mut test = FolderTest::new().await;
+  let view = test.view.clone();
+  test
+    .run_scripts(vec![AddFavorite(view.id.clone()), ReadFavorites])
+    .await;
+  assert_eq!(test.favorites, vec![view.clone()]);
+
+  test
+    .run_scripts(vec![RemoveFavorite(view.id), ReadFavorites])
+    .await;
+  assert!(test.favorites.is_empty());
+}
+
+#[tokio::test]
+async fn favorite_move() {
+  let mut test = FolderTest::new().await;
+  let first_view = test.view.clone();
+  test
+    .run_scripts(vec![CreateView {
+      name: "View A".to_owned(),
+      desc: "View A description".to_owned(),
+      data_type: ViewDataFormatPB::DeltaFormat,
+    }])
+    .await;
+  let second_view = test.view.clone();
+  test
+    .run_scripts(vec![
+      AddFavorite(first_view.id.clone()),
+      AddFavorite(second_view.id.clone()),
+      AddFavorite(first_view.id.clone()),
+      MoveFavorite {
+        view_id: second_view.id.clone(),
+        from: 1,
+        to: 0,
+      },
+      ReadFavorites,
+    ])
+    .await;
+
+  let favorite_ids = test
+    .favorites
+    .iter()
+    .map(|view| view.id.clone())
+    .collect::<Vec<String>>();
+  assert_eq!(favorite_ids, vec![second_view.id, first_view.id]);
+}
+
+#[tokio::test]
+async fn favorite_view_in_trash() {
+  let mut test = FolderTest::new().await;
+  let view = test.view.clone();
+  test
+    .run_scripts(vec![
+      AddFavorite(view.id.clone()),
+      DeleteView,
+      ReadFavorites,
+    ])
+    .await;
+  assert!(test.favorites.is_empty());
+
+  test
+    .run_scripts(vec![RestoreViewFromTrash, ReadFavorites])
+    .await;
+  assert_eq!(test.favorites.len(), 1);
+
+  test
+    .run_scripts(vec![DeleteView, DeleteAllTrash, ReadFavorites])
+    .await;
+  assert!(test.favorites.is_empty());
+}
+
+#[tokio::test]
+async fn recent_views_in_access_order() {
+  let mut test = FolderTest::new().await;
+  let first_view = test.view.clone();
+  test
+    .run_scripts(vec![CreateView {
+      name: "View A".to_owned(),
+      desc: "View A description".to_owned(),
+      data_type: ViewDataFormatPB::DeltaFormat,
+    }])
+    .await;
+  let second_view = test.view.clone();
+  test
+    .run_scripts(vec![
+      SetLatestView(first_view.id.clone()),
+      SetLatestView(second_view.id.clone()),
+      SetLatestView(first_view.id.clone()),
+      ReadRecentViews,
+    ])
+    .await;
+
+  let recent_view_ids = test
+    .recent_views
+    .iter()
+    .map(|recent_view| recent_view.view.id.clone())
+    .collect::<Vec<String>>();
+  // The first view of the default workspace is opened when the user signs up, so it's the
+  // third one.
+  assert_eq!(recent_view_ids[..2], [first_view.id, second_view.id]);
+  assert!(test.recent_views[0].accessed_at >= test.recent_views[1].accessed_at);
+}
+
 #[tokio::test]
 async fn folder_sync_revision_state() {
   let mut test = FolderTest::new().await;
@@ -1,4 +1,8 @@
-use flowy_folder::entities::view::{RepeatedViewIdPB, ViewIdPB};
+use flowy_folder::entities::archive::{ExportWorkspacePayloadPB, ImportWorkspacePayloadPB};
+use flowy_folder::entities::favorite::{RecentViewPB, RepeatedRecentViewPB};
+use flowy_folder::entities::view::{
+  MoveFolderItemPayloadPB, MoveFolderItemType, RepeatedViewIdPB, ViewIdPB,
+};
 use flowy_folder::entities::workspace::WorkspaceIdPB;
 use flowy_folder::entities::{
   app::{AppIdPB, CreateAppPayloadPB, UpdateAppPayloadPB},
@@ -31,6 +35,12 @@ pub enum FolderScript {
   // AssertWorkspaceRevisionJson(String),
   AssertWorkspace(WorkspacePB),
   ReadWorkspace(Option<String>),
+  ExportWorkspace {
+    path: String,
+  },
+  ImportWorkspace {
+    path: String,
+  },
 
   // App
   CreateApp {
@@ -60,6 +70,18 @@ pub enum FolderScript {
   },
   DeleteView,
   DeleteViews(Vec<String>),
+  SetLatestView(String),
+
+  // Favorite
+  AddFavorite(String),
+  RemoveFavorite(String),
+  MoveFavorite {
+    view_id: String,
+    from: i32,
+    to: i32,
+  },
+  ReadFavorites,
+  ReadRecentViews,
 
   // Trash
   RestoreAppFromTrash,
@@ -84,6 +106,8 @@ pub struct FolderTest {
   pub app: AppPB,
   pub view: ViewPB,
   pub trash: Vec<TrashPB>,
+  pub favorites: Vec<ViewPB>,
+  pub recent_views: Vec<RecentViewPB>,
   // pub folder_editor:
 }
 
@@ -115,6 +139,8 @@ impl FolderTest {
       app,
       view,
       trash: vec![],
+      favorites: vec![],
+      recent_views: vec![],
     }
   }
 
@@ -155,6 +181,13 @@ impl FolderTest {
         let workspace = read_workspace(sdk, workspace_id).await.pop().unwrap();
         self.workspace = workspace;
       },
+      FolderScript::ExportWorkspace { path } => {
+        export_workspace(sdk, &self.workspace.id, &path).await;
+      },
+      FolderScript::ImportWorkspace { path } => {
+        let workspace = import_workspace(sdk, &path).await;
+        self.workspace = workspace;
+      },
       FolderScript::CreateApp { name, desc } => {
         let app = create_app(sdk, &self.workspace.id, &name, &desc).await;
         self.app = app;
@@ -207,6 +240,24 @@ impl FolderTest {
       FolderScript::DeleteViews(view_ids) => {
         delete_view(sdk, view_ids).await;
       },
+      FolderScript::SetLatestView(view_id) => {
+        set_latest_view(sdk, &view_id).await;
+      },
+      FolderScript::AddFavorite(view_id) => {
+        add_favorite(sdk, &view_id).await;
+      },
+      FolderScript::RemoveFavorite(view_id) => {
+        remove_favorite(sdk, &view_id).await;
+      },
+      FolderScript::MoveFavorite { view_id, from, to } => {
+        move_favorite(sdk, &view_id, from, to).await;
+      },
+      FolderScript::ReadFavorites => {
+        self.favorites = read_favorites(sdk).await.items;
+      },
+      FolderScript::ReadRecentViews => {
+        self.recent_views = read_recent_views(sdk).await.items;
+      },
       FolderScript::RestoreAppFromTrash => {
         restore_app_from_trash(sdk, &self.app.id).await;
       },
@@ -304,6 +355,30 @@ pub async fn read_workspace(sdk: &FlowySDKTest, workspace_id: Option<String>) ->
   workspaces
 }
 
+pub async fn export_workspace(sdk: &FlowySDKTest, workspace_id: &str, path: &str) {
+  let request = ExportWorkspacePayloadPB {
+    workspace_id: workspace_id.to_owned(),
+    path: path.to_owned(),
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(ExportWorkspace)
+    .payload(request)
+    .async_send()
+    .await;
+}
+
+pub async fn import_workspace(sdk: &FlowySDKTest, path: &str) -> WorkspacePB {
+  let request = ImportWorkspacePayloadPB {
+    path: path.to_owned(),
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(ImportWorkspace)
+    .payload(request)
+    .async_send()
+    .await
+    .parse::<WorkspacePB>()
+}
+
 pub async fn create_app(sdk: &FlowySDKTest, workspace_id: &str, name: &str, desc: &str) -> AppPB {
   let create_app_request = CreateAppPayloadPB {
     workspace_id: workspace_id.to_owned(),
@@ -428,6 +503,69 @@ pub async fn delete_view(sdk: &FlowySDKTest, view_ids: Vec<String>) {
     .await;
 }
 
+pub async fn set_latest_view(sdk: &FlowySDKTest, view_id: &str) {
+  let request = ViewIdPB {
+    value: view_id.to_owned(),
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(SetLatestView)
+    .payload(request)
+    .async_send()
+    .await;
+}
+
+pub async fn add_favorite(sdk: &FlowySDKTest, view_id: &str) {
+  let request = ViewIdPB {
+    value: view_id.to_owned(),
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(AddFavorite)
+    .payload(request)
+    .async_send()
+    .await;
+}
+
+pub async fn remove_favorite(sdk: &FlowySDKTest, view_id: &str) {
+  let request = ViewIdPB {
+    value: view_id.to_owned(),
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(RemoveFavorite)
+    .payload(request)
+    .async_send()
+    .await;
+}
+
+pub async fn move_favorite(sdk: &FlowySDKTest, view_id: &str, from: i32, to: i32) {
+  let request = MoveFolderItemPayloadPB {
+    item_id: view_id.to_owned(),
+    from,
+    to,
+    ty: MoveFolderItemType::MoveFavorite,
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(MoveItem)
+    .payload(request)
+    .async_send()
+    .await;
+}
+
+pub async fn read_favorites(sdk: &FlowySDKTest) -> RepeatedViewPB {
+  FolderEventBuilder::new(sdk.clone())
+    .event(ReadFavorites)
+    .async_send()
+    .await
+    .parse::<RepeatedViewPB>()
+}
+
+pub async fn read_recent_views(sdk: &FlowySDKTest) -> RepeatedRecentViewPB {
+  FolderEventBuilder::new(sdk.clone())
+    .event(ReadRecentViews)
+    .async_send()
+    .await
+    .parse::<RepeatedRecentViewPB>()
+}
+
 pub async fn read_trash(sdk: &FlowySDKTest) -> RepeatedTrashPB {
   FolderEventBuilder::new(sdk.clone())
     .event(ReadTrash)
@@ -1,22 +1,91 @@
-use crate::{RevisionChangeset, RevisionDiskCache, SyncRecord};
-use flowy_error::FlowyResult;
-use revision_model::RevisionRange;
+use crate::{RevisionChangeset, RevisionDiskCache, RevisionState, SyncRecord};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use parking_lot::Mutex;
+use revision_model::{Revision, RevisionRange};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 
+/// The log gets compacted after this number of entries were appended to it.
+const COMPACT_THRESHOLD: usize = 1000;
+
+/// Each entry of the log is framed as: the length of the payload (u32, little endian), the md5
+/// of the payload (16 bytes) and then the payload itself.
+const FRAME_HEADER_LEN: usize = 4 + 16;
+
+/// A [RevisionDiskCache] that stores the revisions in an append-only log file.
+///
+/// Every change is appended to the log as a single entry and flushed to the disk before it's
+/// applied to the in-memory records, so a change is either completely persisted or not at all.
+/// An entry that was partially written when the application crashed fails its checksum and is
+/// discarded when the log is opened again.
+///
+/// The log keeps the deleted and updated records until it gets compacted. Compacting rewrites
+/// the log with the current records into a temporary file and then renames it over the log.
 pub struct FileRevisionDiskCache {
-  path: String,
+  path: PathBuf,
+  log: Mutex<RevisionLog>,
 }
 
 pub type FileRevisionDiskCacheConnection = ();
 
+impl FileRevisionDiskCache {
+  /// Opens the log at the path or creates it if it doesn't exist.
+  pub fn new<P: AsRef<Path>>(path: P) -> FlowyResult<Self> {
+    let path = path.as_ref().to_path_buf();
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+
+    // The log is left untouched until the compacted file is renamed over it, so the compacted
+    // file of an interrupted compaction is discarded.
+    let compacting_path = compacting_path(&path);
+    if compacting_path.exists() {
+      std::fs::remove_file(&compacting_path)?;
+    }
+
+    let log = RevisionLog::open(&path)?;
+    Ok(Self {
+      path,
+      log: Mutex::new(log),
+    })
+  }
+
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// Rewrites the log with the current records, dropping the entries of the deleted and the
+  /// updated records.
+  pub fn compact(&self) -> FlowyResult<()> {
+    self.log.lock().compact(&self.path)
+  }
+
+  fn append(&self, entry: LogEntry) -> FlowyResult<()> {
+    let mut log = self.log.lock();
+    log.append(entry)?;
+    if log.number_of_entries > COMPACT_THRESHOLD {
+      log.compact(&self.path)?;
+    }
+    Ok(())
+  }
+}
+
 impl RevisionDiskCache<FileRevisionDiskCacheConnection> for FileRevisionDiskCache {
-  type Error = ();
+  type Error = FlowyError;
 
   fn create_revision_records(&self, revision_records: Vec<SyncRecord>) -> Result<(), Self::Error> {
-    Ok(())
+    if revision_records.is_empty() {
+      return Ok(());
+    }
+    let records = revision_records.into_iter().map(LogRecord::from).collect();
+    self.append(LogEntry::Insert { records })
   }
 
   fn get_connection(&self) -> Result<FileRevisionDiskCacheConnection, Self::Error> {
-    return Ok(());
+    Ok(())
   }
 
   fn read_revision_records(
@@ -24,7 +93,19 @@ impl RevisionDiskCache<FileRevisionDiskCacheConnection> for FileRevisionDiskCach
     object_id: &str,
     rev_ids: Option<Vec<i64>>,
   ) -> Result<Vec<SyncRecord>, Self::Error> {
-    Ok(vec![])
+    let log = self.log.lock();
+    let records = match log.records.get(object_id) {
+      None => return Ok(vec![]),
+      Some(records) => records,
+    };
+    let records = match rev_ids {
+      None => records.values().cloned().collect(),
+      Some(rev_ids) => rev_ids
+        .iter()
+        .flat_map(|rev_id| records.get(rev_id).cloned())
+        .collect(),
+    };
+    Ok(records.into_iter().map(SyncRecord::from).collect())
   }
 
   fn read_revision_records_with_range(
@@ -32,11 +113,24 @@ impl RevisionDiskCache<FileRevisionDiskCacheConnection> for FileRevisionDiskCach
     object_id: &str,
     range: &RevisionRange,
   ) -> Result<Vec<SyncRecord>, Self::Error> {
-    Ok(vec![])
+    let log = self.log.lock();
+    match log.records.get(object_id) {
+      None => Ok(vec![]),
+      Some(records) => Ok(
+        records
+          .range(range.start..=range.end)
+          .map(|(_, record)| SyncRecord::from(record.clone()))
+          .collect(),
+      ),
+    }
   }
 
   fn update_revision_record(&self, changesets: Vec<RevisionChangeset>) -> FlowyResult<()> {
-    Ok(())
+    if changesets.is_empty() {
+      return Ok(());
+    }
+    let changesets = changesets.into_iter().map(LogChangeset::from).collect();
+    self.append(LogEntry::Update { changesets })
   }
 
   fn delete_revision_records(
@@ -44,7 +138,10 @@ impl RevisionDiskCache<FileRevisionDiskCacheConnection> for FileRevisionDiskCach
     object_id: &str,
     rev_ids: Option<Vec<i64>>,
   ) -> Result<(), Self::Error> {
-    Ok(())
+    self.append(LogEntry::Delete {
+      object_id: object_id.to_owned(),
+      rev_ids,
+    })
   }
 
   fn delete_and_insert_records(
@@ -53,6 +150,453 @@ impl RevisionDiskCache<FileRevisionDiskCacheConnection> for FileRevisionDiskCach
     deleted_rev_ids: Option<Vec<i64>>,
     inserted_records: Vec<SyncRecord>,
   ) -> Result<(), Self::Error> {
-    todo!()
+    // Both of the changes are written in one entry, so they are applied together or not at all.
+    self.append(LogEntry::DeleteAndInsert {
+      object_id: object_id.to_owned(),
+      deleted_rev_ids,
+      records: inserted_records.into_iter().map(LogRecord::from).collect(),
+    })
+  }
+}
+
+struct RevisionLog {
+  file: File,
+  /// The records of each object, ordered by the rev_id.
+  records: HashMap<String, BTreeMap<i64, LogRecord>>,
+  /// The number of entries that were appended to the log since it was last compacted.
+  number_of_entries: usize,
+}
+
+impl RevisionLog {
+  fn open(path: &Path) -> FlowyResult<Self> {
+    let mut file = OpenOptions::new()
+      .read(true)
+      .append(true)
+      .create(true)
+      .open(path)?;
+    let mut bytes = vec![];
+    file.read_to_end(&mut bytes)?;
+
+    let mut log = Self {
+      file,
+      records: HashMap::new(),
+      number_of_entries: 0,
+    };
+    let mut offset = 0;
+    while let Some((entry, len)) = decode_entry(&bytes[offset..]) {
+      log.apply(entry);
+      log.number_of_entries += 1;
+      offset += len;
+    }
+
+    // Drop the trailing bytes of the entry that was being written when the application crashed.
+    // Otherwise, the entries appended after it would be unreachable.
+    if offset < bytes.len() {
+      tracing::warn!(
+        "Discard {} bytes of the incomplete entry in the revision log: {:?}",
+        bytes.len() - offset,
+        path
+      );
+      log.file.set_len(offset as u64)?;
+      log.file.sync_all()?;
+    }
+    log.file.seek(SeekFrom::End(0))?;
+    Ok(log)
+  }
+
+  fn append(&mut self, entry: LogEntry) -> FlowyResult<()> {
+    let frame = encode_entry(&entry)?;
+    let len = self.file.metadata()?.len();
+    if let Err(e) = self
+      .file
+      .write_all(&frame)
+      .and_then(|_| self.file.sync_data())
+    {
+      // Remove the partially written entry, otherwise the entries appended after it would be
+      // discarded together with it when the log is opened again.
+      let _ = self.file.set_len(len);
+      return Err(e.into());
+    }
+    self.apply(entry);
+    self.number_of_entries += 1;
+    Ok(())
+  }
+
+  fn apply(&mut self, entry: LogEntry) {
+    match entry {
+      LogEntry::Insert { records } => self.insert(records),
+      LogEntry::Update { changesets } => {
+        for changeset in changesets {
+          if let Some(record) = self
+            .records
+            .get_mut(&changeset.object_id)
+            .and_then(|records| records.get_mut(&changeset.rev_id))
+          {
+            record.state = changeset.state;
+          }
+        }
+      },
+      LogEntry::Delete { object_id, rev_ids } => self.delete(&object_id, rev_ids),
+      LogEntry::DeleteAndInsert {
+        object_id,
+        deleted_rev_ids,
+        records,
+      } => {
+        self.delete(&object_id, deleted_rev_ids);
+        self.insert(records);
+      },
+    }
+  }
+
+  /// Inserts the records. The record is ignored if a record with the same rev_id exists.
+  fn insert(&mut self, records: Vec<LogRecord>) {
+    for record in records {
+      self
+        .records
+        .entry(record.revision.object_id.clone())
+        .or_default()
+        .entry(record.revision.rev_id)
+        .or_insert(record);
+    }
+  }
+
+  fn delete(&mut self, object_id: &str, rev_ids: Option<Vec<i64>>) {
+    match rev_ids {
+      None => {
+        self.records.remove(object_id);
+      },
+      Some(rev_ids) => {
+        if let Some(records) = self.records.get_mut(object_id) {
+          for rev_id in rev_ids {
+            records.remove(&rev_id);
+          }
+          if records.is_empty() {
+            self.records.remove(object_id);
+          }
+        }
+      },
+    }
+  }
+
+  fn compact(&mut self, path: &Path) -> FlowyResult<()> {
+    let compacting_path = compacting_path(path);
+    let mut compacting_file = File::create(&compacting_path)?;
+    let mut number_of_entries = 0;
+    for records in self.records.values() {
+      let entry = LogEntry::Insert {
+        records: records.values().cloned().collect(),
+      };
+      compacting_file.write_all(&encode_entry(&entry)?)?;
+      number_of_entries += 1;
+    }
+    compacting_file.sync_all()?;
+    drop(compacting_file);
+
+    std::fs::rename(&compacting_path, path)?;
+    sync_parent_dir(path);
+
+    self.file = OpenOptions::new().read(true).append(true).open(path)?;
+    self.number_of_entries = number_of_entries;
+    Ok(())
+  }
+}
+
+fn compacting_path(path: &Path) -> PathBuf {
+  let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+  file_name.push(".compacting");
+  path.with_file_name(file_name)
+}
+
+/// Persists the rename of the log. It's not supported on every platform, so the error is ignored.
+fn sync_parent_dir(path: &Path) {
+  if let Some(parent) = path.parent() {
+    if let Ok(dir) = File::open(parent) {
+      let _ = dir.sync_all();
+    }
+  }
+}
+
+fn encode_entry(entry: &LogEntry) -> FlowyResult<Vec<u8>> {
+  let payload = serde_json::to_vec(entry).map_err(internal_error)?;
+  let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+  frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+  frame.extend_from_slice(&md5::compute(&payload).0);
+  frame.extend_from_slice(&payload);
+  Ok(frame)
+}
+
+/// Returns the entry at the beginning of the bytes and the length of its frame. Returns None if
+/// the frame is incomplete or corrupted.
+fn decode_entry(bytes: &[u8]) -> Option<(LogEntry, usize)> {
+  if bytes.len() < FRAME_HEADER_LEN {
+    return None;
+  }
+  let mut len_bytes = [0u8; 4];
+  len_bytes.copy_from_slice(&bytes[0..4]);
+  let payload_len = u32::from_le_bytes(len_bytes) as usize;
+  let frame_len = FRAME_HEADER_LEN + payload_len;
+  if bytes.len() < frame_len {
+    return None;
+  }
+
+  let payload = &bytes[FRAME_HEADER_LEN..frame_len];
+  if md5::compute(payload).0 != bytes[4..FRAME_HEADER_LEN] {
+    return None;
+  }
+  let entry = serde_json::from_slice(payload).ok()?;
+  Some((entry, frame_len))
+}
+
+#[derive(Serialize, Deserialize)]
+enum LogEntry {
+  Insert {
+    records: Vec<LogRecord>,
+  },
+  Update {
+    changesets: Vec<LogChangeset>,
+  },
+  Delete {
+    object_id: String,
+    rev_ids: Option<Vec<i64>>,
+  },
+  DeleteAndInsert {
+    object_id: String,
+    deleted_rev_ids: Option<Vec<i64>>,
+    records: Vec<LogRecord>,
+  },
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct LogRecord {
+  revision: Revision,
+  state: LogRevisionState,
+}
+
+impl std::convert::From<SyncRecord> for LogRecord {
+  fn from(record: SyncRecord) -> Self {
+    Self {
+      revision: record.revision,
+      state: record.state.into(),
+    }
+  }
+}
+
+impl std::convert::From<LogRecord> for SyncRecord {
+  fn from(record: LogRecord) -> Self {
+    SyncRecord {
+      revision: record.revision,
+      state: record.state.into(),
+      write_to_disk: false,
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+struct LogChangeset {
+  object_id: String,
+  rev_id: i64,
+  state: LogRevisionState,
+}
+
+impl std::convert::From<RevisionChangeset> for LogChangeset {
+  fn from(changeset: RevisionChangeset) -> Self {
+    Self {
+      object_id: changeset.object_id,
+      rev_id: changeset.rev_id,
+      state: changeset.state.into(),
+    }
+  }
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+enum LogRevisionState {
+  Sync,
+  Ack,
+}
+
+impl std::convert::From<RevisionState> for LogRevisionState {
+  fn from(state: RevisionState) -> Self {
+    match state {
+      RevisionState::Sync => LogRevisionState::Sync,
+      RevisionState::Ack => LogRevisionState::Ack,
+    }
+  }
+}
+
+impl std::convert::From<LogRevisionState> for RevisionState {
+  fn from(state: LogRevisionState) -> Self {
+    match state {
+      LogRevisionState::Sync => RevisionState::Sync,
+      LogRevisionState::Ack => RevisionState::Ack,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::disk_cache_impl::file_persistence::FileRevisionDiskCache;
+  use crate::{RevisionChangeset, RevisionDiskCache, RevisionState, SyncRecord};
+  use revision_model::{Revision, RevisionRange};
+  use std::io::Write;
+  use std::path::PathBuf;
+
+  /// The directory of the test log, which is removed when the test finishes.
+  struct TestLogDir(PathBuf);
+
+  impl TestLogDir {
+    fn new() -> Self {
+      Self(
+        std::env::temp_dir()
+          .join("flowy_revision_log_test")
+          .join(nanoid::nanoid!(10)),
+      )
+    }
+
+    fn log_path(&self) -> PathBuf {
+      self.0.join("revision.log")
+    }
+  }
+
+  impl Drop for TestLogDir {
+    fn drop(&mut self) {
+      let _ = std::fs::remove_dir_all(&self.0);
+    }
+  }
+
+  fn make_record(object_id: &str, rev_id: i64) -> SyncRecord {
+    let revision = Revision {
+      base_rev_id: rev_id - 1,
+      rev_id,
+      bytes: format!("revision {}", rev_id).into_bytes(),
+      md5: "".to_owned(),
+      object_id: object_id.to_owned(),
+    };
+    SyncRecord::new(revision)
+  }
+
+  fn rev_ids(records: Vec<SyncRecord>) -> Vec<i64> {
+    records
+      .into_iter()
+      .map(|record| record.revision.rev_id)
+      .collect()
+  }
+
+  #[test]
+  fn file_disk_cache_read_after_reopen_test() {
+    let dir = TestLogDir::new();
+    let path = dir.log_path();
+    let cache = FileRevisionDiskCache::new(&path).unwrap();
+    let records = (1..=5).map(|rev_id| make_record("1", rev_id)).collect();
+    cache.create_revision_records(records).unwrap();
+    cache
+      .create_revision_records(vec![make_record("2", 1)])
+      .unwrap();
+    cache
+      .update_revision_record(vec![RevisionChangeset {
+        object_id: "1".to_owned(),
+        rev_id: 2,
+        state: RevisionState::Ack,
+      }])
+      .unwrap();
+    drop(cache);
+
+    let cache = FileRevisionDiskCache::new(&path).unwrap();
+    let records = cache.read_revision_records("1", None).unwrap();
+    assert_eq!(rev_ids(records.clone()), vec![1, 2, 3, 4, 5]);
+    assert_eq!(records[1].state, RevisionState::Ack);
+    assert_eq!(records[1].revision.bytes, b"revision 2".to_vec());
+
+    let range = RevisionRange { start: 2, end: 4 };
+    let records = cache.read_revision_records_with_range("1", &range).unwrap();
+    assert_eq!(rev_ids(records), vec![2, 3, 4]);
+
+    let records = cache.read_revision_records("1", Some(vec![5, 1])).unwrap();
+    assert_eq!(rev_ids(records), vec![5, 1]);
+    assert_eq!(
+      rev_ids(cache.read_revision_records("2", None).unwrap()),
+      vec![1]
+    );
+  }
+
+  #[test]
+  fn file_disk_cache_delete_and_insert_test() {
+    let dir = TestLogDir::new();
+    let path = dir.log_path();
+    let cache = FileRevisionDiskCache::new(&path).unwrap();
+    let records = (1..=3).map(|rev_id| make_record("1", rev_id)).collect();
+    cache.create_revision_records(records).unwrap();
+    cache
+      .delete_and_insert_records("1", Some(vec![1, 2]), vec![make_record("1", 4)])
+      .unwrap();
+    cache.delete_revision_records("1", Some(vec![3])).unwrap();
+    drop(cache);
+
+    let cache = FileRevisionDiskCache::new(&path).unwrap();
+    assert_eq!(
+      rev_ids(cache.read_revision_records("1", None).unwrap()),
+      vec![4]
+    );
+    cache.delete_revision_records("1", None).unwrap();
+    assert!(cache.read_revision_records("1", None).unwrap().is_empty());
+  }
+
+  #[test]
+  fn file_disk_cache_discard_incomplete_entry_test() {
+    let dir = TestLogDir::new();
+    let path = dir.log_path();
+    let cache = FileRevisionDiskCache::new(&path).unwrap();
+    cache
+      .create_revision_records(vec![make_record("1", 1)])
+      .unwrap();
+    drop(cache);
+
+    // Simulate a crash while writing an entry.
+    let mut file = std::fs::OpenOptions::new()
+      .append(true)
+      .open(&path)
+      .unwrap();
+    file.write_all(&[100, 0, 0, 0, 1, 2, 3]).unwrap();
+    drop(file);
+
+    let cache = FileRevisionDiskCache::new(&path).unwrap();
+    cache
+      .create_revision_records(vec![make_record("1", 2)])
+      .unwrap();
+    drop(cache);
+
+    let cache = FileRevisionDiskCache::new(&path).unwrap();
+    assert_eq!(
+      rev_ids(cache.read_revision_records("1", None).unwrap()),
+      vec![1, 2]
+    );
+  }
+
+  #[test]
+  fn file_disk_cache_compact_test() {
+    let dir = TestLogDir::new();
+    let path = dir.log_path();
+    let cache = FileRevisionDiskCache::new(&path).unwrap();
+    for rev_id in 1..=10 {
+      cache
+        .create_revision_records(vec![make_record("1", rev_id)])
+        .unwrap();
+    }
+    cache
+      .delete_revision_records("1", Some((1..=8).collect()))
+      .unwrap();
+    let len_before_compact = std::fs::metadata(&path).unwrap().len();
+    cache.compact().unwrap();
+    assert!(std::fs::metadata(&path).unwrap().len() < len_before_compact);
+
+    cache
+      .create_revision_records(vec![make_record("1", 11)])
+      .unwrap();
+    drop(cache);
+
+    let cache = FileRevisionDiskCache::new(&path).unwrap();
+    assert_eq!(
+      rev_ids(cache.read_revision_records("1", None).unwrap()),
+      vec![9, 10, 11]
+    );
   }
 }
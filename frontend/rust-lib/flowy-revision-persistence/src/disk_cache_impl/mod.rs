@@ -1,2 +1,5 @@
 #[cfg(feature = "rev-file")]
-pub mod file_persistence;
+mod file_persistence;
+
+#[cfg(feature = "rev-file")]
+pub use file_persistence::*;
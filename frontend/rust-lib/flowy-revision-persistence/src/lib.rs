@@ -1,5 +1,8 @@
 mod disk_cache_impl;
 
+#[cfg(feature = "rev-file")]
+pub use disk_cache_impl::*;
+
 use flowy_error::{FlowyError, FlowyResult};
 use revision_model::{Revision, RevisionRange};
 use std::fmt::Debug;
@@ -1,5 +0,0 @@
-mod persistence;
-mod rev_history;
-
-pub use persistence::*;
-pub use rev_history::*;
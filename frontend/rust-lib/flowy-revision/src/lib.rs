@@ -1,5 +1,6 @@
 mod cache;
 mod conflict_resolve;
+mod rev_history;
 mod rev_manager;
 mod rev_persistence;
 mod rev_queue;
@@ -8,6 +9,7 @@ mod ws_manager;
 
 pub use cache::*;
 pub use conflict_resolve::*;
+pub use rev_history::*;
 pub use rev_manager::*;
 pub use rev_persistence::*;
 pub use rev_snapshot::*;
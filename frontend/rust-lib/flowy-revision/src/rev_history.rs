@@ -0,0 +1,56 @@
+use crate::RevisionSnapshotData;
+use dissimilar::Chunk;
+
+/// A point in the history of an object that the object can be read at or restored to. Each
+/// snapshot of the object is a checkpoint, so the history is read from the snapshots that the
+/// [crate::RevisionSnapshotController] already writes instead of a table of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevisionCheckpoint {
+  pub rev_id: i64,
+  pub timestamp: i64,
+}
+
+impl std::convert::From<RevisionSnapshotData> for RevisionCheckpoint {
+  fn from(snapshot: RevisionSnapshotData) -> Self {
+    RevisionCheckpoint {
+      rev_id: snapshot.rev_id,
+      timestamp: snapshot.timestamp,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckpointDiffChunk {
+  Equal(String),
+  Insert(String),
+  Delete(String),
+}
+
+/// Returns the changes that turn the content of one checkpoint into the content of another.
+pub fn diff_checkpoint_content(old: &str, new: &str) -> Vec<CheckpointDiffChunk> {
+  dissimilar::diff(old, new)
+    .into_iter()
+    .map(|chunk| match chunk {
+      Chunk::Equal(s) => CheckpointDiffChunk::Equal(s.to_owned()),
+      Chunk::Insert(s) => CheckpointDiffChunk::Insert(s.to_owned()),
+      Chunk::Delete(s) => CheckpointDiffChunk::Delete(s.to_owned()),
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{diff_checkpoint_content, CheckpointDiffChunk};
+
+  #[test]
+  fn diff_checkpoint_content_test() {
+    assert_eq!(
+      diff_checkpoint_content("hello world", "hello appflowy"),
+      vec![
+        CheckpointDiffChunk::Equal("hello ".to_owned()),
+        CheckpointDiffChunk::Delete("world".to_owned()),
+        CheckpointDiffChunk::Insert("appflowy".to_owned()),
+      ]
+    );
+  }
+}
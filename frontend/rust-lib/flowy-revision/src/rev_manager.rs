@@ -1,6 +1,6 @@
 use crate::rev_queue::{RevCommandSender, RevisionCommand, RevisionQueue};
 use crate::{
-  RevisionPersistence, RevisionSnapshotController, RevisionSnapshotData,
+  RevisionCheckpoint, RevisionPersistence, RevisionSnapshotController, RevisionSnapshotData,
   RevisionSnapshotPersistence, WSDataProviderDataSource,
 };
 use bytes::Bytes;
@@ -216,6 +216,74 @@ impl<Connection: 'static> RevisionManager<Connection> {
     }
   }
 
+  /// Returns the checkpoints of the object in ascending order of the rev_id.
+  pub async fn read_checkpoints(&self) -> FlowyResult<Vec<RevisionCheckpoint>> {
+    let snapshots = self.rev_snapshot.read_snapshots()?;
+    Ok(
+      snapshots
+        .into_iter()
+        .map(RevisionCheckpoint::from)
+        .collect(),
+    )
+  }
+
+  /// Returns the object as it was at the checkpoint. Restoring the object is up to the caller,
+  /// which saves the changes from the current object to the returned one as a local revision.
+  pub async fn read_checkpoint_object<De>(&self, rev_id: i64) -> FlowyResult<De::Output>
+  where
+    De: RevisionObjectDeserializer,
+  {
+    let snapshot = self.rev_snapshot.read_snapshot(rev_id)?.ok_or_else(|| {
+      FlowyError::record_not_found().context(format!("Can't find the checkpoint: {}", rev_id))
+    })?;
+    self.deserialize_snapshot::<De>(snapshot)
+  }
+
+  /// Returns the object as it was at the timestamp, which is read from the last checkpoint that
+  /// was written at or before it. Returns None if the object has no checkpoint by then.
+  pub async fn read_checkpoint_object_at<De>(
+    &self,
+    timestamp: i64,
+  ) -> FlowyResult<Option<De::Output>>
+  where
+    De: RevisionObjectDeserializer,
+  {
+    let snapshot = self
+      .rev_snapshot
+      .read_snapshots()?
+      .into_iter()
+      .filter(|snapshot| snapshot.timestamp <= timestamp)
+      .max_by_key(|snapshot| (snapshot.timestamp, snapshot.rev_id));
+    match snapshot {
+      None => Ok(None),
+      Some(snapshot) => Ok(Some(self.deserialize_snapshot::<De>(snapshot)?)),
+    }
+  }
+
+  /// Writes a checkpoint of the object at the current revision. Unlike [Self::generate_snapshot],
+  /// which reads the revisions from disk, the data is passed in by the caller, for example, the
+  /// operations of its pad, so the checkpoint includes the revisions that are not written to disk
+  /// yet.
+  pub async fn write_checkpoint(&self, data: Bytes) -> FlowyResult<()> {
+    self
+      .rev_snapshot
+      .write_snapshot(self.rev_id_counter.value(), data.to_vec())
+  }
+
+  fn deserialize_snapshot<De>(&self, snapshot: RevisionSnapshotData) -> FlowyResult<De::Output>
+  where
+    De: RevisionObjectDeserializer,
+  {
+    let revision = Revision::new(
+      &self.object_id,
+      snapshot.base_rev_id,
+      snapshot.rev_id,
+      snapshot.data,
+      "".to_owned(),
+    );
+    De::deserialize_revisions(&self.object_id, vec![revision])
+  }
+
   pub async fn load_revisions(&self) -> FlowyResult<Vec<Revision>> {
     let revisions = RevisionLoader {
       object_id: self.object_id.clone(),
@@ -19,13 +19,16 @@ pub trait RevisionSnapshotPersistence: Send + Sync {
   fn read_snapshot(&self, rev_id: i64) -> FlowyResult<Option<RevisionSnapshotData>>;
 
   fn read_last_snapshot(&self) -> FlowyResult<Option<RevisionSnapshotData>>;
+
+  /// Returns all the snapshots of the object in ascending order of the rev_id.
+  fn read_snapshots(&self) -> FlowyResult<Vec<RevisionSnapshotData>>;
 }
 
 pub trait RevisionSnapshotDataGenerator: Send + Sync {
   fn generate_snapshot_data(&self) -> Option<RevisionSnapshotData>;
 }
 
-const AUTO_GEN_SNAPSHOT_PER_10_REVISION: i64 = 10;
+pub const AUTO_GEN_SNAPSHOT_PER_10_REVISION: i64 = 10;
 
 pub struct RevisionSnapshotController<Connection> {
   user_id: String,
@@ -279,6 +279,10 @@ impl RevisionSnapshotPersistence for RevisionSnapshotMock {
   fn read_last_snapshot(&self) -> FlowyResult<Option<RevisionSnapshotData>> {
     Ok(None)
   }
+
+  fn read_snapshots(&self) -> FlowyResult<Vec<RevisionSnapshotData>> {
+    Ok(vec![])
+  }
 }
 
 pub struct RevisionMergeableMock {}
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE database_row_activity;
+DROP TABLE database_row_comment;
\ No newline at end of file
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE database_row_activity (
+ id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+ database_id TEXT NOT NULL DEFAULT '',
+ row_id TEXT NOT NULL DEFAULT '',
+ field_id TEXT NOT NULL DEFAULT '',
+ old_value TEXT NOT NULL DEFAULT '',
+ new_value TEXT NOT NULL DEFAULT '',
+ user_id TEXT NOT NULL DEFAULT '',
+ timestamp BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE TABLE database_row_comment (
+ id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ database_id TEXT NOT NULL DEFAULT '',
+ row_id TEXT NOT NULL DEFAULT '',
+ content TEXT NOT NULL DEFAULT '',
+ user_id TEXT NOT NULL DEFAULT '',
+ created_at BIGINT NOT NULL DEFAULT 0,
+ modified_at BIGINT NOT NULL DEFAULT 0
+);
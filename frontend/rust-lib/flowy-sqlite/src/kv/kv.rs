@@ -1,9 +1,9 @@
 use crate::kv::schema::{kv_table, kv_table::dsl, KV_SQL};
-use crate::sqlite::{DBConnection, Database, PoolConfig};
+use crate::sqlite::{DBConnection, Database, DatabaseKey, PoolConfig};
 use ::diesel::{query_dsl::*, ExpressionMethods};
 use diesel::{Connection, SqliteConnection};
 use lazy_static::lazy_static;
-use std::{path::Path, sync::RwLock};
+use std::{collections::HashSet, path::Path, sync::RwLock};
 
 macro_rules! impl_get_func {
   (
@@ -36,21 +36,35 @@ macro_rules! impl_set_func {
   };
 }
 const DB_NAME: &str = "kv.db";
+
+/// The encrypted values are saved in the `str_value` with this prefix, followed by the hex of the
+/// encrypted value. The other columns are left empty, so the type of the value isn't revealed.
+const ENCRYPTED_VALUE_PREFIX: &str = "encrypted:";
+
 lazy_static! {
   static ref KV_HOLDER: RwLock<KV> = RwLock::new(KV::new());
 }
 
 pub struct KV {
   database: Option<Database>,
+  /// The key that encrypts the values. The values are saved as they are if it's None.
+  key: Option<DatabaseKey>,
+  /// The keys of the values that are never encrypted, because they're read before the key is set.
+  unencrypted_keys: HashSet<String>,
 }
 
 impl KV {
   fn new() -> Self {
-    KV { database: None }
+    KV {
+      database: None,
+      key: None,
+      unencrypted_keys: HashSet::new(),
+    }
   }
 
   fn set(value: KeyValue) -> Result<(), String> {
     // tracing::trace!("[KV]: set value: {:?}", value);
+    let value = encrypt_value_if_need(value)?;
     let _ = diesel::replace_into(kv_table::table)
       .values(&value)
       .execute(&*(get_connection()?))
@@ -66,7 +80,7 @@ impl KV {
       .first::<KeyValue>(&*conn)
       .map_err(|e| format!("KV get error: {:?}", e))?;
 
-    Ok(value)
+    decrypt_value_if_need(value)
   }
 
   #[allow(dead_code)]
@@ -100,6 +114,67 @@ impl KV {
     Ok(())
   }
 
+  /// Sets the key that encrypts the values that are saved afterwards. The encrypted values can't be
+  /// read until the key is set again.
+  pub fn set_key(key: Option<DatabaseKey>) -> Result<(), String> {
+    let mut store = KV_HOLDER
+      .write()
+      .map_err(|e| format!("KVStore write failed: {:?}", e))?;
+    store.key = key;
+    Ok(())
+  }
+
+  /// Keeps the value of the key unencrypted, so it can be read before the key is set.
+  pub fn keep_unencrypted(key: &str) -> Result<(), String> {
+    let mut store = KV_HOLDER
+      .write()
+      .map_err(|e| format!("KVStore write failed: {:?}", e))?;
+    store.unencrypted_keys.insert(key.to_owned());
+    Ok(())
+  }
+
+  /// Re-encrypts the values that are encrypted with the `from_key` with the `to_key`. A None key
+  /// stands for the unencrypted values. It's called when the database is encrypted, decrypted or
+  /// re-keyed, so the values are encrypted with the same key as the database. The values that
+  /// were migrated already are skipped, so it can be called again if it was interrupted.
+  pub fn migrate_values(
+    from_key: Option<&DatabaseKey>,
+    to_key: Option<&DatabaseKey>,
+  ) -> Result<(), String> {
+    let unencrypted_keys = KV_HOLDER
+      .read()
+      .map_err(|e| format!("KVStore read failed: {:?}", e))?
+      .unencrypted_keys
+      .clone();
+    let conn = get_connection()?;
+    let values = dsl::kv_table
+      .load::<KeyValue>(&*conn)
+      .map_err(|e| format!("KV load error: {:?}", e))?;
+    for value in values {
+      if unencrypted_keys.contains(&value.key) {
+        continue;
+      }
+      let value = match (is_encrypted_value(&value), from_key) {
+        (false, None) => value,
+        // The value that can't be decrypted is encrypted with another key already.
+        (true, Some(from_key)) => match decrypt_value(from_key, value) {
+          Ok(value) => value,
+          Err(_) => continue,
+        },
+        _ => continue,
+      };
+      let value = match to_key {
+        None => value,
+        Some(to_key) => encrypt_value(to_key, value)?,
+      };
+      let _ = diesel::replace_into(kv_table::table)
+        .values(&value)
+        .execute(&*conn)
+        .map_err(|e| format!("KV set error: {:?}", e))?;
+    }
+    Ok(())
+  }
+
   pub fn get_bool(key: &str) -> bool {
     match KV::get(key) {
       Ok(item) => item.bool_value.unwrap_or(false),
@@ -141,6 +216,101 @@ fn get_connection() -> Result<DBConnection, String> {
   }
 }
 
+fn encrypt_value_if_need(value: KeyValue) -> Result<KeyValue, String> {
+  let store = KV_HOLDER
+    .read()
+    .map_err(|e| format!("KVStore read failed: {:?}", e))?;
+  match &store.key {
+    Some(key) if !store.unencrypted_keys.contains(&value.key) => encrypt_value(key, value),
+    _ => Ok(value),
+  }
+}
+
+fn decrypt_value_if_need(value: KeyValue) -> Result<KeyValue, String> {
+  if !is_encrypted_value(&value) {
+    return Ok(value);
+  }
+  let store = KV_HOLDER
+    .read()
+    .map_err(|e| format!("KVStore read failed: {:?}", e))?;
+  match &store.key {
+    None => Err(format!("KV value is encrypted: {}", value.key)),
+    Some(key) => decrypt_value(key, value),
+  }
+}
+
+fn is_encrypted_value(value: &KeyValue) -> bool {
+  value
+    .str_value
+    .as_ref()
+    .map(|s| s.starts_with(ENCRYPTED_VALUE_PREFIX))
+    .unwrap_or(false)
+}
+
+/// Encrypts the value, which is tagged with its type, e.g. "i:1" for the `int_value` 1.
+fn encrypt_value(key: &DatabaseKey, value: KeyValue) -> Result<KeyValue, String> {
+  let plaintext = if let Some(s) = &value.str_value {
+    format!("s:{}", s)
+  } else if let Some(i) = value.int_value {
+    format!("i:{}", i)
+  } else if let Some(f) = value.float_value {
+    format!("f:{}", f)
+  } else if let Some(b) = value.bool_value {
+    format!("b:{}", b)
+  } else {
+    return Ok(value);
+  };
+  let encrypted_bytes = key
+    .encrypt(plaintext.as_bytes())
+    .map_err(|e| format!("KV encrypt error: {:?}", e))?;
+  let mut encrypted_value = KeyValue::new(&value.key);
+  encrypted_value.str_value = Some(format!(
+    "{}{}",
+    ENCRYPTED_VALUE_PREFIX,
+    to_hex(&encrypted_bytes)
+  ));
+  Ok(encrypted_value)
+}
+
+fn decrypt_value(key: &DatabaseKey, value: KeyValue) -> Result<KeyValue, String> {
+  let hex = value
+    .str_value
+    .as_ref()
+    .and_then(|s| s.strip_prefix(ENCRYPTED_VALUE_PREFIX))
+    .ok_or_else(|| format!("KV value is not encrypted: {}", value.key))?;
+  let bytes = from_hex(hex).ok_or_else(|| format!("KV value is invalid: {}", value.key))?;
+  let plaintext = key
+    .decrypt(&bytes)
+    .ok()
+    .and_then(|bytes| String::from_utf8(bytes).ok())
+    .ok_or_else(|| format!("KV decrypt error: {}", value.key))?;
+
+  let mut decrypted_value = KeyValue::new(&value.key);
+  let invalid_value = || format!("KV value is invalid: {}", value.key);
+  match plaintext.split_once(':') {
+    Some(("s", s)) => decrypted_value.str_value = Some(s.to_owned()),
+    Some(("i", i)) => decrypted_value.int_value = Some(i.parse().map_err(|_| invalid_value())?),
+    Some(("f", f)) => decrypted_value.float_value = Some(f.parse().map_err(|_| invalid_value())?),
+    Some(("b", b)) => decrypted_value.bool_value = Some(b.parse().map_err(|_| invalid_value())?),
+    _ => return Err(invalid_value()),
+  }
+  Ok(decrypted_value)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+  bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+  if hex.len() % 2 != 0 {
+    return None;
+  }
+  (0..hex.len())
+    .step_by(2)
+    .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+    .collect()
+}
+
 #[derive(Clone, Debug, Default, Queryable, Identifiable, Insertable, AsChangeset)]
 #[table_name = "kv_table"]
 #[primary_key(key)]
@@ -163,7 +333,9 @@ impl KeyValue {
 
 #[cfg(test)]
 mod tests {
+  use crate::kv::kv::{decrypt_value, encrypt_value, KeyValue};
   use crate::kv::KV;
+  use crate::DatabaseKey;
 
   #[test]
   fn kv_store_test() {
@@ -184,4 +356,27 @@ mod tests {
 
     assert!(!KV::get_bool("2"));
   }
+
+  #[test]
+  fn kv_value_encryption_test() {
+    let key = DatabaseKey::new([1; 32]);
+    let mut value = KeyValue::new("1");
+    value.float_value = Some(0.1);
+
+    let encrypted_value = encrypt_value(&key, value).unwrap();
+    assert_eq!(encrypted_value.float_value, None);
+    assert!(encrypted_value
+      .str_value
+      .as_ref()
+      .unwrap()
+      .starts_with("encrypted:"));
+
+    let decrypted_value = decrypt_value(&key, encrypted_value.clone()).unwrap();
+    assert_eq!(decrypted_value.key, "1");
+    assert_eq!(decrypted_value.float_value, Some(0.1));
+    assert_eq!(decrypted_value.str_value, None);
+
+    let other_key = DatabaseKey::new([2; 32]);
+    assert!(decrypt_value(&other_key, encrypted_value).is_err());
+  }
 }
@@ -6,7 +6,12 @@ pub mod kv;
 mod sqlite;
 
 use crate::sqlite::PoolConfig;
-pub use crate::sqlite::{ConnectionPool, DBConnection, Database};
+pub use crate::sqlite::{
+  db_file_uri, decrypt_database, encrypt_database, is_database_encrypted, is_encryption_supported,
+  rekey_database, verify_database_key, ConnectionPool, DBConnection, Database, DatabaseKey,
+  DATABASE_KEY_LEN,
+};
+pub use crate::sqlite::{Error as DatabaseError, ErrorKind as DatabaseErrorKind};
 
 pub mod schema;
 
@@ -32,10 +37,16 @@ embed_migrations!("../flowy-sqlite/migrations/");
 pub const DB_NAME: &str = "flowy-database.db";
 
 pub fn init(storage_path: &str) -> Result<Database, io::Error> {
+  init_with_key(storage_path, None)
+}
+
+/// Opens the database that is encrypted with the key. The database is opened as an unencrypted
+/// one if the key is None.
+pub fn init_with_key(storage_path: &str, key: Option<DatabaseKey>) -> Result<Database, io::Error> {
   if !Path::new(storage_path).exists() {
     std::fs::create_dir_all(storage_path)?;
   }
-  let pool_config = PoolConfig::default();
+  let pool_config = PoolConfig::default().key(key);
   let database = Database::new(storage_path, DB_NAME, pool_config).map_err(as_io_error)?;
   let conn = database.get_connection().map_err(as_io_error)?;
   embedded_migrations::run(&*conn).map_err(as_io_error)?;
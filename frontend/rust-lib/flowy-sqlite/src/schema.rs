@@ -25,6 +25,31 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    database_row_activity (id) {
+        id -> Integer,
+        database_id -> Text,
+        row_id -> Text,
+        field_id -> Text,
+        old_value -> Text,
+        new_value -> Text,
+        user_id -> Text,
+        timestamp -> BigInt,
+    }
+}
+
+diesel::table! {
+    database_row_comment (id) {
+        id -> Text,
+        database_id -> Text,
+        row_id -> Text,
+        content -> Text,
+        user_id -> Text,
+        created_at -> BigInt,
+        modified_at -> BigInt,
+    }
+}
+
 diesel::table! {
     document_rev_snapshot (snapshot_id) {
         snapshot_id -> Text,
@@ -191,6 +216,8 @@ diesel::table! {
 diesel::allow_tables_to_appear_in_same_query!(
   app_table,
   database_refs,
+  database_row_activity,
+  database_row_comment,
   document_rev_snapshot,
   document_rev_table,
   folder_rev_snapshot,
@@ -0,0 +1,198 @@
+//! At-rest encryption of the databases. The encryption is provided by SQLCipher, which encrypts
+//! every page of the database file, so it requires the `sqlcipher` feature.
+use crate::sqlite::conn_ext::ConnectionExtension;
+use crate::sqlite::errors::*;
+use aes_gcm::{
+  aead::{Aead, KeyInit},
+  Aes256Gcm, Key, Nonce,
+};
+use diesel::{Connection, SqliteConnection};
+use rand::RngCore;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Every unencrypted database file starts with this header. The header of an encrypted database
+/// file is random.
+const SQLITE_HEADER: &[u8] = b"SQLite format 3\0";
+
+pub const DATABASE_KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+/// The 256-bit key that encrypts the database. It's used as is, SQLCipher doesn't derive another
+/// key from it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DatabaseKey([u8; DATABASE_KEY_LEN]);
+
+impl DatabaseKey {
+  pub fn new(bytes: [u8; DATABASE_KEY_LEN]) -> Self {
+    Self(bytes)
+  }
+
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.0
+  }
+
+  /// Returns the key in the format of SQLCipher's raw key: "x'<hex of the key>'"
+  fn to_sql(&self) -> String {
+    let hex = self
+      .0
+      .iter()
+      .map(|byte| format!("{:02X}", byte))
+      .collect::<String>();
+    format!("\"x'{}'\"", hex)
+  }
+
+  /// Encrypts the bytes with AES-256-GCM. It's used for the data that is stored outside the
+  /// encrypted database, e.g. the values of the KV store. The random nonce is prepended to the
+  /// encrypted bytes.
+  pub fn encrypt(&self, bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let encrypted_bytes = self
+      .cipher()
+      .encrypt(Nonce::from_slice(&nonce), bytes)
+      .map_err(|e| format!("Encrypt failed: {:?}", e))?;
+    let mut output = nonce.to_vec();
+    output.extend(encrypted_bytes);
+    Ok(output)
+  }
+
+  /// Decrypts the bytes that were encrypted by [DatabaseKey::encrypt]. Returns
+  /// [ErrorKind::InvalidDatabaseKey] if they were encrypted with another key.
+  pub fn decrypt(&self, bytes: &[u8]) -> Result<Vec<u8>> {
+    if bytes.len() < NONCE_LEN {
+      return Err(ErrorKind::InvalidDatabaseKey.into());
+    }
+    let (nonce, encrypted_bytes) = bytes.split_at(NONCE_LEN);
+    self
+      .cipher()
+      .decrypt(Nonce::from_slice(nonce), encrypted_bytes)
+      .map_err(|_| Error::from_kind(ErrorKind::InvalidDatabaseKey))
+  }
+
+  fn cipher(&self) -> Aes256Gcm {
+    Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.0))
+  }
+}
+
+impl std::fmt::Debug for DatabaseKey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("DatabaseKey(..)")
+  }
+}
+
+pub fn is_encryption_supported() -> bool {
+  cfg!(feature = "sqlcipher")
+}
+
+/// Returns true if the database file exists and is encrypted.
+pub fn is_database_encrypted<P: AsRef<Path>>(path: P) -> Result<bool> {
+  let path = path.as_ref();
+  if !path.exists() {
+    return Ok(false);
+  }
+  let mut header = vec![];
+  std::fs::File::open(path)?
+    .take(SQLITE_HEADER.len() as u64)
+    .read_to_end(&mut header)?;
+  // A database file that was created but not written yet is empty.
+  Ok(!header.is_empty() && header != SQLITE_HEADER)
+}
+
+/// Sets the key of the connection. It must be called before any other statement is executed on
+/// the connection.
+pub(crate) fn apply_key(conn: &SqliteConnection, key: &DatabaseKey) -> Result<()> {
+  ensure_encryption_supported()?;
+  conn.exec(format!("PRAGMA key = {}", key.to_sql()))?;
+  // SQLCipher doesn't verify the key until the first page gets read.
+  conn
+    .exec("SELECT count(*) FROM sqlite_master")
+    .map_err(|_| Error::from_kind(ErrorKind::InvalidDatabaseKey))?;
+  Ok(())
+}
+
+/// Returns an error if the database can't be opened with the key.
+pub fn verify_database_key<P: AsRef<Path>>(path: P, key: &DatabaseKey) -> Result<()> {
+  let conn = SqliteConnection::establish(&path.as_ref().to_string_lossy())?;
+  apply_key(&conn, key)
+}
+
+/// Migrates the unencrypted database to an encrypted one.
+pub fn encrypt_database<P: AsRef<Path>>(path: P, key: &DatabaseKey) -> Result<()> {
+  export_database(path.as_ref(), None, Some(key))
+}
+
+/// Migrates the encrypted database back to an unencrypted one.
+pub fn decrypt_database<P: AsRef<Path>>(path: P, key: &DatabaseKey) -> Result<()> {
+  export_database(path.as_ref(), Some(key), None)
+}
+
+/// Re-encrypts the encrypted database with the new key.
+pub fn rekey_database<P: AsRef<Path>>(
+  path: P,
+  old_key: &DatabaseKey,
+  new_key: &DatabaseKey,
+) -> Result<()> {
+  export_database(path.as_ref(), Some(old_key), Some(new_key))
+}
+
+/// Copies the content of the database into a new database file that is encrypted with the
+/// `to_key`, and then replaces the database file with it. The database file is left untouched
+/// if the copying fails. It must not be called while the database is opened.
+fn export_database(
+  path: &Path,
+  from_key: Option<&DatabaseKey>,
+  to_key: Option<&DatabaseKey>,
+) -> Result<()> {
+  ensure_encryption_supported()?;
+  let exported_path = with_file_name_suffix(path, "-export");
+  if exported_path.exists() {
+    std::fs::remove_file(&exported_path)?;
+  }
+
+  {
+    let conn = SqliteConnection::establish(&path.to_string_lossy())?;
+    if let Some(key) = from_key {
+      apply_key(&conn, key)?;
+    }
+    // Move the content of the write-ahead log into the database file before copying it.
+    conn.exec("PRAGMA wal_checkpoint(TRUNCATE)")?;
+    let to_key = match to_key {
+      None => "''".to_owned(),
+      Some(key) => key.to_sql(),
+    };
+    conn.exec(format!(
+      "ATTACH DATABASE '{}' AS exported KEY {}",
+      exported_path.to_string_lossy().replace('\'', "''"),
+      to_key
+    ))?;
+    conn.exec("SELECT sqlcipher_export('exported')")?;
+    let user_version = conn.query::<diesel::sql_types::Integer, i32>("PRAGMA user_version")?;
+    conn.exec(format!("PRAGMA exported.user_version = {}", user_version))?;
+    conn.exec("DETACH DATABASE exported")?;
+  }
+
+  for suffix in ["-wal", "-shm"] {
+    let path = with_file_name_suffix(path, suffix);
+    if path.exists() {
+      std::fs::remove_file(path)?;
+    }
+  }
+  std::fs::rename(&exported_path, path)?;
+  tracing::info!("Export database: {:?}", path);
+  Ok(())
+}
+
+fn ensure_encryption_supported() -> Result<()> {
+  if is_encryption_supported() {
+    Ok(())
+  } else {
+    Err(ErrorKind::EncryptionNotSupported.into())
+  }
+}
+
+fn with_file_name_suffix(path: &Path, suffix: &str) -> PathBuf {
+  let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+  file_name.push(suffix);
+  path.with_file_name(file_name)
+}
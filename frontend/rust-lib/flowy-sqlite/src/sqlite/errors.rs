@@ -8,6 +8,12 @@ error_chain! {
         UnknownMigrationExists(v: String) {
              display("unknown migration version: '{}'", v),
         }
+        EncryptionNotSupported {
+             display("encryption requires the sqlcipher feature"),
+        }
+        InvalidDatabaseKey {
+             display("the key of the database is invalid"),
+        }
     }
     foreign_links {
         R2D2(::r2d2::Error);
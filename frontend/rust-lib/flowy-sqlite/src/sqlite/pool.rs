@@ -1,4 +1,8 @@
-use crate::sqlite::{errors::*, pragma::*};
+use crate::sqlite::{
+  encryption::{apply_key, DatabaseKey},
+  errors::*,
+  pragma::*,
+};
 use diesel::{connection::Connection, SqliteConnection};
 use r2d2::{CustomizeConnection, ManageConnection, Pool};
 use scheduled_thread_pool::ScheduledThreadPool;
@@ -29,8 +33,11 @@ impl ConnectionPool {
   {
     let manager = ConnectionManager::new(uri);
     let thread_pool = DB_POOL.clone();
+    let customizer_config = DatabaseCustomizerConfig {
+      key: config.key.clone(),
+      ..Default::default()
+    };
     let config = Arc::new(config);
-    let customizer_config = DatabaseCustomizerConfig::default();
 
     let pool = r2d2::Pool::builder()
       .thread_pool(thread_pool)
@@ -53,6 +60,7 @@ pub struct PoolConfig {
   max_size: u32,
   connection_timeout: Duration,
   idle_timeout: Duration,
+  key: Option<DatabaseKey>,
 }
 
 impl Default for PoolConfig {
@@ -62,6 +70,7 @@ impl Default for PoolConfig {
       max_size: 10,
       connection_timeout: Duration::from_secs(10),
       idle_timeout: Duration::from_secs(5 * 60),
+      key: None,
     }
   }
 }
@@ -78,6 +87,12 @@ impl PoolConfig {
     self.max_size = max_size;
     self
   }
+
+  /// Opens the connections of the encrypted database with the key.
+  pub fn key(mut self, key: Option<DatabaseKey>) -> Self {
+    self.key = key;
+    self
+  }
 }
 
 pub struct ConnectionManager {
@@ -114,6 +129,7 @@ pub struct DatabaseCustomizerConfig {
   pub(crate) busy_timeout: i32,
   #[allow(dead_code)]
   pub(crate) secure_delete: bool,
+  pub(crate) key: Option<DatabaseKey>,
 }
 
 impl Default for DatabaseCustomizerConfig {
@@ -123,6 +139,7 @@ impl Default for DatabaseCustomizerConfig {
       synchronous: SQLiteSynchronous::NORMAL,
       busy_timeout: 5000,
       secure_delete: true,
+      key: None,
     }
   }
 }
@@ -143,6 +160,10 @@ impl DatabaseCustomizer {
 
 impl CustomizeConnection<SqliteConnection, crate::sqlite::Error> for DatabaseCustomizer {
   fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<()> {
+    // The key must be set before any other statement is executed.
+    if let Some(key) = &self.config.key {
+      apply_key(conn, key)?;
+    }
     conn.pragma_set_busy_timeout(self.config.busy_timeout)?;
     if self.config.journal_mode != SQLiteJournalMode::WAL {
       conn.pragma_set_journal_mode(self.config.journal_mode, None)?;
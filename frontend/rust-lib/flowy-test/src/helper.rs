@@ -14,7 +14,7 @@ use flowy_user::{
   event_map::UserEvent::{InitUser, SignIn, SignOut, SignUp},
 };
 use lib_dispatch::prelude::{AFPluginDispatcher, AFPluginRequest, ToBytes};
-use std::{fs, path::PathBuf, sync::Arc};
+use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
 
 pub struct ViewTest {
   pub sdk: FlowySDKTest,
@@ -26,10 +26,19 @@ pub struct ViewTest {
 impl ViewTest {
   #[allow(dead_code)]
   pub async fn new(sdk: &FlowySDKTest, layout: ViewLayoutTypePB, data: Vec<u8>) -> Self {
+    Self::new_with_ext(sdk, layout, data, HashMap::new()).await
+  }
+
+  pub async fn new_with_ext(
+    sdk: &FlowySDKTest,
+    layout: ViewLayoutTypePB,
+    data: Vec<u8>,
+    ext: HashMap<String, String>,
+  ) -> Self {
     let workspace = create_workspace(sdk, "Workspace", "").await;
     open_workspace(sdk, &workspace.id).await;
     let app = create_app(sdk, "App", "AppFlowy GitHub Project", &workspace.id).await;
-    let view = create_view(sdk, &app.id, layout, data).await;
+    let view = create_view(sdk, &app.id, layout, data, ext).await;
     Self {
       sdk: sdk.clone(),
       workspace,
@@ -50,9 +59,28 @@ impl ViewTest {
     Self::new(sdk, ViewLayoutTypePB::Calendar, data).await
   }
 
+  pub async fn new_timeline_view(sdk: &FlowySDKTest, data: Vec<u8>) -> Self {
+    Self::new(sdk, ViewLayoutTypePB::Timeline, data).await
+  }
+
+  pub async fn new_gallery_view(sdk: &FlowySDKTest, data: Vec<u8>) -> Self {
+    Self::new(sdk, ViewLayoutTypePB::Gallery, data).await
+  }
+
   pub async fn new_document_view(sdk: &FlowySDKTest) -> Self {
     Self::new(sdk, ViewLayoutTypePB::Document, vec![]).await
   }
+
+  pub async fn new_document_view_from_markdown(sdk: &FlowySDKTest, markdown: &str) -> Self {
+    let ext = HashMap::from([("import_type".to_string(), "markdown".to_string())]);
+    Self::new_with_ext(
+      sdk,
+      ViewLayoutTypePB::Document,
+      markdown.as_bytes().to_vec(),
+      ext,
+    )
+    .await
+  }
 }
 
 async fn create_workspace(sdk: &FlowySDKTest, name: &str, desc: &str) -> WorkspacePB {
@@ -101,6 +129,7 @@ async fn create_view(
   app_id: &str,
   layout: ViewLayoutTypePB,
   data: Vec<u8>,
+  ext: HashMap<String, String>,
 ) -> ViewPB {
   let payload = CreateViewPayloadPB {
     belong_to_id: app_id.to_string(),
@@ -109,7 +138,7 @@ async fn create_view(
     thumbnail: Some("http://1.png".to_string()),
     layout,
     initial_data: data,
-    ext: Default::default(),
+    ext,
   };
 
   FolderEventBuilder::new(sdk.clone())
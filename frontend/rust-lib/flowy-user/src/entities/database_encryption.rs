@@ -0,0 +1,84 @@
+use crate::errors::ErrorCode;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(ProtoBuf, Default)]
+pub struct DatabasePassphrasePB {
+  #[pb(index = 1)]
+  pub passphrase: String,
+}
+
+pub struct DatabasePassphraseParams {
+  pub passphrase: String,
+}
+
+impl TryInto<DatabasePassphraseParams> for DatabasePassphrasePB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<DatabasePassphraseParams, Self::Error> {
+    if self.passphrase.is_empty() {
+      return Err(ErrorCode::PasswordIsEmpty);
+    }
+    Ok(DatabasePassphraseParams {
+      passphrase: self.passphrase,
+    })
+  }
+}
+
+#[derive(ProtoBuf, Default)]
+pub struct ChangeDatabasePassphrasePB {
+  #[pb(index = 1)]
+  pub old_passphrase: String,
+
+  #[pb(index = 2)]
+  pub new_passphrase: String,
+}
+
+pub struct ChangeDatabasePassphraseParams {
+  pub old_passphrase: String,
+  pub new_passphrase: String,
+}
+
+impl TryInto<ChangeDatabasePassphraseParams> for ChangeDatabasePassphrasePB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ChangeDatabasePassphraseParams, Self::Error> {
+    if self.old_passphrase.is_empty() || self.new_passphrase.is_empty() {
+      return Err(ErrorCode::PasswordIsEmpty);
+    }
+    Ok(ChangeDatabasePassphraseParams {
+      old_passphrase: self.old_passphrase,
+      new_passphrase: self.new_passphrase,
+    })
+  }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DatabaseEncryptionPB {
+  /// False if the application is built without the support of encryption.
+  #[pb(index = 1)]
+  pub is_supported: bool,
+
+  #[pb(index = 2)]
+  pub state: DatabaseEncryptionStatePB,
+
+  /// True if the passphrase is required before the user's data can be loaded.
+  #[pb(index = 3)]
+  pub is_locked: bool,
+}
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseEncryptionStatePB {
+  Unencrypted = 0,
+  /// The database gets encrypted the next time it's unlocked.
+  PendingEncryption = 1,
+  Encrypted = 2,
+  /// The database gets decrypted the next time it's unlocked.
+  PendingDecryption = 3,
+}
+
+impl std::default::Default for DatabaseEncryptionStatePB {
+  fn default() -> Self {
+    DatabaseEncryptionStatePB::Unencrypted
+  }
+}
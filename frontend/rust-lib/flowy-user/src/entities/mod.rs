@@ -1,7 +1,9 @@
 pub use auth::*;
+pub use database_encryption::*;
 pub use user_profile::*;
 pub use user_setting::*;
 
 pub mod auth;
+mod database_encryption;
 mod user_profile;
 mod user_setting;
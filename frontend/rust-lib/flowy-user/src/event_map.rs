@@ -22,6 +22,23 @@ pub fn init(user_session: Arc<UserSession>) -> AFPlugin {
     .event(UserEvent::SetAppearanceSetting, set_appearance_setting)
     .event(UserEvent::GetAppearanceSetting, get_appearance_setting)
     .event(UserEvent::GetUserSetting, get_user_setting)
+    .event(
+      UserEvent::GetDatabaseEncryption,
+      get_database_encryption_handler,
+    )
+    .event(
+      UserEvent::EnableDatabaseEncryption,
+      enable_database_encryption_handler,
+    )
+    .event(UserEvent::UnlockDatabase, unlock_database_handler)
+    .event(
+      UserEvent::ChangeDatabasePassphrase,
+      change_database_passphrase_handler,
+    )
+    .event(
+      UserEvent::DisableDatabaseEncryption,
+      disable_database_encryption_handler,
+    )
 }
 
 pub trait UserStatusCallback: Send + Sync + 'static {
@@ -89,4 +106,26 @@ pub enum UserEvent {
   /// Get the settings of the user, such as the user storage folder
   #[event(output = "UserSettingPB")]
   GetUserSetting = 9,
+
+  /// Get the encryption state of the user's local database
+  #[event(output = "DatabaseEncryptionPB")]
+  GetDatabaseEncryption = 10,
+
+  /// Encrypt the user's local database with the passphrase. The database gets encrypted the next
+  /// time it's unlocked
+  #[event(input = "DatabasePassphrasePB")]
+  EnableDatabaseEncryption = 11,
+
+  /// Unlock the user's encrypted local database with the passphrase
+  #[event(input = "DatabasePassphrasePB")]
+  UnlockDatabase = 12,
+
+  /// Change the passphrase of the user's encrypted local database. The database is re-keyed the
+  /// next time it's unlocked
+  #[event(input = "ChangeDatabasePassphrasePB")]
+  ChangeDatabasePassphrase = 13,
+
+  /// Decrypt the user's local database. The database gets decrypted the next time it's unlocked
+  #[event(input = "DatabasePassphrasePB")]
+  DisableDatabaseEncryption = 14,
 }
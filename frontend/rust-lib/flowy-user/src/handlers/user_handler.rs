@@ -1,6 +1,7 @@
 use crate::entities::{
-  AppearanceSettingsPB, UpdateUserProfilePayloadPB, UserProfilePB, UserSettingPB,
-  APPEARANCE_DEFAULT_THEME,
+  AppearanceSettingsPB, ChangeDatabasePassphrasePB, ChangeDatabasePassphraseParams,
+  DatabaseEncryptionPB, DatabasePassphrasePB, DatabasePassphraseParams, UpdateUserProfilePayloadPB,
+  UserProfilePB, UserSettingPB, APPEARANCE_DEFAULT_THEME,
 };
 use crate::{errors::FlowyError, services::UserSession};
 use flowy_sqlite::kv::KV;
@@ -46,7 +47,7 @@ pub async fn update_user_profile_handler(
   Ok(())
 }
 
-const APPEARANCE_SETTING_CACHE_KEY: &str = "appearance_settings";
+pub(crate) const APPEARANCE_SETTING_CACHE_KEY: &str = "appearance_settings";
 
 #[tracing::instrument(level = "debug", skip(data), err)]
 pub async fn set_appearance_setting(
@@ -89,3 +90,51 @@ pub async fn get_user_setting(
   let user_setting = session.user_setting()?;
   data_result_ok(user_setting)
 }
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_database_encryption_handler(
+  session: AFPluginState<Arc<UserSession>>,
+) -> DataResult<DatabaseEncryptionPB, FlowyError> {
+  let encryption = session.get_database_encryption()?;
+  data_result_ok(encryption)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn enable_database_encryption_handler(
+  data: AFPluginData<DatabasePassphrasePB>,
+  session: AFPluginState<Arc<UserSession>>,
+) -> Result<(), FlowyError> {
+  let params: DatabasePassphraseParams = data.into_inner().try_into()?;
+  session.enable_database_encryption(&params.passphrase)?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn unlock_database_handler(
+  data: AFPluginData<DatabasePassphrasePB>,
+  session: AFPluginState<Arc<UserSession>>,
+) -> Result<(), FlowyError> {
+  let params: DatabasePassphraseParams = data.into_inner().try_into()?;
+  session.unlock_database(&params.passphrase).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn change_database_passphrase_handler(
+  data: AFPluginData<ChangeDatabasePassphrasePB>,
+  session: AFPluginState<Arc<UserSession>>,
+) -> Result<(), FlowyError> {
+  let params: ChangeDatabasePassphraseParams = data.into_inner().try_into()?;
+  session.change_database_passphrase(&params.old_passphrase, &params.new_passphrase)?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn disable_database_encryption_handler(
+  data: AFPluginData<DatabasePassphrasePB>,
+  session: AFPluginState<Arc<UserSession>>,
+) -> Result<(), FlowyError> {
+  let params: DatabasePassphraseParams = data.into_inner().try_into()?;
+  session.disable_database_encryption(&params.passphrase)?;
+  Ok(())
+}
@@ -1,6 +1,12 @@
+use crate::services::encryption::{
+  generate_database_key, DatabaseEncryptionState, DatabaseKeyFile,
+};
 use flowy_error::{ErrorCode, FlowyError};
-use flowy_sqlite::ConnectionPool;
-use flowy_sqlite::{schema::user_table, DBConnection, Database};
+use flowy_sqlite::{
+  db_file_uri, decrypt_database, encrypt_database, is_database_encrypted, is_encryption_supported,
+  rekey_database, verify_database_key, ConnectionPool, DatabaseKey, DB_NAME,
+};
+use flowy_sqlite::{kv::KV, schema::user_table, DBConnection, Database};
 use lazy_static::lazy_static;
 use parking_lot::RwLock;
 use std::path::PathBuf;
@@ -9,15 +15,25 @@ use user_model::{SignInResponse, SignUpResponse, UpdateUserProfileParams, UserPr
 
 pub struct UserDB {
   db_dir: String,
+  /// The keys of the unlocked encrypted databases.
+  keys: RwLock<HashMap<String, DatabaseKey>>,
 }
 
 impl UserDB {
   pub fn new(db_dir: &str) -> Self {
     Self {
       db_dir: db_dir.to_owned(),
+      keys: RwLock::new(HashMap::new()),
     }
   }
 
+  fn user_db_dir(&self, user_id: &str) -> String {
+    let mut dir = PathBuf::new();
+    dir.push(&self.db_dir);
+    dir.push(user_id);
+    dir.to_str().unwrap().to_owned()
+  }
+
   fn open_user_db_if_need(&self, user_id: &str) -> Result<Arc<ConnectionPool>, FlowyError> {
     if user_id.is_empty() {
       return Err(ErrorCode::UserIdIsEmpty.into());
@@ -34,13 +50,18 @@ impl UserDB {
       Some(database) => return Ok(database.get_pool()),
     }
 
-    let mut dir = PathBuf::new();
-    dir.push(&self.db_dir);
-    dir.push(user_id);
-    let dir = dir.to_str().unwrap().to_owned();
+    let dir = self.user_db_dir(user_id);
+    // The database has to be unlocked before opening if it's encrypted or is going to be.
+    let key = match DatabaseKeyFile::read(&dir)? {
+      None => None,
+      Some(_) => match self.keys.read().get(user_id) {
+        None => return Err(ErrorCode::DatabaseLocked.into()),
+        Some(key) => Some(key.clone()),
+      },
+    };
 
     tracing::trace!("open user db {} at path: {}", user_id, dir);
-    let db = flowy_sqlite::init(&dir).map_err(|e| {
+    let db = flowy_sqlite::init_with_key(&dir, key).map_err(|e| {
       tracing::error!("open user: {} db failed, {:?}", user_id, e);
       FlowyError::internal().context(e)
     })?;
@@ -55,8 +76,165 @@ impl UserDB {
       None => Err(FlowyError::internal().context("Acquire write lock to close user db failed")),
       Some(mut write_guard) => {
         write_guard.remove(user_id);
+        self.keys.write().remove(user_id);
+        KV::set_key(None).map_err(|e| FlowyError::internal().context(e))?;
+        Ok(())
+      },
+    }
+  }
+
+  pub(crate) fn get_encryption_state(
+    &self,
+    user_id: &str,
+  ) -> Result<Option<DatabaseEncryptionState>, FlowyError> {
+    let key_file = DatabaseKeyFile::read(&self.user_db_dir(user_id))?;
+    Ok(key_file.map(|key_file| key_file.state))
+  }
+
+  /// Returns true if the database needs to be unlocked before it can be opened.
+  pub(crate) fn is_locked(&self, user_id: &str) -> Result<bool, FlowyError> {
+    if DB_MAP.read().contains_key(user_id) {
+      return Ok(false);
+    }
+    let is_encrypted = self.get_encryption_state(user_id)?.is_some();
+    Ok(is_encrypted && !self.keys.read().contains_key(user_id))
+  }
+
+  /// Unlocks the database with the passphrase, so it can be opened. The pending encryption,
+  /// decryption or re-key of the database is applied before it gets opened. The values of the KV
+  /// store are migrated along with the database.
+  pub(crate) fn unlock_user_db(&self, user_id: &str, passphrase: &str) -> Result<(), FlowyError> {
+    let dir = self.user_db_dir(user_id);
+    let mut key_file = match DatabaseKeyFile::read(&dir)? {
+      None => return Ok(()),
+      Some(key_file) => key_file,
+    };
+    let mut key = key_file.decrypt_key(passphrase)?;
+    let new_key = key_file.decrypt_new_key(passphrase)?;
+    if DB_MAP.read().contains_key(user_id) {
+      return Ok(());
+    }
+
+    let db_path = db_file_uri(&dir, DB_NAME);
+    let is_encrypted = is_database_encrypted(&db_path)?;
+    match key_file.state {
+      DatabaseEncryptionState::PendingEncryption => {
+        // The database might be encrypted already if the application exited before the state
+        // was saved.
+        if !is_encrypted {
+          tracing::info!("Encrypt the user db: {}", user_id);
+          encrypt_database(&db_path, &key)?;
+        }
+        migrate_kv_values(None, Some(&key))?;
+        key_file.state = DatabaseEncryptionState::Encrypted;
+        key_file.write(&dir)?;
+      },
+      DatabaseEncryptionState::Encrypted => {
+        let database_key = find_database_key(&db_path, &key, new_key.as_ref())?;
+        if let Some(new_key) = new_key {
+          if database_key == key {
+            tracing::info!("Rekey the user db: {}", user_id);
+            rekey_database(&db_path, &key, &new_key)?;
+          }
+          migrate_kv_values(Some(&key), Some(&new_key))?;
+          key_file =
+            DatabaseKeyFile::new(&new_key, passphrase, DatabaseEncryptionState::Encrypted)?;
+          key_file.write(&dir)?;
+          key = new_key;
+        }
+      },
+      DatabaseEncryptionState::PendingDecryption => {
+        if is_encrypted {
+          tracing::info!("Decrypt the user db: {}", user_id);
+          let database_key = find_database_key(&db_path, &key, new_key.as_ref())?;
+          decrypt_database(&db_path, &database_key)?;
+        }
+        for from_key in std::iter::once(&key).chain(new_key.as_ref()) {
+          migrate_kv_values(Some(from_key), None)?;
+        }
+        DatabaseKeyFile::remove(&dir)?;
+        return Ok(());
+      },
+    }
+    KV::set_key(Some(key.clone())).map_err(|e| FlowyError::internal().context(e))?;
+    self.keys.write().insert(user_id.to_owned(), key);
+    Ok(())
+  }
+
+  /// Enables the encryption of the database. The database keeps being unencrypted until the
+  /// next time it's unlocked, because it can't be rewritten while it's opened.
+  pub(crate) fn enable_encryption(
+    &self,
+    user_id: &str,
+    passphrase: &str,
+  ) -> Result<(), FlowyError> {
+    if !is_encryption_supported() {
+      return Err(ErrorCode::DatabaseEncryptionNotSupported.into());
+    }
+    let dir = self.user_db_dir(user_id);
+    match DatabaseKeyFile::read(&dir)? {
+      Some(key_file) if key_file.state != DatabaseEncryptionState::PendingDecryption => {
+        key_file.decrypt_key(passphrase)?;
         Ok(())
       },
+      Some(mut key_file) => {
+        key_file.decrypt_key(passphrase)?;
+        // Cancel the pending decryption. The database might be decrypted already if the
+        // application exited before the key file was removed, then it gets encrypted again.
+        let db_path = db_file_uri(&dir, DB_NAME);
+        key_file.state = if is_database_encrypted(&db_path)? {
+          DatabaseEncryptionState::Encrypted
+        } else {
+          DatabaseEncryptionState::PendingEncryption
+        };
+        key_file.write(&dir)
+      },
+      None => {
+        let key = generate_database_key();
+        let key_file =
+          DatabaseKeyFile::new(&key, passphrase, DatabaseEncryptionState::PendingEncryption)?;
+        key_file.write(&dir)
+      },
+    }
+  }
+
+  /// Disables the encryption of the database. The database keeps being encrypted until the
+  /// next time it's unlocked.
+  pub(crate) fn disable_encryption(
+    &self,
+    user_id: &str,
+    passphrase: &str,
+  ) -> Result<(), FlowyError> {
+    let dir = self.user_db_dir(user_id);
+    let mut key_file = match DatabaseKeyFile::read(&dir)? {
+      None => return Ok(()),
+      Some(key_file) => key_file,
+    };
+    key_file.decrypt_key(passphrase)?;
+    match key_file.state {
+      DatabaseEncryptionState::PendingEncryption => DatabaseKeyFile::remove(&dir),
+      DatabaseEncryptionState::Encrypted | DatabaseEncryptionState::PendingDecryption => {
+        key_file.state = DatabaseEncryptionState::PendingDecryption;
+        key_file.write(&dir)
+      },
+    }
+  }
+
+  /// Changes the passphrase of the database. The key file gets re-encrypted, so it takes effect
+  /// immediately. The encrypted database gets re-keyed with a new key the next time it's
+  /// unlocked, because it can't be rewritten while it's opened.
+  pub(crate) fn change_passphrase(
+    &self,
+    user_id: &str,
+    old_passphrase: &str,
+    new_passphrase: &str,
+  ) -> Result<(), FlowyError> {
+    let dir = self.user_db_dir(user_id);
+    match DatabaseKeyFile::read(&dir)? {
+      None => Err(FlowyError::record_not_found().context("The database is not encrypted")),
+      Some(key_file) => key_file
+        .change_passphrase(old_passphrase, new_passphrase)?
+        .write(&dir),
     }
   }
 
@@ -71,6 +249,32 @@ impl UserDB {
   }
 }
 
+/// Returns the key that the database is encrypted with. It's the new key if the application
+/// exited after the database was re-keyed but before the key file was saved.
+fn find_database_key(
+  db_path: &str,
+  key: &DatabaseKey,
+  new_key: Option<&DatabaseKey>,
+) -> Result<DatabaseKey, FlowyError> {
+  match new_key {
+    Some(new_key) if verify_database_key(db_path, key).is_err() => {
+      verify_database_key(db_path, new_key)?;
+      Ok(new_key.clone())
+    },
+    _ => {
+      verify_database_key(db_path, key)?;
+      Ok(key.clone())
+    },
+  }
+}
+
+fn migrate_kv_values(
+  from_key: Option<&DatabaseKey>,
+  to_key: Option<&DatabaseKey>,
+) -> Result<(), FlowyError> {
+  KV::migrate_values(from_key, to_key).map_err(|e| FlowyError::internal().context(e))
+}
+
 lazy_static! {
   static ref DB_MAP: RwLock<HashMap<String, Database>> = RwLock::new(HashMap::new());
 }
@@ -0,0 +1,243 @@
+use crate::errors::{ErrorCode, FlowyError, FlowyResult};
+use aes_gcm::{
+  aead::{Aead, KeyInit},
+  Aes256Gcm, Nonce,
+};
+use flowy_sqlite::{DatabaseKey, DATABASE_KEY_LEN};
+use hmac::Hmac;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::path::{Path, PathBuf};
+
+const KEY_FILE_NAME: &str = "database_key.json";
+const PBKDF2_ROUNDS: u32 = 600_000;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum DatabaseEncryptionState {
+  /// The encryption was enabled. The database gets encrypted the next time it's unlocked.
+  PendingEncryption,
+  Encrypted,
+  /// The encryption was disabled. The database gets decrypted the next time it's unlocked.
+  PendingDecryption,
+}
+
+/// The database is encrypted with a random key, which is stored in the key file encrypted with
+/// the key derived from the user's passphrase. Changing the passphrase generates a new key too,
+/// and the database gets re-keyed with it the next time it's unlocked.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct DatabaseKeyFile {
+  pub(crate) state: DatabaseEncryptionState,
+  rounds: u32,
+  salt: Vec<u8>,
+  nonce: Vec<u8>,
+  encrypted_key: Vec<u8>,
+  /// The new key of the database, which is encrypted with the same passphrase as the key.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  new_key: Option<EncryptedKey>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EncryptedKey {
+  nonce: Vec<u8>,
+  encrypted_key: Vec<u8>,
+}
+
+impl DatabaseKeyFile {
+  pub(crate) fn new(
+    key: &DatabaseKey,
+    passphrase: &str,
+    state: DatabaseEncryptionState,
+  ) -> FlowyResult<Self> {
+    Self::with_rounds(key, passphrase, state, PBKDF2_ROUNDS)
+  }
+
+  fn with_rounds(
+    key: &DatabaseKey,
+    passphrase: &str,
+    state: DatabaseEncryptionState,
+    rounds: u32,
+  ) -> FlowyResult<Self> {
+    let mut salt = vec![0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+
+    let cipher = make_cipher(passphrase, &salt, rounds)?;
+    let EncryptedKey {
+      nonce,
+      encrypted_key,
+    } = encrypt_key(&cipher, key)?;
+    Ok(Self {
+      state,
+      rounds,
+      salt,
+      nonce,
+      encrypted_key,
+      new_key: None,
+    })
+  }
+
+  /// Returns the key of the database. Returns [ErrorCode::DatabasePassphraseInvalid] if the
+  /// passphrase doesn't match.
+  pub(crate) fn decrypt_key(&self, passphrase: &str) -> FlowyResult<DatabaseKey> {
+    let cipher = make_cipher(passphrase, &self.salt, self.rounds)?;
+    decrypt_key(&cipher, &self.nonce, &self.encrypted_key)
+  }
+
+  /// Returns the new key that the database gets re-keyed with, or None if there is no pending
+  /// re-key.
+  pub(crate) fn decrypt_new_key(&self, passphrase: &str) -> FlowyResult<Option<DatabaseKey>> {
+    match &self.new_key {
+      None => Ok(None),
+      Some(new_key) => {
+        let cipher = make_cipher(passphrase, &self.salt, self.rounds)?;
+        let key = decrypt_key(&cipher, &new_key.nonce, &new_key.encrypted_key)?;
+        Ok(Some(key))
+      },
+    }
+  }
+
+  /// Re-encrypts the key of the database with the new passphrase. The encrypted database gets
+  /// re-keyed too, so a new key is generated for it unless a re-key is pending already. The
+  /// pending key is kept, because the database might be re-keyed with it already.
+  pub(crate) fn change_passphrase(
+    &self,
+    old_passphrase: &str,
+    new_passphrase: &str,
+  ) -> FlowyResult<Self> {
+    let key = self.decrypt_key(old_passphrase)?;
+    let new_key = match self.decrypt_new_key(old_passphrase)? {
+      Some(new_key) => Some(new_key),
+      None if self.state == DatabaseEncryptionState::Encrypted => Some(generate_database_key()),
+      None => None,
+    };
+    let mut key_file = Self::with_rounds(&key, new_passphrase, self.state, self.rounds)?;
+    if let Some(new_key) = new_key {
+      let cipher = make_cipher(new_passphrase, &key_file.salt, key_file.rounds)?;
+      key_file.new_key = Some(encrypt_key(&cipher, &new_key)?);
+    }
+    Ok(key_file)
+  }
+
+  pub(crate) fn read(dir: &str) -> FlowyResult<Option<Self>> {
+    let path = key_file_path(dir);
+    if !path.exists() {
+      return Ok(None);
+    }
+    let bytes = std::fs::read(path)?;
+    let key_file = serde_json::from_slice(&bytes)?;
+    Ok(Some(key_file))
+  }
+
+  /// Writes the key file into a temporary file and then renames it, so the key file never gets
+  /// partially written.
+  pub(crate) fn write(&self, dir: &str) -> FlowyResult<()> {
+    std::fs::create_dir_all(dir)?;
+    let path = key_file_path(dir);
+    let tmp_path = path.with_extension("json.tmp");
+    let bytes = serde_json::to_vec(self)?;
+    std::fs::write(&tmp_path, bytes)?;
+    std::fs::File::open(&tmp_path)?.sync_all()?;
+    std::fs::rename(&tmp_path, &path)?;
+    Ok(())
+  }
+
+  pub(crate) fn remove(dir: &str) -> FlowyResult<()> {
+    let path = key_file_path(dir);
+    if path.exists() {
+      std::fs::remove_file(path)?;
+    }
+    Ok(())
+  }
+}
+
+pub(crate) fn generate_database_key() -> DatabaseKey {
+  let mut key = [0u8; DATABASE_KEY_LEN];
+  rand::thread_rng().fill_bytes(&mut key);
+  DatabaseKey::new(key)
+}
+
+fn key_file_path(dir: &str) -> PathBuf {
+  Path::new(dir).join(KEY_FILE_NAME)
+}
+
+fn encrypt_key(cipher: &Aes256Gcm, key: &DatabaseKey) -> FlowyResult<EncryptedKey> {
+  let mut nonce = vec![0u8; NONCE_LEN];
+  rand::thread_rng().fill_bytes(&mut nonce);
+  let encrypted_key = cipher
+    .encrypt(Nonce::from_slice(&nonce), key.as_bytes())
+    .map_err(|e| FlowyError::internal().context(format!("Encrypt key failed: {:?}", e)))?;
+  Ok(EncryptedKey {
+    nonce,
+    encrypted_key,
+  })
+}
+
+fn decrypt_key(cipher: &Aes256Gcm, nonce: &[u8], encrypted_key: &[u8]) -> FlowyResult<DatabaseKey> {
+  let bytes = cipher
+    .decrypt(Nonce::from_slice(nonce), encrypted_key)
+    .map_err(|_| FlowyError::from(ErrorCode::DatabasePassphraseInvalid))?;
+  let mut key = [0u8; DATABASE_KEY_LEN];
+  if bytes.len() != key.len() {
+    return Err(FlowyError::internal().context("The length of the database key is invalid"));
+  }
+  key.copy_from_slice(&bytes);
+  Ok(DatabaseKey::new(key))
+}
+
+fn make_cipher(passphrase: &str, salt: &[u8], rounds: u32) -> FlowyResult<Aes256Gcm> {
+  let mut derived_key = [0u8; 32];
+  pbkdf2::pbkdf2::<Hmac<Sha256>>(passphrase.as_bytes(), salt, rounds, &mut derived_key);
+  Aes256Gcm::new_from_slice(&derived_key).map_err(|e| FlowyError::internal().context(e))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::encryption::{
+    generate_database_key, DatabaseEncryptionState, DatabaseKeyFile,
+  };
+  use flowy_error::ErrorCode;
+
+  #[test]
+  fn database_key_file_test() {
+    let key = generate_database_key();
+    let key_file =
+      DatabaseKeyFile::with_rounds(&key, "passphrase", DatabaseEncryptionState::Encrypted, 10)
+        .unwrap();
+    assert_eq!(key_file.decrypt_key("passphrase").unwrap(), key);
+    assert_eq!(
+      key_file.decrypt_key("wrong passphrase").unwrap_err().code,
+      ErrorCode::DatabasePassphraseInvalid.value()
+    );
+
+    let key_file = key_file
+      .change_passphrase("passphrase", "new passphrase")
+      .unwrap();
+    assert_eq!(key_file.decrypt_key("new passphrase").unwrap(), key);
+    assert!(key_file.decrypt_key("passphrase").is_err());
+
+    // The database gets re-keyed with the new key, which is kept until the re-key is done.
+    let new_key = key_file.decrypt_new_key("new passphrase").unwrap().unwrap();
+    assert_ne!(new_key, key);
+    let key_file = key_file
+      .change_passphrase("new passphrase", "another passphrase")
+      .unwrap();
+    assert_eq!(key_file.decrypt_key("another passphrase").unwrap(), key);
+    assert_eq!(
+      key_file.decrypt_new_key("another passphrase").unwrap(),
+      Some(new_key)
+    );
+
+    let key_file = DatabaseKeyFile::with_rounds(
+      &key,
+      "passphrase",
+      DatabaseEncryptionState::PendingEncryption,
+      10,
+    )
+    .unwrap()
+    .change_passphrase("passphrase", "new passphrase")
+    .unwrap();
+    assert_eq!(key_file.decrypt_new_key("new passphrase").unwrap(), None);
+  }
+}
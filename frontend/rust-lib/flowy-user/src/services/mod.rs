@@ -1,3 +1,4 @@
 pub mod database;
+pub(crate) mod encryption;
 mod user_session;
 pub use user_session::*;
@@ -1,10 +1,15 @@
-use crate::entities::{UserProfilePB, UserSettingPB};
+use crate::entities::{
+  AppearanceSettingsPB, DatabaseEncryptionPB, DatabaseEncryptionStatePB, UserProfilePB,
+  UserSettingPB,
+};
 use crate::event_map::UserStatusCallback;
 use crate::{
   errors::{ErrorCode, FlowyError},
   event_map::UserCloudService,
+  handlers::APPEARANCE_SETTING_CACHE_KEY,
   notification::*,
   services::database::{UserDB, UserTable, UserTableChangeset},
+  services::encryption::DatabaseEncryptionState,
 };
 use flowy_sqlite::ConnectionPool;
 use flowy_sqlite::{
@@ -49,6 +54,15 @@ pub struct UserSession {
 impl UserSession {
   pub fn new(config: UserSessionConfig, cloud_service: Arc<dyn UserCloudService>) -> Self {
     let db = UserDB::new(&config.root_dir);
+    // The session and the appearance settings are read before the database is unlocked.
+    for key in [
+      config.session_cache_key.as_str(),
+      APPEARANCE_SETTING_CACHE_KEY,
+    ] {
+      if let Err(e) = KV::keep_unencrypted(key) {
+        tracing::error!("{}", e);
+      }
+    }
     let user_status_callback = RwLock::new(None);
     Self {
       database: db,
@@ -212,6 +226,66 @@ impl UserSession {
     Ok(user_setting)
   }
 
+  pub fn get_database_encryption(&self) -> Result<DatabaseEncryptionPB, FlowyError> {
+    let user_id = self.get_session()?.user_id;
+    let state = match self.database.get_encryption_state(&user_id)? {
+      None => DatabaseEncryptionStatePB::Unencrypted,
+      Some(DatabaseEncryptionState::PendingEncryption) => {
+        DatabaseEncryptionStatePB::PendingEncryption
+      },
+      Some(DatabaseEncryptionState::Encrypted) => DatabaseEncryptionStatePB::Encrypted,
+      Some(DatabaseEncryptionState::PendingDecryption) => {
+        DatabaseEncryptionStatePB::PendingDecryption
+      },
+    };
+    Ok(DatabaseEncryptionPB {
+      is_supported: flowy_sqlite::is_encryption_supported(),
+      state,
+      is_locked: self.database.is_locked(&user_id)?,
+    })
+  }
+
+  /// Unlocks the user's database and then initializes the user's resources that were failed to
+  /// load while the database was locked.
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub async fn unlock_database(&self, passphrase: &str) -> Result<(), FlowyError> {
+    let session = self.get_session()?;
+    if !self.database.is_locked(&session.user_id)? {
+      return Ok(());
+    }
+    self.database.unlock_user_db(&session.user_id, passphrase)?;
+    if let Some(user_status_callback) = self.user_status_callback.read().await.as_ref() {
+      user_status_callback
+        .did_sign_in(&session.token, &session.user_id)
+        .await?;
+    }
+    Ok(())
+  }
+
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub fn enable_database_encryption(&self, passphrase: &str) -> Result<(), FlowyError> {
+    let user_id = self.get_session()?.user_id;
+    self.database.enable_encryption(&user_id, passphrase)
+  }
+
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub fn change_database_passphrase(
+    &self,
+    old_passphrase: &str,
+    new_passphrase: &str,
+  ) -> Result<(), FlowyError> {
+    let user_id = self.get_session()?.user_id;
+    self
+      .database
+      .change_passphrase(&user_id, old_passphrase, new_passphrase)
+  }
+
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub fn disable_database_encryption(&self, passphrase: &str) -> Result<(), FlowyError> {
+    let user_id = self.get_session()?.user_id;
+    self.database.disable_encryption(&user_id, passphrase)
+  }
+
   pub fn user_id(&self) -> Result<String, FlowyError> {
     Ok(self.get_session()?.user_id)
   }
@@ -223,6 +297,20 @@ impl UserSession {
   pub fn token(&self) -> Result<String, FlowyError> {
     Ok(self.get_session()?.token)
   }
+
+  /// Returns the appearance settings in JSON, or None if they were never set.
+  pub fn get_settings(&self) -> Option<String> {
+    KV::get_str(APPEARANCE_SETTING_CACHE_KEY)
+  }
+
+  /// Replaces the appearance settings with the JSON ones. The settings are rejected if they can't
+  /// be deserialized into [AppearanceSettingsPB].
+  pub fn set_settings(&self, settings: String) -> Result<(), FlowyError> {
+    let _: AppearanceSettingsPB =
+      serde_json::from_str(&settings).map_err(|e| FlowyError::invalid_data().context(e))?;
+    KV::set_str(APPEARANCE_SETTING_CACHE_KEY, settings);
+    Ok(())
+  }
 }
 
 impl UserSession {
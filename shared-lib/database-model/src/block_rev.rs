@@ -8,6 +8,10 @@ pub fn gen_row_id() -> String {
   nanoid!(6)
 }
 
+pub fn gen_row_document_id() -> String {
+  format!("r:{}", nanoid!(10))
+}
+
 pub const DEFAULT_ROW_HEIGHT: i32 = 42;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -28,6 +32,24 @@ pub struct RowRevision {
   pub cells: IndexMap<FieldId, CellRevision>,
   pub height: i32,
   pub visibility: bool,
+  /// The timestamp when the row was created. It's zero for the rows created before it's recorded.
+  #[serde(default, skip_serializing_if = "is_zero")]
+  pub created_at: i64,
+  /// The timestamp when the row was last edited.
+  #[serde(default, skip_serializing_if = "is_zero")]
+  pub last_modified: i64,
+  /// The number that is assigned to the row when it's created. It increases in the block.
+  #[serde(default, skip_serializing_if = "is_zero")]
+  pub auto_increment_id: i64,
+  /// The id of the document that is used as the body of the row. The document is created when
+  /// the body of the row is opened for the first time.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub document_id: Option<String>,
+}
+
+/// The rows that don't record the timestamps or the auto increment id keep the same json.
+fn is_zero(value: &i64) -> bool {
+  *value == 0
 }
 
 impl RowRevision {
@@ -38,6 +60,10 @@ impl RowRevision {
       cells: Default::default(),
       height: DEFAULT_ROW_HEIGHT,
       visibility: true,
+      created_at: 0,
+      last_modified: 0,
+      auto_increment_id: 0,
+      document_id: None,
     }
   }
 }
@@ -46,6 +72,8 @@ pub struct RowChangeset {
   pub row_id: String,
   pub height: Option<i32>,
   pub visibility: Option<bool>,
+  pub last_modified: Option<i64>,
+  pub document_id: Option<String>,
   // Contains the key/value changes represents as the update of the cells. For example,
   // if there is one cell was changed, then the `cell_by_field_id` will only have one key/value.
   pub cell_by_field_id: HashMap<FieldId, CellRevision>,
@@ -57,12 +85,17 @@ impl RowChangeset {
       row_id,
       height: None,
       visibility: None,
+      last_modified: None,
+      document_id: None,
       cell_by_field_id: Default::default(),
     }
   }
 
   pub fn is_empty(&self) -> bool {
-    self.height.is_none() && self.visibility.is_none() && self.cell_by_field_id.is_empty()
+    self.height.is_none()
+      && self.visibility.is_none()
+      && self.document_id.is_none()
+      && self.cell_by_field_id.is_empty()
   }
 }
 
@@ -0,0 +1,58 @@
+use crate::FieldTypeRevision;
+use serde::{Deserialize, Serialize};
+use serde_repr::*;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct CalculationRevision {
+  pub id: String,
+  pub field_id: String,
+  pub field_type: FieldTypeRevision,
+  pub calculation_type: CalculationTypeRevision,
+}
+
+#[derive(Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, Clone, Debug)]
+#[repr(u8)]
+pub enum CalculationTypeRevision {
+  Count = 0,
+  CountEmpty = 1,
+  CountUnique = 2,
+  Sum = 3,
+  Average = 4,
+  Median = 5,
+  Min = 6,
+  Max = 7,
+  PercentChecked = 8,
+  EarliestDate = 9,
+  LatestDate = 10,
+}
+
+impl std::default::Default for CalculationTypeRevision {
+  fn default() -> Self {
+    Self::Count
+  }
+}
+
+impl std::convert::From<u8> for CalculationTypeRevision {
+  fn from(num: u8) -> Self {
+    match num {
+      0 => CalculationTypeRevision::Count,
+      1 => CalculationTypeRevision::CountEmpty,
+      2 => CalculationTypeRevision::CountUnique,
+      3 => CalculationTypeRevision::Sum,
+      4 => CalculationTypeRevision::Average,
+      5 => CalculationTypeRevision::Median,
+      6 => CalculationTypeRevision::Min,
+      7 => CalculationTypeRevision::Max,
+      8 => CalculationTypeRevision::PercentChecked,
+      9 => CalculationTypeRevision::EarliestDate,
+      10 => CalculationTypeRevision::LatestDate,
+      _ => CalculationTypeRevision::Count,
+    }
+  }
+}
+
+impl std::convert::From<CalculationTypeRevision> for u8 {
+  fn from(calculation_type: CalculationTypeRevision) -> Self {
+    calculation_type as u8
+  }
+}
@@ -246,6 +246,69 @@ impl CalendarLayoutSetting {
   }
 }
 
+/// The timeline lays out each row as a bar that spans from the date of the start field to the
+/// date of the end field. If both are the same field, the bar spans the date range of the cell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineLayoutSetting {
+  pub start_field_id: String,
+  pub end_field_id: String,
+  /// The relation field that links a row to the rows it depends on.
+  #[serde(default)]
+  pub dependency_field_id: Option<String>,
+}
+
+impl TimelineLayoutSetting {
+  pub fn new(start_field_id: String, end_field_id: String) -> Self {
+    TimelineLayoutSetting {
+      start_field_id,
+      end_field_id,
+      dependency_field_id: None,
+    }
+  }
+}
+
+/// The gallery lays out each row as a card.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GalleryLayoutSetting {
+  pub cover_source: GalleryCoverSource,
+  /// The URL field that the cover is read from if the cover source is
+  /// [GalleryCoverSource::URLField].
+  #[serde(default)]
+  pub cover_field_id: Option<String>,
+  pub card_size: GalleryCardSize,
+  /// The fields that are displayed on the cards, in order.
+  #[serde(default)]
+  pub visible_field_ids: Vec<String>,
+}
+
+impl GalleryLayoutSetting {
+  pub fn new(visible_field_ids: Vec<String>) -> Self {
+    GalleryLayoutSetting {
+      visible_field_ids,
+      ..Default::default()
+    }
+  }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum GalleryCoverSource {
+  #[default]
+  None = 0,
+  URLField = 1,
+  /// The first image in the document of the row.
+  DocumentImage = 2,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum GalleryCardSize {
+  Small = 0,
+  #[default]
+  Medium = 1,
+  Large = 2,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Default, Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 pub enum CalendarLayout {
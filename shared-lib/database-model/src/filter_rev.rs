@@ -1,5 +1,6 @@
-use crate::FieldTypeRevision;
-use serde::{Deserialize, Serialize};
+use crate::{gen_database_filter_id, Configuration, FieldTypeRevision};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_repr::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
 pub struct FilterRevision {
@@ -10,3 +11,272 @@ pub struct FilterRevision {
   #[serde(default)]
   pub content: String,
 }
+
+/// [FilterConfiguration] stores the filters of a view and the tree that combines them.
+///
+/// The filters are stored by field id and field type, the same as the other configurations.
+/// The `root` group references the filters by their id. Filters that are not referenced by
+/// the tree are treated as the children of the root group.
+///
+/// The old format only contains the filters, it is deserialized with an empty `AND` root
+/// group, which keeps the previous behavior.
+#[derive(Debug, Clone, Serialize)]
+pub struct FilterConfiguration {
+  conditions: Configuration<FilterRevision>,
+  pub root: FilterGroupRevision,
+}
+
+impl<'de> Deserialize<'de> for FilterConfiguration {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum FilterConfigurationSerde {
+      Legacy(Configuration<FilterRevision>),
+      Tree {
+        conditions: Configuration<FilterRevision>,
+        #[serde(default = "FilterGroupRevision::root")]
+        root: FilterGroupRevision,
+      },
+    }
+
+    match FilterConfigurationSerde::deserialize(deserializer)? {
+      FilterConfigurationSerde::Legacy(conditions) => Ok(FilterConfiguration {
+        conditions,
+        root: FilterGroupRevision::root(),
+      }),
+      FilterConfigurationSerde::Tree { conditions, root } => {
+        Ok(FilterConfiguration { conditions, root })
+      },
+    }
+  }
+}
+
+impl std::default::Default for FilterConfiguration {
+  fn default() -> Self {
+    Self {
+      conditions: Configuration::default(),
+      root: FilterGroupRevision::root(),
+    }
+  }
+}
+
+impl std::ops::Deref for FilterConfiguration {
+  type Target = Configuration<FilterRevision>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.conditions
+  }
+}
+
+impl std::ops::DerefMut for FilterConfiguration {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.conditions
+  }
+}
+
+pub const ROOT_FILTER_GROUP_ID: &str = "root";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum FilterOperatorRevision {
+  And = 0,
+  Or = 1,
+}
+
+impl std::default::Default for FilterOperatorRevision {
+  fn default() -> Self {
+    FilterOperatorRevision::And
+  }
+}
+
+/// A group combines its children with the `operator`. The children are either the id of a
+/// [FilterRevision] or another group.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FilterGroupRevision {
+  pub id: String,
+  #[serde(default)]
+  pub operator: FilterOperatorRevision,
+  #[serde(default)]
+  pub children: Vec<FilterNodeRevision>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FilterNodeRevision {
+  Filter { filter_id: String },
+  Group(FilterGroupRevision),
+}
+
+impl FilterGroupRevision {
+  pub fn root() -> Self {
+    Self {
+      id: ROOT_FILTER_GROUP_ID.to_owned(),
+      operator: FilterOperatorRevision::And,
+      children: vec![],
+    }
+  }
+
+  pub fn is_root(&self) -> bool {
+    self.id == ROOT_FILTER_GROUP_ID
+  }
+
+  pub fn new(operator: FilterOperatorRevision) -> Self {
+    Self {
+      id: gen_database_filter_id(),
+      operator,
+      children: vec![],
+    }
+  }
+
+  pub fn find_group(&self, group_id: &str) -> Option<&FilterGroupRevision> {
+    if self.id == group_id {
+      return Some(self);
+    }
+    self.children.iter().find_map(|child| match child {
+      FilterNodeRevision::Filter { .. } => None,
+      FilterNodeRevision::Group(group) => group.find_group(group_id),
+    })
+  }
+
+  pub fn find_group_mut(&mut self, group_id: &str) -> Option<&mut FilterGroupRevision> {
+    if self.id == group_id {
+      return Some(self);
+    }
+    self.children.iter_mut().find_map(|child| match child {
+      FilterNodeRevision::Filter { .. } => None,
+      FilterNodeRevision::Group(group) => group.find_group_mut(group_id),
+    })
+  }
+
+  pub fn contains_filter(&self, filter_id: &str) -> bool {
+    self.children.iter().any(|child| match child {
+      FilterNodeRevision::Filter { filter_id: id } => id == filter_id,
+      FilterNodeRevision::Group(group) => group.contains_filter(filter_id),
+    })
+  }
+
+  pub fn add_filter(&mut self, filter_id: &str) {
+    self.children.push(FilterNodeRevision::Filter {
+      filter_id: filter_id.to_owned(),
+    });
+  }
+
+  pub fn add_group(&mut self, group: FilterGroupRevision) {
+    self.children.push(FilterNodeRevision::Group(group));
+  }
+
+  /// Removes the filter from this group and all of its descendants.
+  pub fn remove_filter(&mut self, filter_id: &str) {
+    self.children.retain_mut(|child| match child {
+      FilterNodeRevision::Filter { filter_id: id } => id != filter_id,
+      FilterNodeRevision::Group(group) => {
+        group.remove_filter(filter_id);
+        true
+      },
+    });
+  }
+
+  /// Removes the descendant group with the given id and returns it.
+  pub fn remove_group(&mut self, group_id: &str) -> Option<FilterGroupRevision> {
+    let index = self.children.iter().position(|child| match child {
+      FilterNodeRevision::Filter { .. } => false,
+      FilterNodeRevision::Group(group) => group.id == group_id,
+    });
+
+    match index {
+      Some(index) => match self.children.remove(index) {
+        FilterNodeRevision::Group(group) => Some(group),
+        FilterNodeRevision::Filter { .. } => None,
+      },
+      None => self.children.iter_mut().find_map(|child| match child {
+        FilterNodeRevision::Filter { .. } => None,
+        FilterNodeRevision::Group(group) => group.remove_group(group_id),
+      }),
+    }
+  }
+
+  /// Returns the ids of the filters in this group and all of its descendants.
+  pub fn filter_ids(&self) -> Vec<String> {
+    self
+      .children
+      .iter()
+      .flat_map(|child| match child {
+        FilterNodeRevision::Filter { filter_id } => vec![filter_id.clone()],
+        FilterNodeRevision::Group(group) => group.filter_ids(),
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    FilterConfiguration, FilterGroupRevision, FilterNodeRevision, FilterOperatorRevision,
+    FilterRevision,
+  };
+
+  #[test]
+  fn filter_configuration_legacy_serde_test() {
+    let mut legacy = crate::Configuration::<FilterRevision>::default();
+    let filter_rev = FilterRevision {
+      id: "f1".to_owned(),
+      field_id: "field".to_owned(),
+      field_type: 0,
+      condition: 2,
+      content: "A".to_owned(),
+    };
+    legacy.add_object("field", &0, filter_rev.clone());
+    let json = serde_json::to_string(&legacy).unwrap();
+
+    let configuration: FilterConfiguration = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+      configuration.get_all_objects().first().unwrap().as_ref(),
+      &filter_rev
+    );
+    assert!(configuration.root.is_root());
+    assert_eq!(configuration.root.operator, FilterOperatorRevision::And);
+    assert!(configuration.root.children.is_empty());
+  }
+
+  #[test]
+  fn filter_configuration_tree_serde_test() {
+    let mut configuration = FilterConfiguration::default();
+    configuration.root.operator = FilterOperatorRevision::Or;
+    configuration.root.add_filter("f1");
+    let mut group = FilterGroupRevision::new(FilterOperatorRevision::And);
+    group.add_filter("f2");
+    group.add_filter("f3");
+    configuration.root.add_group(group.clone());
+
+    let json = serde_json::to_string(&configuration).unwrap();
+    let configuration: FilterConfiguration = serde_json::from_str(&json).unwrap();
+    assert_eq!(configuration.root.operator, FilterOperatorRevision::Or);
+    assert_eq!(
+      configuration.root.children[1],
+      FilterNodeRevision::Group(group)
+    );
+    assert_eq!(configuration.root.filter_ids(), vec!["f1", "f2", "f3"]);
+  }
+
+  #[test]
+  fn filter_group_remove_test() {
+    let mut root = FilterGroupRevision::root();
+    root.add_filter("f1");
+    let mut group = FilterGroupRevision::new(FilterOperatorRevision::Or);
+    group.add_filter("f2");
+    let group_id = group.id.clone();
+    root.add_group(group);
+
+    root.remove_filter("f2");
+    assert!(!root.contains_filter("f2"));
+    assert!(root.find_group(&group_id).is_some());
+
+    let removed = root.remove_group(&group_id).unwrap();
+    assert_eq!(removed.id, group_id);
+    assert!(root.find_group(&group_id).is_none());
+    assert_eq!(root.filter_ids(), vec!["f1"]);
+  }
+}
@@ -41,6 +41,10 @@ impl GroupConfigurationRevision {
 #[derive(Default, Serialize, Deserialize)]
 pub struct TextGroupConfigurationRevision {
   pub hide_empty: bool,
+
+  /// The texts that only differ in case are put into the same group if it's true.
+  #[serde(default)]
+  pub case_insensitive: bool,
 }
 
 impl GroupConfigurationContentSerde for TextGroupConfigurationRevision {
@@ -52,9 +56,48 @@ impl GroupConfigurationContentSerde for TextGroupConfigurationRevision {
   }
 }
 
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct NumberGroupConfigurationRevision {
   pub hide_empty: bool,
+
+  /// The numbers are grouped into these ranges. If the ranges are empty, the numbers are grouped
+  /// into the buckets of the same width.
+  #[serde(default)]
+  pub ranges: Vec<NumberRangeRevision>,
+
+  #[serde(default = "default_number_bucket_width")]
+  pub bucket_width: f64,
+}
+
+fn default_number_bucket_width() -> f64 {
+  10.0
+}
+
+impl std::default::Default for NumberGroupConfigurationRevision {
+  fn default() -> Self {
+    Self {
+      hide_empty: false,
+      ranges: vec![],
+      bucket_width: default_number_bucket_width(),
+    }
+  }
+}
+
+/// A range of the numbers that includes the `start` and excludes the `end`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NumberRangeRevision {
+  pub start: f64,
+  pub end: f64,
+}
+
+impl NumberRangeRevision {
+  pub fn new(start: f64, end: f64) -> Self {
+    Self { start, end }
+  }
+
+  pub fn contains(&self, number: f64) -> bool {
+    self.start <= number && number < self.end
+  }
 }
 
 impl GroupConfigurationContentSerde for NumberGroupConfigurationRevision {
@@ -178,7 +221,10 @@ impl std::default::Default for DateCondition {
 
 #[cfg(test)]
 mod tests {
-  use crate::{GroupConfigurationRevision, SelectOptionGroupConfigurationRevision};
+  use crate::{
+    GroupConfigurationContentSerde, GroupConfigurationRevision, NumberGroupConfigurationRevision,
+    NumberRangeRevision, SelectOptionGroupConfigurationRevision,
+  };
 
   #[test]
   fn group_configuration_serde_test() {
@@ -191,6 +237,22 @@ mod tests {
       serde_json::from_str(&rev.content).unwrap();
   }
 
+  #[test]
+  fn number_group_configuration_serde_test() {
+    let rev = NumberGroupConfigurationRevision::from_json(r#"{"hide_empty":false}"#).unwrap();
+    assert!(rev.ranges.is_empty());
+    assert_eq!(rev.bucket_width, 10.0);
+
+    let content = NumberGroupConfigurationRevision {
+      hide_empty: false,
+      ranges: vec![NumberRangeRevision::new(0.0, 10.0)],
+      bucket_width: 5.0,
+    };
+    let rev = NumberGroupConfigurationRevision::from_json(&content.to_json().unwrap()).unwrap();
+    assert_eq!(rev.ranges, content.ranges);
+    assert_eq!(rev.bucket_width, 5.0);
+  }
+
   #[test]
   fn group_configuration_serde_test2() {
     let content = SelectOptionGroupConfigurationRevision { hide_empty: false };
@@ -1,4 +1,5 @@
 mod block_rev;
+mod calculation_rev;
 mod database_rev;
 mod filter_rev;
 mod group_rev;
@@ -7,6 +8,7 @@ mod sort_rev;
 mod view_rev;
 
 pub use block_rev::*;
+pub use calculation_rev::*;
 pub use database_rev::*;
 pub use filter_rev::*;
 pub use group_rev::*;
@@ -1,5 +1,5 @@
 use crate::{
-  FieldRevision, FieldTypeRevision, FilterRevision, GroupConfigurationRevision, SortRevision,
+  CalculationRevision, FieldRevision, FieldTypeRevision, GroupConfigurationRevision, SortRevision,
 };
 use indexmap::IndexMap;
 use nanoid::nanoid;
@@ -19,12 +19,16 @@ pub fn gen_database_sort_id() -> String {
   nanoid!(6)
 }
 
-pub type FilterConfiguration = Configuration<FilterRevision>;
+pub fn gen_database_calculation_id() -> String {
+  nanoid!(6)
+}
 
 pub type GroupConfiguration = Configuration<GroupConfigurationRevision>;
 
 pub type SortConfiguration = Configuration<SortRevision>;
 
+pub type CalculationConfiguration = Configuration<CalculationRevision>;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(transparent)]
 pub struct Configuration<T>
@@ -1,4 +1,4 @@
-use crate::{FilterConfiguration, GroupConfiguration, SortConfiguration};
+use crate::{CalculationConfiguration, FilterConfiguration, GroupConfiguration, SortConfiguration};
 use indexmap::IndexMap;
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
@@ -15,6 +15,8 @@ pub enum LayoutRevision {
   Grid = 0,
   Board = 1,
   Calendar = 2,
+  Timeline = 3,
+  Gallery = 4,
 }
 
 impl ToString for LayoutRevision {
@@ -54,8 +56,16 @@ pub struct DatabaseViewRevision {
   #[serde(default)]
   pub groups: GroupConfiguration,
 
+  /// The rows of each group are grouped again by the field of this configuration. For example,
+  /// the swimlanes of the board.
+  #[serde(default)]
+  pub sub_groups: GroupConfiguration,
+
   #[serde(default)]
   pub sorts: SortConfiguration,
+
+  #[serde(default)]
+  pub calculations: CalculationConfiguration,
 }
 
 const DEFAULT_BASE_VALUE: fn() -> bool = || true;
@@ -77,7 +87,9 @@ impl DatabaseViewRevision {
       layout_settings: Default::default(),
       filters: Default::default(),
       groups: Default::default(),
+      sub_groups: Default::default(),
       sorts: Default::default(),
+      calculations: Default::default(),
     }
   }
 
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// A view that the user marked as favorite. The favorites are kept in the folder, in the order
+/// they were added, so they get synced along with the rest of the folder.
+#[derive(Default, Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FavoriteRevision {
+  /// The id of the view
+  pub id: String,
+
+  #[serde(default)]
+  pub create_time: i64,
+}
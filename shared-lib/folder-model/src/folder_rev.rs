@@ -1,4 +1,4 @@
-use crate::{TrashRevision, WorkspaceRevision};
+use crate::{FavoriteRevision, TrashRevision, WorkspaceRevision};
 use serde::de::{MapAccess, Visitor};
 use serde::{de, Deserialize, Deserializer, Serialize};
 use std::fmt;
@@ -8,6 +8,10 @@ use std::sync::Arc;
 pub struct FolderRevision {
   pub workspaces: Vec<Arc<WorkspaceRevision>>,
   pub trash: Vec<Arc<TrashRevision>>,
+
+  /// Skipped if it's empty, so the folders without favorites are serialized as before.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub favorites: Vec<Arc<FavoriteRevision>>,
 }
 
 impl<'de> Deserialize<'de> for FolderRevision {
@@ -28,6 +32,7 @@ impl<'de> Deserialize<'de> for FolderRevision {
       {
         let mut workspaces: Option<Vec<WorkspaceRevision>> = None;
         let mut trash: Option<Vec<TrashRevision>> = None;
+        let mut favorites: Option<Vec<FavoriteRevision>> = None;
         while let Some(key) = map.next_key::<String>()? {
           if key == "workspaces" && workspaces.is_none() {
             workspaces = Some(map.next_value::<Vec<WorkspaceRevision>>()?);
@@ -35,6 +40,9 @@ impl<'de> Deserialize<'de> for FolderRevision {
           if key == "trash" && trash.is_none() {
             trash = Some(map.next_value::<Vec<TrashRevision>>()?);
           }
+          if key == "favorites" && favorites.is_none() {
+            favorites = Some(map.next_value::<Vec<FavoriteRevision>>()?);
+          }
         }
 
         if let Some(workspaces) = workspaces {
@@ -45,6 +53,11 @@ impl<'de> Deserialize<'de> for FolderRevision {
               .into_iter()
               .map(Arc::new)
               .collect(),
+            favorites: favorites
+              .unwrap_or_default()
+              .into_iter()
+              .map(Arc::new)
+              .collect(),
           });
           Ok(())
         } else {
@@ -54,7 +67,7 @@ impl<'de> Deserialize<'de> for FolderRevision {
     }
 
     let mut folder_rev: Option<FolderRevision> = None;
-    const FIELDS: &[&str] = &["workspaces", "trash"];
+    const FIELDS: &[&str] = &["workspaces", "trash", "favorites"];
     let _ = serde::Deserializer::deserialize_struct(
       deserializer,
       "FolderRevision",
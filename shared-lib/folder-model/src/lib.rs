@@ -2,6 +2,7 @@
 mod macros;
 
 mod app_rev;
+mod favorite_rev;
 pub mod folder;
 mod folder_rev;
 mod trash_rev;
@@ -10,6 +11,7 @@ mod view_rev;
 mod workspace_rev;
 
 pub use app_rev::*;
+pub use favorite_rev::*;
 pub use folder::*;
 pub use folder_rev::*;
 pub use trash_rev::*;
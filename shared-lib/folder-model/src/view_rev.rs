@@ -108,6 +108,8 @@ pub enum ViewLayoutTypeRevision {
   Grid = 3,
   Board = 4,
   Calendar = 5,
+  Timeline = 6,
+  Gallery = 7,
 }
 
 impl std::default::Default for ViewLayoutTypeRevision {
@@ -0,0 +1,87 @@
+//! The node types and attribute keys used by the AppFlowy editor when it stores a document
+//! in the [`NodeTree`](crate::core::NodeTree).
+//!
+//! A document is a tree of nodes. Each `text` node carries its content in a delta and uses the
+//! `subtype` attribute to describe what kind of block it is, for example, a heading or a
+//! checkbox. The inline styles are stored in the attributes of the delta operations.
+
+use crate::core::{AttributeHashMap, Body, NodeData};
+use crate::text_delta::DeltaTextOperations;
+
+pub const EDITOR_NODE_TYPE: &str = "editor";
+pub const TEXT_NODE_TYPE: &str = "text";
+pub const DIVIDER_NODE_TYPE: &str = "divider";
+pub const IMAGE_NODE_TYPE: &str = "image";
+pub const MATH_EQUATION_NODE_TYPE: &str = "math_equation";
+
+pub const SUBTYPE: &str = "subtype";
+pub const HEADING_SUBTYPE: &str = "heading";
+pub const BULLETED_LIST_SUBTYPE: &str = "bulleted-list";
+pub const NUMBER_LIST_SUBTYPE: &str = "number-list";
+pub const CHECKBOX_SUBTYPE: &str = "checkbox";
+pub const QUOTE_SUBTYPE: &str = "quote";
+pub const CODE_BLOCK_SUBTYPE: &str = "code_block";
+
+pub const HEADING_ATTR: &str = "heading";
+pub const CHECKBOX_ATTR: &str = "checkbox";
+pub const NUMBER_ATTR: &str = "number";
+pub const LANGUAGE_ATTR: &str = "language";
+pub const IMAGE_SRC_ATTR: &str = "image_src";
+pub const MATH_EQUATION_ATTR: &str = "math_equation";
+
+pub const BOLD_ATTR: &str = "bold";
+pub const ITALIC_ATTR: &str = "italic";
+pub const UNDERLINE_ATTR: &str = "underline";
+pub const STRIKETHROUGH_ATTR: &str = "strikethrough";
+pub const CODE_ATTR: &str = "code";
+pub const HREF_ATTR: &str = "href";
+pub const COLOR_ATTR: &str = "color";
+pub const BACKGROUND_COLOR_ATTR: &str = "backgroundColor";
+
+/// Returns the subtype of the `text` node. Returns None if the node is a plain paragraph.
+pub fn node_subtype(node: &NodeData) -> Option<String> {
+  node.attributes.get(SUBTYPE)?.str_value()
+}
+
+/// Returns the heading level, 1 to 6, of the node. The level is stored as `h1`...`h6`.
+pub fn heading_level(node: &NodeData) -> usize {
+  node
+    .attributes
+    .get(HEADING_ATTR)
+    .and_then(|value| value.str_value())
+    .and_then(|heading| heading.trim_start_matches('h').parse::<usize>().ok())
+    .unwrap_or(1)
+    .clamp(1, 6)
+}
+
+/// Returns the delta of the node or an empty delta if the node has no text.
+pub fn node_delta(node: &NodeData) -> DeltaTextOperations {
+  match &node.body {
+    Body::Delta(delta) => delta.clone(),
+    Body::Empty => DeltaTextOperations::default(),
+  }
+}
+
+/// Returns the text of the node without any formatting.
+pub fn node_plain_text(node: &NodeData) -> String {
+  node_delta(node)
+    .ops
+    .iter()
+    .filter(|op| op.is_insert())
+    .map(|op| op.get_data())
+    .collect()
+}
+
+pub fn is_attribute_true(attributes: &AttributeHashMap, key: &str) -> bool {
+  attributes
+    .get(key)
+    .and_then(|value| value.bool_value())
+    .unwrap_or(false)
+}
+
+pub fn attribute_str(attributes: &AttributeHashMap, key: &str) -> Option<String> {
+  attributes
+    .get(key)
+    .and_then(|value| value.str_value())
+    .filter(|value| !value.is_empty())
+}
@@ -0,0 +1,441 @@
+use crate::codec::html::html_dom::{
+  collapse_whitespace, parse_html, sanitize_css_value, sanitize_url, HtmlElement, HtmlNode,
+};
+use crate::core::AttributeHashMap;
+use crate::text_delta::{BuildInTextAttributeKey, DeltaTextOperations};
+
+/// Decodes the HTML into [DeltaTextOperations].
+///
+/// The block elements end with a line break that carries the block attributes, and the inline
+/// elements are decoded into the attributes of the text. The elements that have no
+/// corresponding attribute are unwrapped and only their text is kept.
+pub fn html_delta_decoder(html: &str) -> DeltaTextOperations {
+  let mut decoder = HtmlDeltaDecoder::default();
+  let context = DecodeContext::default();
+  decoder.decode_nodes(&parse_html(html), &context);
+  if !decoder.is_line_empty {
+    decoder.end_line(&AttributeHashMap::new());
+  }
+  decoder.delta
+}
+
+#[derive(Clone, Default)]
+struct DecodeContext {
+  inline: AttributeHashMap,
+  block: AttributeHashMap,
+  list: Option<&'static str>,
+  in_pre: bool,
+}
+
+struct HtmlDeltaDecoder {
+  delta: DeltaTextOperations,
+  is_line_empty: bool,
+  number_of_lines: usize,
+}
+
+impl Default for HtmlDeltaDecoder {
+  fn default() -> Self {
+    Self {
+      delta: DeltaTextOperations::default(),
+      is_line_empty: true,
+      number_of_lines: 0,
+    }
+  }
+}
+
+impl HtmlDeltaDecoder {
+  fn decode_nodes(&mut self, nodes: &[HtmlNode], context: &DecodeContext) {
+    for node in nodes {
+      match node {
+        HtmlNode::Text(text) => self.decode_text(text, context),
+        HtmlNode::Element(element) => self.decode_element(element, context),
+      }
+    }
+  }
+
+  fn decode_text(&mut self, text: &str, context: &DecodeContext) {
+    if context.in_pre {
+      let mut lines = text.split('\n').peekable();
+      while let Some(line) = lines.next() {
+        self.insert(line, &context.inline);
+        if lines.peek().is_some() {
+          self.end_line(&context.block);
+        }
+      }
+    } else {
+      let mut text = collapse_whitespace(text);
+      if self.is_line_empty {
+        text = text.trim_start().to_owned();
+      }
+      self.insert(&text, &context.inline);
+    }
+  }
+
+  fn decode_element(&mut self, element: &HtmlElement, context: &DecodeContext) {
+    let mut context = context.clone();
+    match element.name.as_str() {
+      "br" => {
+        self.end_line(&context.block);
+        return;
+      },
+      "ul" | "ol" => {
+        context.list = Some(if element.name == "ol" {
+          "ordered"
+        } else {
+          "bullet"
+        });
+        self.start_block(&context);
+        self.decode_nodes(&element.children, &context);
+        if !self.is_line_empty {
+          self.end_line(&context.block);
+        }
+        return;
+      },
+      "li" => {
+        let list = match list_item_checked(element) {
+          Some(true) => "checked",
+          Some(false) => "unchecked",
+          None => context.list.unwrap_or("bullet"),
+        };
+        context
+          .block
+          .insert(BuildInTextAttributeKey::List.as_ref(), list);
+      },
+      "blockquote" => {
+        context
+          .block
+          .insert(BuildInTextAttributeKey::BlockQuote.as_ref(), true);
+      },
+      "pre" => {
+        context
+          .block
+          .insert(BuildInTextAttributeKey::CodeBlock.as_ref(), true);
+        context.in_pre = true;
+      },
+      "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+        let header = element.name[1..].parse::<usize>().unwrap_or(1);
+        context
+          .block
+          .insert(BuildInTextAttributeKey::Header.as_ref(), header);
+      },
+      "strong" | "b" => insert_true(&mut context.inline, BuildInTextAttributeKey::Bold),
+      "em" | "i" => insert_true(&mut context.inline, BuildInTextAttributeKey::Italic),
+      "u" | "ins" => insert_true(&mut context.inline, BuildInTextAttributeKey::Underline),
+      "s" | "strike" | "del" => {
+        insert_true(&mut context.inline, BuildInTextAttributeKey::StrikeThrough)
+      },
+      "code" => {
+        if !context.in_pre {
+          insert_true(&mut context.inline, BuildInTextAttributeKey::InlineCode)
+        }
+      },
+      "a" => {
+        if let Some(href) = element.attribute("href").and_then(sanitize_url) {
+          context
+            .inline
+            .insert(BuildInTextAttributeKey::Link.as_ref(), href);
+        }
+      },
+      "mark" => {
+        let background = element
+          .style("background-color")
+          .and_then(|s| sanitize_css_value(&s))
+          .unwrap_or_else(|| "#ffff00".to_owned());
+        context
+          .inline
+          .insert(BuildInTextAttributeKey::Background.as_ref(), background);
+      },
+      "img" | "input" | "hr" => return,
+      _ => {},
+    }
+
+    decode_style_attributes(element, &mut context);
+    if is_block_element(&element.name) {
+      self.start_block(&context);
+      let number_of_lines = self.number_of_lines;
+      self.decode_nodes(&element.children, &context);
+      // The empty block element is an empty line. If the block ends with a line break, the
+      // line break has already ended the line.
+      if !self.is_line_empty || number_of_lines == self.number_of_lines {
+        self.end_line(&context.block);
+      }
+    } else {
+      self.decode_nodes(&element.children, &context);
+    }
+  }
+
+  /// The text before the block element is a line of its own.
+  fn start_block(&mut self, context: &DecodeContext) {
+    if !self.is_line_empty {
+      let mut block = context.block.clone();
+      block.remove_key(BuildInTextAttributeKey::List);
+      self.end_line(&block);
+    }
+  }
+
+  fn insert(&mut self, text: &str, attributes: &AttributeHashMap) {
+    if text.is_empty() {
+      return;
+    }
+    self.delta.insert(text, attributes.clone());
+    self.is_line_empty = false;
+  }
+
+  fn end_line(&mut self, block: &AttributeHashMap) {
+    self.delta.insert("\n", block.clone());
+    self.is_line_empty = true;
+    self.number_of_lines += 1;
+  }
+}
+
+fn insert_true(attributes: &mut AttributeHashMap, key: BuildInTextAttributeKey) {
+  attributes.insert(key.as_ref(), true);
+}
+
+fn decode_style_attributes(element: &HtmlElement, context: &mut DecodeContext) {
+  let inline = &mut context.inline;
+  if let Some(color) = element.style("color").and_then(|s| sanitize_css_value(&s)) {
+    inline.insert(BuildInTextAttributeKey::Color.as_ref(), color);
+  }
+  if element.name != "mark" {
+    if let Some(background) = element
+      .style("background-color")
+      .and_then(|s| sanitize_css_value(&s))
+    {
+      inline.insert(BuildInTextAttributeKey::Background.as_ref(), background);
+    }
+  }
+  if let Some(size) = element.style("font-size").and_then(|s| parse_pixel(&s)) {
+    inline.insert(BuildInTextAttributeKey::Size.as_ref(), size);
+  }
+  if let Some(width) = element.style("width").and_then(|s| parse_pixel(&s)) {
+    inline.insert(BuildInTextAttributeKey::Width.as_ref(), width);
+  }
+  if let Some(height) = element.style("height").and_then(|s| parse_pixel(&s)) {
+    inline.insert(BuildInTextAttributeKey::Height.as_ref(), height);
+  }
+  if let Some(font) = element
+    .attribute("data-font")
+    .and_then(|s| s.parse::<usize>().ok())
+  {
+    inline.insert(BuildInTextAttributeKey::Font.as_ref(), font);
+  }
+
+  if is_block_element(&element.name) {
+    let block = &mut context.block;
+    if let Some(align) = element
+      .style("text-align")
+      .and_then(|s| sanitize_css_value(&s))
+    {
+      block.insert(BuildInTextAttributeKey::Align.as_ref(), align);
+    }
+    if let Some(indent) = element
+      .attribute("data-indent")
+      .and_then(|s| s.parse::<usize>().ok())
+    {
+      block.insert(BuildInTextAttributeKey::Indent.as_ref(), indent);
+    }
+  }
+}
+
+/// Returns whether the list item is checked if it's a task list item. The task list item is
+/// marked by the `data-checked` attribute or contains a checkbox.
+pub(crate) fn list_item_checked(element: &HtmlElement) -> Option<bool> {
+  if let Some(checked) = element.attribute("data-checked") {
+    return Some(checked == "true");
+  }
+  element.children.iter().find_map(|child| match child {
+    HtmlNode::Element(input)
+      if input.name == "input" && input.attribute("type") == Some("checkbox") =>
+    {
+      Some(input.attribute("checked").is_some())
+    },
+    _ => None,
+  })
+}
+
+pub(crate) fn parse_pixel(value: &str) -> Option<usize> {
+  value
+    .trim()
+    .trim_end_matches("px")
+    .trim()
+    .parse::<f64>()
+    .ok()
+    .filter(|value| *value >= 0.0)
+    .map(|value| value.round() as usize)
+}
+
+pub(crate) fn is_block_element(name: &str) -> bool {
+  matches!(
+    name,
+    "p"
+      | "div"
+      | "li"
+      | "blockquote"
+      | "pre"
+      | "h1"
+      | "h2"
+      | "h3"
+      | "h4"
+      | "h5"
+      | "h6"
+      | "section"
+      | "article"
+      | "header"
+      | "footer"
+      | "tr"
+      | "dt"
+      | "dd"
+      | "figure"
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::codec::html::{html_delta_decoder, html_delta_encoder};
+  use crate::core::{AttributeBuilder, AttributeHashMap};
+  use crate::text_delta::{BuildInTextAttribute, DeltaTextOperationBuilder, DeltaTextOperations};
+
+  fn assert_round_trip(delta: DeltaTextOperations, expected_html: &str) {
+    let html = html_delta_encoder(&delta);
+    assert_eq!(html, expected_html);
+    assert_eq!(html_delta_decoder(&html), delta);
+  }
+
+  fn attributes(entries: Vec<crate::core::AttributeEntry>) -> AttributeHashMap {
+    let mut builder = AttributeBuilder::new();
+    for entry in entries {
+      builder = builder.insert_entry(entry);
+    }
+    builder.build()
+  }
+
+  #[test]
+  fn html_delta_inline_attributes_test() {
+    let delta = DeltaTextOperationBuilder::new()
+      .insert_with_attributes("bold", attributes(vec![BuildInTextAttribute::Bold(true)]))
+      .insert_with_attributes(
+        "italic",
+        attributes(vec![BuildInTextAttribute::Italic(true)]),
+      )
+      .insert_with_attributes(
+        "underline",
+        attributes(vec![BuildInTextAttribute::Underline(true)]),
+      )
+      .insert_with_attributes(
+        "strike",
+        attributes(vec![BuildInTextAttribute::StrikeThrough(true)]),
+      )
+      .insert_with_attributes(
+        "code",
+        attributes(vec![BuildInTextAttribute::InlineCode(true)]),
+      )
+      .insert_with_attributes(
+        "link",
+        attributes(vec![BuildInTextAttribute::Link("https://appflowy.io")]),
+      )
+      .insert("\n")
+      .build();
+    assert_round_trip(
+      delta,
+      r#"<p><strong>bold</strong><em>italic</em><u>underline</u><s>strike</s><code>code</code><a href="https://appflowy.io">link</a></p>"#,
+    );
+  }
+
+  #[test]
+  fn html_delta_style_attributes_test() {
+    let delta = DeltaTextOperationBuilder::new()
+      .insert_with_attributes(
+        "styled",
+        attributes(vec![
+          BuildInTextAttribute::Color("#ff0000".to_owned()),
+          BuildInTextAttribute::Background("#ffefe3".to_owned()),
+          BuildInTextAttribute::Size(14),
+          BuildInTextAttribute::Width(100),
+          BuildInTextAttribute::Height(20),
+          BuildInTextAttribute::Font(2),
+        ]),
+      )
+      .insert("\n")
+      .build();
+    assert_round_trip(
+      delta,
+      r##"<p><span style="color: #ff0000; background-color: #ffefe3; font-size: 14px; width: 100px; height: 20px" data-font="2">styled</span></p>"##,
+    );
+  }
+
+  #[test]
+  fn html_delta_block_attributes_test() {
+    let delta = DeltaTextOperationBuilder::new()
+      .insert("header")
+      .insert_with_attributes("\n", attributes(vec![BuildInTextAttribute::Header(1)]))
+      .insert("item 1")
+      .insert_with_attributes("\n", attributes(vec![BuildInTextAttribute::Bullet(true)]))
+      .insert("item 2")
+      .insert_with_attributes("\n", attributes(vec![BuildInTextAttribute::Ordered(true)]))
+      .insert("done")
+      .insert_with_attributes("\n", attributes(vec![BuildInTextAttribute::Checked(true)]))
+      .insert("todo")
+      .insert_with_attributes(
+        "\n",
+        attributes(vec![BuildInTextAttribute::UnChecked(true)]),
+      )
+      .insert("quote")
+      .insert_with_attributes(
+        "\n",
+        attributes(vec![BuildInTextAttribute::BlockQuote(true)]),
+      )
+      .insert("fn main() {")
+      .insert_with_attributes(
+        "\n",
+        attributes(vec![BuildInTextAttribute::CodeBlock(true)]),
+      )
+      .insert("}")
+      .insert_with_attributes(
+        "\n",
+        attributes(vec![BuildInTextAttribute::CodeBlock(true)]),
+      )
+      .insert("aligned")
+      .insert_with_attributes(
+        "\n",
+        attributes(vec![
+          BuildInTextAttribute::Align("center".to_owned()),
+          BuildInTextAttribute::Indent(1),
+        ]),
+      )
+      .build();
+    assert_round_trip(
+      delta,
+      r#"<h1>header</h1><ul><li>item 1</li></ul><ol><li>item 2</li></ol><ul><li data-checked="true">done</li><li data-checked="false">todo</li></ul><blockquote><p>quote</p></blockquote><pre><code>fn main() {
+}</code></pre><p data-indent="1" style="text-align: center">aligned</p>"#,
+    );
+  }
+
+  #[test]
+  fn html_delta_empty_line_test() {
+    let delta = DeltaTextOperationBuilder::new().insert("a\n\nb\n").build();
+    assert_round_trip(delta, "<p>a</p><p><br></p><p>b</p>");
+  }
+
+  #[test]
+  fn html_delta_escape_test() {
+    let delta = DeltaTextOperationBuilder::new()
+      .insert("<script>alert(\"x\")</script> & more\n")
+      .build();
+    assert_round_trip(
+      delta,
+      "<p>&lt;script&gt;alert(&quot;x&quot;)&lt;/script&gt; &amp; more</p>",
+    );
+  }
+
+  #[test]
+  fn html_delta_sanitize_test() {
+    let delta = html_delta_decoder(
+      r#"<div onclick="x()"><a href="javascript:alert(1)">link</a><script>alert(1)</script></div>"#,
+    );
+    assert_eq!(
+      delta,
+      DeltaTextOperationBuilder::new().insert("link\n").build()
+    );
+  }
+}
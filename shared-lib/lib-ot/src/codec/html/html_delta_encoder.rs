@@ -0,0 +1,210 @@
+use crate::codec::html::html_dom::{escape_html, sanitize_css_value, sanitize_url};
+use crate::core::AttributeHashMap;
+use crate::text_delta::{BuildInTextAttributeKey, DeltaTextOperations};
+
+/// Encodes the [DeltaTextOperations] into HTML.
+///
+/// Each line of the delta becomes a block element. The attributes of the line break decide
+/// which element it is, for example, the `header` attribute produces the `h1`...`h6` elements.
+/// The consecutive lines of the same list, quote or code block share the same wrapper element.
+pub fn html_delta_encoder(delta: &DeltaTextOperations) -> String {
+  let mut lines: Vec<(String, AttributeHashMap)> = vec![];
+  let mut line = String::new();
+  for op in delta.ops.iter().filter(|op| op.is_insert()) {
+    let attributes = op.get_attributes();
+    let mut segments = op.get_data().split('\n').peekable();
+    while let Some(segment) = segments.next() {
+      line.push_str(&html_inline_span(segment, &attributes));
+      if segments.peek().is_some() {
+        lines.push((std::mem::take(&mut line), attributes.clone()));
+      }
+    }
+  }
+  if !line.is_empty() {
+    lines.push((line, AttributeHashMap::new()));
+  }
+
+  let mut html = String::new();
+  let mut index = 0;
+  while index < lines.len() {
+    let wrapper = line_wrapper(&lines[index].1);
+    let mut end = index + 1;
+    while end < lines.len() && wrapper.is_some() && line_wrapper(&lines[end].1) == wrapper {
+      end += 1;
+    }
+
+    match wrapper {
+      Some(LineWrapper::CodeBlock) => {
+        let code = lines[index..end]
+          .iter()
+          .map(|(line, _)| line.as_str())
+          .collect::<Vec<_>>()
+          .join("\n");
+        html.push_str(&format!("<pre><code>{}</code></pre>", code));
+      },
+      Some(wrapper) => {
+        html.push_str(&format!("<{}>", wrapper.tag()));
+        for (line, attributes) in &lines[index..end] {
+          html.push_str(&html_line(line, attributes));
+        }
+        html.push_str(&format!("</{}>", wrapper.tag()));
+      },
+      None => html.push_str(&html_line(&lines[index].0, &lines[index].1)),
+    }
+    index = end;
+  }
+  html
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LineWrapper {
+  CodeBlock,
+  BulletList,
+  OrderedList,
+  BlockQuote,
+}
+
+impl LineWrapper {
+  fn tag(&self) -> &'static str {
+    match self {
+      LineWrapper::CodeBlock => "pre",
+      LineWrapper::BulletList => "ul",
+      LineWrapper::OrderedList => "ol",
+      LineWrapper::BlockQuote => "blockquote",
+    }
+  }
+}
+
+fn line_wrapper(attributes: &AttributeHashMap) -> Option<LineWrapper> {
+  if is_true(attributes, BuildInTextAttributeKey::CodeBlock) {
+    return Some(LineWrapper::CodeBlock);
+  }
+  if let Some(list) = str_value(attributes, BuildInTextAttributeKey::List) {
+    return match list.as_str() {
+      "ordered" => Some(LineWrapper::OrderedList),
+      _ => Some(LineWrapper::BulletList),
+    };
+  }
+  if is_true(attributes, BuildInTextAttributeKey::BlockQuote) {
+    return Some(LineWrapper::BlockQuote);
+  }
+  None
+}
+
+fn html_line(content: &str, attributes: &AttributeHashMap) -> String {
+  let header =
+    int_value(attributes, BuildInTextAttributeKey::Header).filter(|h| (1..=6).contains(h));
+  let list = str_value(attributes, BuildInTextAttributeKey::List);
+  let tag = match (header, &list) {
+    (Some(header), _) => format!("h{}", header),
+    (None, Some(_)) => "li".to_owned(),
+    (None, None) => "p".to_owned(),
+  };
+
+  let mut html_attributes = String::new();
+  match list.as_deref() {
+    Some("checked") => html_attributes.push_str(r#" data-checked="true""#),
+    Some("unchecked") => html_attributes.push_str(r#" data-checked="false""#),
+    _ => {},
+  }
+  if let Some(indent) = int_value(attributes, BuildInTextAttributeKey::Indent) {
+    html_attributes.push_str(&format!(r#" data-indent="{}""#, indent));
+  }
+  if let Some(align) =
+    str_value(attributes, BuildInTextAttributeKey::Align).and_then(|s| sanitize_css_value(&s))
+  {
+    html_attributes.push_str(&format!(r#" style="text-align: {}""#, align));
+  }
+
+  // The browsers collapse the empty element, so the empty line is kept by a line break.
+  if header.is_none() && list.is_none() && content.is_empty() {
+    return format!("<{}{}><br></{}>", tag, html_attributes, tag);
+  }
+  format!("<{}{}>{}</{}>", tag, html_attributes, content, tag)
+}
+
+/// Encodes the text with its inline attributes. The block attributes are ignored.
+fn html_inline_span(text: &str, attributes: &AttributeHashMap) -> String {
+  if text.is_empty() {
+    return String::new();
+  }
+
+  let mut html = escape_html(text);
+  if is_true(attributes, BuildInTextAttributeKey::InlineCode) {
+    html = format!("<code>{}</code>", html);
+  }
+  if is_true(attributes, BuildInTextAttributeKey::StrikeThrough) {
+    html = format!("<s>{}</s>", html);
+  }
+  if is_true(attributes, BuildInTextAttributeKey::Underline) {
+    html = format!("<u>{}</u>", html);
+  }
+  if is_true(attributes, BuildInTextAttributeKey::Italic) {
+    html = format!("<em>{}</em>", html);
+  }
+  if is_true(attributes, BuildInTextAttributeKey::Bold) {
+    html = format!("<strong>{}</strong>", html);
+  }
+
+  let mut styles = vec![];
+  if let Some(color) =
+    str_value(attributes, BuildInTextAttributeKey::Color).and_then(|s| sanitize_css_value(&s))
+  {
+    styles.push(format!("color: {}", color));
+  }
+  if let Some(background) =
+    str_value(attributes, BuildInTextAttributeKey::Background).and_then(|s| sanitize_css_value(&s))
+  {
+    styles.push(format!("background-color: {}", background));
+  }
+  if let Some(size) = int_value(attributes, BuildInTextAttributeKey::Size) {
+    styles.push(format!("font-size: {}px", size));
+  }
+  if let Some(width) = int_value(attributes, BuildInTextAttributeKey::Width) {
+    styles.push(format!("width: {}px", width));
+  }
+  if let Some(height) = int_value(attributes, BuildInTextAttributeKey::Height) {
+    styles.push(format!("height: {}px", height));
+  }
+  let font = int_value(attributes, BuildInTextAttributeKey::Font);
+  if !styles.is_empty() || font.is_some() {
+    let mut span_attributes = String::new();
+    if !styles.is_empty() {
+      span_attributes.push_str(&format!(r#" style="{}""#, styles.join("; ")));
+    }
+    if let Some(font) = font {
+      span_attributes.push_str(&format!(r#" data-font="{}""#, font));
+    }
+    html = format!("<span{}>{}</span>", span_attributes, html);
+  }
+
+  if let Some(link) =
+    str_value(attributes, BuildInTextAttributeKey::Link).and_then(|s| sanitize_url(&s))
+  {
+    html = format!(r#"<a href="{}">{}</a>"#, escape_html(&link), html);
+  }
+  html
+}
+
+fn is_true(attributes: &AttributeHashMap, key: BuildInTextAttributeKey) -> bool {
+  attributes
+    .get(key.as_ref())
+    .and_then(|value| value.bool_value())
+    .unwrap_or(false)
+}
+
+fn str_value(attributes: &AttributeHashMap, key: BuildInTextAttributeKey) -> Option<String> {
+  attributes
+    .get(key.as_ref())
+    .and_then(|value| value.str_value())
+    .filter(|value| !value.is_empty())
+}
+
+fn int_value(attributes: &AttributeHashMap, key: BuildInTextAttributeKey) -> Option<i64> {
+  attributes
+    .get(key.as_ref())?
+    .value
+    .as_ref()?
+    .parse::<i64>()
+    .ok()
+}
@@ -0,0 +1,482 @@
+//! A small and forgiving HTML parser that builds the [HtmlNode] tree consumed by the HTML
+//! decoders. It only keeps what the decoders need: the elements, their attributes and the text.
+//! The comments, the doctype and the content of the elements that can't be displayed as
+//! document content, for example, `script` or `style`, are dropped while parsing.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum HtmlNode {
+  Element(HtmlElement),
+  Text(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct HtmlElement {
+  pub name: String,
+  pub attributes: Vec<(String, String)>,
+  pub children: Vec<HtmlNode>,
+}
+
+impl HtmlElement {
+  fn new(name: String, attributes: Vec<(String, String)>) -> Self {
+    Self {
+      name,
+      attributes,
+      children: vec![],
+    }
+  }
+
+  pub fn attribute(&self, name: &str) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find(|(key, _)| key == name)
+      .map(|(_, value)| value.as_str())
+  }
+
+  /// Returns the value of the CSS property declared in the `style` attribute.
+  pub fn style(&self, property: &str) -> Option<String> {
+    self.attribute("style")?.split(';').find_map(|declaration| {
+      let (key, value) = declaration.split_once(':')?;
+      if key.trim().eq_ignore_ascii_case(property) {
+        Some(value.trim().to_owned())
+      } else {
+        None
+      }
+    })
+  }
+
+  /// Returns the text of the element and its descendants.
+  pub fn text(&self) -> String {
+    let mut text = String::new();
+    for child in &self.children {
+      match child {
+        HtmlNode::Element(element) => text.push_str(&element.text()),
+        HtmlNode::Text(s) => text.push_str(s),
+      }
+    }
+    text
+  }
+}
+
+const VOID_ELEMENTS: [&str; 12] = [
+  "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "wbr",
+];
+
+const DROPPED_ELEMENTS: [&str; 9] = [
+  "script", "style", "iframe", "object", "noscript", "template", "head", "title", "svg",
+];
+
+/// Parses the HTML into a list of nodes. The unclosed elements are closed at the end of the
+/// input and the unmatched end tags are ignored.
+pub(crate) fn parse_html(html: &str) -> Vec<HtmlNode> {
+  let mut stack: Vec<HtmlElement> = vec![HtmlElement::default()];
+  let mut tokenizer = HtmlTokenizer::new(html);
+  while let Some(token) = tokenizer.next_token() {
+    match token {
+      HtmlToken::Text(text) => {
+        if let Some(parent) = stack.last_mut() {
+          parent.children.push(HtmlNode::Text(text));
+        }
+      },
+      HtmlToken::StartTag {
+        name,
+        attributes,
+        self_closing,
+      } => {
+        if DROPPED_ELEMENTS.contains(&name.as_str()) {
+          if !self_closing {
+            tokenizer.skip_until_end_tag(&name);
+          }
+          continue;
+        }
+
+        let element = HtmlElement::new(name, attributes);
+        if self_closing || VOID_ELEMENTS.contains(&element.name.as_str()) {
+          if let Some(parent) = stack.last_mut() {
+            parent.children.push(HtmlNode::Element(element));
+          }
+        } else {
+          stack.push(element);
+        }
+      },
+      HtmlToken::EndTag { name } => {
+        // Ignore the end tag if there is no open element with the same name.
+        if let Some(index) = stack.iter().rposition(|element| element.name == name) {
+          if index == 0 {
+            continue;
+          }
+          while stack.len() > index {
+            close_element(&mut stack);
+          }
+        }
+      },
+    }
+  }
+
+  while stack.len() > 1 {
+    close_element(&mut stack);
+  }
+  stack.pop().map(|root| root.children).unwrap_or_default()
+}
+
+fn close_element(stack: &mut Vec<HtmlElement>) {
+  if let Some(element) = stack.pop() {
+    if let Some(parent) = stack.last_mut() {
+      parent.children.push(HtmlNode::Element(element));
+    }
+  }
+}
+
+enum HtmlToken {
+  StartTag {
+    name: String,
+    attributes: Vec<(String, String)>,
+    self_closing: bool,
+  },
+  EndTag {
+    name: String,
+  },
+  Text(String),
+}
+
+struct HtmlTokenizer<'a> {
+  html: &'a str,
+  position: usize,
+}
+
+impl<'a> HtmlTokenizer<'a> {
+  fn new(html: &'a str) -> Self {
+    Self { html, position: 0 }
+  }
+
+  fn rest(&self) -> &'a str {
+    &self.html[self.position..]
+  }
+
+  fn next_token(&mut self) -> Option<HtmlToken> {
+    loop {
+      let rest = self.rest();
+      if rest.is_empty() {
+        return None;
+      }
+
+      if let Some(after) = rest.strip_prefix("<!--") {
+        self.position += 4 + after.find("-->").map(|i| i + 3).unwrap_or(after.len());
+        continue;
+      }
+
+      if rest.starts_with("<!") || rest.starts_with("<?") {
+        self.position += rest.find('>').map(|i| i + 1).unwrap_or(rest.len());
+        continue;
+      }
+
+      if let Some(after) = rest.strip_prefix("</") {
+        let end = after.find('>').unwrap_or(after.len());
+        let name = after[..end].trim().to_ascii_lowercase();
+        self.position += 2 + (end + 1).min(after.len());
+        return Some(HtmlToken::EndTag { name });
+      }
+
+      if rest.starts_with('<')
+        && rest[1..]
+          .chars()
+          .next()
+          .map(|c| c.is_ascii_alphabetic())
+          .unwrap_or(false)
+      {
+        return Some(self.read_start_tag());
+      }
+
+      // The text runs until the next tag. A `<` that doesn't start a tag is a part of the text.
+      let first_len = rest.chars().next().map(|c| c.len_utf8()).unwrap_or(1);
+      let end = rest[first_len..]
+        .find('<')
+        .map(|i| i + first_len)
+        .unwrap_or(rest.len());
+      self.position += end;
+      return Some(HtmlToken::Text(decode_entities(&rest[..end])));
+    }
+  }
+
+  fn read_start_tag(&mut self) -> HtmlToken {
+    let rest = self.rest();
+    let bytes = rest.as_bytes();
+    let mut index = 1;
+    while index < bytes.len()
+      && !bytes[index].is_ascii_whitespace()
+      && bytes[index] != b'>'
+      && bytes[index] != b'/'
+    {
+      index += 1;
+    }
+    let name = rest[1..index].to_ascii_lowercase();
+
+    let mut attributes = vec![];
+    let mut self_closing = false;
+    loop {
+      while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+        index += 1;
+      }
+      if index >= bytes.len() {
+        break;
+      }
+      match bytes[index] {
+        b'>' => {
+          index += 1;
+          break;
+        },
+        b'/' => {
+          self_closing = true;
+          index += 1;
+          continue;
+        },
+        _ => {},
+      }
+
+      let start = index;
+      while index < bytes.len()
+        && !bytes[index].is_ascii_whitespace()
+        && !matches!(bytes[index], b'=' | b'>' | b'/')
+      {
+        index += 1;
+      }
+      let key = rest[start..index].to_ascii_lowercase();
+      if start == index {
+        // Skip the unexpected character, for example, a `=` without the attribute name.
+        index += 1;
+        continue;
+      }
+
+      while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+        index += 1;
+      }
+      let mut value = String::new();
+      if index < bytes.len() && bytes[index] == b'=' {
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+          index += 1;
+        }
+        if index < bytes.len() && (bytes[index] == b'"' || bytes[index] == b'\'') {
+          let quote = bytes[index];
+          let value_start = index + 1;
+          index = value_start;
+          while index < bytes.len() && bytes[index] != quote {
+            index += 1;
+          }
+          value = decode_entities(&rest[value_start..index]);
+          index = (index + 1).min(bytes.len());
+        } else {
+          let value_start = index;
+          while index < bytes.len() && !bytes[index].is_ascii_whitespace() && bytes[index] != b'>' {
+            index += 1;
+          }
+          value = decode_entities(&rest[value_start..index]);
+        }
+      }
+      attributes.push((key, value));
+    }
+
+    self.position += index;
+    HtmlToken::StartTag {
+      name,
+      attributes,
+      self_closing,
+    }
+  }
+
+  fn skip_until_end_tag(&mut self, name: &str) {
+    let rest = self.rest();
+    let lowercase = rest.to_ascii_lowercase();
+    let end_tag = format!("</{}", name);
+    match lowercase.find(&end_tag) {
+      None => self.position = self.html.len(),
+      Some(start) => {
+        let end = rest[start..]
+          .find('>')
+          .map(|i| start + i + 1)
+          .unwrap_or(rest.len());
+        self.position += end;
+      },
+    }
+  }
+}
+
+fn decode_entities(s: &str) -> String {
+  if !s.contains('&') {
+    return s.to_owned();
+  }
+
+  let mut decoded = String::with_capacity(s.len());
+  let mut rest = s;
+  while let Some(start) = rest.find('&') {
+    decoded.push_str(&rest[..start]);
+    rest = &rest[start..];
+    let entity = rest[1..]
+      .find(';')
+      .filter(|end| *end <= 10)
+      .and_then(|end| decode_entity(&rest[1..end + 1]).map(|c| (c, end + 2)));
+    match entity {
+      Some((c, len)) => {
+        decoded.push(c);
+        rest = &rest[len..];
+      },
+      None => {
+        decoded.push('&');
+        rest = &rest[1..];
+      },
+    }
+  }
+  decoded.push_str(rest);
+  decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+  match entity {
+    "amp" => Some('&'),
+    "lt" => Some('<'),
+    "gt" => Some('>'),
+    "quot" => Some('"'),
+    "apos" => Some('\''),
+    "nbsp" => Some('\u{a0}'),
+    _ => {
+      let number = entity.strip_prefix('#')?;
+      let code = match number
+        .strip_prefix('x')
+        .or_else(|| number.strip_prefix('X'))
+      {
+        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+        None => number.parse::<u32>().ok()?,
+      };
+      char::from_u32(code)
+    },
+  }
+}
+
+pub(crate) fn escape_html(s: &str) -> String {
+  let mut escaped = String::with_capacity(s.len());
+  for c in s.chars() {
+    match c {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&#39;"),
+      _ => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+/// Returns the url if it's safe to be used as a link, otherwise returns None. The urls with a
+/// scheme other than http, https and mailto, for example, `javascript:`, are rejected.
+pub(crate) fn sanitize_url(url: &str) -> Option<String> {
+  let url = url.trim();
+  if url.is_empty() {
+    return None;
+  }
+  let lowercase = url.to_ascii_lowercase();
+  let scheme_end = lowercase.find(|c: char| matches!(c, ':' | '/' | '?' | '#'));
+  match scheme_end {
+    Some(index) if lowercase[index..].starts_with(':') => {
+      let scheme = &lowercase[..index];
+      if matches!(scheme, "http" | "https" | "mailto") {
+        Some(url.to_owned())
+      } else {
+        None
+      }
+    },
+    _ => Some(url.to_owned()),
+  }
+}
+
+/// Returns the CSS value if it only contains the characters used by colors and sizes, which
+/// prevents the value from escaping the `style` attribute.
+pub(crate) fn sanitize_css_value(value: &str) -> Option<String> {
+  let value = value.trim();
+  if !value.is_empty()
+    && value.chars().all(|c| {
+      c.is_ascii_alphanumeric() || matches!(c, '#' | '(' | ')' | ',' | '.' | ' ' | '%' | '-')
+    })
+  {
+    Some(value.to_owned())
+  } else {
+    None
+  }
+}
+
+/// Replaces the runs of whitespace with a single space, like the browser does when it renders
+/// the text outside of a `pre` element.
+pub(crate) fn collapse_whitespace(s: &str) -> String {
+  let mut collapsed = String::with_capacity(s.len());
+  let mut is_previous_whitespace = false;
+  for c in s.chars() {
+    if c.is_whitespace() && c != '\u{a0}' {
+      if !is_previous_whitespace {
+        collapsed.push(' ');
+      }
+      is_previous_whitespace = true;
+    } else {
+      collapsed.push(c);
+      is_previous_whitespace = false;
+    }
+  }
+  collapsed
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::codec::html::html_dom::{parse_html, sanitize_url, HtmlElement, HtmlNode};
+
+  fn element(name: &str, attributes: Vec<(&str, &str)>, children: Vec<HtmlNode>) -> HtmlNode {
+    HtmlNode::Element(HtmlElement {
+      name: name.to_owned(),
+      attributes: attributes
+        .into_iter()
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        .collect(),
+      children,
+    })
+  }
+
+  #[test]
+  fn parse_html_test() {
+    let nodes = parse_html(r#"<p class=a>Hello <b>world</b>&amp;<br/><img src="x.png"></p>"#);
+    assert_eq!(
+      nodes,
+      vec![element(
+        "p",
+        vec![("class", "a")],
+        vec![
+          HtmlNode::Text("Hello ".to_owned()),
+          element("b", vec![], vec![HtmlNode::Text("world".to_owned())]),
+          HtmlNode::Text("&".to_owned()),
+          element("br", vec![], vec![]),
+          element("img", vec![("src", "x.png")], vec![]),
+        ]
+      )]
+    );
+  }
+
+  #[test]
+  fn parse_html_drop_script_test() {
+    let nodes = parse_html("<!-- comment --><script>alert('<p>')</script><p>text</p>");
+    assert_eq!(
+      nodes,
+      vec![element(
+        "p",
+        vec![],
+        vec![HtmlNode::Text("text".to_owned())]
+      )]
+    );
+  }
+
+  #[test]
+  fn sanitize_url_test() {
+    assert_eq!(
+      sanitize_url("https://appflowy.io"),
+      Some("https://appflowy.io".to_owned())
+    );
+    assert_eq!(sanitize_url("/docs"), Some("/docs".to_owned()));
+    assert_eq!(sanitize_url("JavaScript:alert(1)"), None);
+  }
+}
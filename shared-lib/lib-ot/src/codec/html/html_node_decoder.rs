@@ -0,0 +1,304 @@
+use crate::codec::editor_node::*;
+use crate::codec::html::html_delta_decoder::{is_block_element, list_item_checked};
+use crate::codec::html::html_dom::{
+  collapse_whitespace, parse_html, sanitize_url, HtmlElement, HtmlNode,
+};
+use crate::codec::html::html_node_encoder::css_color_to_editor;
+use crate::core::{AttributeHashMap, Body, NodeData};
+use crate::text_delta::DeltaTextOperations;
+
+/// Decodes the HTML into the `editor` node of a document.
+///
+/// The inline content outside of any block element becomes a paragraph. The first paragraph of
+/// a list item is the text of the item and its other blocks become the children of the item.
+pub fn html_node_decoder(html: &str) -> NodeData {
+  let mut editor = NodeData::new(EDITOR_NODE_TYPE);
+  editor.children = decode_blocks(&parse_html(html), &BlockContext::default());
+  if editor.children.is_empty() {
+    editor.children.push(NodeData::new(TEXT_NODE_TYPE));
+  }
+  editor
+}
+
+#[derive(Clone, Default)]
+struct BlockContext {
+  in_quote: bool,
+}
+
+/// The text segments of the paragraph that is being decoded.
+type InlineSegments = Vec<(String, AttributeHashMap)>;
+
+fn decode_blocks(nodes: &[HtmlNode], context: &BlockContext) -> Vec<NodeData> {
+  let mut blocks = vec![];
+  let mut segments = InlineSegments::new();
+  for node in nodes {
+    match node {
+      HtmlNode::Element(element) if is_block(element) => {
+        flush_paragraph(&mut segments, context, &mut blocks);
+        decode_block(element, context, &mut blocks);
+      },
+      _ => decode_inline(node, &AttributeHashMap::new(), &mut segments),
+    }
+  }
+  flush_paragraph(&mut segments, context, &mut blocks);
+  blocks
+}
+
+fn is_block(element: &HtmlElement) -> bool {
+  is_block_element(&element.name)
+    || matches!(
+      element.name.as_str(),
+      "ul" | "ol" | "hr" | "img" | "table" | "tbody" | "thead"
+    )
+}
+
+fn decode_block(element: &HtmlElement, context: &BlockContext, blocks: &mut Vec<NodeData>) {
+  match element.name.as_str() {
+    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+      let mut node = text_node(inline_segments(&element.children));
+      node.attributes.insert(SUBTYPE, HEADING_SUBTYPE);
+      node.attributes.insert(HEADING_ATTR, element.name.clone());
+      blocks.push(node);
+    },
+    "ul" | "ol" => {
+      let mut number = element
+        .attribute("start")
+        .and_then(|s| s.parse::<i64>().ok())
+        .unwrap_or(1);
+      for child in &element.children {
+        if let HtmlNode::Element(item) = child {
+          if item.name == "li" {
+            blocks.push(decode_list_item(item, &element.name, number, context));
+            number += 1;
+          } else {
+            blocks.extend(decode_blocks(&[child.clone()], context));
+          }
+        }
+      }
+    },
+    "blockquote" => {
+      let context = BlockContext { in_quote: true };
+      blocks.extend(decode_blocks(&element.children, &context));
+    },
+    "pre" => {
+      let text = element.text();
+      let mut node = text_node(vec![(
+        text.trim_end_matches('\n').to_owned(),
+        AttributeHashMap::new(),
+      )]);
+      node.attributes.insert(SUBTYPE, CODE_BLOCK_SUBTYPE);
+      if let Some(language) = code_language(element) {
+        node.attributes.insert(LANGUAGE_ATTR, language);
+      }
+      blocks.push(node);
+    },
+    "hr" => blocks.push(NodeData::new(DIVIDER_NODE_TYPE)),
+    "img" => {
+      if let Some(src) = element.attribute("src").and_then(sanitize_url) {
+        let mut node = NodeData::new(IMAGE_NODE_TYPE);
+        node.attributes.insert(IMAGE_SRC_ATTR, src);
+        blocks.push(node);
+      }
+    },
+    _ => {
+      let children = decode_blocks(&element.children, context);
+      if children.is_empty() && element.name == "p" {
+        // The empty paragraph is an empty line.
+        blocks.push(quote_if_needed(text_node(vec![]), context));
+      } else {
+        blocks.extend(children);
+      }
+    },
+  }
+}
+
+fn decode_list_item(
+  item: &HtmlElement,
+  list_name: &str,
+  number: i64,
+  context: &BlockContext,
+) -> NodeData {
+  let mut children = decode_blocks(&item.children, context);
+  let is_first_paragraph = children
+    .first()
+    .map(|node| node.node_type == TEXT_NODE_TYPE && node_subtype(node).is_none())
+    .unwrap_or(false);
+  let mut node = if is_first_paragraph {
+    children.remove(0)
+  } else {
+    text_node(vec![])
+  };
+
+  match (list_item_checked(item), list_name) {
+    (Some(checked), _) => {
+      node.attributes.insert(SUBTYPE, CHECKBOX_SUBTYPE);
+      node.attributes.insert(CHECKBOX_ATTR, checked);
+    },
+    (None, "ol") => {
+      node.attributes.insert(SUBTYPE, NUMBER_LIST_SUBTYPE);
+      node.attributes.insert(NUMBER_ATTR, number);
+    },
+    (None, _) => node.attributes.insert(SUBTYPE, BULLETED_LIST_SUBTYPE),
+  }
+  node.children.extend(children);
+  node
+}
+
+fn code_language(pre: &HtmlElement) -> Option<String> {
+  let code = pre.children.iter().find_map(|child| match child {
+    HtmlNode::Element(element) if element.name == "code" => Some(element),
+    _ => None,
+  })?;
+  code
+    .attribute("class")?
+    .split_whitespace()
+    .find_map(|class| class.strip_prefix("language-"))
+    .map(|language| language.to_owned())
+}
+
+fn inline_segments(nodes: &[HtmlNode]) -> InlineSegments {
+  let mut segments = InlineSegments::new();
+  for node in nodes {
+    decode_inline(node, &AttributeHashMap::new(), &mut segments);
+  }
+  segments
+}
+
+fn decode_inline(node: &HtmlNode, attributes: &AttributeHashMap, segments: &mut InlineSegments) {
+  let element = match node {
+    HtmlNode::Text(text) => {
+      segments.push((collapse_whitespace(text), attributes.clone()));
+      return;
+    },
+    HtmlNode::Element(element) => element,
+  };
+
+  let mut attributes = attributes.clone();
+  match element.name.as_str() {
+    "br" => {
+      segments.push(("\n".to_owned(), attributes));
+      return;
+    },
+    "input" => return,
+    "strong" | "b" => attributes.insert(BOLD_ATTR, true),
+    "em" | "i" => attributes.insert(ITALIC_ATTR, true),
+    "u" | "ins" => attributes.insert(UNDERLINE_ATTR, true),
+    "s" | "strike" | "del" => attributes.insert(STRIKETHROUGH_ATTR, true),
+    "code" => attributes.insert(CODE_ATTR, true),
+    "a" => {
+      if let Some(href) = element.attribute("href").and_then(sanitize_url) {
+        attributes.insert(HREF_ATTR, href);
+      }
+    },
+    _ => {},
+  }
+  if let Some(color) = element.style("color").and_then(|s| css_color_to_editor(&s)) {
+    attributes.insert(COLOR_ATTR, color);
+  }
+  if let Some(background) = element
+    .style("background-color")
+    .and_then(|s| css_color_to_editor(&s))
+  {
+    attributes.insert(BACKGROUND_COLOR_ATTR, background);
+  }
+
+  for child in &element.children {
+    decode_inline(child, &attributes, segments);
+  }
+}
+
+fn flush_paragraph(
+  segments: &mut InlineSegments,
+  context: &BlockContext,
+  blocks: &mut Vec<NodeData>,
+) {
+  let segments = std::mem::take(segments);
+  if segments.iter().all(|(text, _)| text.trim().is_empty()) {
+    return;
+  }
+  blocks.push(quote_if_needed(text_node(segments), context));
+}
+
+fn quote_if_needed(mut node: NodeData, context: &BlockContext) -> NodeData {
+  if context.in_quote {
+    node.attributes.insert(SUBTYPE, QUOTE_SUBTYPE);
+  }
+  node
+}
+
+/// Returns the `text` node of the segments. The leading and trailing whitespaces and the
+/// trailing line breaks are removed, as the browser does when it renders the block.
+fn text_node(mut segments: InlineSegments) -> NodeData {
+  if let Some((text, _)) = segments.first_mut() {
+    *text = text.trim_start().to_owned();
+  }
+  while let Some((text, _)) = segments.last_mut() {
+    let trimmed = text.trim_end().to_owned();
+    if trimmed.is_empty() {
+      segments.pop();
+    } else {
+      *text = trimmed;
+      break;
+    }
+  }
+
+  let mut delta = DeltaTextOperations::default();
+  for (text, attributes) in segments {
+    delta.insert(&text, attributes);
+  }
+  let mut node = NodeData::new(TEXT_NODE_TYPE);
+  node.body = Body::Delta(delta);
+  node
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::codec::html::{html_node_decoder, html_node_encoder};
+  use crate::core::NodeData;
+
+  fn assert_round_trip(json: &str, expected_html: &str) {
+    let nodes: Vec<NodeData> = serde_json::from_str(json).unwrap();
+    let html = html_node_encoder(&nodes);
+    assert_eq!(html, expected_html);
+    assert_eq!(html_node_decoder(&html).children, nodes);
+  }
+
+  #[test]
+  fn html_node_inline_attributes_test() {
+    assert_round_trip(
+      r#"[{"type":"text","body":{"delta":[{"insert":"bold","attributes":{"bold":true}},{"insert":" "},{"insert":"italic","attributes":{"italic":true}},{"insert":" "},{"insert":"underline","attributes":{"underline":true}},{"insert":" "},{"insert":"strike","attributes":{"strikethrough":true}},{"insert":" "},{"insert":"code","attributes":{"code":true}},{"insert":" "},{"insert":"link","attributes":{"href":"https://appflowy.io"}},{"insert":" "},{"insert":"color","attributes":{"color":"0xff00b5ff","backgroundColor":"0x4dffeb3b"}}]}}]"#,
+      r##"<p><strong>bold</strong> <em>italic</em> <u>underline</u> <s>strike</s> <code>code</code> <a href="https://appflowy.io">link</a> <span style="color: #00b5ffff; background-color: #ffeb3b4d">color</span></p>"##,
+    );
+  }
+
+  #[test]
+  fn html_node_blocks_test() {
+    assert_round_trip(
+      r#"[{"type":"text","attributes":{"subtype":"heading","heading":"h2"},"body":{"delta":[{"insert":"heading"}]}},{"type":"text","attributes":{"subtype":"quote"},"body":{"delta":[{"insert":"quote"}]}},{"type":"text","attributes":{"subtype":"code_block","language":"rust"},"body":{"delta":[{"insert":"fn main() {\n}"}]}},{"type":"divider"},{"type":"image","attributes":{"image_src":"https://appflowy.io/logo.png"}},{"type":"text","body":{"delta":[]}}]"#,
+      r#"<h2>heading</h2><blockquote>quote</blockquote><pre><code class="language-rust">fn main() {
+}</code></pre><hr><img src="https://appflowy.io/logo.png"><p><br></p>"#,
+    );
+  }
+
+  #[test]
+  fn html_node_lists_test() {
+    assert_round_trip(
+      r#"[{"type":"text","attributes":{"subtype":"number-list","number":1},"body":{"delta":[{"insert":"first"}]},"children":[{"type":"text","attributes":{"subtype":"bulleted-list"},"body":{"delta":[{"insert":"nested"}]}}]},{"type":"text","attributes":{"subtype":"number-list","number":2},"body":{"delta":[{"insert":"second"}]}},{"type":"text","attributes":{"subtype":"checkbox","checkbox":true},"body":{"delta":[{"insert":"done"}]}}]"#,
+      r#"<ol><li>first<ul><li>nested</li></ul></li><li>second</li></ol><ul><li data-checked="true">done</li></ul>"#,
+    );
+  }
+
+  #[test]
+  fn html_node_decode_browser_html_test() {
+    let html = r#"<meta charset="utf-8"><div>
+      <p>Hello <b>world</b></p>
+      <ul><li><input type="checkbox" checked> task</li></ul>
+      <script>alert(1)</script>
+    </div>"#;
+    let editor = html_node_decoder(html);
+    assert_eq!(
+      serde_json::to_string(&editor.children).unwrap(),
+      r#"[{"type":"text","body":{"delta":[{"insert":"Hello "},{"insert":"world","attributes":{"bold":true}}]}},{"type":"text","attributes":{"subtype":"checkbox","checkbox":true},"body":{"delta":[{"insert":"task"}]}}]"#
+    );
+  }
+}
@@ -0,0 +1,210 @@
+use crate::codec::editor_node::*;
+use crate::codec::html::html_dom::{escape_html, sanitize_url};
+use crate::core::{AttributeHashMap, NodeData};
+
+/// Encodes the nodes of a document into HTML.
+///
+/// The nested nodes of a list item are encoded inside the `li` element, the nested nodes of the
+/// other blocks follow the block. Like the Markdown encoder, the container nodes, for example,
+/// the `editor` node, are flattened.
+pub fn html_node_encoder(nodes: &[NodeData]) -> String {
+  let blocks = flatten_nodes(nodes);
+  let mut html = String::new();
+  let mut index = 0;
+  while index < blocks.len() {
+    let wrapper = list_wrapper(blocks[index]);
+    match wrapper {
+      None => {
+        html.push_str(&html_block(blocks[index]));
+        index += 1;
+      },
+      Some(tag) => {
+        html.push_str(&format!("<{}>", tag));
+        while index < blocks.len() && list_wrapper(blocks[index]) == Some(tag) {
+          html.push_str(&html_list_item(blocks[index]));
+          index += 1;
+        }
+        html.push_str(&format!("</{}>", tag));
+      },
+    }
+  }
+  html
+}
+
+/// Returns the nodes that are encoded as blocks, which excludes the container nodes.
+fn flatten_nodes(nodes: &[NodeData]) -> Vec<&NodeData> {
+  let mut blocks = vec![];
+  for node in nodes {
+    match node.node_type.as_str() {
+      TEXT_NODE_TYPE | DIVIDER_NODE_TYPE | IMAGE_NODE_TYPE => blocks.push(node),
+      _ => blocks.extend(flatten_nodes(&node.children)),
+    }
+  }
+  blocks
+}
+
+fn list_wrapper(node: &NodeData) -> Option<&'static str> {
+  if node.node_type != TEXT_NODE_TYPE {
+    return None;
+  }
+  match node_subtype(node).as_deref() {
+    Some(BULLETED_LIST_SUBTYPE) | Some(CHECKBOX_SUBTYPE) => Some("ul"),
+    Some(NUMBER_LIST_SUBTYPE) => Some("ol"),
+    _ => None,
+  }
+}
+
+fn html_list_item(node: &NodeData) -> String {
+  let checked = if node_subtype(node).as_deref() == Some(CHECKBOX_SUBTYPE) {
+    format!(
+      r#" data-checked="{}""#,
+      is_attribute_true(&node.attributes, CHECKBOX_ATTR)
+    )
+  } else {
+    "".to_owned()
+  };
+  format!(
+    "<li{}>{}{}</li>",
+    checked,
+    html_inline_encoder(node),
+    html_node_encoder(&node.children)
+  )
+}
+
+fn html_block(node: &NodeData) -> String {
+  let block = match node.node_type.as_str() {
+    DIVIDER_NODE_TYPE => return "<hr>".to_owned(),
+    IMAGE_NODE_TYPE => {
+      return match attribute_str(&node.attributes, IMAGE_SRC_ATTR).and_then(|s| sanitize_url(&s)) {
+        None => "".to_owned(),
+        Some(src) => format!(r#"<img src="{}">"#, escape_html(&src)),
+      };
+    },
+    _ => match node_subtype(node).as_deref() {
+      Some(HEADING_SUBTYPE) => {
+        let level = heading_level(node);
+        format!("<h{}>{}</h{}>", level, html_inline_encoder(node), level)
+      },
+      Some(QUOTE_SUBTYPE) => format!("<blockquote>{}</blockquote>", html_inline_encoder(node)),
+      Some(CODE_BLOCK_SUBTYPE) => {
+        let class = attribute_str(&node.attributes, LANGUAGE_ATTR)
+          .map(|language| format!(r#" class="language-{}""#, escape_html(&language)))
+          .unwrap_or_default();
+        format!(
+          "<pre><code{}>{}</code></pre>",
+          class,
+          escape_html(&node_plain_text(node))
+        )
+      },
+      _ => {
+        let content = html_inline_encoder(node);
+        if content.is_empty() {
+          // The browsers collapse the empty paragraph, so the empty line is kept by a line break.
+          "<p><br></p>".to_owned()
+        } else {
+          format!("<p>{}</p>", content)
+        }
+      },
+    },
+  };
+  format!("{}{}", block, html_node_encoder(&node.children))
+}
+
+/// Encodes the delta of the node into HTML by wrapping each text span with the elements of
+/// its inline attributes.
+pub fn html_inline_encoder(node: &NodeData) -> String {
+  node_delta(node)
+    .ops
+    .iter()
+    .filter(|op| op.is_insert())
+    .map(|op| html_inline_span(op.get_data(), &op.get_attributes()))
+    .collect()
+}
+
+fn html_inline_span(text: &str, attributes: &AttributeHashMap) -> String {
+  let mut html = escape_html(text).replace('\n', "<br>");
+  if is_attribute_true(attributes, CODE_ATTR) {
+    html = format!("<code>{}</code>", html);
+  }
+  if is_attribute_true(attributes, STRIKETHROUGH_ATTR) {
+    html = format!("<s>{}</s>", html);
+  }
+  if is_attribute_true(attributes, UNDERLINE_ATTR) {
+    html = format!("<u>{}</u>", html);
+  }
+  if is_attribute_true(attributes, ITALIC_ATTR) {
+    html = format!("<em>{}</em>", html);
+  }
+  if is_attribute_true(attributes, BOLD_ATTR) {
+    html = format!("<strong>{}</strong>", html);
+  }
+
+  let mut styles = vec![];
+  if let Some(color) = attribute_str(attributes, COLOR_ATTR).and_then(|s| editor_color_to_css(&s)) {
+    styles.push(format!("color: {}", color));
+  }
+  if let Some(background) =
+    attribute_str(attributes, BACKGROUND_COLOR_ATTR).and_then(|s| editor_color_to_css(&s))
+  {
+    styles.push(format!("background-color: {}", background));
+  }
+  if !styles.is_empty() {
+    html = format!(r#"<span style="{}">{}</span>"#, styles.join("; "), html);
+  }
+
+  if let Some(href) = attribute_str(attributes, HREF_ATTR).and_then(|s| sanitize_url(&s)) {
+    html = format!(r#"<a href="{}">{}</a>"#, escape_html(&href), html);
+  }
+  html
+}
+
+/// The editor stores the colors as `0xAARRGGBB`, which is converted to the CSS `#RRGGBBAA`.
+pub(crate) fn editor_color_to_css(color: &str) -> Option<String> {
+  let hex = color
+    .strip_prefix("0x")
+    .or_else(|| color.strip_prefix("0X"))?;
+  if hex.len() != 8 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+    return None;
+  }
+  let hex = hex.to_ascii_lowercase();
+  Some(format!("#{}{}", &hex[2..], &hex[..2]))
+}
+
+/// Converts the CSS color in hex or `rgb()`/`rgba()` notation to the editor's `0xAARRGGBB`.
+pub(crate) fn css_color_to_editor(color: &str) -> Option<String> {
+  let color = color.trim().to_ascii_lowercase();
+  if let Some(hex) = color.strip_prefix('#') {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+      return None;
+    }
+    let expanded = match hex.len() {
+      3 => format!("ff{}", hex.chars().flat_map(|c| [c, c]).collect::<String>()),
+      6 => format!("ff{}", hex),
+      8 => format!("{}{}", &hex[6..], &hex[..6]),
+      _ => return None,
+    };
+    return Some(format!("0x{}", expanded));
+  }
+
+  let arguments = color
+    .strip_prefix("rgba(")
+    .or_else(|| color.strip_prefix("rgb("))?
+    .strip_suffix(')')?;
+  let values = arguments
+    .split(',')
+    .map(|value| value.trim().parse::<f64>().ok())
+    .collect::<Option<Vec<f64>>>()?;
+  let (rgb, alpha) = match values.as_slice() {
+    [r, g, b] => ([*r, *g, *b], 1.0),
+    [r, g, b, a] => ([*r, *g, *b], *a),
+    _ => return None,
+  };
+  let channel = |value: f64| value.round().clamp(0.0, 255.0) as u8;
+  Some(format!(
+    "0x{:02x}{:02x}{:02x}{:02x}",
+    channel(alpha * 255.0),
+    channel(rgb[0]),
+    channel(rgb[1]),
+    channel(rgb[2])
+  ))
+}
@@ -0,0 +1,10 @@
+mod html_delta_decoder;
+mod html_delta_encoder;
+mod html_dom;
+mod html_node_decoder;
+mod html_node_encoder;
+
+pub use html_delta_decoder::*;
+pub use html_delta_encoder::*;
+pub use html_node_decoder::*;
+pub use html_node_encoder::*;
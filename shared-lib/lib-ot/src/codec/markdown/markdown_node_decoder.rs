@@ -0,0 +1,353 @@
+use crate::codec::editor_node::*;
+use crate::core::{AttributeHashMap, Body, NodeData};
+use crate::text_delta::DeltaTextOperations;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag};
+
+/// Decodes the Markdown into the `editor` node of a document.
+///
+/// The headings, lists, task lists, quotes and code blocks are decoded into `text` nodes with
+/// the corresponding subtype. The nested lists become the children of their list item. The
+/// emphasis, strong, strikethrough, inline code and links are stored as the attributes of the
+/// delta.
+pub fn markdown_node_decoder(markdown: &str) -> NodeData {
+  let mut options = Options::empty();
+  options.insert(Options::ENABLE_STRIKETHROUGH);
+  options.insert(Options::ENABLE_TASKLISTS);
+
+  let mut decoder = MarkdownNodeDecoder::new();
+  for event in Parser::new_ext(markdown, options) {
+    decoder.handle_event(event);
+  }
+  decoder.finish()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum BlockKind {
+  Container,
+  ListItem,
+  Text,
+}
+
+struct OpenBlock {
+  kind: BlockKind,
+  node: NodeData,
+  delta: DeltaTextOperations,
+}
+
+impl OpenBlock {
+  fn new(kind: BlockKind, node: NodeData) -> Self {
+    Self {
+      kind,
+      node,
+      delta: DeltaTextOperations::default(),
+    }
+  }
+
+  fn into_node_data(self) -> NodeData {
+    let mut node = self.node;
+    if self.kind != BlockKind::Container {
+      node.body = Body::Delta(self.delta);
+    }
+    node
+  }
+}
+
+struct MarkdownNodeDecoder {
+  /// The blocks that are not closed yet. The first one is the `editor` node.
+  blocks: Vec<OpenBlock>,
+  /// The next number of each nested list. None if the list is a bulleted list.
+  lists: Vec<Option<u64>>,
+  quote_depth: usize,
+  bold: usize,
+  italic: usize,
+  strikethrough: usize,
+  links: Vec<String>,
+  /// The nodes, for example, the images, that will be inserted after the current text node.
+  pending_nodes: Vec<NodeData>,
+  in_image: bool,
+}
+
+impl MarkdownNodeDecoder {
+  fn new() -> Self {
+    Self {
+      blocks: vec![OpenBlock::new(
+        BlockKind::Container,
+        NodeData::new(EDITOR_NODE_TYPE),
+      )],
+      lists: vec![],
+      quote_depth: 0,
+      bold: 0,
+      italic: 0,
+      strikethrough: 0,
+      links: vec![],
+      pending_nodes: vec![],
+      in_image: false,
+    }
+  }
+
+  fn handle_event(&mut self, event: Event) {
+    match event {
+      Event::Start(tag) => self.start_tag(tag),
+      Event::End(tag) => self.end_tag(tag),
+      Event::Text(text) => {
+        if !self.in_image {
+          self.insert_text(&text, self.inline_attributes());
+        }
+      },
+      Event::Code(code) => {
+        let mut attributes = self.inline_attributes();
+        attributes.insert(CODE_ATTR, true);
+        self.insert_text(&code, attributes);
+      },
+      Event::Html(html) => self.insert_text(&html, AttributeHashMap::new()),
+      Event::SoftBreak => self.insert_text(" ", self.inline_attributes()),
+      Event::HardBreak => self.insert_text("\n", AttributeHashMap::new()),
+      Event::Rule => self.push_node(NodeData::new(DIVIDER_NODE_TYPE)),
+      Event::TaskListMarker(checked) => {
+        if let Some(item) = self
+          .blocks
+          .iter_mut()
+          .rev()
+          .find(|block| block.kind == BlockKind::ListItem)
+        {
+          item.node.attributes.insert(SUBTYPE, CHECKBOX_SUBTYPE);
+          item.node.attributes.insert(CHECKBOX_ATTR, checked);
+          item.node.attributes.remove_key(NUMBER_ATTR);
+        }
+      },
+      Event::FootnoteReference(_) => {},
+    }
+  }
+
+  fn start_tag(&mut self, tag: Tag) {
+    match tag {
+      Tag::Paragraph => {
+        // The first paragraph of a list item is the text of the item itself.
+        let is_item_text = matches!(
+          self.blocks.last(),
+          Some(block) if block.kind == BlockKind::ListItem
+            && block.delta.is_empty()
+            && block.node.children.is_empty()
+        );
+        if !is_item_text {
+          let mut node = NodeData::new(TEXT_NODE_TYPE);
+          if self.quote_depth > 0 {
+            node.attributes.insert(SUBTYPE, QUOTE_SUBTYPE);
+          }
+          self.blocks.push(OpenBlock::new(BlockKind::Text, node));
+        }
+      },
+      Tag::Heading(level, _, _) => {
+        let mut node = NodeData::new(TEXT_NODE_TYPE);
+        node.attributes.insert(SUBTYPE, HEADING_SUBTYPE);
+        node
+          .attributes
+          .insert(HEADING_ATTR, format!("h{}", level as usize));
+        self.blocks.push(OpenBlock::new(BlockKind::Text, node));
+      },
+      Tag::CodeBlock(kind) => {
+        let mut node = NodeData::new(TEXT_NODE_TYPE);
+        node.attributes.insert(SUBTYPE, CODE_BLOCK_SUBTYPE);
+        if let CodeBlockKind::Fenced(language) = kind {
+          if !language.is_empty() {
+            node.attributes.insert(LANGUAGE_ATTR, language.to_string());
+          }
+        }
+        self.blocks.push(OpenBlock::new(BlockKind::Text, node));
+      },
+      Tag::BlockQuote => self.quote_depth += 1,
+      Tag::List(start) => self.lists.push(start),
+      Tag::Item => {
+        let mut node = NodeData::new(TEXT_NODE_TYPE);
+        match self.lists.last_mut() {
+          Some(Some(number)) => {
+            node.attributes.insert(SUBTYPE, NUMBER_LIST_SUBTYPE);
+            node.attributes.insert(NUMBER_ATTR, *number as i64);
+            *number += 1;
+          },
+          _ => node.attributes.insert(SUBTYPE, BULLETED_LIST_SUBTYPE),
+        }
+        self.blocks.push(OpenBlock::new(BlockKind::ListItem, node));
+      },
+      Tag::Emphasis => self.italic += 1,
+      Tag::Strong => self.bold += 1,
+      Tag::Strikethrough => self.strikethrough += 1,
+      Tag::Link(_, dest, _) => self.links.push(dest.to_string()),
+      Tag::Image(_, dest, _) => {
+        self.in_image = true;
+        let mut node = NodeData::new(IMAGE_NODE_TYPE);
+        node.attributes.insert(IMAGE_SRC_ATTR, dest.to_string());
+        self.pending_nodes.push(node);
+      },
+      _ => {},
+    }
+  }
+
+  fn end_tag(&mut self, tag: Tag) {
+    match tag {
+      Tag::Paragraph | Tag::Heading(_, _, _) => {
+        if matches!(self.blocks.last(), Some(block) if block.kind == BlockKind::Text) {
+          self.close_block();
+        }
+      },
+      Tag::CodeBlock(_) => {
+        if let Some(block) = self.blocks.last_mut() {
+          // The content of the code block always ends with a line break.
+          if let Ok(content) = block.delta.content() {
+            let content = content.trim_end_matches('\n').to_owned();
+            block.delta = DeltaTextOperations::default();
+            block.delta.insert(&content, AttributeHashMap::new());
+          }
+        }
+        self.close_block();
+      },
+      Tag::BlockQuote => self.quote_depth = self.quote_depth.saturating_sub(1),
+      Tag::List(_) => {
+        self.lists.pop();
+      },
+      Tag::Item => self.close_block(),
+      Tag::Emphasis => self.italic = self.italic.saturating_sub(1),
+      Tag::Strong => self.bold = self.bold.saturating_sub(1),
+      Tag::Strikethrough => self.strikethrough = self.strikethrough.saturating_sub(1),
+      Tag::Link(_, _, _) => {
+        self.links.pop();
+      },
+      Tag::Image(_, _, _) => self.in_image = false,
+      _ => {},
+    }
+  }
+
+  fn inline_attributes(&self) -> AttributeHashMap {
+    let mut attributes = AttributeHashMap::new();
+    if self.bold > 0 {
+      attributes.insert(BOLD_ATTR, true);
+    }
+    if self.italic > 0 {
+      attributes.insert(ITALIC_ATTR, true);
+    }
+    if self.strikethrough > 0 {
+      attributes.insert(STRIKETHROUGH_ATTR, true);
+    }
+    if let Some(href) = self.links.last() {
+      attributes.insert(HREF_ATTR, href.clone());
+    }
+    attributes
+  }
+
+  fn insert_text(&mut self, text: &str, attributes: AttributeHashMap) {
+    match self.blocks.last_mut() {
+      Some(block) if block.kind != BlockKind::Container => block.delta.insert(text, attributes),
+      _ => {
+        // The inline content outside of any paragraph, for example, the html block, is
+        // decoded as a paragraph.
+        let mut block = OpenBlock::new(BlockKind::Text, NodeData::new(TEXT_NODE_TYPE));
+        block.delta.insert(text.trim_end_matches('\n'), attributes);
+        self.push_node(block.into_node_data());
+      },
+    }
+  }
+
+  fn close_block(&mut self) {
+    if self.blocks.len() <= 1 {
+      return;
+    }
+    if let Some(block) = self.blocks.pop() {
+      self.push_node(block.into_node_data());
+      let pending_nodes = std::mem::take(&mut self.pending_nodes);
+      for node in pending_nodes {
+        self.push_node(node);
+      }
+    }
+  }
+
+  /// Appends the node to the children of the innermost open block.
+  fn push_node(&mut self, node: NodeData) {
+    if let Some(parent) = self.blocks.last_mut() {
+      parent.node.children.push(node);
+    }
+  }
+
+  fn finish(mut self) -> NodeData {
+    while self.blocks.len() > 1 {
+      self.close_block();
+    }
+    let mut editor = self.blocks.pop().unwrap().into_node_data();
+    editor.children.append(&mut self.pending_nodes);
+    if editor.children.is_empty() {
+      editor.children.push(NodeData::new(TEXT_NODE_TYPE));
+    }
+    editor
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::codec::markdown::{markdown_node_decoder, markdown_node_encoder};
+  use crate::core::NodeData;
+
+  fn decode_to_json(markdown: &str) -> String {
+    serde_json::to_string(&markdown_node_decoder(markdown).children).unwrap()
+  }
+
+  #[test]
+  fn markdown_node_decoder_heading_test() {
+    assert_eq!(
+      decode_to_json("# header 1\n## header 2"),
+      r#"[{"type":"text","attributes":{"subtype":"heading","heading":"h1"},"body":{"delta":[{"insert":"header 1"}]}},{"type":"text","attributes":{"subtype":"heading","heading":"h2"},"body":{"delta":[{"insert":"header 2"}]}}]"#
+    );
+  }
+
+  #[test]
+  fn markdown_node_decoder_inline_attributes_test() {
+    assert_eq!(
+      decode_to_json("**bold** _italic_ ~~strike~~ `code` [appflowy](https://www.appflowy.io/)"),
+      r#"[{"type":"text","body":{"delta":[{"insert":"bold","attributes":{"bold":true}},{"insert":" "},{"insert":"italic","attributes":{"italic":true}},{"insert":" "},{"insert":"strike","attributes":{"strikethrough":true}},{"insert":" "},{"insert":"code","attributes":{"code":true}},{"insert":" "},{"insert":"appflowy","attributes":{"href":"https://www.appflowy.io/"}}]}}]"#
+    );
+  }
+
+  #[test]
+  fn markdown_node_decoder_nested_list_test() {
+    assert_eq!(
+      decode_to_json("1. first\n   * nested\n2. second"),
+      r#"[{"type":"text","attributes":{"subtype":"number-list","number":1},"body":{"delta":[{"insert":"first"}]},"children":[{"type":"text","attributes":{"subtype":"bulleted-list"},"body":{"delta":[{"insert":"nested"}]}}]},{"type":"text","attributes":{"subtype":"number-list","number":2},"body":{"delta":[{"insert":"second"}]}}]"#
+    );
+  }
+
+  #[test]
+  fn markdown_node_decoder_task_list_test() {
+    assert_eq!(
+      decode_to_json("- [x] done\n- [ ] todo"),
+      r#"[{"type":"text","attributes":{"subtype":"checkbox","checkbox":true},"body":{"delta":[{"insert":"done"}]}},{"type":"text","attributes":{"subtype":"checkbox","checkbox":false},"body":{"delta":[{"insert":"todo"}]}}]"#
+    );
+  }
+
+  #[test]
+  fn markdown_node_decoder_code_block_test() {
+    assert_eq!(
+      decode_to_json("```rust\nfn main() {}\n```"),
+      r#"[{"type":"text","attributes":{"subtype":"code_block","language":"rust"},"body":{"delta":[{"insert":"fn main() {}"}]}}]"#
+    );
+  }
+
+  #[test]
+  fn markdown_node_decoder_quote_test() {
+    assert_eq!(
+      decode_to_json("> quote block"),
+      r#"[{"type":"text","attributes":{"subtype":"quote"},"body":{"delta":[{"insert":"quote block"}]}}]"#
+    );
+  }
+
+  #[test]
+  fn markdown_node_decoder_empty_test() {
+    let editor = markdown_node_decoder("");
+    assert_eq!(editor.node_type, "editor");
+    assert_eq!(editor.children, vec![NodeData::new("text")]);
+  }
+
+  #[test]
+  fn markdown_node_round_trip_test() {
+    let markdown = "# AppFlowy\n\n**bold** text\n\n* item\n  - [x] done\n\n> quote\n\n---\n\n```rust\nfn main() {}\n```\n";
+    let editor = markdown_node_decoder(markdown);
+    assert_eq!(markdown_node_encoder(&[editor]), markdown);
+  }
+}
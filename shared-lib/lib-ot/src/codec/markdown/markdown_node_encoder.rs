@@ -0,0 +1,252 @@
+use crate::codec::editor_node::*;
+use crate::core::{AttributeHashMap, NodeData};
+
+/// Encodes the nodes of a document into Markdown.
+///
+/// The nodes are the children of the document's root. Container nodes, for example, the
+/// `editor` or `callout` nodes, are flattened and their children are encoded in place.
+pub fn markdown_node_encoder(nodes: &[NodeData]) -> String {
+  join_markdown_blocks(markdown_blocks(nodes))
+}
+
+struct MarkdownBlock {
+  is_list_item: bool,
+  content: String,
+}
+
+impl MarkdownBlock {
+  fn new(is_list_item: bool, content: String) -> Self {
+    Self {
+      is_list_item,
+      content,
+    }
+  }
+}
+
+fn markdown_blocks(nodes: &[NodeData]) -> Vec<MarkdownBlock> {
+  let mut blocks = vec![];
+  let mut number = 0;
+  for node in nodes {
+    let subtype = node_subtype(node);
+    if subtype.as_deref() == Some(NUMBER_LIST_SUBTYPE) {
+      number += 1;
+    } else {
+      number = 0;
+    }
+
+    match node.node_type.as_str() {
+      TEXT_NODE_TYPE => {
+        if let Some(block) = markdown_text_block(node, subtype.as_deref(), number) {
+          blocks.push(block);
+        }
+      },
+      DIVIDER_NODE_TYPE => blocks.push(MarkdownBlock::new(false, "---".to_owned())),
+      IMAGE_NODE_TYPE => {
+        if let Some(src) = attribute_str(&node.attributes, IMAGE_SRC_ATTR) {
+          blocks.push(MarkdownBlock::new(false, format!("![]({})", src)));
+        }
+      },
+      MATH_EQUATION_NODE_TYPE => {
+        if let Some(equation) = attribute_str(&node.attributes, MATH_EQUATION_ATTR) {
+          blocks.push(MarkdownBlock::new(false, format!("$${}$$", equation)));
+        }
+      },
+      _ => blocks.extend(markdown_blocks(&node.children)),
+    }
+  }
+  blocks
+}
+
+fn join_markdown_blocks(blocks: Vec<MarkdownBlock>) -> String {
+  let mut markdown = String::new();
+  let mut is_previous_list_item = false;
+  for (index, block) in blocks.into_iter().enumerate() {
+    if index > 0 {
+      markdown.push('\n');
+      // Consecutive list items stay in the same list, the other blocks are separated by an
+      // empty line.
+      if !(is_previous_list_item && block.is_list_item) {
+        markdown.push('\n');
+      }
+    }
+    markdown.push_str(&block.content);
+    is_previous_list_item = block.is_list_item;
+  }
+
+  if !markdown.is_empty() {
+    markdown.push('\n');
+  }
+  markdown
+}
+
+fn markdown_text_block(
+  node: &NodeData,
+  subtype: Option<&str>,
+  number: usize,
+) -> Option<MarkdownBlock> {
+  if subtype == Some(CODE_BLOCK_SUBTYPE) {
+    let language = attribute_str(&node.attributes, LANGUAGE_ATTR).unwrap_or_default();
+    let content = format!("```{}\n{}\n```", language, node_plain_text(node));
+    return Some(MarkdownBlock::new(false, content));
+  }
+
+  let text = markdown_inline_encoder(node);
+  if text.is_empty() && node.children.is_empty() {
+    return None;
+  }
+
+  let (prefix, is_list_item) = match subtype {
+    Some(HEADING_SUBTYPE) => (format!("{} ", "#".repeat(heading_level(node))), false),
+    Some(BULLETED_LIST_SUBTYPE) => ("* ".to_owned(), true),
+    Some(NUMBER_LIST_SUBTYPE) => (format!("{}. ", number), true),
+    Some(CHECKBOX_SUBTYPE) => {
+      if is_attribute_true(&node.attributes, CHECKBOX_ATTR) {
+        ("- [x] ".to_owned(), true)
+      } else {
+        ("- [ ] ".to_owned(), true)
+      }
+    },
+    Some(QUOTE_SUBTYPE) => ("> ".to_owned(), false),
+    _ => ("".to_owned(), false),
+  };
+
+  // The lines following the first one are indented to stay in the list item. The quote
+  // repeats its prefix on every line.
+  let indent = " ".repeat(prefix.chars().count());
+  let mut lines = vec![];
+  for (index, line) in text.split('\n').enumerate() {
+    if index == 0 || subtype == Some(QUOTE_SUBTYPE) {
+      lines.push(format!("{}{}", prefix, line));
+    } else {
+      lines.push(format!("{}{}", indent, line));
+    }
+  }
+
+  if !node.children.is_empty() {
+    let children = markdown_node_encoder(&node.children);
+    let child_indent = if is_list_item { indent.as_str() } else { "" };
+    if !is_list_item {
+      lines.push("".to_owned());
+    }
+    for line in children.trim_end_matches('\n').split('\n') {
+      if line.is_empty() {
+        lines.push("".to_owned());
+      } else {
+        lines.push(format!("{}{}", child_indent, line));
+      }
+    }
+  }
+
+  Some(MarkdownBlock::new(is_list_item, lines.join("\n")))
+}
+
+/// Encodes the delta of the node into Markdown by wrapping each text span with the markers of
+/// its inline attributes.
+pub fn markdown_inline_encoder(node: &NodeData) -> String {
+  node_delta(node)
+    .ops
+    .iter()
+    .filter(|op| op.is_insert())
+    .map(|op| markdown_inline_span(op.get_data(), &op.get_attributes()))
+    .collect()
+}
+
+fn markdown_inline_span(text: &str, attributes: &AttributeHashMap) -> String {
+  // The Markdown markers can't wrap the leading or trailing whitespaces, so keep the padding
+  // outside of them.
+  let content = text.trim();
+  if content.is_empty() || attributes.is_empty() {
+    return text.to_owned();
+  }
+  let start = text.len() - text.trim_start().len();
+  let end = start + content.len();
+
+  let mut span = content.to_owned();
+  if is_attribute_true(attributes, CODE_ATTR) {
+    span = format!("`{}`", span);
+  }
+  if is_attribute_true(attributes, BOLD_ATTR) {
+    span = format!("**{}**", span);
+  }
+  if is_attribute_true(attributes, ITALIC_ATTR) {
+    span = format!("_{}_", span);
+  }
+  if is_attribute_true(attributes, STRIKETHROUGH_ATTR) {
+    span = format!("~~{}~~", span);
+  }
+  if is_attribute_true(attributes, UNDERLINE_ATTR) {
+    span = format!("<u>{}</u>", span);
+  }
+  if attribute_str(attributes, BACKGROUND_COLOR_ATTR).is_some() {
+    span = format!("<mark>{}</mark>", span);
+  }
+  if let Some(href) = attribute_str(attributes, HREF_ATTR) {
+    span = format!("[{}]({})", span, href);
+  }
+  format!("{}{}{}", &text[..start], span, &text[end..])
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::codec::markdown::markdown_node_encoder;
+  use crate::core::NodeData;
+
+  fn encode(json: &str) -> String {
+    let nodes: Vec<NodeData> = serde_json::from_str(json).unwrap();
+    markdown_node_encoder(&nodes)
+  }
+
+  #[test]
+  fn markdown_node_encoder_heading_test() {
+    let json = r#"[{"type":"text","attributes":{"subtype":"heading","heading":"h1"},"body":{"delta":[{"insert":"header 1"}]}},{"type":"text","attributes":{"subtype":"heading","heading":"h3"},"body":{"delta":[{"insert":"header 3"}]}}]"#;
+    assert_eq!(encode(json), "# header 1\n\n### header 3\n");
+  }
+
+  #[test]
+  fn markdown_node_encoder_inline_attributes_test() {
+    let json = r#"[{"type":"text","body":{"delta":[{"insert":"bold ","attributes":{"bold":true}},{"insert":"italics","attributes":{"italic":true}},{"insert":" "},{"insert":"underlined","attributes":{"underline":true}},{"insert":" "},{"insert":"strike","attributes":{"strikethrough":true}},{"insert":" "},{"insert":"highlighted","attributes":{"backgroundColor":"0x4dffeb3b"}},{"insert":" "},{"insert":"print()","attributes":{"code":true}}]}}]"#;
+    assert_eq!(
+      encode(json),
+      "**bold** _italics_ <u>underlined</u> ~~strike~~ <mark>highlighted</mark> `print()`\n"
+    );
+  }
+
+  #[test]
+  fn markdown_node_encoder_link_test() {
+    let json = r#"[{"type":"text","body":{"delta":[{"insert":"appflowy","attributes":{"href":"https://www.appflowy.io/"}}]}}]"#;
+    assert_eq!(encode(json), "[appflowy](https://www.appflowy.io/)\n");
+  }
+
+  #[test]
+  fn markdown_node_encoder_list_test() {
+    let json = r#"[{"type":"text","body":{"delta":[{"insert":"list"}]}},{"type":"text","attributes":{"subtype":"number-list"},"body":{"delta":[{"insert":"item 1"}]}},{"type":"text","attributes":{"subtype":"number-list"},"body":{"delta":[{"insert":"item 2"}]}},{"type":"text","attributes":{"subtype":"bulleted-list"},"body":{"delta":[{"insert":"item 3"}]}}]"#;
+    assert_eq!(encode(json), "list\n\n1. item 1\n2. item 2\n* item 3\n");
+  }
+
+  #[test]
+  fn markdown_node_encoder_nested_list_test() {
+    let json = r#"[{"type":"text","attributes":{"subtype":"bulleted-list"},"body":{"delta":[{"insert":"parent"}]},"children":[{"type":"text","attributes":{"subtype":"bulleted-list"},"body":{"delta":[{"insert":"child"}]}}]}]"#;
+    assert_eq!(encode(json), "* parent\n  * child\n");
+  }
+
+  #[test]
+  fn markdown_node_encoder_checkbox_test() {
+    let json = r#"[{"type":"text","attributes":{"subtype":"checkbox","checkbox":true},"body":{"delta":[{"insert":"checked"}]}},{"type":"text","attributes":{"subtype":"checkbox","checkbox":false},"body":{"delta":[{"insert":"unchecked"}]}}]"#;
+    assert_eq!(encode(json), "- [x] checked\n- [ ] unchecked\n");
+  }
+
+  #[test]
+  fn markdown_node_encoder_quote_and_code_block_test() {
+    let json = r#"[{"type":"text","attributes":{"subtype":"quote"},"body":{"delta":[{"insert":"this is a quote block"}]}},{"type":"text","attributes":{"subtype":"code_block","language":"rust"},"body":{"delta":[{"insert":"fn main() {}"}]}}]"#;
+    assert_eq!(
+      encode(json),
+      "> this is a quote block\n\n```rust\nfn main() {}\n```\n"
+    );
+  }
+
+  #[test]
+  fn markdown_node_encoder_skip_empty_text_test() {
+    let json = r#"[{"type":"editor","children":[{"type":"text","body":{"delta":[{"insert":"a"}]}},{"type":"text"},{"type":"divider"},{"type":"text","body":{"delta":[{"insert":"b"}]}}]}]"#;
+    assert_eq!(encode(json), "a\n\n---\n\nb\n");
+  }
+}
@@ -1 +1,6 @@
 // pub mod markdown_encoder;
+mod markdown_node_decoder;
+mod markdown_node_encoder;
+
+pub use markdown_node_decoder::*;
+pub use markdown_node_encoder::*;
@@ -1 +1,3 @@
+pub mod editor_node;
+pub mod html;
 pub mod markdown;
@@ -238,12 +238,12 @@ impl TransactionBuilder {
       return self;
     }
 
-    let mut node_id = node_id.unwrap();
-    let mut deleted_nodes = vec![];
-    for _ in 0..length {
-      deleted_nodes.push(self.get_deleted_node_data(node_tree, node_id));
-      node_id = node_tree.following_siblings(node_id).next().unwrap();
-    }
+    // The following siblings start with the node itself.
+    let deleted_nodes = node_tree
+      .following_siblings(node_id.unwrap())
+      .take(length)
+      .map(|node_id| self.get_deleted_node_data(node_tree, node_id))
+      .collect::<Vec<_>>();
 
     self.operations.push_op(NodeOperation::Delete {
       path: path.clone(),
@@ -1,7 +1,9 @@
 use crate::node::script::NodeScript::*;
 use crate::node::script::NodeTest;
 
-use lib_ot::core::{Changeset, NodeData, NodeDataBuilder};
+use lib_ot::core::{
+  Changeset, NodeData, NodeDataBuilder, NodeTree, Transaction, TransactionBuilder,
+};
 
 #[test]
 fn operation_delete_nested_node_test() {
@@ -178,3 +180,35 @@ fn operation_update_node_after_delete_test() {
   ];
   test.run_scripts(scripts);
 }
+
+#[test]
+fn operation_delete_nodes_then_undo_test() {
+  let mut node_tree = NodeTree::default();
+  let nodes = vec![
+    NodeData::new("text_1"),
+    NodeData::new("text_2"),
+    NodeData::new("text_3"),
+  ];
+  let transaction = TransactionBuilder::new()
+    .insert_nodes_at_path(0, nodes.clone())
+    .build();
+  node_tree.apply_transaction(transaction).unwrap();
+
+  let transaction = TransactionBuilder::new()
+    .delete_nodes_at_path(&node_tree, &0.into(), 2)
+    .build();
+  let undo_operations = transaction.operations.inverted();
+  node_tree.apply_transaction(transaction).unwrap();
+  assert_eq!(
+    node_tree.get_node_data_at_path(&0.into()),
+    Some(nodes[2].clone())
+  );
+
+  // The deleted nodes are the two different nodes, so undoing the deletion brings them back.
+  node_tree
+    .apply_transaction(Transaction::from_operations(undo_operations))
+    .unwrap();
+  for (index, node) in nodes.into_iter().enumerate() {
+    assert_eq!(node_tree.get_node_data_at_path(&index.into()), Some(node));
+  }
+}